@@ -0,0 +1,436 @@
+use result::{Result, Ok, Err};
+use parse::{Regexp, Empty, Concat, Alternate, Star, Plus, Quest, Capture,
+            EmptyLook, LookAhead, NegLookAhead, Error, UnsupportedFeature,
+            ProgramTooLarge, look_begin_text, look_end_text, parse};
+use compile::{Prog, InstMatch, InstChar, InstClass, InstAlt, InstSave,
+              InstEmpty, InstLook, nullable, compile};
+use charclass::{contains, next_char, max_char};
+use pike::Machine;
+use backtrack::BitState;
+use visit::children;
+use std::map::{HashMap, Map};
+use std::sort;
+
+// How many times a capture group takes part in a match of the whole
+// pattern, and whether what it matches can be empty.
+struct GroupRepeat {
+    min: uint,
+    // None if there is no bound.
+    max: Option<uint>,
+    can_be_empty: bool
+}
+
+impl GroupRepeat {
+    // Whether every match sets the group, so that templates and
+    // validators can count on its value.
+    fn always_present(&self) -> bool {
+        self.min > 0
+    }
+}
+
+// The bound after repeating any number of times what is repeated at
+// most max times.
+fn unbounded(max: Option<uint>) -> Option<uint> {
+    if max == Some(0) { Some(0) } else { None }
+}
+
+fn repeats(re: @Regexp, min: uint, max: Option<uint>,
+           out: &mut ~[GroupRepeat]) {
+    match *re {
+        Alternate(subs) => {
+            for subs.each |sub| {
+                repeats(*sub, 0, max, out);
+            }
+        }
+        Star(sub, _) => repeats(sub, 0, unbounded(max), out),
+        Plus(sub, _) => repeats(sub, min, unbounded(max), out),
+        Quest(sub, _) => repeats(sub, 0, max, out),
+        Capture(cap, _, sub) => {
+            if out.len() <= cap {
+                let never = GroupRepeat {
+                    min: 0,
+                    max: Some(0),
+                    can_be_empty: true
+                };
+                out.grow(cap + 1 - out.len(), &never);
+            }
+            out[cap] = GroupRepeat {
+                min: min,
+                max: max,
+                can_be_empty: nullable(sub)
+            };
+            repeats(sub, min, max, out);
+        }
+        // Groups inside lookahead assertions never capture.
+        LookAhead(sub) | NegLookAhead(sub) => repeats(sub, 0, Some(0), out),
+        _ => {
+            for children(re).each |sub| {
+                repeats(*sub, min, max, out);
+            }
+        }
+    }
+}
+
+// The repetitions of each capture group of re, indexed by group number,
+// with the whole match as group 0.
+fn group_repeats(re: @Regexp) -> ~[GroupRepeat] {
+    let mut out = ~[GroupRepeat {
+        min: 1,
+        max: Some(1),
+        can_be_empty: nullable(re)
+    }];
+    repeats(re, 1, Some(1), &mut out);
+    return out;
+}
+
+// Like group_repeats, for a pattern.
+fn analyze_groups(s: &str) -> Result<~[GroupRepeat], Error> {
+    match parse(s) {
+        Ok(re) => Ok(group_repeats(re)),
+        Err(e) => Err(e)
+    }
+}
+
+// Where every match of a pattern is pinned to the text, so that callers
+// can tell validation from search, or skip indexing substrings.
+enum Anchoring {
+    Unanchored,
+    // Matches start at the start of the text.
+    AnchoredStart,
+    // Matches end at the end of the text.
+    AnchoredEnd,
+    AnchoredBoth
+}
+
+impl Anchoring: cmp::Eq {
+    pure fn eq(&self, other: &Anchoring) -> bool {
+        *self as uint == *other as uint
+    }
+    pure fn ne(&self, other: &Anchoring) -> bool {
+        !self.eq(other)
+    }
+}
+
+// Whether re matches only the empty string, so that it does not move
+// what follows it from the text boundary it is anchored to.
+fn empty_width(re: @Regexp) -> bool {
+    match *re {
+        Empty | EmptyLook(_) | LookAhead(_) | NegLookAhead(_) => true,
+        Capture(_, _, sub) => empty_width(sub),
+        _ => false
+    }
+}
+
+// Whether every match of re starts at the start of the text, or with
+// at_end set, ends at its end.
+fn anchored(re: @Regexp, at_end: bool) -> bool {
+    match *re {
+        EmptyLook(look) => {
+            look == if at_end { look_end_text } else { look_begin_text }
+        }
+        Concat(subs) => {
+            let n = subs.len();
+            for uint::range(0, n) |i| {
+                let sub = subs[if at_end { n - 1 - i } else { i }];
+                if anchored(sub, at_end) {
+                    return true;
+                }
+                if !empty_width(sub) {
+                    return false;
+                }
+            }
+            false
+        }
+        Alternate(subs) => subs.all(|sub| anchored(*sub, at_end)),
+        Plus(sub, _) | Capture(_, _, sub) => anchored(sub, at_end),
+        _ => false
+    }
+}
+
+// How re is anchored, looking through groups, repetitions and
+// alternations. Multi-line ^ and $ do not count.
+fn anchoring(re: @Regexp) -> Anchoring {
+    match (anchored(re, false), anchored(re, true)) {
+        (true, true) => AnchoredBoth,
+        (true, false) => AnchoredStart,
+        (false, true) => AnchoredEnd,
+        (false, false) => Unanchored
+    }
+}
+
+// Like anchoring, for a pattern.
+fn analyze_anchoring(s: &str) -> Result<Anchoring, Error> {
+    match parse(s) {
+        Ok(re) => Ok(anchoring(re)),
+        Err(e) => Err(e)
+    }
+}
+
+// Whether re matches the empty string, assertions included: \b does not,
+// but ^$ does.
+fn is_match_empty(re: @Regexp) -> bool {
+    let prog = match compile(re, true, false) {
+        Ok(prog) => @prog,
+        Err(_) => return nullable(re)
+    };
+    if prog.lookahead {
+        let b = BitState::new(prog, 0);
+        return b.run([], 0, true, true).is_some();
+    }
+    let m = Machine::new(prog);
+    m.run([], 0, true, true).is_some()
+}
+
+// Building DFAs can take exponentially many states. Past this many,
+// equivalent gives up with ProgramTooLarge.
+const max_dfa_states: uint = 10000;
+
+// Add to set the instructions reached from pc by empty transitions.
+// Assertions are taken to hold.
+fn dfa_closure(prog: &Prog, pc: uint, seen: &mut ~[bool], set: &mut ~[uint]) {
+    if seen[pc] {
+        return;
+    }
+    seen[pc] = true;
+    match prog.inst[pc] {
+        InstAlt(x, y) => {
+            dfa_closure(prog, x, seen, set);
+            dfa_closure(prog, y, seen, set);
+        }
+        InstSave(_, x) | InstEmpty(_, x) | InstLook(_, _, x) => {
+            dfa_closure(prog, x, seen, set);
+        }
+        InstMatch | InstChar(_, _) | InstClass(_, _) => set.push(pc),
+        _ => {}
+    }
+}
+
+// The state a DFA for prog is in after c from the one for set. States
+// are sorted sets of instructions.
+fn dfa_step(prog: &Prog, set: &[uint], c: Option<char>) -> ~[uint] {
+    let mut seen = vec::from_elem(prog.inst.len(), false);
+    let mut next = ~[];
+    match c {
+        None => dfa_closure(prog, prog.start, &mut seen, &mut next),
+        Some(c) => {
+            for set.each |pc| {
+                match prog.inst[*pc] {
+                    InstChar(ch, x) if ch == c => {
+                        dfa_closure(prog, x, &mut seen, &mut next);
+                    }
+                    InstClass(ranges, x) if contains(*ranges, c) => {
+                        dfa_closure(prog, x, &mut seen, &mut next);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    sort::merge_sort(|a, b| *a <= *b, next)
+}
+
+fn dfa_accepts(prog: &Prog, set: &[uint]) -> bool {
+    set.any(|pc| match prog.inst[*pc] { InstMatch => true, _ => false })
+}
+
+// The first chars of the ranges of chars no instruction of progs tells
+// apart, so that DFAs only need a transition for each of them.
+fn dfa_atoms(progs: &[@Prog]) -> ~[char] {
+    let mut points = ~['\x00'];
+    for progs.each |prog| {
+        for prog.inst.each |inst| {
+            let ranges = match *inst {
+                InstChar(c, _) => ~[(c, c)],
+                InstClass(ranges, _) => copy *ranges,
+                _ => ~[]
+            };
+            for ranges.each |r| {
+                let (lo, hi) = *r;
+                points.push(lo);
+                if hi != max_char {
+                    points.push(next_char(hi));
+                }
+            }
+        }
+    }
+    let points = sort::merge_sort(|a, b| *a <= *b, points);
+    let mut atoms = ~[];
+    for points.each |c| {
+        if atoms.is_empty() || atoms.last() != *c {
+            atoms.push(*c);
+        }
+    }
+    return atoms;
+}
+
+fn state_key(sets: &[~[uint]]) -> ~str {
+    str::connect(sets.map(|set| {
+        str::connect(set.map(|pc| pc.to_str()), ",")
+    }), ";")
+}
+
+// Explore the product of the DFAs for progs, calling f with the states
+// of each reachable tuple of them until it returns false. Return false
+// if there are more than max_dfa_states.
+fn each_dfa_state(progs: &[@Prog], f: fn(&[~[uint]]) -> bool) -> bool {
+    let atoms = dfa_atoms(progs);
+    let start = progs.map(|prog| dfa_step(*prog, [], None));
+    let seen: HashMap<~str, ()> = HashMap();
+    seen.insert(state_key(start), ());
+    let mut queue = ~[start];
+    while queue.is_not_empty() {
+        let sets = queue.pop();
+        if !f(sets) {
+            return true;
+        }
+        for atoms.each |c| {
+            let mut next = ~[];
+            for progs.eachi |i, prog| {
+                next.push(dfa_step(*prog, sets[i], Some(*c)));
+            }
+            if next.all(|set| set.is_empty()) {
+                again;
+            }
+            let key = state_key(next);
+            if !seen.contains_key(copy key) {
+                if seen.size() == max_dfa_states {
+                    return false;
+                }
+                seen.insert(key, ());
+                queue.push(next);
+            }
+        }
+    }
+    return true;
+}
+
+fn compile_dfa(re: @Regexp) -> Result<@Prog, Error> {
+    match compile(re, true, false) {
+        Ok(prog) => Ok(@prog),
+        Err(e) => Err(e)
+    }
+}
+
+// Whether re matches no string at all, like [^\x00-\x{10ffff}]. As
+// assertions are taken to hold, patterns only their assertions rule out,
+// like a^, are not found to match nothing.
+fn matches_nothing(re: @Regexp) -> bool {
+    let prog = match compile_dfa(re) {
+        Ok(prog) => prog,
+        Err(_) => return false
+    };
+    let mut accepts = false;
+    let complete = do each_dfa_state([prog]) |sets| {
+        accepts = dfa_accepts(prog, sets[0]);
+        !accepts
+    };
+    complete && !accepts
+}
+
+// Whether a and b match the same strings, for finding duplicate rules.
+// Patterns with assertions are not supported.
+fn equivalent(a: @Regexp, b: @Regexp) -> Result<bool, Error> {
+    let mut progs = ~[];
+    for [a, b].each |re| {
+        match compile_dfa(*re) {
+            Ok(prog) => progs.push(prog),
+            Err(e) => return Err(e)
+        }
+    }
+    for progs.each |prog| {
+        if prog.lookahead ||
+            prog.inst.any(|inst| match *inst {
+                InstEmpty(_, _) => true,
+                _ => false
+            }) {
+            return Err(UnsupportedFeature);
+        }
+    }
+    let mut same = true;
+    let complete = do each_dfa_state(progs) |sets| {
+        same = dfa_accepts(progs[0], sets[0]) == dfa_accepts(progs[1], sets[1]);
+        same
+    };
+    if !complete {
+        return Err(ProgramTooLarge);
+    }
+    return Ok(same);
+}
+
+#[test]
+fn test_group_repeats() {
+    fn test(s: &str, expected: &[(uint, Option<uint>, bool)]) {
+        let found = analyze_groups(s).get().map(|g| {
+            (g.min, g.max, g.can_be_empty)
+        });
+        assert found == vec::from_slice(expected);
+    }
+    test("a", [(1, Some(1), false)]);
+    test("(a)(b)?(c*)", [(1, Some(1), false), (1, Some(1), false),
+                         (0, Some(1), false), (1, Some(1), true)]);
+    test("(a)?b*", [(1, Some(1), true), (0, Some(1), false)]);
+    test("(a|(b))+", [(1, Some(1), false), (1, None, false),
+                      (0, None, false)]);
+    test("x(?:(y)*)?", [(1, Some(1), false), (0, None, false)]);
+    test("(?P<n>a(?=(b)))", [(1, Some(1), false), (1, Some(1), false),
+                             (0, Some(0), false)]);
+    let groups = analyze_groups("(a)|(b)").get();
+    assert groups[0].always_present();
+    assert !groups[1].always_present();
+    assert analyze_groups("(").is_err();
+}
+
+#[test]
+fn test_anchoring() {
+    fn test(s: &str, expected: Anchoring) {
+        assert analyze_anchoring(s).get() == expected;
+    }
+    test("abc", Unanchored);
+    test("^abc", AnchoredStart);
+    test("abc$", AnchoredEnd);
+    test("^abc$", AnchoredBoth);
+    test("(^a|^b)c", AnchoredStart);
+    test("^a|b", Unanchored);
+    test("(?:^)+a(?=b)$", AnchoredBoth);
+    test("(^)?a", Unanchored);
+    test("(?m)^a$", Unanchored);
+    test("\\b^a", AnchoredStart);
+    test("a^", Unanchored);
+}
+
+#[test]
+fn test_emptiness() {
+    fn empty(s: &str) -> bool {
+        is_match_empty(parse(s).get())
+    }
+    assert empty("a*");
+    assert empty("a|");
+    assert empty("^$");
+    assert !empty("a");
+    assert !empty("\\b");
+    assert !empty("(?=a)");
+    fn nothing(s: &str) -> bool {
+        matches_nothing(parse(s).get())
+    }
+    assert nothing("a[^\u0000-\U0010ffff]");
+    assert nothing("(?:[^\u0000-\U0010ffff]b)+");
+    assert !nothing("a|[^\u0000-\U0010ffff]");
+    assert !nothing("");
+    assert !nothing("x*y");
+}
+
+#[test]
+fn test_equivalent() {
+    fn test(a: &str, b: &str) -> bool {
+        equivalent(parse(a).get(), parse(b).get()).get()
+    }
+    assert test("a*a", "a+");
+    assert test("(a|b)*", "[ab]*");
+    assert test("a|ab", "ab|a");
+    assert test("(?i)k", "[kK\u212a]");
+    assert test("[a-c]x|bx", "[a-c]x");
+    assert !test("a*", "a+");
+    assert !test("[a-c]", "[a-d]");
+    assert !test("ab", "ba");
+    assert equivalent(parse("a\\b").get(), parse("a").get()).is_err();
+}
@@ -0,0 +1,362 @@
+use compile::{Prog, InstMatch, InstFail, InstChar, InstClass, InstAlt, InstSave,
+              InstEmpty, InstLook};
+use charclass::contains;
+#[cfg(test)]
+use parse::parse;
+#[cfg(test)]
+use compile::compile;
+#[cfg(test)]
+use pike::Machine;
+
+// The backtracker is used when the program and the input are small enough
+// for a bitmap of (instruction, position) pairs to fit in this many bits.
+const max_backtrack_prog: uint = 500;
+const max_backtrack_bits: uint = 256 * 1024;
+
+// Programs with lookahead assertions, which the backtracker runs much
+// faster than the Pike VM, get a larger budget, of 256 KiB per bitmap.
+const max_lookahead_bits: uint = 8 * 256 * 1024;
+
+// The products below are not computed, so that they cannot overflow for
+// huge inputs.
+fn should_backtrack(prog: &Prog, len: uint) -> bool {
+    let n = prog.inst.len();
+    n <= max_backtrack_prog && len < max_backtrack_bits / n
+}
+
+// Whether a BitState may be made for prog and input of len bytes. Past
+// this, even programs with lookahead assertions are run by the Pike VM.
+fn fits_bitmap(prog: &Prog, len: uint) -> bool {
+    len < max_lookahead_bits / prog.inst.len()
+}
+
+enum Job {
+    // Explore the program from pc at pos.
+    Visit(uint, uint),
+    // Restore a capture slot on the way back.
+    Restore(uint, Option<uint>)
+}
+
+fn has_bit(bits: &[u32], n: uint) -> bool {
+    bits[n / 32] & (1u32 << (n % 32)) != 0
+}
+
+// A backtracker that never explores the same (instruction, position) pair
+// twice, so that it runs in time linear in the size of the bitmap.
+struct BitState {
+    prog: @Prog,
+    mut visited: ~[u32],
+    mut jobs: ~[Job],
+    // The slots of the path being explored, and of the match once found.
+    mut caps: ~[Option<uint>],
+    mut len: uint,
+    // For programs with lookahead assertions, the pairs from which the
+    // program of an assertion is known to match, and those from which it
+    // is known not to. As this does not depend on where the assertion
+    // started, each pair is explored by one assertion only, not again by
+    // the assertions at each following position.
+    mut live: ~[u32],
+    mut dead: ~[u32],
+    // The pairs explored by the assertions being run.
+    mut explored: ~[u32]
+}
+
+impl BitState {
+    static fn new(prog: @Prog, len: uint) -> BitState {
+        assert fits_bitmap(prog, len);
+        let words = prog.inst.len() * (len + 1) / 32 + 1;
+        let look_words = if prog.lookahead { words } else { 0 };
+        BitState {
+            prog: prog,
+            visited: vec::from_elem(words, 0u32),
+            jobs: ~[],
+            caps: vec::from_elem(2 * (prog.ncap + 1), None),
+            len: len,
+            live: vec::from_elem(look_words, 0u32),
+            dead: vec::from_elem(look_words, 0u32),
+            explored: vec::from_elem(look_words, 0u32)
+        }
+    }
+    // Make the state ready for another search, of input of len bytes,
+    // keeping the bitmaps if they are large enough.
+    fn reset(&self, len: uint) {
+        assert fits_bitmap(self.prog, len);
+        let words = self.prog.inst.len() * (len + 1) / 32 + 1;
+        let lookahead = self.prog.lookahead;
+        if self.visited.len() < words {
+            self.visited = vec::from_elem(words, 0u32);
+            if lookahead {
+                self.live = vec::from_elem(words, 0u32);
+                self.dead = vec::from_elem(words, 0u32);
+                self.explored = vec::from_elem(words, 0u32);
+            }
+        } else {
+            // Lookahead assertions leave nothing in explored.
+            for uint::range(0, words) |i| {
+                self.visited[i] = 0;
+                if lookahead {
+                    self.live[i] = 0;
+                    self.dead[i] = 0;
+                }
+            }
+        }
+        self.len = len;
+        self.jobs.truncate(0);
+        for uint::range(0, self.caps.len()) |n| {
+            self.caps[n] = None;
+        }
+    }
+    // The number of the bit for (pc, pos) in the bitmaps.
+    fn index(&self, pc: uint, pos: uint) -> uint {
+        pc * (self.len + 1) + pos
+    }
+    // Mark (pc, pos) as visited, returning whether it already was.
+    fn visit(&self, pc: uint, pos: uint) -> bool {
+        let n = self.index(pc, pos);
+        let bit = 1u32 << (n % 32);
+        if self.visited[n / 32] & bit != 0 {
+            return true;
+        }
+        self.visited[n / 32] |= bit;
+        return false;
+    }
+    // Explore from pc at pos in priority order until a match is found.
+    fn try(&self, input: &[u8], pc: uint, pos: uint, anchor_end: bool)
+        -> bool {
+        self.jobs.push(Visit(pc, pos));
+        while self.jobs.is_not_empty() {
+            let (pc0, pos0) = match self.jobs.pop() {
+                Visit(pc, pos) => (pc, pos),
+                Restore(n, v) => {
+                    self.caps[n] = v;
+                    again;
+                }
+            };
+            let mut pc = pc0;
+            let mut pos = pos0;
+            loop {
+                if self.visit(pc, pos) {
+                    break;
+                }
+                match self.prog.inst[pc] {
+                    InstFail => break,
+                    InstAlt(x, y) => {
+                        self.jobs.push(Visit(y, pos));
+                        pc = x;
+                    }
+                    InstSave(n, x) => {
+                        self.jobs.push(Restore(n, self.caps[n]));
+                        self.caps[n] = Some(pos);
+                        pc = x;
+                    }
+                    InstEmpty(look, x) => {
+                        if !self.prog.look(input, pos, look) {
+                            break;
+                        }
+                        pc = x;
+                    }
+                    InstLook(negated, sub, x) => {
+                        if self.lookahead(input, sub, pos) == negated {
+                            break;
+                        }
+                        pc = x;
+                    }
+                    InstChar(ch, x) => {
+                        match self.prog.char_at(input, pos) {
+                            (Some(c), next) if c == ch => {
+                                pc = x;
+                                pos = next;
+                            }
+                            _ => break
+                        }
+                    }
+                    InstClass(ranges, x) => {
+                        match self.prog.char_at(input, pos) {
+                            (Some(c), next) if contains(*ranges, c) => {
+                                pc = x;
+                                pos = next;
+                            }
+                            _ => break
+                        }
+                    }
+                    InstMatch => {
+                        if anchor_end && pos != self.len {
+                            break;
+                        }
+                        return true;
+                    }
+                }
+            }
+        }
+        return false;
+    }
+    // Whether the lookahead program at pc matches at pos. Groups in it do
+    // not capture.
+    fn lookahead(&self, input: &[u8], pc: uint, pos: uint) -> bool {
+        // The pairs on the way from (pc, pos) to the one being explored,
+        // and every pair explored.
+        let mut trail = ~[];
+        let mut touched = ~[];
+        // The pairs left to explore, with the length of trail when each
+        // was queued.
+        let mut jobs = ~[(pc, pos, 0)];
+        // Set once a pair is cut off for being on trail, as another pair
+        // that led there in vain may still match through it.
+        let mut cyclic = false;
+        let mut found = false;
+        while !found && jobs.is_not_empty() {
+            let (pc0, pos0, len) = jobs.pop();
+            // What was explored since the job was queued failed.
+            while trail.len() > len {
+                let n = trail.pop();
+                if !cyclic {
+                    self.dead[n / 32] |= 1u32 << (n % 32);
+                }
+            }
+            let mut pc = pc0;
+            let mut pos = pos0;
+            loop {
+                let n = self.index(pc, pos);
+                if has_bit(self.live, n) {
+                    found = true;
+                    break;
+                }
+                if has_bit(self.dead, n) {
+                    break;
+                }
+                if has_bit(self.explored, n) {
+                    cyclic = true;
+                    break;
+                }
+                self.explored[n / 32] |= 1u32 << (n % 32);
+                touched.push(n);
+                trail.push(n);
+                match self.prog.inst[pc] {
+                    InstFail => break,
+                    InstAlt(x, y) => {
+                        jobs.push((y, pos, trail.len()));
+                        pc = x;
+                    }
+                    InstSave(_, x) => pc = x,
+                    InstEmpty(look, x) => {
+                        if !self.prog.look(input, pos, look) {
+                            break;
+                        }
+                        pc = x;
+                    }
+                    InstLook(negated, sub, x) => {
+                        if self.lookahead(input, sub, pos) == negated {
+                            break;
+                        }
+                        pc = x;
+                    }
+                    InstChar(ch, x) => {
+                        match self.prog.char_at(input, pos) {
+                            (Some(c), next) if c == ch => {
+                                pc = x;
+                                pos = next;
+                            }
+                            _ => break
+                        }
+                    }
+                    InstClass(ranges, x) => {
+                        match self.prog.char_at(input, pos) {
+                            (Some(c), next) if contains(*ranges, c) => {
+                                pc = x;
+                                pos = next;
+                            }
+                            _ => break
+                        }
+                    }
+                    InstMatch => {
+                        found = true;
+                        break;
+                    }
+                }
+            }
+        }
+        // Every pair on the way to a match matches too, while after a
+        // failure, none of the pairs explored can.
+        if found {
+            for trail.each |n| {
+                self.live[*n / 32] |= 1u32 << (*n % 32);
+            }
+        } else {
+            for touched.each |n| {
+                self.dead[*n / 32] |= 1u32 << (*n % 32);
+            }
+        }
+        for touched.each |n| {
+            self.explored[*n / 32] &= !(1u32 << (*n % 32));
+        }
+        return found;
+    }
+    // Like Machine::run, on a new or reset state.
+    fn run(&self, input: &[u8], start: uint, anchored: bool, anchor_end: bool)
+        -> Option<~[Option<uint>]> {
+        if self.search(input, start, anchored, anchor_end) {
+            Some(copy self.caps)
+        } else {
+            None
+        }
+    }
+    // Like Machine::search, leaving the slots of the match in caps.
+    fn search(&self, input: &[u8], start: uint, anchored: bool,
+              anchor_end: bool) -> bool {
+        let mut pos = start;
+        loop {
+            if self.try(input, self.prog.start, pos, anchor_end) {
+                return true;
+            }
+            if anchored || pos >= input.len() {
+                return false;
+            }
+            let (_, next) = self.prog.char_at(input, pos);
+            pos = next;
+        }
+    }
+}
+
+#[test]
+fn test_backtrack() {
+    fn test(s: &str, t: &str) {
+        let prog = @compile(parse(s).get(), false, false).get();
+        let reused = BitState::new(prog, 0);
+        do str::byte_slice(t) |b| {
+            for uint::range(0, b.len() + 1) |start| {
+                for ~[false, true].each |anchor_end| {
+                    let m = Machine::new(prog);
+                    let expected = m.run(b, start, false, *anchor_end);
+                    let bs = BitState::new(prog, b.len());
+                    assert bs.run(b, start, false, *anchor_end) == expected;
+                    reused.reset(b.len());
+                    assert reused.run(b, start, false, *anchor_end) ==
+                        expected;
+                }
+            }
+        }
+    }
+    test("a", "xaxa");
+    test("(a|ab)(c|bcd)(d*)", "abcd");
+    test("(a*)+b", "aaab");
+    test("(a+|b+)*c", "abbac");
+    test("((a)|b)*", "abab");
+    test("x*", "");
+    test("[a-c]+\\pL", "zabc\u00e9");
+    test("\\b[a-z]*\\b", "ab cd");
+    test("(?=[a-z]*[0-9])[a-z0-9]+", "abc a1b");
+    test("(?:a(?!b))*c", "aabac aac");
+    test("(?:(?=.*x)[a-z])+", "abxab");
+    test("a(?=b(?!c))", "abc abd");
+}
+
+#[test]
+fn test_huge_input() {
+    let prog = compile(parse("a(?=b)").get(), false, false).get();
+    assert !should_backtrack(&prog, uint::max_value);
+    assert !should_backtrack(&prog, uint::max_value / 2);
+    assert should_backtrack(&prog, 100);
+    assert !fits_bitmap(&prog, uint::max_value);
+    assert !fits_bitmap(&prog, max_lookahead_bits / prog.inst.len());
+    assert fits_bitmap(&prog, max_lookahead_bits / prog.inst.len() - 1);
+}
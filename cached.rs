@@ -0,0 +1,104 @@
+use std::map::{HashMap, Map};
+use hash::HashUtil;
+use regex::Regex;
+
+// A cached string and the result of searching it.
+struct Entry {
+    text: ~str,
+    hash: u64,
+    found: Option<(uint, uint)>
+}
+
+// A regex remembering the results of its last searches, for rules run
+// over and over on the same few strings. Results are kept for up to
+// capacity distinct strings; past that, the string cached first is
+// forgotten first.
+struct CachedRegex {
+    re: Regex,
+    capacity: uint,
+    // The index in entries of each cached string, by its hash, so that
+    // looking a string up does not copy it.
+    slots: HashMap<u64, uint>,
+    // Cached strings, used as a ring once full, with the oldest at next.
+    mut entries: ~[Entry],
+    mut next: uint
+}
+
+impl CachedRegex {
+    static fn new(re: Regex, capacity: uint) -> CachedRegex {
+        CachedRegex {
+            re: re,
+            capacity: capacity,
+            slots: HashMap(),
+            entries: ~[],
+            next: 0
+        }
+    }
+    fn find(&self, s: &str) -> Option<(uint, uint)> {
+        let hash = s.hash();
+        match self.slots.find(hash) {
+            Some(i) if str::eq_slice(self.entries[i].text, s) => {
+                return self.entries[i].found;
+            }
+            _ => {}
+        }
+        let found = self.re.find(s);
+        if self.capacity == 0 {
+            return found;
+        }
+        let entry = Entry {text: s.to_str(), hash: hash, found: found};
+        if self.entries.len() < self.capacity {
+            self.slots.insert(hash, self.entries.len());
+            self.entries.push(entry);
+        } else {
+            let i = self.next;
+            // A string with the same hash may have taken the slot.
+            if self.slots.find(self.entries[i].hash) == Some(i) {
+                self.slots.remove(self.entries[i].hash);
+            }
+            self.entries[i] = entry;
+            self.slots.insert(hash, i);
+            self.next = (i + 1) % self.capacity;
+        }
+        return found;
+    }
+    fn is_match(&self, s: &str) -> bool {
+        self.find(s).is_some()
+    }
+    // Number of strings whose results are cached.
+    fn len(&self) -> uint {
+        self.entries.len()
+    }
+    fn clear(&self) {
+        for self.entries.each |entry| {
+            self.slots.remove(entry.hash);
+        }
+        self.entries = ~[];
+        self.next = 0;
+    }
+}
+
+#[test]
+fn test_cached() {
+    fn cached(cr: &CachedRegex, s: &str) -> bool {
+        cr.entries.any(|entry| str::eq_slice(entry.text, s))
+    }
+    let cr = CachedRegex::new(Regex::new("b+").get(), 2);
+    assert cr.find("abbc") == Some((1, 3));
+    assert cr.find("abbc") == Some((1, 3));
+    assert cr.len() == 1;
+    assert !cr.is_match("xyz");
+    assert cr.is_match("b");
+    assert cr.len() == 2;
+    assert cached(&cr, "xyz");
+    assert !cached(&cr, "abbc");
+    assert cr.find("c") == None;
+    assert cached(&cr, "b") && !cached(&cr, "xyz");
+    assert cr.find("b") == Some((0, 1));
+    cr.clear();
+    assert cr.len() == 0;
+    assert cr.is_match("b");
+    let cr = CachedRegex::new(Regex::new("b").get(), 0);
+    assert cr.is_match("b");
+    assert cr.len() == 0;
+}
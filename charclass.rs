@@ -0,0 +1,227 @@
+// Character classes are sorted lists of disjoint, non-adjacent ranges of
+// chars, both ends inclusive.
+
+use std::sort;
+#[cfg(test)]
+use unicode::{fold_table, lower_table, upper_table, title_table};
+
+const max_char: char = '\U0010ffff';
+
+// The chars next to c, skipping surrogates, which are not chars.
+fn next_char(c: char) -> char {
+    if c == '\ud7ff' { '\ue000' } else { (c as uint + 1) as char }
+}
+
+fn prev_char(c: char) -> char {
+    if c == '\ue000' { '\ud7ff' } else { (c as uint - 1) as char }
+}
+
+// Sort and merge ranges into a class.
+fn normalize(ranges: &[(char, char)]) -> ~[(char, char)] {
+    let sorted = sort::merge_sort(|a, b| a.first() <= b.first(), ranges);
+    let mut cc: ~[(char, char)] = ~[];
+    for sorted.each |r| {
+        let (lo, hi) = *r;
+        let n = cc.len();
+        if n > 0 {
+            let (plo, phi) = cc[n-1];
+            if phi == max_char || lo <= next_char(phi) {
+                if hi > phi {
+                    cc[n-1] = (plo, hi);
+                }
+                again;
+            }
+        }
+        cc.push((lo, hi));
+    }
+    return cc;
+}
+
+fn negate(cc: &[(char, char)]) -> ~[(char, char)] {
+    let mut ranges = ~[];
+    let mut lo = '\x00';
+    let mut done = false;
+    for cc.each |r| {
+        let (rlo, rhi) = *r;
+        if rlo > lo {
+            ranges.push((lo, prev_char(rlo)));
+        }
+        if rhi == max_char {
+            done = true;
+            break;
+        }
+        lo = next_char(rhi);
+    }
+    if !done {
+        ranges.push((lo, max_char));
+    }
+    return ranges;
+}
+
+// The ranges of a POSIX class such as [:alpha:], given its name. These
+// are ASCII only, as in the C locale.
+fn posix_class(name: &str) -> Option<~[(char, char)]> {
+    match name {
+        "alnum" => Some(~[('0', '9'), ('A', 'Z'), ('a', 'z')]),
+        "alpha" => Some(~[('A', 'Z'), ('a', 'z')]),
+        "ascii" => Some(~[('\x00', '\x7f')]),
+        "blank" => Some(~[('\t', '\t'), (' ', ' ')]),
+        "cntrl" => Some(~[('\x00', '\x1f'), ('\x7f', '\x7f')]),
+        "digit" => Some(~[('0', '9')]),
+        "graph" => Some(~[('!', '~')]),
+        "lower" => Some(~[('a', 'z')]),
+        "print" => Some(~[(' ', '~')]),
+        "punct" => Some(~[('!', '/'), (':', '@'), ('[', '`'), ('{', '~')]),
+        "space" => Some(~[('\t', '\r'), (' ', ' ')]),
+        "upper" => Some(~[('A', 'Z')]),
+        "word" => Some(~[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')]),
+        "xdigit" => Some(~[('0', '9'), ('A', 'F'), ('a', 'f')]),
+        _ => None
+    }
+}
+
+// Remove from cc all chars above max.
+fn clip(cc: &[(char, char)], max: char) -> ~[(char, char)] {
+    let mut ranges = ~[];
+    for cc.each |r| {
+        let (lo, hi) = *r;
+        if lo > max {
+            break;
+        }
+        ranges.push((lo, if hi > max { max } else { hi }));
+    }
+    return ranges;
+}
+
+fn contains(cc: &[(char, char)], c: char) -> bool {
+    let mut lo = 0;
+    let mut hi = cc.len();
+    while lo < hi {
+        let m = (lo + hi) / 2;
+        let (rlo, rhi) = cc[m];
+        if c < rlo {
+            hi = m;
+        } else if c > rhi {
+            lo = m + 1;
+        } else {
+            return true;
+        }
+    }
+    return false;
+}
+
+// Whether the classes a and b have a char in common.
+fn intersects(a: &[(char, char)], b: &[(char, char)]) -> bool {
+    let mut i = 0;
+    let mut j = 0;
+    while i < a.len() && j < b.len() {
+        let (alo, ahi) = a[i];
+        let (blo, bhi) = b[j];
+        if ahi < blo {
+            i += 1;
+        } else if bhi < alo {
+            j += 1;
+        } else {
+            return true;
+        }
+    }
+    return false;
+}
+
+// The char c is paired with in table, a list of pairs sorted by the
+// first char, or c itself if it is in none.
+fn lookup(table: &[(char, char)], c: char) -> char {
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let m = (lo + hi) / 2;
+        let (from, to) = table[m];
+        if c < from {
+            hi = m;
+        } else if c > from {
+            lo = m + 1;
+        } else {
+            return to;
+        }
+    }
+    return c;
+}
+
+// The next char in the case folding orbit of c, or c itself if it has
+// none. table is unicode::fold_table().
+fn simple_fold(table: &[(char, char)], c: char) -> char {
+    lookup(table, c)
+}
+
+// The simple case mapping of c, or c itself if it has none. table is
+// unicode::upper_table(), lower_table() or title_table(). Being table
+// driven, this does not depend on the locale.
+fn to_case(table: &[(char, char)], c: char) -> char {
+    lookup(table, c)
+}
+
+// Add to cc every char that folds to one of its chars. Unless
+// unicode is set, only ASCII chars are added.
+fn fold(table: &[(char, char)], cc: &[(char, char)], unicode: bool)
+    -> ~[(char, char)] {
+    let mut ranges = vec::from_slice(cc);
+    for table.each |pair| {
+        let (c, _) = *pair;
+        if !contains(cc, c) || (!unicode && c > '\x7f') {
+            again;
+        }
+        let mut d = simple_fold(table, c);
+        while d != c {
+            if unicode || d <= '\x7f' {
+                ranges.push((d, d));
+            }
+            d = simple_fold(table, d);
+        }
+    }
+    return normalize(ranges);
+}
+
+#[test]
+fn test_class() {
+    let cc = normalize(~[('d', 'f'), ('a', 'b'), ('c', 'c'), ('x', 'y')]);
+    assert cc == ~[('a', 'f'), ('x', 'y')];
+    assert contains(cc, 'e');
+    assert !contains(cc, 'g');
+    let cc = negate(cc);
+    assert cc == ~[('\x00', '`'), ('g', 'w'), ('z', max_char)];
+    assert negate(cc) == ~[('a', 'f'), ('x', 'y')];
+    assert negate(~[('\x00', '\ud7ff'), ('\ue000', max_char)]) == ~[];
+    assert next_char('\ud7ff') == '\ue000';
+    assert intersects(~[('a', 'c'), ('x', 'y')], ~[('d', 'e'), ('y', 'z')]);
+    assert !intersects(~[('a', 'c'), ('x', 'y')], ~[('d', 'w')]);
+    assert !intersects(~[], ~[('a', 'z')]);
+}
+
+#[test]
+fn test_fold() {
+    let table = fold_table();
+    assert simple_fold(table, 'A') == 'a';
+    assert simple_fold(table, 'a') == 'A';
+    assert simple_fold(table, '1') == '1';
+    assert fold(table, ~[('k', 'k')], true) ==
+        ~[('K', 'K'), ('k', 'k'), ('\u212a', '\u212a')];
+    assert fold(table, ~[('k', 'k')], false) == ~[('K', 'K'), ('k', 'k')];
+}
+
+#[test]
+fn test_to_case() {
+    let lower = lower_table();
+    let upper = upper_table();
+    let title = title_table();
+    assert to_case(lower, 'A') == 'a';
+    assert to_case(lower, 'a') == 'a';
+    assert to_case(lower, '1') == '1';
+    assert to_case(lower, '\u03a3') == '\u03c3';
+    assert to_case(lower, '\u039c') == '\u03bc';
+    assert to_case(lower, '\u212a') == 'k';
+    assert to_case(upper, '\u03c2') == '\u03a3';
+    assert to_case(upper, '\u00b5') == '\u039c';
+    assert to_case(upper, '\u01c6') == '\u01c4';
+    assert to_case(title, '\u01c6') == '\u01c5';
+    assert to_case(title, 'a') == 'A';
+}
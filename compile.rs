@@ -0,0 +1,696 @@
+use result::{Result, Ok, Err};
+use io::{Reader, Writer, ReaderUtil, WriterUtil};
+use utf8::{decode, decode_last};
+use charclass::max_char;
+use parse::{Regexp, Empty, Literal, Class, Concat, Alternate, Star, Plus, Quest, Capture,
+            EmptyLook, LookAhead, NegLookAhead, Error, ProgramTooLarge,
+            CompileTimeout, UnsupportedFeature, InvalidProgram, past_deadline,
+            is_word_char, look_word_boundary, look_not_word_boundary,
+            look_not_after_word, look_not_before_word, look_begin_text,
+            look_end_text, look_begin_line, look_end_line};
+#[cfg(test)]
+use parse::parse;
+#[cfg(test)]
+use pike::Machine;
+
+enum Inst {
+    InstMatch,
+    InstFail,
+    InstChar(char, uint),
+    InstClass(@~[(char, char)], uint),
+    InstAlt(uint, uint),
+    InstSave(uint, uint),
+    // Go on only if an EmptyLook assertion holds.
+    InstEmpty(uint, uint),
+    // Go on only if the program at the second pc, which ends in its own
+    // InstMatch, matches here, or does not if the first field is set.
+    InstLook(bool, uint, uint)
+}
+
+struct Prog {
+    inst: ~[Inst],
+    start: uint,
+    ncap: uint,
+    anchored: bool,
+    // Whether the input is matched byte by byte instead of as UTF-8.
+    bytes: bool,
+    // Whether the program matches the input backwards, from the end of a
+    // match to its start.
+    reversed: bool,
+    // Whether the program has lookahead assertions, which the backtracker
+    // runs faster than the Pike VM.
+    lookahead: bool
+}
+
+impl Prog {
+    // Heap bytes held by the program, the ranges of its classes included.
+    fn memory_usage(&self) -> uint {
+        let mut size = self.inst.len() * sys::size_of::<Inst>();
+        for self.inst.each |inst| {
+            match *inst {
+                InstClass(ranges, _) => {
+                    size += sys::size_of::<~[(char, char)]>() +
+                        ranges.len() * sys::size_of::<(char, char)>();
+                }
+                _ => {}
+            }
+        }
+        return size;
+    }
+    // The char at pos in input and the position following it, or None at
+    // the end of input.
+    fn char_at(&self, input: &[u8], pos: uint) -> (Option<char>, uint) {
+        if pos >= input.len() {
+            (None, pos)
+        } else if self.bytes {
+            (Some(input[pos] as char), pos + 1)
+        } else {
+            let (c, next) = decode(input, pos);
+            (Some(c), next)
+        }
+    }
+    // The char before pos in input and its position, or None at the
+    // start of input.
+    fn char_before(&self, input: &[u8], pos: uint) -> (Option<char>, uint) {
+        if pos == 0 {
+            (None, pos)
+        } else if self.bytes {
+            (Some(input[pos - 1] as char), pos - 1)
+        } else {
+            let (c, prev) = decode_last(input, pos);
+            (Some(c), prev)
+        }
+    }
+    // The char a search reads at pos and the position it goes on at.
+    fn step(&self, input: &[u8], pos: uint) -> (Option<char>, uint) {
+        if self.reversed {
+            self.char_before(input, pos)
+        } else {
+            self.char_at(input, pos)
+        }
+    }
+    // Whether the EmptyLook assertion look holds at pos in input.
+    fn look(&self, input: &[u8], pos: uint, look: uint) -> bool {
+        let before = match self.char_before(input, pos) {
+            (Some(c), _) => is_word_char(c),
+            (None, _) => false
+        };
+        let after = match self.char_at(input, pos) {
+            (Some(c), _) => is_word_char(c),
+            (None, _) => false
+        };
+        if look == look_word_boundary {
+            before != after
+        } else if look == look_not_word_boundary {
+            before == after
+        } else if look == look_not_after_word {
+            !before
+        } else if look == look_not_before_word {
+            !after
+        } else if look == look_begin_text {
+            pos == 0
+        } else if look == look_end_text {
+            pos == input.len()
+        } else if look == look_begin_line {
+            pos == 0 || input[pos - 1] == '\n' as u8
+        } else if look == look_end_line {
+            pos == input.len() || input[pos] == '\n' as u8
+        } else {
+            fail
+        }
+    }
+    // Whether pos is where a search runs out of input.
+    fn at_end(&self, input: &[u8], pos: uint) -> bool {
+        if self.reversed { pos == 0 } else { pos == input.len() }
+    }
+}
+
+const default_size_limit: uint = 100000;
+
+// A choice between going on at x and at next, preferring x if greedy.
+fn alt(greedy: bool, x: uint, next: uint) -> Inst {
+    if greedy { InstAlt(x, next) } else { InstAlt(next, x) }
+}
+
+// Whether re can match without consuming any input.
+fn nullable(re: @Regexp) -> bool {
+    match *re {
+        Empty | EmptyLook(_) | LookAhead(_) | NegLookAhead(_) | Star(_, _) |
+        Quest(_, _) => true,
+        Literal(_) | Class(_) => false,
+        Concat(subs) => subs.all(|sub| nullable(*sub)),
+        Alternate(subs) => subs.any(|sub| nullable(*sub)),
+        Plus(sub, _) | Capture(_, _, sub) => nullable(sub),
+        _ => fail
+    }
+}
+
+struct Compiler {
+    mut inst: ~[Inst],
+    mut ncap: uint,
+    // Programs of more than size_limit instructions are rejected.
+    mut size_limit: uint,
+    // Compiling fails with CompileTimeout once this time has passed.
+    mut deadline: Option<u64>,
+    mut timed_out: bool,
+    mut lookahead: bool,
+    // Set when re cannot be compiled as asked, such as lookahead in a
+    // reversed program.
+    mut unsupported: bool,
+    // Compile a program matching backwards. Concatenations run from last
+    // to first, and captures record their end before their start.
+    mut reversed: bool
+}
+
+impl Compiler {
+    static fn new() -> Compiler {
+        Compiler {
+            inst: ~[],
+            ncap: 0,
+            size_limit: default_size_limit,
+            deadline: None,
+            timed_out: false,
+            lookahead: false,
+            unsupported: false,
+            reversed: false
+        }
+    }
+    fn emit(&self, i: Inst) -> uint {
+        self.inst.push(i);
+        return self.inst.len() - 1;
+    }
+    // Compile re so that it continues at next, and return its entry point.
+    fn c(&self, re: @Regexp, next: uint) -> uint {
+        if self.timed_out || past_deadline(self.deadline) {
+            self.timed_out = true;
+            return next;
+        }
+        match *re {
+            Empty => next,
+            Literal(c) => self.emit(InstChar(c, next)),
+            Class(ranges) => self.emit(InstClass(@ranges, next)),
+            Concat(subs) => {
+                let mut pc = next;
+                if self.reversed {
+                    for subs.each |sub| {
+                        pc = self.c(*sub, pc);
+                    }
+                    return pc;
+                }
+                let mut i = subs.len();
+                while i > 0 {
+                    i -= 1;
+                    pc = self.c(subs[i], pc);
+                }
+                pc
+            }
+            Alternate(subs) => {
+                let mut i = subs.len() - 1;
+                let mut pc = self.c(subs[i], next);
+                while i > 0 {
+                    i -= 1;
+                    let x = self.c(subs[i], next);
+                    pc = self.emit(InstAlt(x, pc));
+                }
+                pc
+            }
+            // When sub can match empty, an iteration may come back to the
+            // loop without consuming input, where the loop has already been
+            // visited. Compiling x* as (x+)? puts the exit after the
+            // iteration instead, so that the empty iteration is the one
+            // reported, as RE2 does.
+            Star(sub, greedy) if nullable(sub) => {
+                self.c(@Quest(@Plus(sub, greedy), greedy), next)
+            }
+            Star(sub, greedy) => {
+                let pc = self.emit(InstFail);
+                let x = self.c(sub, pc);
+                self.inst[pc] = alt(greedy, x, next);
+                pc
+            }
+            Plus(sub, greedy) => {
+                let pc = self.emit(InstFail);
+                let x = self.c(sub, pc);
+                self.inst[pc] = alt(greedy, x, next);
+                x
+            }
+            Quest(sub, greedy) => {
+                let x = self.c(sub, next);
+                self.emit(alt(greedy, x, next))
+            }
+            EmptyLook(look) => self.emit(InstEmpty(look, next)),
+            LookAhead(sub) | NegLookAhead(sub) => {
+                if self.reversed {
+                    self.unsupported = true;
+                    return next;
+                }
+                let negated = match *re {
+                    NegLookAhead(_) => true,
+                    _ => false
+                };
+                self.lookahead = true;
+                let m = self.emit(InstMatch);
+                let x = self.c(sub, m);
+                self.emit(InstLook(negated, x, next))
+            }
+            Capture(cap, _, sub) => {
+                if cap > self.ncap {
+                    self.ncap = cap;
+                }
+                let (first, last) = if self.reversed {
+                    (2 * cap + 1, 2 * cap)
+                } else {
+                    (2 * cap, 2 * cap + 1)
+                };
+                let x = self.emit(InstSave(last, next));
+                let x = self.c(sub, x);
+                self.emit(InstSave(first, x))
+            }
+            _ => fail
+        }
+    }
+    // If anchored is set, matches are only searched for at the starting
+    // position instead of at every position of the input. If bytes is
+    // set, chars of re stand for bytes.
+    fn compile(&self, re: @Regexp, anchored: bool, bytes: bool)
+        -> Result<Prog, Error> {
+        let pc = self.emit(InstMatch);
+        let start = self.c(@Capture(0, None, re), pc);
+        if self.timed_out {
+            return Err(CompileTimeout);
+        }
+        if self.unsupported {
+            return Err(UnsupportedFeature);
+        }
+        if self.inst.len() > self.size_limit {
+            return Err(ProgramTooLarge);
+        }
+        let mut inst = ~[];
+        inst <-> self.inst;
+        return Ok(Prog {
+            inst: inst,
+            start: start,
+            ncap: self.ncap,
+            anchored: anchored,
+            bytes: bytes,
+            reversed: self.reversed,
+            lookahead: self.lookahead
+        });
+    }
+}
+
+fn compile(re: @Regexp, anchored: bool, bytes: bool) -> Result<Prog, Error> {
+    let c = Compiler::new();
+    c.compile(re, anchored, bytes)
+}
+
+// Compiled programs are encoded as this magic, the format version, and
+// then the fields of the program, with numbers as 64-bit little-endian
+// integers.
+const prog_magic: &static/str = "rree";
+const prog_version: uint = 1;
+
+fn put_uint(w: @Writer, n: uint) {
+    let mut bytes = ~[];
+    for uint::range(0, 8) |i| {
+        bytes.push(((n as u64 >> (8 * i)) & 0xff) as u8);
+    }
+    w.write(bytes);
+}
+
+fn get_uint(r: @Reader) -> Option<uint> {
+    let bytes = r.read_bytes(8);
+    if bytes.len() != 8 {
+        return None;
+    }
+    let mut n = 0u64;
+    for uint::range(0, 8) |i| {
+        n |= (bytes[i] as u64) << (8 * i);
+    }
+    if n > uint::max_value as u64 {
+        return None;
+    }
+    return Some(n as uint);
+}
+
+// A decoded char, which must not be a surrogate or out of range.
+fn get_char(r: @Reader) -> Option<char> {
+    match get_uint(r) {
+        Some(n) if n <= max_char as uint && (n < 0xd800 || n > 0xdfff) => {
+            Some(n as char)
+        }
+        _ => None
+    }
+}
+
+impl Prog {
+    fn encode(&self, w: @Writer) {
+        w.write_str(prog_magic);
+        put_uint(w, prog_version);
+        put_uint(w, self.start);
+        put_uint(w, self.ncap);
+        let flags = [self.anchored, self.bytes, self.reversed, self.lookahead];
+        for flags.each |b| {
+            put_uint(w, if *b { 1 } else { 0 });
+        }
+        put_uint(w, self.inst.len());
+        for self.inst.each |inst| {
+            match *inst {
+                InstMatch => put_uint(w, 0),
+                InstFail => put_uint(w, 1),
+                InstChar(c, x) => {
+                    put_uint(w, 2);
+                    put_uint(w, c as uint);
+                    put_uint(w, x);
+                }
+                InstClass(ranges, x) => {
+                    put_uint(w, 3);
+                    put_uint(w, ranges.len());
+                    for ranges.each |r| {
+                        let (lo, hi) = *r;
+                        put_uint(w, lo as uint);
+                        put_uint(w, hi as uint);
+                    }
+                    put_uint(w, x);
+                }
+                InstAlt(x, y) => {
+                    put_uint(w, 4);
+                    put_uint(w, x);
+                    put_uint(w, y);
+                }
+                InstSave(n, x) => {
+                    put_uint(w, 5);
+                    put_uint(w, n);
+                    put_uint(w, x);
+                }
+                InstEmpty(look, x) => {
+                    put_uint(w, 6);
+                    put_uint(w, look);
+                    put_uint(w, x);
+                }
+                InstLook(negated, sub, x) => {
+                    put_uint(w, 7);
+                    put_uint(w, if negated { 1 } else { 0 });
+                    put_uint(w, sub);
+                    put_uint(w, x);
+                }
+            }
+        }
+    }
+    // Decode a program written by encode, failing with InvalidProgram
+    // unless it is well formed: every pc and capture slot in range,
+    // classes sorted, the lookahead flag set exactly if there are
+    // lookahead assertions, and none of them reachable from its own
+    // program, which the engines would run within itself without end.
+    static fn decode(r: @Reader) -> Result<Prog, Error> {
+        match decode_prog(r) {
+            Some(prog) => Ok(prog),
+            None => Err(InvalidProgram)
+        }
+    }
+}
+
+fn decode_prog(r: @Reader) -> Option<Prog> {
+    if r.read_bytes(prog_magic.len()) != str::to_bytes(prog_magic) {
+        return None;
+    }
+    if get_uint(r) != Some(prog_version) {
+        return None;
+    }
+    let start = match get_uint(r) { Some(n) => n, None => return None };
+    let ncap = match get_uint(r) { Some(n) => n, None => return None };
+    let mut flags = ~[];
+    for 4.times {
+        match get_uint(r) {
+            Some(0) => flags.push(false),
+            Some(1) => flags.push(true),
+            _ => return None
+        }
+    }
+    let n = match get_uint(r) { Some(n) => n, None => return None };
+    if start >= n || ncap >= uint::max_value / 2 || ncap > n {
+        return None;
+    }
+    let nslot = 2 * (ncap + 1);
+    let mut inst = ~[];
+    while inst.len() < n {
+        let tag = match get_uint(r) { Some(tag) => tag, None => return None };
+        let i = match tag {
+            0 => InstMatch,
+            1 => InstFail,
+            2 => {
+                let c = match get_char(r) { Some(c) => c, None => return None };
+                match get_uint(r) {
+                    Some(x) if x < n => InstChar(c, x),
+                    _ => return None
+                }
+            }
+            3 => {
+                let len = match get_uint(r) {
+                    Some(len) => len,
+                    None => return None
+                };
+                let mut ranges = ~[];
+                let mut prev = None;
+                for len.times {
+                    let (lo, hi) = match (get_char(r), get_char(r)) {
+                        (Some(lo), Some(hi)) => (lo, hi),
+                        _ => return None
+                    };
+                    let sorted = match prev {
+                        Some(p) => p < lo,
+                        None => true
+                    };
+                    if lo > hi || !sorted {
+                        return None;
+                    }
+                    ranges.push((lo, hi));
+                    prev = Some(hi);
+                }
+                match get_uint(r) {
+                    Some(x) if x < n => InstClass(@ranges, x),
+                    _ => return None
+                }
+            }
+            4 => {
+                match (get_uint(r), get_uint(r)) {
+                    (Some(x), Some(y)) if x < n && y < n => InstAlt(x, y),
+                    _ => return None
+                }
+            }
+            5 => {
+                match (get_uint(r), get_uint(r)) {
+                    (Some(slot), Some(x)) if slot < nslot && x < n => {
+                        InstSave(slot, x)
+                    }
+                    _ => return None
+                }
+            }
+            6 => {
+                match (get_uint(r), get_uint(r)) {
+                    (Some(look), Some(x))
+                        if look >= look_word_boundary &&
+                           look <= look_end_line && x < n => {
+                        InstEmpty(look, x)
+                    }
+                    _ => return None
+                }
+            }
+            7 => {
+                match (get_uint(r), get_uint(r), get_uint(r)) {
+                    (Some(negated), Some(sub), Some(x))
+                        if negated <= 1 && sub < n && x < n => {
+                        InstLook(negated == 1, sub, x)
+                    }
+                    _ => return None
+                }
+            }
+            _ => return None
+        };
+        inst.push(i);
+    }
+    let comp = components(inst);
+    let mut lookahead = false;
+    for inst.eachi |pc, i| {
+        match *i {
+            InstLook(_, sub, _) => {
+                if comp[sub] == comp[pc] {
+                    return None;
+                }
+                lookahead = true;
+            }
+            _ => {}
+        }
+    }
+    if lookahead != flags[3] {
+        return None;
+    }
+    return Some(Prog {
+        inst: inst,
+        start: start,
+        ncap: ncap,
+        anchored: flags[0],
+        bytes: flags[1],
+        reversed: flags[2],
+        lookahead: flags[3]
+    });
+}
+
+// The pcs an instruction goes on at, and for a lookahead assertion, the
+// start of its program.
+fn successors(inst: &Inst) -> ~[uint] {
+    match *inst {
+        InstMatch | InstFail => ~[],
+        InstChar(_, x) => ~[x],
+        InstClass(_, x) => ~[x],
+        InstAlt(x, y) => ~[x, y],
+        InstSave(_, x) => ~[x],
+        InstEmpty(_, x) => ~[x],
+        InstLook(_, sub, x) => ~[sub, x]
+    }
+}
+
+// The strongly connected components of the graph of successors of inst,
+// as the number of the component of each pc, found by Tarjan's algorithm
+// without recursion, so that long programs do not overflow the stack.
+fn components(inst: &[Inst]) -> ~[uint] {
+    let n = inst.len();
+    let unseen = uint::max_value;
+    let mut index = vec::from_elem(n, unseen);
+    let mut low = vec::from_elem(n, 0u);
+    let mut comp = vec::from_elem(n, unseen);
+    let mut on_stack = vec::from_elem(n, false);
+    let mut stack = ~[];
+    let mut count = 0;
+    let mut ncomp = 0;
+    for uint::range(0, n) |root| {
+        if index[root] != unseen {
+            again;
+        }
+        // The path followed from root, as each pc on it and the number
+        // of its successors visited so far.
+        let mut path = ~[(root, 0u)];
+        index[root] = count;
+        low[root] = count;
+        count += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        while path.is_not_empty() {
+            let (pc, k) = path.last();
+            let next = successors(&inst[pc]);
+            if k < next.len() {
+                path[path.len() - 1] = (pc, k + 1);
+                let x = next[k];
+                if index[x] == unseen {
+                    index[x] = count;
+                    low[x] = count;
+                    count += 1;
+                    stack.push(x);
+                    on_stack[x] = true;
+                    path.push((x, 0));
+                } else if on_stack[x] && index[x] < low[pc] {
+                    low[pc] = index[x];
+                }
+                again;
+            }
+            path.pop();
+            if path.is_not_empty() {
+                let (parent, _) = path.last();
+                if low[pc] < low[parent] {
+                    low[parent] = low[pc];
+                }
+            }
+            if low[pc] == index[pc] {
+                loop {
+                    let x = stack.pop();
+                    on_stack[x] = false;
+                    comp[x] = ncomp;
+                    if x == pc {
+                        break;
+                    }
+                }
+                ncomp += 1;
+            }
+        }
+    }
+    return comp;
+}
+
+#[test]
+fn test_encode() {
+    fn encode(prog: &Prog) -> ~[u8] {
+        io::with_bytes_writer(|w| prog.encode(w))
+    }
+    fn decode(bytes: &[u8]) -> Result<Prog, Error> {
+        io::with_bytes_reader(bytes, |r| Prog::decode(r))
+    }
+    let patterns = ["", "a(b|c)*d", "(?i)k[^x]\\bz$", "a(?!b)+?",
+                    "(?P<n>\\pL)?", "(?=a(?!b))*[a-c]"];
+    let inputs = ["", "abd", "xacbd", "K\u212a z", "aab", "ab ac",
+                  "\u00e9t\u00e9", "cab"];
+    for patterns.each |s| {
+        let prog = compile(parse(*s).get(), false, false).get();
+        let bytes = encode(&prog);
+        let decoded = decode(bytes).get();
+        assert decoded.inst.len() == prog.inst.len();
+        assert decoded.start == prog.start;
+        assert decoded.ncap == prog.ncap;
+        assert decoded.lookahead == prog.lookahead;
+        assert encode(&decoded) == bytes;
+        // The decoded program finds the same matches.
+        let (prog, decoded) = (@prog, @decoded);
+        for inputs.each |input| {
+            let b = str::to_bytes(*input);
+            let mut start = 0;
+            loop {
+                assert Machine::new(decoded).run(b, start, false, false) ==
+                    Machine::new(prog).run(b, start, false, false);
+                if start == b.len() {
+                    break;
+                }
+                start = str::char_range_at(*input, start).next;
+            }
+        }
+    }
+    let prog = compile(parse("ab").get(), true, true).get();
+    let bytes = encode(&prog);
+    let decoded = decode(bytes).get();
+    assert decoded.anchored && decoded.bytes && !decoded.reversed;
+    assert decode([]).is_err();
+    assert decode(vec::slice(bytes, 0, bytes.len() - 1)).is_err();
+    let mut bad = copy bytes;
+    bad[0] = 'x' as u8;
+    assert decode(bad).is_err();
+    // A start pc past the end of the program.
+    let mut bad = copy bytes;
+    bad[12] = 0xff;
+    assert decode(bad).is_err();
+    // Lookahead assertions, and only they, need the lookahead flag, which
+    // starts at byte 52.
+    let mut bad = encode(&compile(parse("a(?=b)").get(), false,
+                                  false).get());
+    assert bad[52] == 1;
+    bad[52] = 0;
+    assert decode(bad).is_err();
+    let mut bad = encode(&compile(parse("ab").get(), false, false).get());
+    bad[52] = 1;
+    assert decode(bad).is_err();
+    // An assertion whose program runs the assertion again, directly or
+    // through another one.
+    fn looks(inst: ~[Inst]) -> ~[u8] {
+        encode(&Prog {
+            inst: inst,
+            start: 0,
+            ncap: 0,
+            anchored: false,
+            bytes: false,
+            reversed: false,
+            lookahead: true
+        })
+    }
+    assert decode(looks(~[InstLook(false, 0, 1), InstMatch])).is_err();
+    assert decode(looks(~[InstLook(false, 2, 1), InstMatch,
+                          InstLook(true, 3, 1), InstAlt(0, 1)])).is_err();
+    assert decode(looks(~[InstLook(false, 2, 1), InstMatch,
+                          InstLook(true, 3, 1), InstChar('a', 1)])).is_ok();
+}
@@ -0,0 +1,199 @@
+use result::{Result, Ok, Err};
+use parse::{Regexp, Literal, Concat, Alternate, Star, Plus, Quest, Capture, Error,
+            parse};
+
+// A structural difference between two patterns. Paths are indices of
+// children from the root of the new pattern's tree, except that the last
+// index of Removed is the child's position in the old pattern.
+enum Diff {
+    // A literal char changed.
+    LiteralChanged(~[uint], char, char),
+    // A repetition operator changed, e.g. from * to +?.
+    QuantifierChanged(~[uint], ~str, ~str),
+    // A branch of an alternation, or an item of a concatenation, was added.
+    Added(~[uint], @Regexp),
+    Removed(~[uint], @Regexp),
+    // Anything else that differs.
+    Replaced(~[uint], @Regexp, @Regexp)
+}
+
+fn path_to_str(path: &[uint]) -> ~str {
+    if path.is_empty() {
+        return ~"root";
+    }
+    str::connect(path.map(|i| i.to_str()), ".")
+}
+
+impl Diff {
+    fn to_str(&self) -> ~str {
+        match *self {
+            LiteralChanged(path, old, new) => {
+                fmt!("%s: literal %s changed to %s", path_to_str(path),
+                     Literal(old).to_str(), Literal(new).to_str())
+            }
+            QuantifierChanged(path, old, new) => {
+                fmt!("%s: quantifier %s changed to %s", path_to_str(path),
+                     old, new)
+            }
+            Added(path, re) => {
+                fmt!("%s: added %s", path_to_str(path), re.to_str())
+            }
+            Removed(path, re) => {
+                fmt!("%s: removed %s", path_to_str(path), re.to_str())
+            }
+            Replaced(path, old, new) => {
+                fmt!("%s: %s replaced by %s", path_to_str(path),
+                     old.to_str(), new.to_str())
+            }
+        }
+    }
+}
+
+// The operator and operand of a repetition.
+fn quantifier(re: @Regexp) -> Option<(~str, @Regexp)> {
+    let (op, sub, greedy) = match *re {
+        Star(sub, greedy) => (~"*", sub, greedy),
+        Plus(sub, greedy) => (~"+", sub, greedy),
+        Quest(sub, greedy) => (~"?", sub, greedy),
+        _ => return None
+    };
+    Some((if greedy { op } else { op + ~"?" }, sub))
+}
+
+// The index pairs of a longest common subsequence of a and b.
+fn common(a: &[~str], b: &[~str]) -> ~[(uint, uint)] {
+    let n = a.len();
+    let m = b.len();
+    // len[i * (m + 1) + j] is the length for a[i..] and b[j..].
+    let mut len = vec::from_elem((n + 1) * (m + 1), 0u);
+    let mut i = n;
+    while i > 0 {
+        i -= 1;
+        let mut j = m;
+        while j > 0 {
+            j -= 1;
+            len[i * (m + 1) + j] = if a[i] == b[j] {
+                len[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                uint::max(len[(i + 1) * (m + 1) + j], len[i * (m + 1) + j + 1])
+            };
+        }
+    }
+    let mut pairs = ~[];
+    let mut i = 0;
+    let mut j = 0;
+    while i < n && j < m {
+        if a[i] == b[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if len[(i + 1) * (m + 1) + j] >= len[i * (m + 1) + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    return pairs;
+}
+
+// Compare the children of a concatenation or alternation. Children equal
+// on both sides are lined up; between them, children are compared in
+// place when there are as many on both sides, and are otherwise reported
+// as removed and added.
+fn diff_subs(old: &[@Regexp], new: &[@Regexp], path: &[uint],
+             diffs: &mut ~[Diff]) {
+    let keys_old = old.map(|sub| sub.to_str());
+    let keys_new = new.map(|sub| sub.to_str());
+    let pairs = common(keys_old, keys_new) + ~[(old.len(), new.len())];
+    let mut i = 0;
+    let mut j = 0;
+    for pairs.each |pair| {
+        let (pi, pj) = *pair;
+        if pi - i == pj - j {
+            while i < pi {
+                diff_regexp(old[i], new[j], path + ~[j], diffs);
+                i += 1;
+                j += 1;
+            }
+        } else {
+            while i < pi {
+                diffs.push(Removed(path + ~[i], old[i]));
+                i += 1;
+            }
+            while j < pj {
+                diffs.push(Added(path + ~[j], new[j]));
+                j += 1;
+            }
+        }
+        i += 1;
+        j += 1;
+    }
+}
+
+fn diff_regexp(old: @Regexp, new: @Regexp, path: &[uint],
+               diffs: &mut ~[Diff]) {
+    if old.to_str() == new.to_str() {
+        return;
+    }
+    match (quantifier(old), quantifier(new)) {
+        (Some((op_old, sub_old)), Some((op_new, sub_new))) => {
+            if op_old != op_new {
+                diffs.push(QuantifierChanged(vec::from_slice(path), op_old,
+                                             op_new));
+            }
+            diff_regexp(sub_old, sub_new, path + ~[0], diffs);
+            return;
+        }
+        _ => {}
+    }
+    match (*old, *new) {
+        (Literal(a), Literal(b)) => {
+            diffs.push(LiteralChanged(vec::from_slice(path), a, b));
+        }
+        (Concat(a), Concat(b)) | (Alternate(a), Alternate(b)) => {
+            diff_subs(a, b, path, diffs);
+        }
+        (Capture(_, a, sub_old), Capture(_, b, sub_new)) if a == b => {
+            diff_regexp(sub_old, sub_new, path + ~[0], diffs);
+        }
+        _ => {
+            diffs.push(Replaced(vec::from_slice(path), old, new));
+        }
+    }
+}
+
+// The structural differences between two patterns, for tools reviewing
+// changes to them. Equal patterns have none.
+fn diff(old: &str, new: &str) -> Result<~[Diff], Error> {
+    let old = match parse(old) {
+        Ok(re) => re,
+        Err(e) => return Err(e)
+    };
+    let new = match parse(new) {
+        Ok(re) => re,
+        Err(e) => return Err(e)
+    };
+    let mut diffs = ~[];
+    diff_regexp(old, new, ~[], &mut diffs);
+    return Ok(diffs);
+}
+
+#[test]
+fn test_diff() {
+    fn test(old: &str, new: &str, expected: &[&str]) {
+        let diffs = diff(old, new).get().map(|d| d.to_str());
+        assert diffs == expected.map(|s| s.to_str());
+    }
+    test("a(b|c)*", "a(b|c)*", []);
+    test("abc", "abd", ["2: literal c changed to d"]);
+    test("abc", "abxc", ["2: added x"]);
+    test("axbc", "abc", ["1: removed x"]);
+    test("foo|bar", "foo|bar|baz", ["2: added baz"]);
+    test("x(foo|bar|baz)y", "x(foo|baz)y", ["1.0.1: removed bar"]);
+    test("a*b", "a+b", ["0: quantifier * changed to +"]);
+    test("a*b", "a*?b", ["0: quantifier * changed to *?"]);
+    test("(a*)?", "(b*)+", ["root: quantifier ? changed to +",
+                            "0.0.0: literal a changed to b"]);
+    test("x[a-c]", "x[a-d]", ["1: [a-c] replaced by [a-d]"]);
+    assert diff("(", "a").is_err();
+}
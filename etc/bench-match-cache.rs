@@ -0,0 +1,65 @@
+// Compares is_match with is_match_with over a million lines, and a loop
+// of find_at with each_match over a long text, to show the cost of
+// allocating scratch space for each search.
+//
+//     rustc -O -L . etc/bench-match-cache.rs && ./bench-match-cache
+
+extern mod std;
+extern mod rree;
+
+use std::time::precise_time_ns;
+use rree::regex::Regex;
+
+fn time(name: &str, f: fn()) {
+    let start = precise_time_ns();
+    f();
+    let ns = precise_time_ns() - start;
+    io::println(fmt!("%s: %? ms", name, ns / 1000000));
+}
+
+fn main() {
+    let re = Regex::new("[0-9]+-[0-9]+ (ERROR|WARN)").get();
+    let lines = [~"2024-01 INFO started", ~"2024-02 WARN disk low",
+                 ~"no timestamp here", ~"2024-03 ERROR failed"];
+    let n = 1000000;
+    let mut found = 0;
+    do time("is_match") {
+        for uint::range(0, n) |i| {
+            if re.is_match(lines[i % lines.len()]) {
+                found += 1;
+            }
+        }
+    }
+    let cache = re.cache();
+    do time("is_match_with") {
+        for uint::range(0, n) |i| {
+            if re.is_match_with(&cache, lines[i % lines.len()]) {
+                found += 1;
+            }
+        }
+    }
+    assert found == n;
+    let text = do vec::from_fn(n / 10) |i| {
+        copy lines[i % lines.len()]
+    };
+    let text = str::connect(text, "\n");
+    let mut matches = 0;
+    do time("find_at") {
+        let mut pos = 0;
+        loop {
+            match re.find_at(text, pos) {
+                Some((_, end)) => {
+                    matches += 1;
+                    pos = end;
+                }
+                None => break
+            }
+        }
+    }
+    do time("each_match") {
+        for re.each_match(text) |_start, _end| {
+            matches += 1;
+        }
+    }
+    assert matches == n / 10;
+}
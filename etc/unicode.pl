@@ -0,0 +1,129 @@
+#!/usr/bin/env perl
+# Generate unicode.rs from the Unicode database shipped with perl:
+#
+#     perl etc/unicode.pl > unicode.rs
+
+use strict;
+use warnings;
+use Unicode::UCD qw(prop_invlist prop_invmap prop_values prop_value_aliases
+                    all_casefolds);
+
+sub char_lit {
+    my $c = shift;
+    return $c > 0xffff ? sprintf("'\\U%08x'", $c) : sprintf("'\\u%04x'", $c);
+}
+
+# Ranges of an inversion list, without surrogates, which are not chars.
+sub ranges {
+    my @list = @_;
+    push @list, 0x110000 if @list % 2;
+    my @ranges;
+    while (my ($lo, $hi) = splice(@list, 0, 2)) {
+        $hi -= 1;
+        if ($lo < 0xd800 && $hi >= 0xd800) {
+            push @ranges, [$lo, 0xd7ff];
+            $lo = 0xe000;
+        } elsif ($lo >= 0xd800 && $lo <= 0xdfff) {
+            $lo = 0xe000;
+        }
+        push @ranges, [$lo, $hi] if $lo <= $hi;
+    }
+    return @ranges;
+}
+
+sub emit_table {
+    my ($fn, @ranges) = @_;
+    print "fn $fn() -> ~[(char, char)] {\n    ~[";
+    my $i = 0;
+    for my $r (@ranges) {
+        print $i % 3 ? " " : "\n        ";
+        print "(", char_lit($r->[0]), ", ", char_lit($r->[1]), ")";
+        print "," if $i < $#ranges;
+        $i++;
+    }
+    print "\n    ]\n}\n\n";
+}
+
+my (@version) = split /\./, Unicode::UCD::UnicodeVersion();
+print "// Generated by etc/unicode.pl. Do not edit.\n\n";
+printf "const unicode_version: (uint, uint, uint) = (%d, %d, %d);\n\n", @version;
+
+my @categories;
+for my $gc (sort(prop_values("General_Category"))) {
+    # Unassigned code points and surrogates are left out, and with them
+    # LC, which is only an alias.
+    next if $gc eq "Cn" || $gc eq "Cs" || $gc eq "LC";
+    my @list;
+    if ($gc eq "C") {
+        @list = map { ranges(prop_invlist("General_Category=$_")) }
+                    ("Cc", "Cf", "Co");
+        @list = sort { $a->[0] <=> $b->[0] } @list;
+    } else {
+        @list = ranges(prop_invlist("General_Category=$gc"));
+    }
+    emit_table("category_" . lc($gc), @list);
+    push @categories, $gc;
+}
+
+my @scripts;
+for my $sc (sort(prop_values("Script"))) {
+    my (undef, $name) = prop_value_aliases("Script", $sc);
+    next if !defined($name) || $name eq "Unknown";
+    my @list = prop_invlist("Script=$sc");
+    emit_table("script_" . lc($name), ranges(@list));
+    push @scripts, $name;
+}
+
+print "// Ranges of a general category, such as L or Lu, or of a script,\n";
+print "// such as Greek.\n";
+print "fn table(name: &str) -> Option<~[(char, char)]> {\n";
+print "    match name {\n";
+for my $gc (@categories) {
+    printf "        \"%s\" => Some(category_%s()),\n", $gc, lc($gc);
+}
+for my $name (@scripts) {
+    printf "        \"%s\" => Some(script_%s()),\n", $name, lc($name);
+}
+print "        _ => None\n    }\n}\n\n";
+
+# Simple case folding orbits: each char is paired with the next larger
+# char folding to the same char, and the largest with the smallest.
+my %orbits;
+my $folds = all_casefolds();
+for my $code (keys %$folds) {
+    my $fold = $folds->{$code};
+    next unless $fold->{status} eq "C" || $fold->{status} eq "S";
+    my $c = hex($fold->{code});
+    my $t = hex($fold->{simple} || $fold->{mapping});
+    $orbits{$t}{$c} = 1;
+    $orbits{$t}{$t} = 1;
+}
+my @pairs;
+for my $t (keys %orbits) {
+    my @orbit = sort { $a <=> $b } keys %{$orbits{$t}};
+    for my $i (0..$#orbit) {
+        push @pairs, [$orbit[$i], $orbit[($i + 1) % @orbit]];
+    }
+}
+@pairs = sort { $a->[0] <=> $b->[0] } @pairs;
+print "// Pairs of each char and the next char in its case folding orbit,\n";
+print "// sorted by the first.\n";
+emit_table("fold_table", @pairs);
+
+# Simple case mappings, fields 12 to 14 of UnicodeData.txt, which perl
+# gives as maps of ranges, each char in a range mapped to the same offset
+# from the map of its start. A map of 0 leaves the chars as they are.
+for my $case ("upper", "lower", "title") {
+    my ($list, $map) = prop_invmap("Simple_\u${case}case_Mapping");
+    my @pairs;
+    for my $i (0..$#$list) {
+        next unless $map->[$i];
+        my $end = $i < $#$list ? $list->[$i + 1] : 0x110000;
+        for my $c ($list->[$i]..$end - 1) {
+            push @pairs, [$c, $map->[$i] + $c - $list->[$i]];
+        }
+    }
+    print "// Pairs of each char with a simple ${case}case mapping and ",
+          "the char\n// it maps to, sorted by the first.\n";
+    emit_table("${case}_table", @pairs);
+}
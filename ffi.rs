@@ -0,0 +1,161 @@
+use libc::{c_char, c_int, c_void, size_t};
+use regex::Regex;
+
+// A C interface to regexes. In C terms, it is:
+//
+//     typedef struct rree_regex rree_regex;
+//
+//     #define RREE_OK 0
+//     #define RREE_ERROR_NULL 1      /* a null pointer was given */
+//     #define RREE_ERROR_UTF8 2      /* a string is not valid UTF-8 */
+//     #define RREE_ERROR_PATTERN 3   /* the pattern is invalid */
+//
+//     rree_regex *rree_compile(const char *pattern, int *error);
+//     int rree_match(const rree_regex *re, const char *text);
+//     size_t rree_captures_len(const rree_regex *re);
+//     int rree_captures(const rree_regex *re, const char *text,
+//                       size_t *spans, size_t nspans);
+//     void rree_free(rree_regex *re);
+//
+// Strings are NUL-terminated UTF-8. rree_compile returns a regex owned
+// by the caller, to be given back to rree_free exactly once, or null with
+// *error set, if error is not null. rree_match returns 1 if text matches,
+// 0 if not and a negative error code on bad arguments. rree_captures
+// writes the byte offsets of the groups of the leftmost match to spans,
+// start and end for each group in order, with (size_t)-1 for groups that
+// did not match, and at most nspans values. It returns 1 if text matches,
+// 0 if not, and a negative error code. Nothing here is retained from
+// strings passed in.
+//
+// Regexes hold managed boxes, so a regex may only be used by the thread
+// that compiled it.
+
+const rree_ok: c_int = 0;
+const rree_error_null: c_int = 1;
+const rree_error_utf8: c_int = 2;
+const rree_error_pattern: c_int = 3;
+
+// The string p points to, or an error code.
+unsafe fn c_str(p: *c_char) -> Result<~str, c_int> {
+    if ptr::is_null(p) {
+        return Err(rree_error_null);
+    }
+    let s = str::raw::from_c_str(p);
+    if !str::is_utf8(str::to_bytes(s)) {
+        return Err(rree_error_utf8);
+    }
+    return Ok(s);
+}
+
+#[no_mangle]
+extern fn rree_compile(pattern: *c_char, error: *mut c_int) -> *c_void {
+    unsafe {
+        let code = match c_str(pattern) {
+            Ok(s) => match Regex::new(s) {
+                Ok(re) => {
+                    if !ptr::is_mut_null(error) {
+                        *error = rree_ok;
+                    }
+                    return cast::transmute(~re);
+                }
+                Err(_) => rree_error_pattern
+            },
+            Err(code) => code
+        };
+        if !ptr::is_mut_null(error) {
+            *error = code;
+        }
+        return ptr::null();
+    }
+}
+
+#[no_mangle]
+extern fn rree_match(re: *c_void, text: *c_char) -> c_int {
+    unsafe {
+        if ptr::is_null(re) {
+            return -rree_error_null;
+        }
+        let re: &Regex = cast::transmute(re);
+        match c_str(text) {
+            Ok(s) => if re.is_match(s) { 1 } else { 0 },
+            Err(code) => -code
+        }
+    }
+}
+
+#[no_mangle]
+extern fn rree_captures_len(re: *c_void) -> size_t {
+    unsafe {
+        if ptr::is_null(re) {
+            return 0;
+        }
+        let re: &Regex = cast::transmute(re);
+        re.captures_len() as size_t
+    }
+}
+
+#[no_mangle]
+extern fn rree_captures(re: *c_void, text: *c_char, spans: *mut size_t,
+                        nspans: size_t) -> c_int {
+    unsafe {
+        if ptr::is_null(re) || (ptr::is_mut_null(spans) && nspans > 0) {
+            return -rree_error_null;
+        }
+        let re: &Regex = cast::transmute(re);
+        let s = match c_str(text) {
+            Ok(s) => s,
+            Err(code) => return -code
+        };
+        let caps = match re.exec(s, 0, false, false) {
+            Some(caps) => caps,
+            None => return 0
+        };
+        for caps.eachi |i, cap| {
+            if i as size_t >= nspans {
+                break;
+            }
+            *ptr::mut_offset(spans, i) = match *cap {
+                Some(pos) => pos as size_t,
+                None => -1 as size_t
+            };
+        }
+        return 1;
+    }
+}
+
+#[no_mangle]
+extern fn rree_free(re: *c_void) {
+    unsafe {
+        if !ptr::is_null(re) {
+            let _re: ~Regex = cast::transmute(re);
+        }
+    }
+}
+
+#[test]
+fn test_ffi() {
+    do str::as_c_str("(a+)(x)?b") |pattern| {
+        let mut error = -1;
+        let re = rree_compile(pattern, ptr::to_mut_unsafe_ptr(&mut error));
+        assert error == rree_ok;
+        assert rree_captures_len(re) == 3;
+        do str::as_c_str("zaab") |text| {
+            assert rree_match(re, text) == 1;
+            let mut spans = vec::from_elem(6, 0 as size_t);
+            let n = rree_captures(re, text, vec::raw::to_mut_ptr(spans), 6);
+            assert n == 1;
+            assert spans == ~[1, 4, 1, 3, -1 as size_t, -1 as size_t];
+        }
+        do str::as_c_str("zzz") |text| {
+            assert rree_match(re, text) == 0;
+        }
+        assert rree_match(re, ptr::null()) == -rree_error_null;
+        rree_free(re);
+    }
+    do str::as_c_str("(") |pattern| {
+        let mut error = -1;
+        let re = rree_compile(pattern, ptr::to_mut_unsafe_ptr(&mut error));
+        assert ptr::is_null(re);
+        assert error == rree_error_pattern;
+    }
+}
@@ -0,0 +1,105 @@
+use rand::Rng;
+use parse::{Regexp, Empty, Literal, Class, Concat, Alternate, Star, Plus,
+            Quest, Capture, EmptyLook, LookAhead, NegLookAhead};
+#[cfg(test)]
+use parse::parse;
+#[cfg(test)]
+use regex::Regex;
+
+// Random strings matching a pattern, for property tests and for making
+// test fixtures from grammars. * and + repeat at most max_repeat times,
+// though + always matches at least once. Assertions are not taken into
+// account, so for patterns with \b, lookaheads, or anchors elsewhere than
+// at its ends, a generated string may not match. Returns None if a class
+// that matches nothing is reached.
+fn generate(re: @Regexp, rng: @Rng, max_repeat: uint) -> Option<~str> {
+    let mut s = ~"";
+    if generate_into(re, rng, max_repeat, &mut s) { Some(s) } else { None }
+}
+
+// The ranges of cc without the surrogates, which are not chars of any
+// string.
+fn without_surrogates(cc: &[(char, char)]) -> ~[(char, char)] {
+    let mut ranges = ~[];
+    for cc.each |r| {
+        let (lo, hi) = *r;
+        if lo < 0xd800 as char {
+            ranges.push((lo, if hi < 0xd800 as char { hi }
+                             else { 0xd7ff as char }));
+        }
+        if hi > 0xdfff as char {
+            ranges.push((if lo > 0xdfff as char { lo }
+                         else { 0xe000 as char }, hi));
+        }
+    }
+    return ranges;
+}
+
+fn repeat(sub: @Regexp, rng: @Rng, max_repeat: uint, min: uint,
+          s: &mut ~str) -> bool {
+    let max = uint::max(min, max_repeat);
+    for rng.gen_uint_range(min, max + 1).times {
+        if !generate_into(sub, rng, max_repeat, s) {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn generate_into(re: @Regexp, rng: @Rng, max_repeat: uint, s: &mut ~str)
+    -> bool {
+    match *re {
+        Empty | EmptyLook(_) | LookAhead(_) | NegLookAhead(_) => {}
+        Literal(c) => str::push_char(s, c),
+        Class(ranges) => {
+            let ranges = without_surrogates(ranges);
+            if ranges.is_empty() {
+                return false;
+            }
+            let (lo, hi) = ranges[rng.gen_uint_range(0, ranges.len())];
+            let c = rng.gen_uint_range(lo as uint, hi as uint + 1) as char;
+            str::push_char(s, c);
+        }
+        Concat(subs) => {
+            for subs.each |sub| {
+                if !generate_into(*sub, rng, max_repeat, s) {
+                    return false;
+                }
+            }
+        }
+        Alternate(subs) => {
+            let sub = subs[rng.gen_uint_range(0, subs.len())];
+            return generate_into(sub, rng, max_repeat, s);
+        }
+        Star(sub, _) => return repeat(sub, rng, max_repeat, 0, s),
+        Plus(sub, _) => return repeat(sub, rng, max_repeat, 1, s),
+        Quest(sub, _) => {
+            if rng.gen_bool() {
+                return generate_into(sub, rng, max_repeat, s);
+            }
+        }
+        Capture(_, _, sub) => return generate_into(sub, rng, max_repeat, s),
+        _ => fail
+    }
+    return true;
+}
+
+#[test]
+fn test_generate() {
+    let rng = rand::Rng();
+    let patterns = ["abc", "a*b+c?", "(foo|ba[rz])+", "[0-9]x|\\pL\\pN",
+                    "^(?i)k$", "x(?:y|)*", "[^a]", "[\\x{d7ff}-\\x{e000}]"];
+    for patterns.each |p| {
+        let re = Regex::new(*p).get();
+        for 50.times {
+            let s = generate(parse(*p).get(), rng, 5).get();
+            assert str::is_utf8(str::to_bytes(s));
+            assert re.is_full_match(s);
+        }
+    }
+    assert generate(parse("a*").get(), rng, 0) == Some(~"");
+    assert generate(parse("a+").get(), rng, 0) == Some(~"a");
+    let s = generate(parse("[a-c]+").get(), rng, 3).get();
+    assert s.len() >= 1 && s.len() <= 3;
+    assert generate(parse("a[^\\x00-\\x{10ffff}]").get(), rng, 3).is_none();
+}
@@ -0,0 +1,334 @@
+use parse::{Regexp, Literal, Class, Concat, Alternate, Plus, Capture,
+            EmptyLook, look_end_text};
+use visit::children;
+use charclass::contains;
+use libc::{c_int, c_void, size_t};
+#[cfg(test)]
+use parse::{parse, Parser};
+
+// A node of a trie of byte strings.
+struct Node {
+    // The child for each byte.
+    next: ~[(u8, uint)],
+    // The number of the first literal ending here, if any.
+    accept: Option<uint>
+}
+
+// A matcher for patterns that are an alternation of plain literals, such
+// as GET|POST|PUT, which need no automaton. Literals are kept in a trie
+// of the bytes they match, and as in the pattern, at a given position the
+// first literal matching there wins.
+struct LiteralSet {
+    nodes: ~[Node]
+}
+
+// Add the bytes literal c matches to s. In bytes mode, the parser has
+// already split literals into bytes.
+fn push_literal(s: &mut ~[u8], c: char, bytes: bool) {
+    if bytes {
+        s.push(c as u8);
+    } else {
+        *s += str::to_bytes(str::from_char(c));
+    }
+}
+
+// The bytes re matches if it is a nonempty literal string.
+fn literal_string(re: @Regexp, bytes: bool) -> Option<~[u8]> {
+    let mut s = ~[];
+    match *re {
+        Literal(c) => push_literal(&mut s, c, bytes),
+        Concat(subs) => {
+            for subs.each |sub| {
+                match **sub {
+                    Literal(c) => push_literal(&mut s, c, bytes),
+                    _ => return None
+                }
+            }
+        }
+        _ => return None
+    }
+    if s.is_empty() { None } else { Some(s) }
+}
+
+// The literals of re, in order, if it is one or an alternation of them.
+fn alternation_literals(re: @Regexp, bytes: bool) -> Option<~[~[u8]]> {
+    let subs = match *re {
+        Alternate(subs) => subs,
+        _ => ~[re]
+    };
+    let mut literals = ~[];
+    for subs.each |sub| {
+        match literal_string(*sub, bytes) {
+            Some(s) => literals.push(s),
+            None => return None
+        }
+    }
+    return Some(literals);
+}
+
+// The bytes of the longest literal string every match of re contains, if
+// any, taken from a run of literals in a concatenation that is not
+// optional.
+fn required_literal(re: @Regexp, bytes: bool) -> Option<~[u8]> {
+    let mut best = ~[];
+    match *re {
+        Literal(c) => push_literal(&mut best, c, bytes),
+        Concat(subs) => {
+            let mut run = ~[];
+            for subs.each |sub| {
+                match **sub {
+                    Literal(c) => {
+                        push_literal(&mut run, c, bytes);
+                        again;
+                    }
+                    _ => {}
+                }
+                if run.len() > best.len() {
+                    best <-> run;
+                }
+                run = ~[];
+                match required_literal(*sub, bytes) {
+                    Some(s) if s.len() > best.len() => best = s,
+                    _ => {}
+                }
+            }
+            if run.len() > best.len() {
+                best = run;
+            }
+        }
+        Plus(sub, _) | Capture(_, _, sub) => {
+            return required_literal(sub, bytes);
+        }
+        _ => {}
+    }
+    if best.is_empty() { None } else { Some(best) }
+}
+
+// Whether a match of re may hold a \n, or depend on whether the text ends
+// after it, so that the lines of the text cannot be searched on their own.
+fn spans_lines(re: @Regexp) -> bool {
+    match *re {
+        Literal(c) => c == '\n',
+        Class(ranges) => contains(ranges, '\n'),
+        EmptyLook(look) => look == look_end_text,
+        _ => children(re).any(|sub| spans_lines(*sub))
+    }
+}
+
+// The position of the first b in haystack at or after start.
+fn find_byte(haystack: &[u8], b: u8, start: uint) -> Option<uint> {
+    if start >= haystack.len() {
+        return None;
+    }
+    unsafe {
+        let p = vec::raw::to_ptr(haystack);
+        let q = libc::memchr(ptr::offset(p, start) as *c_void, b as c_int,
+                             (haystack.len() - start) as size_t);
+        if q.is_null() { None } else { Some(q as uint - p as uint) }
+    }
+}
+
+// The position of the first occurrence of needle in haystack at or after
+// start. Candidates are found with memchr on the first byte of needle.
+fn find_literal(haystack: &[u8], needle: &[u8], start: uint) -> Option<uint> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    // Only positions up to last may start an occurrence.
+    let candidates = vec::view(haystack, 0, haystack.len() - needle.len() + 1);
+    let mut i = start;
+    loop {
+        i = match find_byte(candidates, needle[0], i) {
+            Some(i) => i,
+            None => return None
+        };
+        if vec::view(haystack, i, i + needle.len()) == needle {
+            return Some(i);
+        }
+        i += 1;
+    }
+}
+
+impl LiteralSet {
+    static fn new(literals: &[~[u8]]) -> LiteralSet {
+        let mut nodes = ~[Node {next: ~[], accept: None}];
+        for literals.eachi |i, literal| {
+            let mut n = 0;
+            for literal.each |b| {
+                let b = *b;
+                let mut child = None;
+                for nodes[n].next.each |edge| {
+                    let (c, m) = *edge;
+                    if c == b {
+                        child = Some(m);
+                    }
+                }
+                n = match child {
+                    Some(m) => m,
+                    None => {
+                        let m = nodes.len();
+                        nodes.push(Node {next: ~[], accept: None});
+                        nodes[n].next.push((b, m));
+                        m
+                    }
+                };
+            }
+            if nodes[n].accept.is_none() {
+                nodes[n].accept = Some(i);
+            }
+        }
+        return LiteralSet {nodes: nodes};
+    }
+    // Heap bytes held by the trie.
+    fn memory_usage(&self) -> uint {
+        let mut size = self.nodes.len() * sys::size_of::<Node>();
+        for self.nodes.each |node| {
+            size += node.next.len() * sys::size_of::<(u8, uint)>();
+        }
+        return size;
+    }
+    // Try the literals for pattern re, returning None if it is not an
+    // alternation of literals. bytes is whether re was parsed in bytes
+    // mode.
+    static fn from_regexp(re: @Regexp, bytes: bool) -> Option<LiteralSet> {
+        match alternation_literals(re, bytes) {
+            Some(literals) => Some(LiteralSet::new(literals)),
+            None => None
+        }
+    }
+    fn child(&self, n: uint, b: u8) -> Option<uint> {
+        for self.nodes[n].next.each |edge| {
+            let (c, m) = *edge;
+            if c == b {
+                return Some(m);
+            }
+        }
+        return None;
+    }
+    // Call f with the number and end of each literal matching at pos,
+    // shorter ones first. Of identical literals, only the first is
+    // reported.
+    fn each_at(&self, input: &[u8], pos: uint, f: fn(uint, uint) -> bool) {
+        let mut n = 0;
+        let mut i = pos;
+        loop {
+            match self.nodes[n].accept {
+                Some(k) => {
+                    if !f(k, i) {
+                        return;
+                    }
+                }
+                None => {}
+            }
+            if i == input.len() {
+                return;
+            }
+            n = match self.child(n, input[i]) {
+                Some(m) => m,
+                None => return
+            };
+            i += 1;
+        }
+    }
+    // The end of the first literal matching at pos.
+    fn match_at(&self, input: &[u8], pos: uint, anchor_end: bool)
+        -> Option<uint> {
+        let mut best: Option<(uint, uint)> = None;
+        for self.each_at(input, pos) |k, end| {
+            if !anchor_end || end == input.len() {
+                let better = match best {
+                    Some((b, _)) => k < b,
+                    None => true
+                };
+                if better {
+                    best = Some((k, end));
+                }
+            }
+        }
+        match best {
+            Some((_, end)) => Some(end),
+            None => None
+        }
+    }
+    // Like Machine::run, for a program without groups.
+    fn run(&self, input: &[u8], start: uint, anchored: bool,
+           anchor_end: bool) -> Option<~[Option<uint>]> {
+        match self.find(input, start, anchored, anchor_end) {
+            Some((pos, end)) => Some(~[Some(pos), Some(end)]),
+            None => None
+        }
+    }
+    // Like run, giving the offsets of the match without allocating.
+    fn find(&self, input: &[u8], start: uint, anchored: bool,
+            anchor_end: bool) -> Option<(uint, uint)> {
+        let mut pos = start;
+        while pos <= input.len() {
+            match self.match_at(input, pos, anchor_end) {
+                Some(end) => return Some((pos, end)),
+                None => {}
+            }
+            if anchored {
+                break;
+            }
+            pos += 1;
+        }
+        return None;
+    }
+}
+
+#[test]
+fn test_literal_set() {
+    fn find(re: &str, s: &str) -> Option<(uint, uint)> {
+        let set = LiteralSet::from_regexp(parse(re).get(), false).get();
+        match str::byte_slice(s, |b| set.run(b, 0, false, false)) {
+            Some(caps) => Some((caps[0].get(), caps[1].get())),
+            None => None
+        }
+    }
+    assert find("GET|POST|PUT", "xPUT") == Some((1, 4));
+    assert find("GET|POST|PUT", "PU") == None;
+    assert find("a|ab", "ab") == Some((0, 1));
+    assert find("ab|a", "ab") == Some((0, 2));
+    assert find("\u00e9t\u00e9|x", "l'\u00e9t\u00e9") == Some((2, 7));
+    assert LiteralSet::from_regexp(parse("a|b*").get(), false).is_none();
+    assert LiteralSet::from_regexp(parse("(a)|b").get(), false).is_none();
+    assert LiteralSet::from_regexp(parse("a|").get(), false).is_none();
+    // In bytes mode, literals are bytes already.
+    let re = @Alternate(~[@Concat(~[@Literal('\xc3'), @Literal('\xa9')]),
+                          @Literal('\xff')]);
+    let set = LiteralSet::from_regexp(re, true).get();
+    assert set.run(~[0x00, 0xff, 0xc3, 0xa9], 0, false, false) ==
+        Some(~[Some(1), Some(2)]);
+    assert set.run(~[0xc3, 0xbf, 0xc3, 0xa9], 0, false, false) ==
+        Some(~[Some(2), Some(4)]);
+}
+
+#[test]
+fn test_required_literal() {
+    fn test(re: &str, expected: Option<&str>) {
+        let found = required_literal(parse(re).get(), false);
+        assert found == expected.map(|s| str::to_bytes(*s));
+    }
+    test("[a-z]+@[a-z]+\\.com", Some(".com"));
+    test("x(abc)+[0-9]ab", Some("abc"));
+    test("a|b", None);
+    test("(?:foo)?bar*", Some("ba"));
+    test("[0-9]+", None);
+    test("\u00e9t\u00e9", Some("\u00e9t\u00e9"));
+    let p = Parser::new();
+    p.bytes = true;
+    assert required_literal(p.parse("[a-z]+\\xff\\xfe").get(), true) ==
+        Some(~[0xff, 0xfe]);
+    assert !spans_lines(parse("[a-z]+@[a-z]+\\.com").get());
+    assert !spans_lines(parse("a.(?=b)").get());
+    assert spans_lines(parse("a[ \\n]").get());
+    assert spans_lines(parse("(?=[^x])").get());
+    assert spans_lines(parse("a$").get());
+    let b = str::to_bytes("abcabd");
+    assert find_literal(b, str::to_bytes("abd"), 0) == Some(3);
+    assert find_literal(b, str::to_bytes("ab"), 1) == Some(3);
+    assert find_literal(b, str::to_bytes("x"), 0) == None;
+    assert find_literal(b, str::to_bytes("abcabdx"), 0) == None;
+    assert find_literal(b, str::to_bytes("d"), 6) == None;
+    assert find_byte(b, 'c' as u8, 3) == None;
+}
@@ -0,0 +1,226 @@
+use compile::{Prog, InstMatch, InstFail, InstChar, InstClass, InstAlt, InstSave,
+              InstEmpty, InstLook};
+use charclass::{contains, intersects};
+#[cfg(test)]
+use parse::parse;
+#[cfg(test)]
+use compile::compile;
+#[cfg(test)]
+use pike::Machine;
+
+// Finding out whether a program is one-pass takes time quadratic in its
+// size, so larger programs are not considered.
+const max_onepass_prog: uint = 1000;
+
+// An instruction reached by following empty transitions, and the capture
+// slots saved on the way.
+struct Leaf {
+    pc: uint,
+    saves: ~[uint]
+}
+
+// Follow empty transitions from pc and collect the instructions reached,
+// in priority order, like Machine::add does.
+fn closure(prog: &Prog, pc: uint, saves: ~[uint], seen: &mut ~[bool],
+           leaves: &mut ~[Leaf]) {
+    if seen[pc] {
+        return;
+    }
+    seen[pc] = true;
+    match prog.inst[pc] {
+        InstAlt(x, y) => {
+            closure(prog, x, copy saves, seen, leaves);
+            closure(prog, y, saves, seen, leaves);
+        }
+        InstSave(n, next) => {
+            let mut saves = saves;
+            saves.push(n);
+            closure(prog, next, saves, seen, leaves);
+        }
+        InstFail => {}
+        _ => {
+            leaves.push(Leaf {pc: pc, saves: saves});
+        }
+    }
+}
+
+// The chars an instruction consumes.
+fn leaf_chars(prog: &Prog, pc: uint) -> ~[(char, char)] {
+    match prog.inst[pc] {
+        InstChar(c, _) => ~[(c, c)],
+        InstClass(ranges, _) => copy *ranges,
+        _ => ~[]
+    }
+}
+
+// A program is one-pass when, at each point of an anchored search, the
+// next char of the input selects at most one way to go on, so that its
+// captures can be computed in a single scan of the input with a single set
+// of capture slots, instead of tracking many threads. Programs with
+// empty-width assertions are not considered.
+struct OnePass {
+    prog: @Prog,
+    // The closure of each instruction a search can be at after
+    // consuming a char, and of the start of the program.
+    states: ~[~[Leaf]]
+}
+
+impl OnePass {
+    // Analyze prog, returning None if it is not one-pass.
+    static fn new(prog: @Prog) -> Option<OnePass> {
+        let n = prog.inst.len();
+        if n > max_onepass_prog {
+            return None;
+        }
+        let mut states: ~[~[Leaf]] = vec::from_fn(n, |_| ~[]);
+        let mut entries = ~[prog.start];
+        for prog.inst.each |inst| {
+            match *inst {
+                InstChar(_, x) | InstClass(_, x) => entries.push(x),
+                InstEmpty(_, _) | InstLook(_, _, _) => return None,
+                _ => {}
+            }
+        }
+        for entries.each |pc| {
+            if states[*pc].is_not_empty() {
+                again;
+            }
+            let mut seen = vec::from_elem(n, false);
+            let mut leaves = ~[];
+            closure(prog, *pc, ~[], &mut seen, &mut leaves);
+            for uint::range(0, leaves.len()) |i| {
+                let a = leaf_chars(prog, leaves[i].pc);
+                for uint::range(i + 1, leaves.len()) |j| {
+                    if intersects(a, leaf_chars(prog, leaves[j].pc)) {
+                        return None;
+                    }
+                }
+            }
+            states[*pc] = leaves;
+        }
+        return Some(OnePass {prog: prog, states: states});
+    }
+    // Heap bytes held by the tables.
+    fn memory_usage(&self) -> uint {
+        let mut size = self.states.len() * sys::size_of::<~[Leaf]>();
+        for self.states.each |leaves| {
+            size += leaves.len() * sys::size_of::<Leaf>();
+            for leaves.each |leaf| {
+                size += leaf.saves.len() * sys::size_of::<uint>();
+            }
+        }
+        return size;
+    }
+    // Like Machine::run for an anchored search.
+    fn run(&self, input: &[u8], start: uint, anchor_end: bool)
+        -> Option<~[Option<uint>]> {
+        let nslot = 2 * (self.prog.ncap + 1);
+        let mut caps = vec::from_elem(nslot, None);
+        let mut matched = vec::from_elem(nslot, None);
+        if self.search(input, start, anchor_end, &mut caps, &mut matched) {
+            Some(matched)
+        } else {
+            None
+        }
+    }
+    // Like run, using caps for the slots of the search and leaving those
+    // of the match in matched. Both must have a slot for each group.
+    fn search(&self, input: &[u8], start: uint, anchor_end: bool,
+              caps: &mut ~[Option<uint>], matched: &mut ~[Option<uint>])
+        -> bool {
+        for uint::range(0, caps.len()) |n| {
+            caps[n] = None;
+        }
+        let mut found = false;
+        let mut pc = self.prog.start;
+        let mut pos = start;
+        loop {
+            let (c, next) = self.prog.char_at(input, pos);
+            let leaves = &self.states[pc];
+            let mut follow = None;
+            for leaves.eachi |i, leaf| {
+                match self.prog.inst[leaf.pc] {
+                    InstMatch => {
+                        if !anchor_end || pos == input.len() {
+                            // Keep the match in case the higher priority
+                            // way on, if any, fails later.
+                            for uint::range(0, caps.len()) |n| {
+                                matched[n] = caps[n];
+                            }
+                            for leaf.saves.each |n| {
+                                matched[*n] = Some(pos);
+                            }
+                            found = true;
+                            break;
+                        }
+                    }
+                    InstChar(ch, x) => {
+                        match c {
+                            Some(d) if d == ch => follow = Some((x, i)),
+                            _ => {}
+                        }
+                    }
+                    InstClass(ranges, x) => {
+                        match c {
+                            Some(d) if contains(*ranges, d) => {
+                                follow = Some((x, i));
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            match follow {
+                Some((x, i)) => {
+                    for leaves[i].saves.each |n| {
+                        caps[*n] = Some(pos);
+                    }
+                    pc = x;
+                    pos = next;
+                }
+                None => break
+            }
+        }
+        return found;
+    }
+}
+
+#[test]
+fn test_onepass() {
+    fn is_onepass(s: &str) -> bool {
+        let prog = @compile(parse(s).get(), true, false).get();
+        OnePass::new(prog).is_some()
+    }
+    assert is_onepass("abc");
+    assert is_onepass("a*b");
+    assert is_onepass("([0-9]+)-([0-9]+)");
+    assert is_onepass("([^:]*):(.*)");
+    assert is_onepass("x(a|b)*y");
+    assert !is_onepass("a*a");
+    assert !is_onepass("(a|ab)c");
+    assert !is_onepass("(.*):(.*)");
+}
+
+#[test]
+fn test_onepass_run() {
+    fn test(s: &str, t: &str) {
+        let prog = @compile(parse(s).get(), true, false).get();
+        let op = OnePass::new(prog).get();
+        do str::byte_slice(t) |b| {
+            for uint::range(0, b.len() + 1) |start| {
+                for ~[false, true].each |anchor_end| {
+                    let m = Machine::new(prog);
+                    let expected = m.run(b, start, true, *anchor_end);
+                    assert op.run(b, start, *anchor_end) == expected;
+                }
+            }
+        }
+    }
+    test("([0-9]+)-([0-9]+)", "12-345-6");
+    test("([^:]*):(.*)", "key:value:x");
+    test("x(a|b)*y", "xabbay");
+    test("(a*)(b?)", "aab");
+    test("(a)?", "a");
+    test("\\pL+\\.", "\u00e9t\u00e9.");
+}
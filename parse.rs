@@ -1,34 +1,96 @@
 use result::{Result, Ok, Err};
 use io::{Writer, WriterUtil};
+use charclass::{max_char, normalize, negate, fold, clip, posix_class};
+use unicode::{table, fold_table};
+use std::time::precise_time_ns;
+use visit::children;
 
 enum Regexp {
     Empty,
     Literal(char),
+    Class(~[(char, char)]),
     Concat(~[@Regexp]),
     Alternate(~[@Regexp]),
-    Star(@Regexp),
-    Plus(@Regexp),
-    Quest(@Regexp),
-    Capture(uint, @Regexp),
-    LeftParen(uint),
+    // Repetitions, and whether they are greedy.
+    Star(@Regexp, bool),
+    Plus(@Regexp, bool),
+    Quest(@Regexp, bool),
+    Capture(uint, Option<~str>, @Regexp),
+    // An empty-width assertion about the chars around a position.
+    EmptyLook(uint),
+    // Assertions that what follows matches, or does not match, a regexp.
+    LookAhead(@Regexp),
+    NegLookAhead(@Regexp),
+    // The number of the group, or 0 if it does not capture, and the flags
+    // to restore when it is closed.
+    LeftParen(uint, uint),
+    // The start of a lookahead, whether it is negated, and the flags to
+    // restore when it is closed.
+    LeftLook(bool, uint),
     VerticalBar
 }
 
 enum Error {
     MissingParen,
-    RepeatArgument
+    RepeatArgument,
+    InvalidNamedCapture,
+    TrailingBackslash,
+    InvalidEscape,
+    NestingDepth,
+    ProgramTooLarge,
+    MissingBracket,
+    InvalidCharRange,
+    InvalidCharClass,
+    InvalidFlags,
+    UnicodeDisabled,
+    CompileTimeout,
+    // The pattern needs a feature the engine asked to run it lacks.
+    UnsupportedFeature,
+    // Backreferences such as \1 are not supported.
+    UnsupportedBackreference,
+    // An encoded program is malformed or of another version.
+    InvalidProgram,
+    // A \x{...} or \u{...} escape is past U+10FFFF or a surrogate.
+    InvalidCodePoint
+}
+
+// Flags, set by (?flags) and (?flags:re) groups.
+const flag_fold_case: uint = 1;    // i
+const flag_unicode: uint = 2;      // u
+const flag_multi_line: uint = 4;   // m
+const flag_dot_nl: uint = 8;       // s
+const flag_ungreedy: uint = 16;    // U
+const flag_verbose: uint = 32;     // x
+
+// Assertions of EmptyLook. look_not_after_word and look_not_before_word
+// have no syntax and are only used to match whole words.
+const look_word_boundary: uint = 1;        // \b
+const look_not_word_boundary: uint = 2;    // \B
+const look_not_after_word: uint = 3;
+const look_not_before_word: uint = 4;
+const look_begin_text: uint = 5;           // ^
+const look_end_text: uint = 6;             // $
+const look_begin_line: uint = 7;           // ^ in multi-line mode
+const look_end_line: uint = 8;             // $ in multi-line mode
+
+// A capture group as written in the pattern. start and end are byte
+// offsets of the group, parentheses included.
+struct Group {
+    name: Option<~str>,
+    start: uint,
+    end: uint
 }
 
 impl Regexp {
     fn is_marker(&self) -> bool {
         match *self {
-            LeftParen(_) | VerticalBar => true,
+            LeftParen(_, _) | LeftLook(_, _) | VerticalBar => true,
             _ => false
         }
     }
     fn is_left_paren(&self) -> bool {
         match *self {
-            LeftParen(_) => true,
+            LeftParen(_, _) | LeftLook(_, _) => true,
             _ => false
         }
     }
@@ -38,16 +100,734 @@ impl Regexp {
             _ => false
         }
     }
+    // Write the regexp back as a pattern, which parses to the same tree.
+    fn to_str(&self) -> ~str {
+        let mut t = ~"";
+        self.write(&mut t, false);
+        return t;
+    }
+    // Like to_str, for a regexp parsed in bytes mode. Literals and class
+    // bounds past \x7f stand for bytes there and are written as \x
+    // escapes, since the chars themselves would parse to their UTF-8.
+    fn to_bytes_str(&self) -> ~str {
+        let mut t = ~"";
+        self.write(&mut t, true);
+        return t;
+    }
+    fn write(&self, t: &mut ~str, bytes: bool) {
+        match *self {
+            Empty => {}
+            Literal(c) if bytes && c > '\x7f' => {
+                str::push_str(t, fmt!("\\x%02x", c as uint));
+            }
+            Literal(c) => {
+                if is_meta(c) {
+                    str::push_char(t, '\\');
+                }
+                str::push_char(t, c);
+            }
+            Class(ranges) => {
+                // [] is not a class, so write one matching nothing as the
+                // negation of every char.
+                if ranges.is_empty() {
+                    if bytes {
+                        str::push_str(t, "[^\\x00-\\xff]");
+                    } else {
+                        str::push_str(t, "[^\\x00-\\x{10ffff}]");
+                    }
+                    return;
+                }
+                str::push_char(t, '[');
+                for ranges.each |r| {
+                    let (lo, hi) = *r;
+                    write_class_char(t, lo, bytes);
+                    if lo != hi {
+                        str::push_char(t, '-');
+                        write_class_char(t, hi, bytes);
+                    }
+                }
+                str::push_char(t, ']');
+            }
+            Concat(subs) => {
+                for subs.each |sub| {
+                    match **sub {
+                        Alternate(_) => sub.write_group(t, bytes),
+                        _ => sub.write(t, bytes)
+                    }
+                }
+            }
+            Alternate(subs) => {
+                for subs.eachi |i, sub| {
+                    if i > 0 {
+                        str::push_char(t, '|');
+                    }
+                    sub.write(t, bytes);
+                }
+            }
+            Star(sub, greedy) => {
+                sub.write_repeated(t, bytes);
+                str::push_char(t, '*');
+                if !greedy {
+                    str::push_char(t, '?');
+                }
+            }
+            Plus(sub, greedy) => {
+                sub.write_repeated(t, bytes);
+                str::push_char(t, '+');
+                if !greedy {
+                    str::push_char(t, '?');
+                }
+            }
+            Quest(sub, greedy) => {
+                sub.write_repeated(t, bytes);
+                str::push_char(t, '?');
+                if !greedy {
+                    str::push_char(t, '?');
+                }
+            }
+            EmptyLook(look) if look == look_word_boundary => {
+                str::push_str(t, "\\b");
+            }
+            EmptyLook(look) if look == look_not_word_boundary => {
+                str::push_str(t, "\\B");
+            }
+            EmptyLook(look) if look == look_begin_text => {
+                str::push_char(t, '^');
+            }
+            EmptyLook(look) if look == look_end_text => {
+                str::push_char(t, '$');
+            }
+            EmptyLook(look) if look == look_begin_line => {
+                str::push_str(t, "(?m:^)");
+            }
+            EmptyLook(look) if look == look_end_line => {
+                str::push_str(t, "(?m:$)");
+            }
+            LookAhead(sub) => {
+                str::push_str(t, "(?=");
+                sub.write(t, bytes);
+                str::push_char(t, ')');
+            }
+            NegLookAhead(sub) => {
+                str::push_str(t, "(?!");
+                sub.write(t, bytes);
+                str::push_char(t, ')');
+            }
+            Capture(_, name, sub) => {
+                str::push_char(t, '(');
+                match name {
+                    Some(name) => {
+                        str::push_str(t, "?P<");
+                        str::push_str(t, name);
+                        str::push_char(t, '>');
+                    }
+                    None => {}
+                }
+                sub.write(t, bytes);
+                str::push_char(t, ')');
+            }
+            _ => fail
+        }
+    }
+    fn write_group(&self, t: &mut ~str, bytes: bool) {
+        str::push_str(t, "(?:");
+        self.write(t, bytes);
+        str::push_char(t, ')');
+    }
+    // Write the operand of a repetition operator.
+    fn write_repeated(&self, t: &mut ~str, bytes: bool) {
+        match *self {
+            Literal(_) | Class(_) | Capture(_, _, _) | LookAhead(_) |
+            NegLookAhead(_) => self.write(t, bytes),
+            _ => self.write_group(t, bytes)
+        }
+    }
+    // A short name for the kind of node.
+    fn name(&self) -> ~str {
+        match *self {
+            Empty => ~"emp",
+            Literal(_) => ~"lit",
+            Class(_) => ~"cc",
+            Concat(_) => ~"cat",
+            Alternate(_) => ~"alt",
+            Star(_, _) => ~"star",
+            Plus(_, _) => ~"plus",
+            Quest(_, _) => ~"que",
+            Capture(_, _, _) => ~"cap",
+            EmptyLook(_) => ~"look",
+            LookAhead(_) => ~"ahead",
+            NegLookAhead(_) => ~"nahead",
+            _ => fail
+        }
+    }
+    // Render the tree in the DOT language of Graphviz. Each node is
+    // labeled with its kind and the part of the pattern it stands for,
+    // as written back by to_str, since nodes do not keep their offsets
+    // in the original pattern.
+    fn to_dot(&self) -> ~str {
+        let mut t = ~"digraph regexp {\n";
+        let mut n = 0;
+        write_dot(self, &mut t, &mut n);
+        t += ~"}\n";
+        return t;
+    }
+}
+
+// Escape s for a quoted DOT string.
+fn dot_quote(s: &str) -> ~str {
+    let mut t = ~"";
+    for str::each_char(s) |c| {
+        if c == '"' || c == '\\' {
+            str::push_char(&mut t, '\\');
+        }
+        str::push_char(&mut t, c);
+    }
+    return t;
+}
+
+// Write the node for re and its subtree, numbering nodes from n, and
+// return the number of the node.
+fn write_dot(re: &Regexp, t: &mut ~str, n: &mut uint) -> uint {
+    let id = *n;
+    *n += 1;
+    let mut kind = re.name();
+    match *re {
+        Star(_, false) | Plus(_, false) | Quest(_, false) => {
+            kind += ~" lazy";
+        }
+        Capture(cap, name, _) => {
+            kind += fmt!(" %u", cap);
+            match name {
+                Some(name) => kind += ~" " + name,
+                None => {}
+            }
+        }
+        _ => {}
+    }
+    *t += fmt!("  n%u [label=\"%s\\n%s\"];\n", id, kind,
+               dot_quote(re.to_str()));
+    for children(re).each |sub| {
+        let child = write_dot(*sub, t, n);
+        *t += fmt!("  n%u -> n%u;\n", id, child);
+    }
+    return id;
+}
+
+fn write_class_char(t: &mut ~str, c: char, bytes: bool) {
+    if bytes && c > '\x7f' {
+        str::push_str(t, fmt!("\\x%02x", c as uint));
+        return;
+    }
+    if c == '\\' || c == ']' || c == '[' || c == '^' || c == '-' {
+        str::push_char(t, '\\');
+    }
+    str::push_char(t, c);
+}
+
+// Characters with a special meaning in patterns, now or in the future.
+// Exactly these can be escaped with a backslash, and - as well inside
+// classes.
+fn is_meta(c: char) -> bool {
+    match c {
+        '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' |
+        '[' | ']' | '{' | '}' | '^' | '$' => true,
+        _ => false
+    }
+}
+
+// Escape all metacharacters in s, so that the result matches s literally.
+fn quote(s: &str) -> ~str {
+    let mut t = ~"";
+    for str::each_char(s) |c| {
+        if is_meta(c) {
+            str::push_char(&mut t, '\\');
+        }
+        str::push_char(&mut t, c);
+    }
+    return t;
+}
+
+fn is_word_char(c: char) -> bool {
+    (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') ||
+        (c >= '0' && c <= '9') || c == '_'
+}
+
+// Parse the flags of a (?flags) or (?flags:re) group, following "(?".
+// Return the new flags, the length consumed and whether a group is opened.
+fn parse_flags(t: &str, flags: uint) -> Option<(uint, uint, bool)> {
+    let mut flags = flags;
+    let mut negate = false;
+    let mut empty = true;
+    let mut u = t;
+    while u.is_not_empty() {
+        let (c, v) = str::view_shift_char(u);
+        u = v;
+        match c {
+            '-' => {
+                if negate {
+                    return None;
+                }
+                negate = true;
+                empty = true;
+            }
+            ':' | ')' => {
+                if empty {
+                    return None;
+                }
+                return Some((flags, t.len() - u.len(), c == ':'));
+            }
+            _ => {
+                let flag = match c {
+                    'i' => flag_fold_case,
+                    'u' => flag_unicode,
+                    'm' => flag_multi_line,
+                    's' => flag_dot_nl,
+                    'U' => flag_ungreedy,
+                    'x' => flag_verbose,
+                    _ => return None
+                };
+                if negate {
+                    flags &= !flag;
+                } else {
+                    flags |= flag;
+                }
+                empty = false;
+            }
+        }
+    }
+    return None;
+}
+
+// Parse the name of a (?P<name>re) group, which t starts with.
+fn parse_name(t: &str) -> Option<(~str, uint)> {
+    if !t.starts_with("?P<") {
+        return None;
+    }
+    let mut name = ~"";
+    let mut u = str::view(t, 3, t.len());
+    while u.is_not_empty() {
+        let (c, v) = str::view_shift_char(u);
+        u = v;
+        if c == '>' {
+            if name.is_empty() {
+                return None;
+            }
+            return Some((name, t.len() - u.len()));
+        }
+        if !is_word_char(c) {
+            return None;
+        }
+        str::push_char(&mut name, c);
+    }
+    return None;
+}
+
+const default_nest_limit: uint = 1000;
+
+// Options for parsing a pattern. The flags among them can also be set and
+// cleared in the pattern, as (?i), (?m), (?s), (?U) and (?x).
+struct ParseOptions {
+    // Match letters in either case.
+    mut case_insensitive: bool,
+    // Let ^ and $ match at the start and end of lines, not only of text.
+    mut multi_line: bool,
+    // Let . match \n.
+    mut dot_matches_nl: bool,
+    // Make repetitions non-greedy, and non-greedy ones, written with a
+    // trailing ?, greedy.
+    mut ungreedy: bool,
+    // Ignore whitespace outside classes, and comments from # to the end
+    // of the line. Whitespace and # can still be matched by escaping
+    // them.
+    mut ignore_whitespace: bool,
+    // Allow octal escapes such as \012. A single non-zero digit is
+    // still not allowed, as it would read as a backreference.
+    mut allow_octal_escapes: bool,
+    // Take an escaped punctuation char with no special meaning, such as
+    // \% or \~, as the char itself, as some other regexp libraries do,
+    // instead of failing with InvalidEscape. Escaped letters and digits,
+    // like \d or \n, still fail, as they mean something else elsewhere.
+    // Off by default, since even punctuation may have been meant as an
+    // escape this parser does not know.
+    mut literal_unknown_escapes: bool,
+    // Maximum nesting depth of groups.
+    mut nest_limit: uint
+}
+
+impl ParseOptions {
+    static fn new() -> ParseOptions {
+        ParseOptions {
+            case_insensitive: false,
+            multi_line: false,
+            dot_matches_nl: false,
+            ungreedy: false,
+            ignore_whitespace: false,
+            allow_octal_escapes: false,
+            literal_unknown_escapes: false,
+            nest_limit: default_nest_limit
+        }
+    }
+}
+
+// Whether an unknown escape of c is taken as c under
+// literal_unknown_escapes.
+fn is_literal_escape(c: char) -> bool {
+    !(c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z' || c >= '0' && c <= '9')
+}
+
+fn is_octal_digit(c: char) -> bool {
+    c >= '0' && c <= '7'
+}
+
+// Parse an octal escape, which t starts with after the backslash, of up
+// to three digits. Return the char and the length consumed.
+fn parse_octal(t: &str) -> Option<(char, uint)> {
+    let mut c = 0u;
+    let mut len = 0;
+    while len < 3 && len < t.len() && is_octal_digit(t[len] as char) {
+        c = c * 8 + (t[len] - '0' as u8) as uint;
+        len += 1;
+    }
+    if len == 0 || (len == 1 && c != 0) {
+        return None;
+    }
+    return Some((c as char, len));
+}
+
+fn hex_digit(c: char) -> Option<uint> {
+    match c {
+        '0' .. '9' => Some(c as uint - '0' as uint),
+        'a' .. 'f' => Some(c as uint - 'a' as uint + 10),
+        'A' .. 'F' => Some(c as uint - 'A' as uint + 10),
+        _ => None
+    }
+}
+
+// Parse a numeric escape, which t starts with after the backslash: \xHH
+// with two hex digits, \x{...} or \u{...} with any number of them, or \0
+// for NUL if no digit follows. Return None if t starts with none of
+// these, and otherwise the char and the length consumed.
+fn parse_numeric_escape(t: &str) -> Option<Result<(char, uint), Error>> {
+    if t.starts_with("0") {
+        if t.len() > 1 && char::is_digit(t[1] as char) {
+            return None;
+        }
+        return Some(Ok(('\x00', 1)));
+    }
+    let braced = t.len() > 1 && t[1] == '{' as u8;
+    if !t.starts_with("x") && !(t.starts_with("u") && braced) {
+        return None;
+    }
+    if !braced {
+        if t.len() < 3 {
+            return Some(Err(InvalidEscape));
+        }
+        return match (hex_digit(t[1] as char), hex_digit(t[2] as char)) {
+            (Some(a), Some(b)) => Some(Ok(((a * 16 + b) as char, 3))),
+            _ => Some(Err(InvalidEscape))
+        };
+    }
+    let end = match str::find_char(t, '}') {
+        Some(end) if end > 2 => end,
+        _ => return Some(Err(InvalidEscape))
+    };
+    let mut c = 0u;
+    for str::each_char(str::view(t, 2, end)) |d| {
+        match hex_digit(d) {
+            Some(v) => c = c * 16 + v,
+            None => return Some(Err(InvalidEscape))
+        }
+        if c > 0x10ffff {
+            return Some(Err(InvalidCodePoint));
+        }
+    }
+    if c >= 0xd800 && c <= 0xdfff {
+        return Some(Err(InvalidCodePoint));
+    }
+    return Some(Ok((c as char, end + 1)));
+}
+
+// Whether the time in nanoseconds given by deadline, if any, has passed.
+fn past_deadline(deadline: Option<u64>) -> bool {
+    match deadline {
+        Some(t) => precise_time_ns() >= t,
+        None => false
+    }
 }
 
 struct Parser {
     mut stack: ~[@Regexp],
-    mut ncap: uint
+    mut ncap: uint,
+    mut groups: ~[Group],
+    // Open groups, which may not exceed nest_limit.
+    mut depth: uint,
+    mut nest_limit: uint,
+    // Parsing fails with CompileTimeout once this time has passed.
+    mut deadline: Option<u64>,
+    mut flags: uint,
+    // In bytes mode, ASCII chars and classes stand for bytes and other
+    // chars for their UTF-8 encoding, while numeric escapes up to \xff
+    // stand for single bytes, in classes too. Unicode classes are not
+    // allowed.
+    mut bytes: bool,
+    // Whether octal escapes are allowed.
+    mut octal: bool,
+    // Whether unknown escapes stand for the escaped char.
+    mut literal_escapes: bool,
+    // The case folding table, loaded when first needed.
+    mut folds: ~[(char, char)]
 }
 
 impl Parser {
     static fn new() -> Parser {
-        Parser {stack: ~[], ncap: 0}
+        Parser {
+            stack: ~[],
+            ncap: 0,
+            groups: ~[],
+            depth: 0,
+            nest_limit: default_nest_limit,
+            deadline: None,
+            flags: flag_unicode,
+            bytes: false,
+            octal: false,
+            literal_escapes: false,
+            folds: ~[]
+        }
+    }
+    static fn with_options(opts: &ParseOptions) -> Parser {
+        let p = Parser::new();
+        if opts.case_insensitive {
+            p.flags |= flag_fold_case;
+        }
+        if opts.multi_line {
+            p.flags |= flag_multi_line;
+        }
+        if opts.dot_matches_nl {
+            p.flags |= flag_dot_nl;
+        }
+        if opts.ungreedy {
+            p.flags |= flag_ungreedy;
+        }
+        if opts.ignore_whitespace {
+            p.flags |= flag_verbose;
+        }
+        p.octal = opts.allow_octal_escapes;
+        p.literal_escapes = opts.literal_unknown_escapes;
+        p.nest_limit = opts.nest_limit;
+        return p;
+    }
+    fn fold(&self, ranges: &[(char, char)]) -> ~[(char, char)] {
+        if self.folds.is_empty() {
+            self.folds = fold_table();
+        }
+        fold(self.folds, ranges, self.unicode())
+    }
+    fn unicode(&self) -> bool {
+        self.flags & flag_unicode != 0 && !self.bytes
+    }
+    // The node for literal c, taking case folding into account.
+    fn literal(&self, c: char) -> @Regexp {
+        if self.bytes && c > '\x7f' {
+            let bytes = str::to_bytes(str::from_char(c));
+            return @Concat(bytes.map(|b| @Literal(*b as char)));
+        }
+        if self.flags & flag_fold_case != 0 {
+            let ranges = self.fold(~[(c, c)]);
+            if ranges != ~[(c, c)] {
+                return @Class(ranges);
+            }
+        }
+        return @Literal(c);
+    }
+    // The node for c given by a numeric escape.
+    fn escaped_literal(&self, c: char) -> @Regexp {
+        if self.bytes && c > '\x7f' && c <= '\xff' {
+            return @Literal(c);
+        }
+        return self.literal(c);
+    }
+    // The node for ranges, which must be normalized.
+    fn class(&self, ranges: ~[(char, char)]) -> @Regexp {
+        if self.flags & flag_fold_case != 0 {
+            return @Class(self.fold(ranges));
+        }
+        return @Class(ranges);
+    }
+    // Parse the name of a \p or \P class, which t starts with. Return the
+    // ranges and the length consumed.
+    fn parse_unicode_class(&self, t: &str, negated: bool)
+        -> Result<(~[(char, char)], uint), Error> {
+        if !self.unicode() {
+            return Err(UnicodeDisabled);
+        }
+        if t.is_empty() {
+            return Err(InvalidCharClass);
+        }
+        let (c, u) = str::view_shift_char(t);
+        let (name, len) = if c == '{' {
+            let end = match str::find_char(u, '}') {
+                Some(end) => end,
+                None => return Err(InvalidCharClass)
+            };
+            (str::slice(u, 0, end), 1 + end + 1)
+        } else {
+            (str::from_char(c), t.len() - u.len())
+        };
+        let mut name = name;
+        let mut negated = negated;
+        if name.starts_with("^") {
+            negated = !negated;
+            name = str::slice(name, 1, name.len());
+        }
+        let ranges = if name == ~"Any" {
+            ~[('\x00', max_char)]
+        } else {
+            match table(name) {
+                Some(ranges) => ranges,
+                None => return Err(InvalidCharClass)
+            }
+        };
+        let ranges = if negated { negate(ranges) } else { ranges };
+        return Ok((ranges, len));
+    }
+    // Parse a POSIX class such as [:alpha:] or [:^alpha:], which t starts
+    // with, returning its ranges and the length consumed.
+    fn parse_posix_class(&self, t: &str) -> Result<(~[(char, char)], uint), Error> {
+        let u = str::view(t, 2, t.len());
+        let end = match str::find_str(u, ":]") {
+            Some(end) => end,
+            None => return Err(InvalidCharClass)
+        };
+        let mut name = str::slice(u, 0, end);
+        let negated = name.starts_with("^");
+        if negated {
+            name = str::slice(name, 1, name.len());
+        }
+        let ranges = match posix_class(name) {
+            Some(ranges) => ranges,
+            None => return Err(InvalidCharClass)
+        };
+        let ranges = if negated { negate(ranges) } else { ranges };
+        return Ok((ranges, 2 + end + 2));
+    }
+    // Parse a char in a class, which t starts with, returning it and the
+    // length consumed.
+    fn parse_class_char(&self, t: &str) -> Result<(char, uint), Error> {
+        let (c, u) = str::view_shift_char(t);
+        if c != '\\' {
+            if self.bytes && c > '\x7f' {
+                return Err(InvalidCharClass);
+            }
+            return Ok((c, t.len() - u.len()));
+        }
+        if u.is_empty() {
+            return Err(TrailingBackslash);
+        }
+        let mut escape = None;
+        if self.octal {
+            escape = parse_octal(u);
+        }
+        if escape.is_none() {
+            match parse_numeric_escape(u) {
+                Some(Ok(r)) => escape = Some(r),
+                Some(Err(e)) => return Err(e),
+                None => {}
+            }
+        }
+        match escape {
+            Some((c, len)) => {
+                if self.bytes && c > '\xff' {
+                    return Err(InvalidCharClass);
+                }
+                return Ok((c, 1 + len));
+            }
+            None => {}
+        }
+        let (d, v) = str::view_shift_char(u);
+        if !is_meta(d) && d != '-' &&
+            !(self.literal_escapes && is_literal_escape(d)) {
+            return Err(InvalidEscape);
+        }
+        if self.bytes && d > '\x7f' {
+            return Err(InvalidCharClass);
+        }
+        return Ok((d, t.len() - v.len()));
+    }
+    // Parse a bracketed class following [, returning its ranges and the
+    // length consumed.
+    fn parse_class(&self, t: &str) -> Result<(~[(char, char)], uint), Error> {
+        let mut u = t;
+        let negated = u.starts_with("^");
+        if negated {
+            u = str::view(u, 1, u.len());
+        }
+        let mut ranges = ~[];
+        let mut first = true;
+        loop {
+            if u.is_empty() {
+                return Err(MissingBracket);
+            }
+            // A ] right after the opening bracket is taken literally.
+            if u.starts_with("]") && !first {
+                u = str::view(u, 1, u.len());
+                break;
+            }
+            first = false;
+            if u.starts_with("\\p") || u.starts_with("\\P") {
+                let v = str::view(u, 2, u.len());
+                match self.parse_unicode_class(v, u.starts_with("\\P")) {
+                    Ok((r, len)) => {
+                        ranges += r;
+                        u = str::view(v, len, v.len());
+                    }
+                    Err(e) => return Err(e)
+                }
+                again;
+            }
+            if u.starts_with("[:") {
+                match self.parse_posix_class(u) {
+                    Ok((r, len)) => {
+                        ranges += r;
+                        u = str::view(u, len, u.len());
+                    }
+                    Err(e) => return Err(e)
+                }
+                again;
+            }
+            let (lo, len) = match self.parse_class_char(u) {
+                Ok(r) => r,
+                Err(e) => return Err(e)
+            };
+            u = str::view(u, len, u.len());
+            let mut hi = lo;
+            if u.starts_with("-") && !u.starts_with("-]") && u.len() > 1 {
+                u = str::view(u, 1, u.len());
+                let (c, len) = match self.parse_class_char(u) {
+                    Ok(r) => r,
+                    Err(e) => return Err(e)
+                };
+                u = str::view(u, len, u.len());
+                if c < lo {
+                    return Err(InvalidCharRange);
+                }
+                hi = c;
+            }
+            ranges.push((lo, hi));
+        }
+        let mut ranges = normalize(ranges);
+        if self.flags & flag_fold_case != 0 {
+            ranges = self.fold(ranges);
+            if past_deadline(self.deadline) {
+                return Err(CompileTimeout);
+            }
+        }
+        if negated {
+            ranges = negate(ranges);
+            if self.bytes {
+                ranges = clip(ranges, '\xff');
+            }
+        }
+        return Ok((ranges, t.len() - u.len()));
     }
     fn concat(&self) {
         let mut i = self.stack.len();
@@ -85,91 +865,250 @@ impl Parser {
         }
         return false;
     }
-}
-
-fn parse(s: &str) -> Result<@Regexp, Error> {
-    let p = Parser::new();
-    let mut t = s;
-    while t.is_not_empty() {
-        let (c, u) = str::view_shift_char(t);
-        t = u;
-        match c {
-            '(' => {
-                p.ncap += 1;
-                p.stack.push(@LeftParen(p.ncap));
-            }
-            '|' => {
-                p.concat();
-                if !p.swap_vertical_bar() {
-                    p.stack.push(@VerticalBar);
-                }
-            }
-            ')' => {
-                p.concat();
-                if p.swap_vertical_bar() {
-                    p.stack.pop();
-                    p.alternate();
-                }
-                let n = p.stack.len();
-                if n < 2 {
-                    return Err(MissingParen);
-                }
-                let sub = p.stack.pop();
-                let paren = p.stack.pop();
-                let re = match *paren {
-                    LeftParen(cap) => @Capture(cap, sub),
-                    _ => return Err(MissingParen)
-                };
-                p.stack.push(re);
-            }
-            '*' | '+' | '?' => {
-                let n = p.stack.len();
-                if n < 1 {
-                    return Err(RepeatArgument);
-                }
-                let sub = p.stack.pop();
-                if sub.is_marker() {
-                    return Err(RepeatArgument);
-                }
-                let re = match c {
-                    '*' => @Star(sub),
-                    '+' => @Plus(sub),
-                    '?' => @Quest(sub),
-                    _ => fail
-                };
-                p.stack.push(re);
+    fn parse(&self, s: &str) -> Result<@Regexp, Error> {
+        let mut t = s;
+        while t.is_not_empty() {
+            if past_deadline(self.deadline) {
+                return Err(CompileTimeout);
             }
-            _ => {
-                p.stack.push(@Literal(c));
+            let pos = s.len() - t.len();
+            let (c, u) = str::view_shift_char(t);
+            t = u;
+            if self.flags & flag_verbose != 0 {
+                if char::is_whitespace(c) {
+                    again;
+                }
+                if c == '#' {
+                    t = match str::find_char(t, '\n') {
+                        Some(end) => str::view(t, end + 1, t.len()),
+                        None => str::view(t, t.len(), t.len())
+                    };
+                    again;
+                }
+            }
+            match c {
+                '(' => {
+                    let mut name = None;
+                    if t.starts_with("?P") {
+                        match parse_name(t) {
+                            Some((n, len)) => {
+                                name = Some(n);
+                                t = str::view(t, len, t.len());
+                            }
+                            None => return Err(InvalidNamedCapture)
+                        }
+                    } else if t.starts_with("?=") || t.starts_with("?!") {
+                        let negated = t.starts_with("?!");
+                        t = str::view(t, 2, t.len());
+                        self.depth += 1;
+                        if self.depth > self.nest_limit {
+                            return Err(NestingDepth);
+                        }
+                        self.stack.push(@LeftLook(negated, self.flags));
+                        again;
+                    } else if t.starts_with("?") {
+                        let u = str::view(t, 1, t.len());
+                        let (flags, len, group) = match parse_flags(u, self.flags) {
+                            Some(r) => r,
+                            None => return Err(InvalidFlags)
+                        };
+                        t = str::view(u, len, u.len());
+                        if !group {
+                            self.flags = flags;
+                            again;
+                        }
+                        self.depth += 1;
+                        if self.depth > self.nest_limit {
+                            return Err(NestingDepth);
+                        }
+                        self.stack.push(@LeftParen(0, self.flags));
+                        self.flags = flags;
+                        again;
+                    }
+                    self.depth += 1;
+                    if self.depth > self.nest_limit {
+                        return Err(NestingDepth);
+                    }
+                    self.ncap += 1;
+                    self.groups.push(Group {name: name, start: pos, end: 0});
+                    self.stack.push(@LeftParen(self.ncap, self.flags));
+                }
+                '|' => {
+                    self.concat();
+                    if !self.swap_vertical_bar() {
+                        self.stack.push(@VerticalBar);
+                    }
+                }
+                ')' => {
+                    self.concat();
+                    if self.swap_vertical_bar() {
+                        self.stack.pop();
+                        self.alternate();
+                    }
+                    let n = self.stack.len();
+                    if n < 2 {
+                        return Err(MissingParen);
+                    }
+                    let sub = self.stack.pop();
+                    let paren = self.stack.pop();
+                    let re = match *paren {
+                        LeftParen(0, flags) => {
+                            self.flags = flags;
+                            sub
+                        }
+                        LeftParen(cap, flags) => {
+                            self.flags = flags;
+                            self.groups[cap-1].end = pos + 1;
+                            @Capture(cap, copy self.groups[cap-1].name, sub)
+                        }
+                        LeftLook(negated, flags) => {
+                            self.flags = flags;
+                            if negated {
+                                @NegLookAhead(sub)
+                            } else {
+                                @LookAhead(sub)
+                            }
+                        }
+                        _ => return Err(MissingParen)
+                    };
+                    self.depth -= 1;
+                    self.stack.push(re);
+                }
+                '*' | '+' | '?' => {
+                    let n = self.stack.len();
+                    if n < 1 {
+                        return Err(RepeatArgument);
+                    }
+                    let sub = self.stack.pop();
+                    if sub.is_marker() {
+                        return Err(RepeatArgument);
+                    }
+                    let mut greedy = self.flags & flag_ungreedy == 0;
+                    if t.starts_with("?") {
+                        greedy = !greedy;
+                        t = str::view(t, 1, t.len());
+                    }
+                    let re = match c {
+                        '*' => @Star(sub, greedy),
+                        '+' => @Plus(sub, greedy),
+                        '?' => @Quest(sub, greedy),
+                        _ => fail
+                    };
+                    self.stack.push(re);
+                }
+                '[' => {
+                    let (ranges, len) = match self.parse_class(t) {
+                        Ok(r) => r,
+                        Err(e) => return Err(e)
+                    };
+                    t = str::view(t, len, t.len());
+                    self.stack.push(@Class(ranges));
+                }
+                '.' => {
+                    let max = if self.bytes { '\xff' } else { max_char };
+                    let ranges = if self.flags & flag_dot_nl != 0 {
+                        ~[('\x00', max)]
+                    } else {
+                        ~[('\x00', '\x09'), ('\x0b', max)]
+                    };
+                    self.stack.push(@Class(ranges));
+                }
+                '^' | '$' => {
+                    let multi_line = self.flags & flag_multi_line != 0;
+                    let look = match (c, multi_line) {
+                        ('^', false) => look_begin_text,
+                        ('$', false) => look_end_text,
+                        ('^', true) => look_begin_line,
+                        _ => look_end_line
+                    };
+                    self.stack.push(@EmptyLook(look));
+                }
+                '\\' => {
+                    if t.is_empty() {
+                        return Err(TrailingBackslash);
+                    }
+                    let (d, u) = str::view_shift_char(t);
+                    if d == 'p' || d == 'P' {
+                        let (ranges, len) = match self.parse_unicode_class(u, d == 'P') {
+                            Ok(r) => r,
+                            Err(e) => return Err(e)
+                        };
+                        t = str::view(u, len, u.len());
+                        self.stack.push(self.class(ranges));
+                        again;
+                    }
+                    if self.octal {
+                        match parse_octal(t) {
+                            Some((c, len)) => {
+                                t = str::view(t, len, t.len());
+                                self.stack.push(self.escaped_literal(c));
+                                again;
+                            }
+                            None => {}
+                        }
+                    }
+                    match parse_numeric_escape(t) {
+                        Some(Ok((c, len))) => {
+                            t = str::view(t, len, t.len());
+                            self.stack.push(self.escaped_literal(c));
+                            again;
+                        }
+                        Some(Err(e)) => return Err(e),
+                        None => {}
+                    }
+                    t = u;
+                    if self.flags & flag_verbose != 0 &&
+                        (char::is_whitespace(d) || d == '#') {
+                        self.stack.push(self.literal(d));
+                        again;
+                    }
+                    if d == 'b' || d == 'B' {
+                        let look = if d == 'b' {
+                            look_word_boundary
+                        } else {
+                            look_not_word_boundary
+                        };
+                        self.stack.push(@EmptyLook(look));
+                        again;
+                    }
+                    if d >= '1' && d <= '9' {
+                        return Err(UnsupportedBackreference);
+                    }
+                    if !is_meta(d) &&
+                        !(self.literal_escapes && is_literal_escape(d)) {
+                        return Err(InvalidEscape);
+                    }
+                    self.stack.push(self.literal(d));
+                }
+                _ => {
+                    self.stack.push(self.literal(c));
+                }
             }
         }
+        self.concat();
+        if self.swap_vertical_bar() {
+            self.stack.pop();
+            self.alternate();
+        }
+        if self.stack.len() != 1 {
+            return Err(MissingParen);
+        }
+        return Ok(self.stack[0]);
     }
-    p.concat();
-    if p.swap_vertical_bar() {
-        p.stack.pop();
-        p.alternate();
-    }
-    if p.stack.len() != 1 {
-        return Err(MissingParen);
-    }
-    return Ok(p.stack[0]);
+}
+
+fn parse(s: &str) -> Result<@Regexp, Error> {
+    let p = Parser::new();
+    p.parse(s)
+}
+
+fn parse_with(s: &str, opts: &ParseOptions) -> Result<@Regexp, Error> {
+    let p = Parser::with_options(opts);
+    p.parse(s)
 }
 
 #[cfg(test)]
 impl Regexp {
-    fn name(&self) -> ~str {
-        match *self {
-            Empty => ~"emp",
-            Literal(_) => ~"lit",
-            Concat(_) => ~"cat",
-            Alternate(_) => ~"alt",
-            Star(_) => ~"star",
-            Plus(_) => ~"plus",
-            Quest(_) => ~"que",
-            Capture(_, _) => ~"cap",
-            _ => fail
-        }
-    }
     fn dump(self, writer: @Writer) {
         writer.write_str(self.name());
         writer.write_char('{');
@@ -177,29 +1116,64 @@ impl Regexp {
             Literal(c) => {
                 writer.write_char(c);
             }
+            Class(ranges) => {
+                for ranges.eachi |i, r| {
+                    let (lo, hi) = *r;
+                    if i > 0 {
+                        writer.write_char(' ');
+                    }
+                    writer.write_str(fmt!("0x%x", lo as uint));
+                    if lo != hi {
+                        writer.write_str(fmt!("-0x%x", hi as uint));
+                    }
+                }
+            }
             Concat(subs) | Alternate(subs) => {
                 for subs.each |sub| {
                     sub.dump(writer);
                 }
             }
-            Star(sub) | Plus(sub) | Quest(sub) | Capture(_, sub) => {
+            Capture(_, name, sub) => {
+                match name {
+                    Some(name) => {
+                        writer.write_str(name);
+                        writer.write_char(':');
+                    }
+                    None => {}
+                }
+                sub.dump(writer);
+            }
+            LookAhead(sub) | NegLookAhead(sub) => {
+                sub.dump(writer);
+            }
+            Star(sub, greedy) | Plus(sub, greedy) | Quest(sub, greedy) => {
+                if !greedy {
+                    writer.write_str("lazy:");
+                }
                 sub.dump(writer);
             }
+            EmptyLook(look) => {
+                writer.write_str(look.to_str());
+            }
             _ => {}
         }
         writer.write_char('}');
     }
 }
 
+#[cfg(test)]
+fn dump(s: &str) -> ~str {
+    let result = parse(s);
+    assert result.is_ok();
+    do io::with_str_writer |writer| {
+        result.get().dump(writer);
+    }
+}
+
 #[test]
 fn test_parse() {
     fn test_ok(s: &str, t: &str) {
-        let result = parse(s);
-        assert result.is_ok();
-        let u = do io::with_str_writer |writer| {
-            result.get().dump(writer);
-        };
-        assert t == u;
+        assert t == dump(s);
     }
     test_ok("", "emp{}");
     test_ok("a", "lit{a}");
@@ -209,4 +1183,219 @@ fn test_parse() {
     test_ok("a+", "plus{lit{a}}");
     test_ok("a?", "que{lit{a}}");
     test_ok("(a)", "cap{lit{a}}");
+    test_ok("(?P<name>a)", "cap{name:lit{a}}");
+    test_ok("\\(\\*", "cat{lit{(}lit{*}}");
+    test_ok("[a-c]", "cc{0x61-0x63}");
+    test_ok("[^a]", "cc{0x0-0x60 0x62-0x10ffff}");
+    test_ok("[]a]", "cc{0x5d 0x61}");
+    test_ok("[a-]", "cc{0x2d 0x61}");
+    test_ok("[\\]-\\^]", "cc{0x5d-0x5e}");
+    test_ok(".", "cc{0x0-0x9 0xb-0x10ffff}");
+    test_ok("(?i)a", "cc{0x41 0x61}");
+    test_ok("(?i)k", "cc{0x4b 0x6b 0x212a}");
+    test_ok("(?i-u)k", "cc{0x4b 0x6b}");
+    test_ok("(?i)[a-c]", "cc{0x41-0x43 0x61-0x63}");
+    test_ok("(?i)1", "lit{1}");
+    test_ok("(?:a|b)c", "cat{alt{lit{a}lit{b}}lit{c}}");
+    test_ok("\\ba\\B", "cat{look{1}lit{a}look{2}}");
+    test_ok("(?i:a)a", "cat{cc{0x41 0x61}lit{a}}");
+    test_ok("a*?", "star{lazy:lit{a}}");
+    test_ok("(?U)a+a+?", "cat{plus{lazy:lit{a}}plus{lit{a}}}");
+    test_ok("^a$", "cat{look{5}lit{a}look{6}}");
+    test_ok("(?m)^$", "cat{look{7}look{8}}");
+    test_ok("(?s).", "cc{0x0-0x10ffff}");
+    test_ok("a(?=b)", "cat{lit{a}ahead{lit{b}}}");
+    test_ok("(?!a|b)*", "star{nahead{alt{lit{a}lit{b}}}}");
+    assert parse("\\").is_err();
+    assert parse("\\q").is_err();
+    assert parse("[a").is_err();
+    assert parse("[z-a]").is_err();
+    assert parse("\\p{Foo}").is_err();
+    assert parse("[[:foo:]]").is_err();
+    assert parse("[[:alpha]").is_err();
+    assert parse("(?-u)\\pL").is_err();
+    assert parse("(?z)").is_err();
+    assert parse("(?)").is_err();
+    assert parse("(?i-)").is_err();
+    assert parse("(?=a").is_err();
+    match parse("(a)\\1") {
+        Err(UnsupportedBackreference) => {}
+        _ => fail
+    }
+}
+
+#[test]
+fn test_numeric_escapes() {
+    fn test_ok(s: &str, t: &str) {
+        assert t == dump(s);
+    }
+    test_ok("\\x41\\x{1F600}", "cat{lit{A}lit{\U0001f600}}");
+    test_ok("\\u{e9}\\0", "cat{lit{\u00e9}lit{\x00}}");
+    test_ok("[\\x00-\\x1f\\u{7F}]", "cc{0x0-0x1f 0x7f}");
+    test_ok("(?i)\\x61", "cc{0x41 0x61}");
+    for ["\\x4", "\\xg0", "\\x{}", "\\x{41", "\\u41", "\\01",
+         "[\\x{zz}]"].each |s| {
+        match parse(*s) {
+            Err(InvalidEscape) => {}
+            _ => fail
+        }
+    }
+    for ["\\x{110000}", "\\u{d800}", "[\\x{fffffffff}]"].each |s| {
+        match parse(*s) {
+            Err(InvalidCodePoint) => {}
+            _ => fail
+        }
+    }
+}
+
+#[test]
+fn test_to_str() {
+    fn test(s: &str) {
+        assert parse(s).get().to_str() == s.to_str();
+    }
+    test("");
+    test("ab|c|");
+    test("(a|b)*c+d?");
+    test("(?P<x>a(b))");
+    test("\\(\\*\\\\");
+    test("\\bx\\B");
+    test("^a*?b+?c??$");
+    test("(?m:^)a(?m:$)");
+    test("a(?=b|c)(?!d)*");
+    test("a[^\\x00-\\x{10ffff}]");
+    fn test_bytes(s: &str) {
+        let p = Parser::new();
+        p.bytes = true;
+        assert p.parse(s).get().to_bytes_str() == s.to_str();
+    }
+    test_bytes("a\\xff\\x80*");
+    test_bytes("[\\x00-\\x7f\\xc0-\\xff]");
+    test_bytes("a[^\\x00-\\xff]");
+    test_bytes("\\xc3\\xa9");
+}
+
+#[test]
+fn test_to_dot() {
+    let dot = parse("a|(?P<x>\"*?)").get().to_dot();
+    assert dot == ~"digraph regexp {\n" +
+        ~"  n0 [label=\"alt\\na|(?P<x>\\\"*?)\"];\n" +
+        ~"  n1 [label=\"lit\\na\"];\n" +
+        ~"  n0 -> n1;\n" +
+        ~"  n2 [label=\"cap 1 x\\n(?P<x>\\\"*?)\"];\n" +
+        ~"  n3 [label=\"star lazy\\n\\\"*?\"];\n" +
+        ~"  n4 [label=\"lit\\n\\\"\"];\n" +
+        ~"  n3 -> n4;\n" +
+        ~"  n2 -> n3;\n" +
+        ~"  n0 -> n2;\n" +
+        ~"}\n";
+}
+
+#[test]
+fn test_parse_with() {
+    fn test(s: &str, opts: &ParseOptions, t: &str) {
+        let re = parse_with(s, opts);
+        assert re.is_ok();
+        assert re.get().to_str() == t.to_str();
+    }
+    let opts = ParseOptions::new();
+    test("a+", &opts, "a+");
+    assert parse_with("\\012", &opts).is_err();
+    opts.case_insensitive = true;
+    test("a", &opts, "[Aa]");
+    opts.case_insensitive = false;
+    opts.multi_line = true;
+    test("^$", &opts, "(?m:^)(?m:$)");
+    opts.multi_line = false;
+    opts.ungreedy = true;
+    test("a+b+?", &opts, "a+?b+");
+    opts.ungreedy = false;
+    opts.allow_octal_escapes = true;
+    test("\\101\\0[\\102]", &opts, "A\x00[B]");
+    assert parse_with("\\1", &opts).is_err();
+    opts.allow_octal_escapes = false;
+    assert parse_with("\\q", &opts).is_err();
+    opts.literal_unknown_escapes = true;
+    test("\\%[\\~]\\.\\b", &opts, "%[~]\\.\\b");
+    for ["\\q", "\\n", "\\d+", "[\\t]", "[\\w]", "(a)\\1"].each |s| {
+        assert parse_with(*s, &opts).is_err();
+    }
+    opts.literal_unknown_escapes = false;
+    opts.nest_limit = 1;
+    assert parse_with("((a))", &opts).is_err();
+    opts.nest_limit = default_nest_limit;
+    opts.dot_matches_nl = true;
+    assert parse_with(".", &opts).get().to_str() != parse(".").get().to_str();
+    opts.dot_matches_nl = false;
+    opts.ignore_whitespace = true;
+    test("a b\n\t* # comment\n c", &opts, "ab*c");
+    test("a\\ \\#[ #]", &opts, "a #[ #]");
+}
+
+#[test]
+fn test_verbose() {
+    assert dump("(?x) a | b # c") == ~"alt{lit{a}lit{b}}";
+    assert dump("(?x: a ) b") == ~"cat{lit{a}lit{ }lit{b}}";
+    assert dump("(?x)\\ ") == ~"lit{ }";
+    assert parse("\\ ").is_err();
+}
+
+#[test]
+fn test_quote() {
+    assert quote("a+b") == ~"a\\+b";
+    assert quote("\\(x|y)?*") == ~"\\\\\\(x\\|y\\)\\?\\*";
+    let s = "(a|b)*+?\\c";
+    let re = parse(quote(s)).get();
+    let u = do io::with_str_writer |writer| {
+        re.dump(writer);
+    };
+    let mut t = ~"cat{";
+    for str::each_char(s) |c| {
+        t += ~"lit{" + str::from_char(c) + ~"}";
+    }
+    t += ~"}";
+    assert t == u;
+}
+
+#[test]
+fn test_unicode_class() {
+    fn test_same(s: &str, t: &str) {
+        assert dump(s) == dump(t);
+    }
+    test_same("\\PN", "[^\\pN]");
+    test_same("\\p{^N}", "\\PN");
+    test_same("\\P{^N}", "\\pN");
+    test_same("\\p{N}", "\\pN");
+    test_same("[\\p{Lu}\\p{Ll}\\p{Lt}\\p{Lm}\\p{Lo}]", "\\pL");
+    test_same("\\p{Any}", "[\x00-\U0010ffff]");
+    test_same("[[:alpha:][:digit:]_]", "[A-Za-z0-9_]");
+    test_same("[[:word:]]", "[A-Za-z0-9_]");
+    test_same("[[:^digit:]]", "[^0-9]");
+    test_same("[^[:space:]]", "[^\t\n\x0b\x0c\r ]");
+    test_same("[[:punct:]]", "[!-/:-@\\[-`{-~]");
+}
+
+#[test]
+fn test_groups() {
+    let p = Parser::new();
+    assert p.parse("(?P<x>a)(b(c))").is_ok();
+    assert p.ncap == 3;
+    assert p.groups[0].name == Some(~"x");
+    assert p.groups[1].name.is_none();
+    assert (p.groups[0].start, p.groups[0].end) == (0, 8);
+    assert (p.groups[1].start, p.groups[1].end) == (8, 14);
+    assert (p.groups[2].start, p.groups[2].end) == (10, 13);
+    assert parse("(?P<>a)").is_err();
+    assert parse("(?P<a-b>a)").is_err();
+    assert parse("(?x)").is_ok();
+    assert parse("(?y)").is_err();
+}
+
+#[test]
+fn test_nest_limit() {
+    let p = Parser::new();
+    p.nest_limit = 3;
+    assert p.parse("((()))()").is_ok();
+    let p = Parser::new();
+    p.nest_limit = 3;
+    assert p.parse("(((())))").is_err();
 }
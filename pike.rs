@@ -0,0 +1,242 @@
+use compile::{Prog, InstMatch, InstChar, InstClass, InstAlt, InstSave,
+              InstEmpty, InstLook};
+use charclass::contains;
+
+struct Machine {
+    prog: @Prog,
+    // The pcs of the threads at the current and the next position, and
+    // their capture slots, nslot per thread, in the same order. They keep
+    // their size from run to run, so a kept machine allocates nothing.
+    mut clist: ~[uint],
+    mut nlist: ~[uint],
+    mut ccaps: ~[Option<uint>],
+    mut ncaps: ~[Option<uint>],
+    // The slots of the thread being added.
+    mut caps: ~[Option<uint>],
+    mut nslot: uint,
+    mut seen: ~[bool],
+    // The slots of the match found by the last run, if found is set.
+    mut matched: ~[Option<uint>],
+    mut found: bool,
+    // Find the longest match instead of the one of highest priority.
+    mut longest: bool,
+    // Stop at the first match end found, without tracking captures.
+    mut earliest: bool,
+    // The furthest position the last run looked at the input, counting
+    // assertions that looked at the char there.
+    mut horizon: uint,
+    // Where the last match found ends.
+    mut end: uint,
+    // The machine lookahead assertions are run on, made when first
+    // needed.
+    mut looker: Option<@Machine>
+}
+
+impl Machine {
+    // Lookahead assertions are run as searches of their own, so texts
+    // too long for the backtracker can be searched, but in time that may
+    // grow with the square of their length.
+    static fn new(prog: @Prog) -> Machine {
+        let nslot = 2 * (prog.ncap + 1);
+        Machine {
+            prog: prog,
+            clist: ~[],
+            nlist: ~[],
+            ccaps: ~[],
+            ncaps: ~[],
+            caps: vec::from_elem(nslot, None),
+            nslot: nslot,
+            seen: vec::from_elem(prog.inst.len(), false),
+            matched: vec::from_elem(nslot, None),
+            found: false,
+            longest: false,
+            earliest: false,
+            horizon: 0,
+            end: 0,
+            looker: None
+        }
+    }
+    fn clear(&self) {
+        for uint::range(0, self.seen.len()) |i| {
+            self.seen[i] = false;
+        }
+    }
+    // Follow empty transitions from pc with the slots in caps and queue
+    // the resulting threads on nlist, in priority order. caps is left as
+    // it was.
+    fn add(&self, input: &[u8], pc: uint, pos: uint) {
+        if self.seen[pc] {
+            return;
+        }
+        self.seen[pc] = true;
+        match self.prog.inst[pc] {
+            InstAlt(x, y) => {
+                self.add(input, x, pos);
+                self.add(input, y, pos);
+            }
+            InstSave(n, next) => {
+                if n < self.nslot {
+                    let old = self.caps[n];
+                    self.caps[n] = Some(pos);
+                    self.add(input, next, pos);
+                    self.caps[n] = old;
+                } else {
+                    self.add(input, next, pos);
+                }
+            }
+            InstEmpty(look, next) => {
+                if pos > self.horizon {
+                    self.horizon = pos;
+                }
+                if self.prog.look(input, pos, look) {
+                    self.add(input, next, pos);
+                }
+            }
+            InstLook(negated, sub, next) => {
+                if self.lookahead(input, sub, pos) != negated {
+                    self.add(input, next, pos);
+                }
+            }
+            _ => {
+                self.nlist.push(pc);
+                for uint::range(0, self.nslot) |n| {
+                    self.ncaps.push(self.caps[n]);
+                }
+            }
+        }
+    }
+    // Set caps to the slots of thread i of clist.
+    fn load(&self, i: uint) {
+        for uint::range(0, self.nslot) |n| {
+            self.caps[n] = self.ccaps[i * self.nslot + n];
+        }
+    }
+    fn reset_caps(&self) {
+        for uint::range(0, self.nslot) |n| {
+            self.caps[n] = None;
+        }
+    }
+    // Whether the lookahead program at pc matches at pos.
+    fn lookahead(&self, input: &[u8], pc: uint, pos: uint) -> bool {
+        let m = match self.looker {
+            Some(m) => m,
+            None => {
+                let m = @Machine::new(self.prog);
+                m.earliest = true;
+                self.looker = Some(m);
+                m
+            }
+        };
+        let found = m.search_from(input, pc, pos, true, false);
+        if m.horizon > self.horizon {
+            self.horizon = m.horizon;
+        }
+        return found;
+    }
+    // Run the program on input starting at start. If anchored is set,
+    // the match must begin at start; if anchor_end is set, it must end
+    // at the end of input, or at its start for a reversed program. Unless
+    // the program is in bytes mode, input must be valid UTF-8.
+    fn run(&self, input: &[u8], start: uint, anchored: bool, anchor_end: bool)
+        -> Option<~[Option<uint>]> {
+        if !self.search(input, start, anchored, anchor_end) {
+            return None;
+        }
+        Some(vec::slice(self.matched, 0, self.nslot))
+    }
+    // Like run, but leave the slots of the match in matched, so that
+    // nothing is allocated.
+    fn search(&self, input: &[u8], start: uint, anchored: bool,
+              anchor_end: bool) -> bool {
+        self.search_from(input, self.prog.start, start, anchored, anchor_end)
+    }
+    // Like search, for the program starting at pc.
+    fn search_from(&self, input: &[u8], pc: uint, start: uint,
+                   anchored: bool, anchor_end: bool) -> bool {
+        self.nslot = if self.earliest { 0 } else { 2 * (self.prog.ncap + 1) };
+        let nslot = self.nslot;
+        let mut pos = start;
+        self.horizon = start;
+        self.found = false;
+        // A run stopped early leaves threads behind, which a machine kept
+        // for another run must not see.
+        self.nlist.truncate(0);
+        self.ncaps.truncate(0);
+        self.clear();
+        self.reset_caps();
+        self.add(input, pc, pos);
+        loop {
+            self.clist <-> self.nlist;
+            self.ccaps <-> self.ncaps;
+            self.nlist.truncate(0);
+            self.ncaps.truncate(0);
+            self.clear();
+            if self.clist.is_empty() {
+                break;
+            }
+            if pos > self.horizon {
+                self.horizon = pos;
+            }
+            let (c, next) = self.prog.step(input, pos);
+            let mut step_matched = false;
+            for uint::range(0, self.clist.len()) |i| {
+                match self.prog.inst[self.clist[i]] {
+                    InstMatch => {
+                        if step_matched {
+                            again;
+                        }
+                        if !anchor_end || self.prog.at_end(input, pos) {
+                            for uint::range(0, nslot) |n| {
+                                self.matched[n] = self.ccaps[i * nslot + n];
+                            }
+                            self.found = true;
+                            self.end = pos;
+                            step_matched = true;
+                            if !self.longest {
+                                // Lower priority threads are cut off.
+                                break;
+                            }
+                        }
+                    }
+                    InstChar(ch, x) => {
+                        match c {
+                            Some(d) if d == ch => {
+                                self.load(i);
+                                self.add(input, x, next);
+                            }
+                            _ => {}
+                        }
+                    }
+                    InstClass(ranges, x) => {
+                        match c {
+                            Some(d) if contains(*ranges, d) => {
+                                self.load(i);
+                                self.add(input, x, next);
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            if c.is_none() || (self.earliest && step_matched) {
+                break;
+            }
+            pos = next;
+            if !anchored && !self.found {
+                self.reset_caps();
+                self.add(input, pc, pos);
+            }
+        }
+        return self.found;
+    }
+    // The end of the first match found by run, which is where the
+    // shortest match ends. Higher priority matches may end later.
+    fn shortest(&self, input: &[u8], start: uint, anchored: bool)
+        -> Option<uint> {
+        self.earliest = true;
+        let found = self.search(input, start, anchored, false);
+        self.earliest = false;
+        if found { Some(self.end) } else { None }
+    }
+}
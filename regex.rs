@@ -0,0 +1,1536 @@
+use result::{Result, Ok, Err};
+use parse::{Error, Group, Parser, Concat, EmptyLook, UnsupportedFeature,
+            InvalidProgram, default_nest_limit, flag_unicode, flag_multi_line,
+            look_not_after_word, look_not_before_word};
+use compile::{Prog, Compiler, default_size_limit};
+use std::time::precise_time_ns;
+use private::global::global_data_modify;
+use pike::Machine;
+use backtrack::{BitState, should_backtrack, fits_bitmap};
+use onepass::OnePass;
+use literals::{LiteralSet, required_literal, spans_lines, find_literal,
+               find_byte};
+use charclass::{contains, to_case};
+use unicode::{category_ll, category_lu, lower_table, upper_table,
+              title_table};
+use template::{parse_template, expand_pieces};
+
+struct Config {
+    // Only match at the starting position of a search.
+    mut anchored: bool,
+    // Maximum nesting depth of groups in the pattern.
+    mut nest_limit: uint,
+    // Maximum number of instructions in the compiled program.
+    mut size_limit: uint,
+    // Whether \p classes and Unicode case folding are allowed. Patterns
+    // can still turn them on or off with (?u) and (?-u).
+    mut unicode: bool,
+    // Match bytes instead of UTF-8 text. ASCII chars and classes in the
+    // pattern, including . and negated classes, then stand for bytes,
+    // and other chars for their UTF-8 encoding. Unicode classes and
+    // Unicode case folding are not available.
+    mut bytes: bool,
+    // Maximum time in milliseconds to spend parsing and compiling, after
+    // which compilation fails with CompileTimeout.
+    mut time_limit: Option<u64>,
+    // Only match whole words: a match may not be preceded or followed by
+    // a word char. Unlike wrapping the pattern in \b, this also works for
+    // patterns starting or ending with a non-word char.
+    mut word: bool,
+    // Let ^ and $ also match after and before each \n, as (?m) does.
+    mut multi_line: bool
+}
+
+impl Config {
+    static fn new() -> Config {
+        Config {
+            anchored: false,
+            nest_limit: default_nest_limit,
+            size_limit: default_size_limit,
+            unicode: true,
+            bytes: false,
+            time_limit: None,
+            word: false,
+            multi_line: false
+        }
+    }
+    // The configuration used by Regex::new and friends: the one given to
+    // set_default_config, if any, or else the built-in one.
+    static fn default() -> Config {
+        let mut found = None;
+        unsafe {
+            do global_data_modify(default_config_key) |config| {
+                match config {
+                    Some(config) => {
+                        found = Some(copy *config);
+                        Some(config)
+                    }
+                    None => None
+                }
+            }
+        }
+        match found {
+            Some(config) => config,
+            None => Config::new()
+        }
+    }
+}
+
+fn default_config_key(_config: Config) {}
+
+// Set the configuration used by Regex::new and friends in every task of
+// the process, for instance to enforce size limits throughout an
+// application. It can only be set once, best at startup; later calls
+// return false and have no effect. The default is kept in global data,
+// which is locked while it is read or set, so tasks may compile regexes
+// while another sets it.
+fn set_default_config(config: &Config) -> bool {
+    let mut set = false;
+    unsafe {
+        do global_data_modify(default_config_key) |old| {
+            match old {
+                Some(old) => Some(old),
+                None => {
+                    set = true;
+                    Some(~copy *config)
+                }
+            }
+        }
+    }
+    return set;
+}
+
+// The Unicode version of the tables classes, case folding and case
+// mapping are built from. Matching depends on these tables only, never on
+// the locale.
+fn unicode_version() -> (uint, uint, uint) {
+    unicode::unicode_version
+}
+
+// s with each char mapped by table, one of the simple case mappings.
+fn map_case(table: &[(char, char)], s: &str) -> ~str {
+    let mut t = ~"";
+    for str::each_char(s) |c| {
+        str::push_char(&mut t, to_case(table, c));
+    }
+    return t;
+}
+
+// The tables transfer_case looks chars up in, built once for all the
+// replacements of a call.
+struct CaseTables {
+    // The chars of categories Lu and Ll, which tell the case of from.
+    upper: ~[(char, char)],
+    lower: ~[(char, char)],
+    // The case mappings applied to to.
+    to_upper: ~[(char, char)],
+    to_lower: ~[(char, char)],
+    to_title: ~[(char, char)]
+}
+
+impl CaseTables {
+    static fn new() -> CaseTables {
+        CaseTables {
+            upper: category_lu(),
+            lower: category_ll(),
+            to_upper: upper_table(),
+            to_lower: lower_table(),
+            to_title: title_table()
+        }
+    }
+}
+
+// Apply the case pattern of from to to. Only all upper case, capitalized
+// and all lower case are recognized; otherwise to is left unchanged.
+fn transfer_case(tables: &CaseTables, from: &str, to: &str) -> ~str {
+    let mut upper = 0;
+    let mut lower = 0;
+    let mut first_upper = false;
+    for str::each_char(from) |c| {
+        if contains(tables.upper, c) {
+            if upper == 0 && lower == 0 {
+                first_upper = true;
+            }
+            upper += 1;
+        } else if contains(tables.lower, c) {
+            lower += 1;
+        }
+    }
+    if upper > 1 && lower == 0 {
+        return map_case(tables.to_upper, to);
+    }
+    if upper == 0 && lower > 0 {
+        return map_case(tables.to_lower, to);
+    }
+    if upper == 1 && first_upper && to.is_not_empty() {
+        let n = str::char_range_at(to, 0).next;
+        return map_case(tables.to_title, str::view(to, 0, n)) +
+            map_case(tables.to_lower, str::view(to, n, to.len()));
+    }
+    return to.to_str();
+}
+
+// Where the search following a match from start to end in s begins, if
+// anywhere: an empty match moves the search on by one char.
+fn next_search(s: &str, start: uint, end: uint) -> Option<uint> {
+    if start < end {
+        Some(end)
+    } else if end < s.len() {
+        Some(str::char_range_at(s, end).next)
+    } else {
+        None
+    }
+}
+
+// Where a match is in a text, as byte offsets, for slicing the text.
+struct Span {
+    start: uint,
+    end: uint
+}
+
+impl Span {
+    fn len(&self) -> uint {
+        self.end - self.start
+    }
+    // The matched text of s.
+    fn slice(&self, s: &str) -> ~str {
+        str::slice(s, self.start, self.end)
+    }
+    // The char indices of the span in s, for reporting columns. This
+    // counts the chars of s up to the end of the span.
+    fn char_span(&self, s: &str) -> (uint, uint) {
+        let start = str::count_chars(s, 0, self.start);
+        (start, start + str::count_chars(s, self.start, self.end))
+    }
+}
+
+// The groups of a match, with the text they were found in.
+struct Captures {
+    text: ~str,
+    // The capture slots, as Regex::exec returns them.
+    locs: ~[Option<uint>],
+    names: ~[Option<~str>]
+}
+
+impl Captures {
+    // Number of groups, counting the whole match as group 0.
+    fn len(&self) -> uint {
+        self.names.len()
+    }
+    // Where group i matched, unless it did not take part in the match.
+    fn pos(&self, i: uint) -> Option<Span> {
+        if i >= self.len() {
+            return None;
+        }
+        match (self.locs[2 * i], self.locs[2 * i + 1]) {
+            (Some(start), Some(end)) => Some(Span {start: start, end: end}),
+            _ => None
+        }
+    }
+    // The text group i matched.
+    fn at(&self, i: uint) -> Option<~str> {
+        match self.pos(i) {
+            Some(span) => Some(span.slice(self.text)),
+            None => None
+        }
+    }
+    // The text the group named name matched.
+    fn name(&self, name: &str) -> Option<~str> {
+        for self.names.eachi |i, n| {
+            match *n {
+                Some(ref n) if str::eq_slice(*n, name) => return self.at(i),
+                _ => {}
+            }
+        }
+        return None;
+    }
+    // template with $1, ${name} and the like replaced by the text of the
+    // groups they refer to, as described in template.rs.
+    fn expand(&self, template: &str) -> ~str {
+        let mut t = ~"";
+        self.expand_into(template, &mut t);
+        return t;
+    }
+    // Like expand, appending to dst.
+    fn expand_into(&self, template: &str, dst: &mut ~str) {
+        expand_pieces(parse_template(template), self.text, self.locs,
+                      self.names, dst);
+    }
+}
+
+// The matches of a regex in a text, and for each the furthest offset
+// looked at in finding it, which rescan uses to tell which matches an
+// edit can change.
+struct Scan {
+    mut matches: ~[(uint, uint)],
+    mut horizons: ~[uint]
+}
+
+// Scratch space for searches with a regex, made by Regex::cache and kept
+// between searches so that matching many strings in a row allocates
+// nothing once it has grown to the size they need.
+struct MatchCache {
+    // The engines the searches needed so far.
+    mut machine: Option<@Machine>,
+    mut backtrack: Option<@BitState>,
+    // The slots of the match found by the last search, and those the
+    // one-pass engine works on.
+    mut slots: ~[Option<uint>],
+    mut scratch: ~[Option<uint>]
+}
+
+impl MatchCache {
+    fn machine_for(&self, prog: @Prog) -> @Machine {
+        match self.machine {
+            Some(m) => m,
+            None => {
+                let m = @Machine::new(prog);
+                self.machine = Some(m);
+                m
+            }
+        }
+    }
+    // A backtracker ready to search len bytes.
+    fn backtrack_for(&self, prog: @Prog, len: uint) -> @BitState {
+        match self.backtrack {
+            Some(b) => {
+                b.reset(len);
+                b
+            }
+            None => {
+                let b = @BitState::new(prog, len);
+                self.backtrack = Some(b);
+                b
+            }
+        }
+    }
+}
+
+// Offsets are uints, as wide as pointers, so any text that fits in memory
+// can be searched, beyond 4 GiB on 64-bit targets. Searches need memory in
+// proportion to the pattern, except for the bitmaps of the backtracker,
+// which is only run while they fit in fixed budgets. Past them, patterns
+// with lookahead assertions are run by the Pike VM, which has no limit,
+// but may take time growing with the square of the text length.
+struct Regex {
+    prog: @Prog,
+    // The program compiled to match backwards, unless the pattern has
+    // lookahead assertions.
+    rprog: Option<@Prog>,
+    // Anchored searches use this instead if the program is one-pass.
+    onepass: Option<@OnePass>,
+    // Searches use this instead if the pattern is an alternation of
+    // literals.
+    literals: Option<@LiteralSet>,
+    // The bytes of a literal every match contains. Searches of text
+    // without it end without running the engine, which makes scanning
+    // large texts for rare matches cheap.
+    required: Option<~[u8]>,
+    // Whether no match can span lines, so that searches with a required
+    // literal only run the engine on the lines holding it.
+    windowed: bool,
+    groups: ~[Group]
+}
+
+impl Regex {
+    static fn new(s: &str) -> Result<Regex, Error> {
+        Regex::with_config(s, &Config::default())
+    }
+    // Like new, but in bytes mode, for use with the _bytes methods.
+    static fn new_bytes(s: &str) -> Result<Regex, Error> {
+        let config = Config::default();
+        config.bytes = true;
+        Regex::with_config(s, &config)
+    }
+    // Like new, but every search only matches at its starting position.
+    static fn new_anchored(s: &str) -> Result<Regex, Error> {
+        let config = Config::default();
+        config.anchored = true;
+        Regex::with_config(s, &config)
+    }
+    // Like new, but only matching whole words.
+    static fn new_word(s: &str) -> Result<Regex, Error> {
+        let config = Config::default();
+        config.word = true;
+        Regex::with_config(s, &config)
+    }
+    static fn with_config(s: &str, config: &Config) -> Result<Regex, Error> {
+        let deadline = match config.time_limit {
+            Some(ms) => Some(precise_time_ns() + ms * 1000000),
+            None => None
+        };
+        let p = Parser::new();
+        p.nest_limit = config.nest_limit;
+        p.deadline = deadline;
+        p.flags = if config.unicode { flag_unicode } else { 0 };
+        if config.multi_line {
+            p.flags |= flag_multi_line;
+        }
+        p.bytes = config.bytes;
+        let re = match p.parse(s) {
+            Ok(re) => re,
+            Err(e) => return Err(e)
+        };
+        let re = if config.word {
+            @Concat(~[@EmptyLook(look_not_after_word), re,
+                      @EmptyLook(look_not_before_word)])
+        } else {
+            re
+        };
+        let c = Compiler::new();
+        c.size_limit = config.size_limit;
+        c.deadline = deadline;
+        let prog = match c.compile(re, config.anchored, config.bytes) {
+            Ok(prog) => prog,
+            Err(e) => return Err(e)
+        };
+        let c = Compiler::new();
+        c.size_limit = config.size_limit;
+        c.deadline = deadline;
+        c.reversed = true;
+        let rprog = match c.compile(re, true, config.bytes) {
+            Ok(rprog) => Some(@rprog),
+            Err(UnsupportedFeature) => None,
+            Err(e) => return Err(e)
+        };
+        let prog = @prog;
+        let onepass = match OnePass::new(prog) {
+            Some(onepass) => Some(@onepass),
+            None => None
+        };
+        let literals = match LiteralSet::from_regexp(re, config.bytes) {
+            Some(literals) => Some(@literals),
+            None => None
+        };
+        let required = match required_literal(re, config.bytes) {
+            Some(literal) if literals.is_none() => Some(literal),
+            _ => None
+        };
+        let groups = ~[Group {name: None, start: 0, end: s.len()}] + p.groups;
+        return Ok(Regex {
+            prog: prog,
+            rprog: rprog,
+            onepass: onepass,
+            literals: literals,
+            required: required,
+            windowed: !spans_lines(re),
+            groups: groups
+        });
+    }
+    // A regex running prog, as Prog::decode gives it, so that patterns
+    // compiled ahead of time are not parsed again. A program keeps nothing
+    // else of its pattern, so the regex has no literal shortcuts, group
+    // names or reversed program, and its groups have empty spans. Reversed
+    // programs, which only match backwards, fail with InvalidProgram.
+    static fn from_prog(prog: Prog) -> Result<Regex, Error> {
+        if prog.reversed {
+            return Err(InvalidProgram);
+        }
+        let prog = @prog;
+        let onepass = match OnePass::new(prog) {
+            Some(onepass) => Some(@onepass),
+            None => None
+        };
+        let group = Group {name: None, start: 0, end: 0};
+        return Ok(Regex {
+            prog: prog,
+            rprog: None,
+            onepass: onepass,
+            literals: None,
+            required: None,
+            windowed: false,
+            groups: vec::from_elem(prog.ncap + 1, group)
+        });
+    }
+    fn exec(&self, s: &str, start: uint, anchored: bool, anchor_end: bool)
+        -> Option<~[Option<uint>]> {
+        do str::byte_slice(s) |b| {
+            self.exec_bytes(b, start, anchored, anchor_end)
+        }
+    }
+    // Only regexes in bytes mode may be run on input that is not UTF-8.
+    fn exec_bytes(&self, s: &[u8], start: uint, anchored: bool,
+                  anchor_end: bool) -> Option<~[Option<uint>]> {
+        let cache = self.cache();
+        if !self.exec_with(&cache, s, start, anchored, anchor_end, false) {
+            return None;
+        }
+        let mut slots = ~[];
+        slots <-> cache.slots;
+        return Some(slots);
+    }
+    // Like exec_bytes, with the scratch space of cache, leaving the slots
+    // of the match in cache.slots. If earliest is set, the search only
+    // finds out whether there is a match, and the slots may not be set.
+    fn exec_with(&self, cache: &MatchCache, s: &[u8], start: uint,
+                 anchored: bool, anchor_end: bool, earliest: bool) -> bool {
+        let anchored = anchored || self.prog.anchored;
+        match self.literals {
+            Some(literals) => {
+                match literals.find(s, start, anchored, anchor_end) {
+                    Some((i, j)) => {
+                        cache.slots[0] = Some(i);
+                        cache.slots[1] = Some(j);
+                        return true;
+                    }
+                    None => return false
+                }
+            }
+            None => {}
+        }
+        let (found, _) = do self.each_window(s, start, anchored,
+                                             anchor_end) |end, pos| {
+            self.exec_engine(cache, vec::view(s, 0, end), pos, anchored,
+                             anchor_end, earliest)
+        };
+        return found;
+    }
+    // Run search on the parts of s from start on that may hold the
+    // leftmost match, given as the end of the text to search and where to
+    // start in it, until it finds one. Without the required literal, no
+    // part is searched; when matches cannot span lines, only the lines
+    // holding it are. Also returns the furthest offset looked at in
+    // picking the parts.
+    fn each_window(&self, s: &[u8], start: uint, anchored: bool,
+                   anchor_end: bool, search: fn(uint, uint) -> bool)
+        -> (bool, uint) {
+        let literal = match self.required {
+            Some(ref literal) => vec::view(*literal, 0, literal.len()),
+            None => return (search(s.len(), start), start)
+        };
+        let mut pos = start;
+        let mut horizon = start;
+        loop {
+            let i = match find_literal(s, literal, pos) {
+                Some(i) => i,
+                None => return (false, s.len())
+            };
+            if i + literal.len() - 1 > horizon {
+                horizon = i + literal.len() - 1;
+            }
+            if anchored || anchor_end || !self.windowed {
+                return (search(s.len(), start), horizon);
+            }
+            // The line holding the occurrence, which a match holding it
+            // cannot leave.
+            let mut line = i;
+            while line > pos && s[line - 1] != '\n' as u8 {
+                line -= 1;
+            }
+            let end = match find_byte(s, '\n' as u8, i + literal.len()) {
+                Some(end) => end,
+                None => s.len()
+            };
+            if end > horizon {
+                horizon = end;
+            }
+            if search(end, line) {
+                return (true, horizon);
+            }
+            if end == s.len() {
+                return (false, horizon);
+            }
+            pos = end + 1;
+        }
+    }
+    // Like exec_with, running the engine suited to the search on all of s.
+    fn exec_engine(&self, cache: &MatchCache, s: &[u8], start: uint,
+                   anchored: bool, anchor_end: bool, earliest: bool) -> bool {
+        if anchored {
+            match self.onepass {
+                Some(onepass) => {
+                    return onepass.search(s, start, anchor_end,
+                                          &mut cache.scratch,
+                                          &mut cache.slots);
+                }
+                None => {}
+            }
+        }
+        // The backtracker runs lookahead assertions in linear time, while
+        // the machine runs each as a search of its own.
+        if should_backtrack(self.prog, s.len()) ||
+            self.prog.lookahead && fits_bitmap(self.prog, s.len()) {
+            let b = cache.backtrack_for(self.prog, s.len());
+            if !b.search(s, start, anchored, anchor_end) {
+                return false;
+            }
+            for uint::range(0, b.caps.len()) |n| {
+                cache.slots[n] = b.caps[n];
+            }
+            return true;
+        }
+        let m = cache.machine_for(self.prog);
+        m.earliest = earliest;
+        let found = m.search(s, start, anchored, anchor_end);
+        m.earliest = false;
+        if found && !earliest {
+            for uint::range(0, m.nslot) |n| {
+                cache.slots[n] = m.matched[n];
+            }
+        }
+        return found;
+    }
+    // Heap bytes held by the compiled regex. Searches allocate their
+    // scratch space per call, so nothing else is retained.
+    fn memory_usage(&self) -> uint {
+        let mut size = sys::size_of::<Prog>() + self.prog.memory_usage();
+        match self.rprog {
+            Some(rprog) => {
+                size += sys::size_of::<Prog>() + rprog.memory_usage();
+            }
+            None => {}
+        }
+        match self.onepass {
+            Some(onepass) => {
+                size += sys::size_of::<OnePass>() + onepass.memory_usage();
+            }
+            None => {}
+        }
+        match self.literals {
+            Some(literals) => {
+                size += sys::size_of::<LiteralSet>() +
+                    literals.memory_usage();
+            }
+            None => {}
+        }
+        match self.required {
+            Some(ref required) => size += required.len(),
+            None => {}
+        }
+        size += self.groups.len() * sys::size_of::<Group>();
+        for self.groups.each |group| {
+            match group.name {
+                Some(ref name) => size += name.len(),
+                None => {}
+            }
+        }
+        return size;
+    }
+    // Number of capture groups, counting the whole match as group 0.
+    fn captures_len(&self) -> uint {
+        self.groups.len()
+    }
+    fn capture_names(&self) -> ~[Option<~str>] {
+        self.groups.map(|g| copy g.name)
+    }
+    // Byte offsets of group i in the pattern, parentheses included.
+    fn capture_span(&self, i: uint) -> Option<(uint, uint)> {
+        if i >= self.groups.len() {
+            return None;
+        }
+        Some((self.groups[i].start, self.groups[i].end))
+    }
+    fn is_match(&self, s: &str) -> bool {
+        self.exec(s, 0, false, false).is_some()
+    }
+    // Byte offsets of the leftmost match at or after start.
+    fn find_at(&self, s: &str, start: uint) -> Option<(uint, uint)> {
+        match self.exec(s, start, false, false) {
+            Some(caps) => Some((caps[0].get(), caps[1].get())),
+            None => None
+        }
+    }
+    fn find(&self, s: &str) -> Option<(uint, uint)> {
+        self.find_at(s, 0)
+    }
+    // Like find_at and find, giving a Span.
+    fn find_span_at(&self, s: &str, start: uint) -> Option<Span> {
+        match self.find_at(s, start) {
+            Some((start, end)) => Some(Span {start: start, end: end}),
+            None => None
+        }
+    }
+    fn find_span(&self, s: &str) -> Option<Span> {
+        self.find_span_at(s, 0)
+    }
+    // Scratch space for is_match_with and find_with. It may only be used
+    // with this regex.
+    fn cache(&self) -> MatchCache {
+        let nslot = 2 * (self.prog.ncap + 1);
+        MatchCache {
+            machine: None,
+            backtrack: None,
+            slots: vec::from_elem(nslot, None),
+            scratch: vec::from_elem(nslot, None)
+        }
+    }
+    // Like is_match and find, reusing cache. They take the same ways to
+    // a match as the other searches, but allocate nothing once cache has
+    // grown to the size the regex and the texts need.
+    fn is_match_with(&self, cache: &MatchCache, s: &str) -> bool {
+        do str::byte_slice(s) |b| {
+            self.exec_with(cache, b, 0, false, false, true)
+        }
+    }
+    fn find_with(&self, cache: &MatchCache, s: &str) -> Option<(uint, uint)> {
+        self.find_at_with(cache, s, 0)
+    }
+    fn find_at_with(&self, cache: &MatchCache, s: &str, start: uint)
+        -> Option<(uint, uint)> {
+        let found = do str::byte_slice(s) |b| {
+            self.exec_with(cache, b, start, false, false, false)
+        };
+        if found {
+            Some((cache.slots[0].get(), cache.slots[1].get()))
+        } else {
+            None
+        }
+    }
+    // The groups of the leftmost match in s.
+    fn captures(&self, s: &str) -> Option<Captures> {
+        match self.exec(s, 0, false, false) {
+            Some(caps) => Some(Captures {
+                text: s.to_str(),
+                locs: caps,
+                names: self.capture_names()
+            }),
+            None => None
+        }
+    }
+    // The spans of the matches each_match finds.
+    fn spans(&self, s: &str) -> ~[Span] {
+        let mut spans = ~[];
+        for self.each_match(s) |start, end| {
+            spans.push(Span {start: start, end: end});
+        }
+        return spans;
+    }
+    // The end of some match, found as early as possible, for callers who
+    // only ask whether there is one and where it can end. This is not
+    // where find's match ends: "a+" in "aaa" ends at 1 here.
+    fn shortest_match(&self, s: &str) -> Option<uint> {
+        if self.prog.lookahead {
+            return match self.find(s) {
+                Some((_, end)) => Some(end),
+                None => None
+            };
+        }
+        let m = Machine::new(self.prog);
+        do str::byte_slice(s) |b| {
+            m.shortest(b, 0, self.prog.anchored)
+        }
+    }
+    // Like find_at, but also return the furthest offset the search looked
+    // at. The match depends on no text beyond the char at that offset.
+    // With lookahead assertions, the whole of s is taken to be looked at.
+    fn search(&self, s: &str, start: uint) -> (Option<(uint, uint)>, uint) {
+        self.search_with(&self.cache(), s, start)
+    }
+    // Like search, reusing cache.
+    fn search_with(&self, cache: &MatchCache, s: &str, start: uint)
+        -> (Option<(uint, uint)>, uint) {
+        if self.prog.lookahead {
+            return (self.find_at_with(cache, s, start), s.len());
+        }
+        let m = cache.machine_for(self.prog);
+        let anchored = self.prog.anchored;
+        let mut horizon = start;
+        let (found, looked) = do str::byte_slice(s) |b| {
+            do self.each_window(b, start, anchored, false) |end, pos| {
+                let found = m.search(vec::view(b, 0, end), pos, anchored,
+                                     false);
+                if m.horizon > horizon {
+                    horizon = m.horizon;
+                }
+                found
+            }
+        };
+        if looked > horizon {
+            horizon = looked;
+        }
+        let found = if found {
+            Some((m.matched[0].get(), m.matched[1].get()))
+        } else {
+            None
+        };
+        return (found, horizon);
+    }
+    // The leftmost match in each line of s that has one, as its line
+    // number, counted from 1, and its byte offsets in s. Each line is
+    // searched on its own, without its \n, so ^ and $ match at its ends.
+    // A final \n ends the last line rather than starting an empty one.
+    fn find_lines(&self, s: &str) -> ~[(uint, uint, uint)] {
+        let mut found = ~[];
+        let mut start = 0;
+        let mut line = 1;
+        while start < s.len() || start == 0 {
+            let end = match str::find_char_from(s, '\n', start) {
+                Some(end) => end,
+                None => s.len()
+            };
+            match self.find(str::view(s, start, end)) {
+                Some((i, j)) => found.push((line, start + i, start + j)),
+                None => {}
+            }
+            start = end + 1;
+            line += 1;
+        }
+        return found;
+    }
+    // Call f with the byte offsets of each successive non-overlapping
+    // match in s. Empty matches abutting a preceding match are skipped.
+    // The searches share one cache and take the fast paths find does,
+    // since no horizons are needed.
+    fn each_match(&self, s: &str, f: fn(uint, uint) -> bool) {
+        let cache = self.cache();
+        self.scan_with(s, 0, None,
+                       |pos| (self.find_at_with(&cache, s, pos), pos),
+                       |start, end, _horizon| f(start, end))
+    }
+    // The loop of each_match, started at pos after a match ending at
+    // prev_end. f also gets the furthest offset looked at since the
+    // preceding match.
+    fn scan_from(&self, s: &str, pos: uint, prev_end: Option<uint>,
+                 f: fn(uint, uint, uint) -> bool) {
+        let cache = self.cache();
+        self.scan_with(s, pos, prev_end,
+                       |pos| self.search_with(&cache, s, pos), f)
+    }
+    // The loop of each_match and scan_from, finding each match with
+    // search, which returns it as search_with does.
+    fn scan_with(&self, s: &str, pos: uint, prev_end: Option<uint>,
+                 search: fn(uint) -> (Option<(uint, uint)>, uint),
+                 f: fn(uint, uint, uint) -> bool) {
+        let mut pos = pos;
+        let mut prev_end = prev_end;
+        let mut horizon = pos;
+        while pos <= s.len() {
+            let (found, h) = search(pos);
+            if h > horizon {
+                horizon = h;
+            }
+            let (start, end) = match found {
+                Some(m) => m,
+                None => break
+            };
+            let abutting = match prev_end {
+                Some(e) => start == end && e == start,
+                None => false
+            };
+            if !abutting {
+                if !f(start, end, horizon) {
+                    break;
+                }
+                prev_end = Some(end);
+                horizon = end;
+            }
+            match next_search(s, start, end) {
+                Some(next) => pos = next,
+                None => break
+            }
+        }
+    }
+    // All matches in s, as each_match finds them, for later use by rescan.
+    fn scan(&self, s: &str) -> Scan {
+        let scan = Scan {matches: ~[], horizons: ~[]};
+        for self.scan_from(s, 0, None) |start, end, horizon| {
+            scan.matches.push((start, end));
+            scan.horizons.push(horizon);
+        }
+        return scan;
+    }
+    // Update old, the scan of a text, after the bytes from start to
+    // old_end in it were replaced, giving s with the new bytes from start
+    // to new_end. Matches whose search only looked at text before the
+    // edit are kept, and searching stops as soon as a match after the
+    // edit lines up with an old one, as everything from there on is the
+    // same as before, only shifted.
+    fn rescan(&self, old: &Scan, s: &str, start: uint, old_end: uint,
+              new_end: uint) -> Scan {
+        let scan = Scan {matches: ~[], horizons: ~[]};
+        let mut pos = 0;
+        let mut prev_end = None;
+        let mut i = 0;
+        while i < old.matches.len() && old.horizons[i] < start {
+            let (ms, me) = old.matches[i];
+            scan.matches.push((ms, me));
+            scan.horizons.push(old.horizons[i]);
+            pos = next_search(s, ms, me).get();
+            prev_end = Some(me);
+            i += 1;
+        }
+        let shift = |p: uint| p - old_end + new_end;
+        let mut synced = false;
+        for self.scan_from(s, pos, prev_end) |ms, me, horizon| {
+            scan.matches.push((ms, me));
+            scan.horizons.push(horizon);
+            if ms >= new_end {
+                while i < old.matches.len() &&
+                    (old.matches[i].first() < old_end ||
+                     shift(old.matches[i].first()) < ms) {
+                    i += 1;
+                }
+                if i < old.matches.len() &&
+                    shift(old.matches[i].first()) == ms &&
+                    shift(old.matches[i].second()) == me {
+                    synced = true;
+                    break;
+                }
+            }
+        }
+        if synced {
+            for uint::range(i + 1, old.matches.len()) |j| {
+                let (ms, me) = old.matches[j];
+                scan.matches.push((shift(ms), shift(me)));
+                scan.horizons.push(shift(old.horizons[j]));
+            }
+        }
+        return scan;
+    }
+    // Replace every match in s by f applied to the matched text.
+    fn replace_with(&self, s: &str, f: fn(&str) -> ~str) -> ~str {
+        let mut t = ~"";
+        let mut last = 0;
+        for self.each_match(s) |start, end| {
+            t += str::slice(s, last, start);
+            t += f(str::view(s, start, end));
+            last = end;
+        }
+        t += str::slice(s, last, s.len());
+        return t;
+    }
+    fn replace_all(&self, s: &str, rep: &str) -> ~str {
+        self.replace_with(s, |_m| rep.to_str())
+    }
+    // Like replace_all, but template is expanded for each match, as by
+    // Captures::expand.
+    fn replace_all_expanded(&self, s: &str, template: &str) -> ~str {
+        let pieces = parse_template(template);
+        let names = self.capture_names();
+        let mut t = ~"";
+        let mut last = 0;
+        for self.each_match(s) |start, end| {
+            str::push_str(&mut t, str::view(s, last, start));
+            // The anchored search finds the same match, with its groups.
+            let caps = self.exec(s, start, true, false).get();
+            expand_pieces(pieces, s, caps, names, &mut t);
+            last = end;
+        }
+        str::push_str(&mut t, str::view(s, last, s.len()));
+        return t;
+    }
+    // Like replace_all, but rep takes on the case of each matched text,
+    // so that replacing HTTP with https gives HTTPS.
+    fn replace_all_preserving_case(&self, s: &str, rep: &str) -> ~str {
+        let tables = CaseTables::new();
+        self.replace_with(s, |m| transfer_case(&tables, m, rep))
+    }
+    // Split s on matches of the regex. Empty matches do not split.
+    fn split(&self, s: &str) -> ~[~str] {
+        self.split_inner(s, None)
+    }
+    // Like split, but matches starting between a pair of quote characters
+    // do not split, so that a,"b,c",d split on , gives three fields.
+    // A doubled quote inside a quoted field toggles twice and is harmless.
+    // Fields are returned with their quotes intact.
+    fn split_quoted(&self, s: &str, quote: char) -> ~[~str] {
+        self.split_inner(s, Some(quote))
+    }
+    fn split_inner(&self, s: &str, quote: Option<char>) -> ~[~str] {
+        let mut fields = ~[];
+        let mut last = 0;
+        let mut pos = 0;
+        let mut scanned = 0;
+        let mut quoted = false;
+        while pos <= s.len() {
+            let (start, end) = match self.find_at(s, pos) {
+                Some(m) => m,
+                None => break
+            };
+            match quote {
+                Some(q) => {
+                    for str::each_char(str::view(s, scanned, start)) |c| {
+                        if c == q {
+                            quoted = !quoted;
+                        }
+                    }
+                    scanned = start;
+                }
+                None => {}
+            }
+            if start == end || quoted {
+                if start == s.len() {
+                    break;
+                }
+                pos = str::char_range_at(s, start).next;
+            } else {
+                fields.push(str::slice(s, last, start));
+                last = end;
+                pos = end;
+            }
+        }
+        fields.push(str::slice(s, last, s.len()));
+        return fields;
+    }
+    // The start of the leftmost match ending at end, found by running the
+    // reversed program backwards from end instead of trying every start.
+    // Patterns with lookahead assertions have no reversed program and
+    // fail with UnsupportedFeature.
+    fn match_start(&self, s: &str, end: uint) -> Result<Option<uint>, Error> {
+        let rprog = match self.rprog {
+            Some(rprog) => rprog,
+            None => return Err(UnsupportedFeature)
+        };
+        let m = Machine::new(rprog);
+        m.longest = true;
+        let caps = do str::byte_slice(s) |b| {
+            m.run(b, end, true, false)
+        };
+        match caps {
+            Some(caps) => Ok(caps[0]),
+            None => Ok(None)
+        }
+    }
+    // Like find, but the match must end at the end of s. This scans s
+    // backwards once rather than searching forwards from every position.
+    fn find_suffix(&self, s: &str) -> Option<(uint, uint)> {
+        let start = match self.match_start(s, s.len()) {
+            Ok(Some(start)) => start,
+            Ok(None) => return None,
+            Err(_) => {
+                return match self.exec(s, 0, false, true) {
+                    Some(caps) => Some((caps[0].get(), caps[1].get())),
+                    None => None
+                };
+            }
+        };
+        if self.prog.anchored && start != 0 {
+            return None;
+        }
+        match self.exec(s, start, true, true) {
+            Some(caps) => Some((caps[0].get(), caps[1].get())),
+            None => None
+        }
+    }
+    fn is_match_bytes(&self, s: &[u8]) -> bool {
+        assert self.prog.bytes;
+        self.exec_bytes(s, 0, false, false).is_some()
+    }
+    fn find_bytes_at(&self, s: &[u8], start: uint) -> Option<(uint, uint)> {
+        assert self.prog.bytes;
+        match self.exec_bytes(s, start, false, false) {
+            Some(caps) => Some((caps[0].get(), caps[1].get())),
+            None => None
+        }
+    }
+    fn find_bytes(&self, s: &[u8]) -> Option<(uint, uint)> {
+        self.find_bytes_at(s, 0)
+    }
+    // Whether a match starts exactly at byte offset start.
+    fn matches_at(&self, s: &str, start: uint) -> bool {
+        self.exec(s, start, true, false).is_some()
+    }
+    // Whether the whole of s matches.
+    fn is_full_match(&self, s: &str) -> bool {
+        self.exec(s, 0, true, true).is_some()
+    }
+}
+
+#[test]
+fn test_match() {
+    fn test(s: &str, t: &str, is_match: bool, full: bool) {
+        let re = Regex::new(s).get();
+        assert re.is_match(t) == is_match;
+        assert re.is_full_match(t) == full;
+    }
+    test("", "", true, true);
+    test("", "a", true, false);
+    test("a", "a", true, true);
+    test("a", "ba", true, false);
+    test("a", "b", false, false);
+    test("ab", "xaby", true, false);
+    test("a|b", "b", true, true);
+    test("a*", "aaa", true, true);
+    test("a+", "", false, false);
+    test("ab?", "a", true, true);
+    test("(a|b)*c", "abbac", true, true);
+    test("(a|ab)(c|bcd)", "abcd", true, true);
+    test("^ab", "xab", false, false);
+    test("ab$", "abx", false, false);
+    test("^a*$", "aa", true, true);
+    test("(?m)^b$", "a\nb\nc", true, false);
+}
+
+#[test]
+fn test_lookahead() {
+    fn test(re: &str, s: &str, expected: Option<(uint, uint)>) {
+        assert Regex::new(re).get().find(s) == expected;
+    }
+    test("a(?=b)", "acab", Some((2, 3)));
+    test("a(?!b)", "abac", Some((2, 3)));
+    test("(?=[a-z]*[0-9])[a-z0-9]+", "abc a1", Some((4, 6)));
+    test("x(?=(a)b)", "xab", Some((0, 1)));
+    test("(?!)", "", None);
+    test("a(?=b|$)", "ac a", Some((3, 4)));
+    let re = Regex::new("(x)(?=(y))").get();
+    assert re.exec("xy", 0, false, false) ==
+        Some(~[Some(0), Some(1), Some(0), Some(1), None, None]);
+    let mut found = ~[];
+    for re.each_match("xyxxy") |start, end| {
+        found.push((start, end));
+    }
+    assert found == ~[(0, 1), (3, 4)];
+}
+
+#[test]
+fn test_lazy() {
+    fn test(re: &str, s: &str, expected: Option<(uint, uint)>) {
+        assert Regex::new(re).get().find(s) == expected;
+    }
+    test("a+?", "aaa", Some((0, 1)));
+    test("a*?", "aaa", Some((0, 0)));
+    test("a??b", "ab", Some((0, 2)));
+    test("<.*?>", "<a><b>", Some((0, 3)));
+    test("(?U)<.*>", "<a><b>", Some((0, 3)));
+    test("(?U)<.*?>", "<a><b>", Some((0, 6)));
+}
+
+#[test]
+fn test_anchored() {
+    let re = Regex::new("ab").get();
+    assert re.matches_at("xab", 1);
+    assert !re.matches_at("xab", 0);
+    let re = Regex::new_anchored("ab").get();
+    assert re.is_match("abc");
+    assert !re.is_match("xab");
+}
+
+#[test]
+fn test_find_suffix() {
+    fn test(re: &str, s: &str, expected: Option<(uint, uint)>) {
+        let re = Regex::new(re).get();
+        assert re.find_suffix(s) == expected;
+        let found = match re.exec(s, 0, false, true) {
+            Some(caps) => Some((caps[0].get(), caps[1].get())),
+            None => None
+        };
+        assert found == expected;
+    }
+    test("ab", "xabab", Some((3, 5)));
+    test("a+", "baaa", Some((1, 4)));
+    test("(a|ab)(c|bcd)", "xabcd", Some((1, 5)));
+    test("x*", "abc", Some((3, 3)));
+    test("ab", "abx", None);
+    test("\u00e9+", "a\u00e9\u00e9", Some((1, 5)));
+    let re = Regex::new("b(a*)").get();
+    assert re.match_start("xbaay", 4).get() == Some(1);
+    assert re.match_start("xbaay", 5).get() == None;
+    let re = Regex::new("a(?=b)").get();
+    assert re.match_start("ab", 1).is_err();
+    assert re.find_suffix("ab") == None;
+    assert re.find_suffix("aab") == None;
+    let re = Regex::new_anchored("a+").get();
+    assert re.find_suffix("aaa") == Some((0, 3));
+    assert re.find_suffix("baa") == None;
+}
+
+#[test]
+fn test_span() {
+    let re = Regex::new("[0-9]+").get();
+    let s = "\u00e9t\u00e9 42 7";
+    let span = re.find_span(s).get();
+    assert (span.start, span.end) == (6, 8);
+    assert span.len() == 2;
+    assert span.slice(s) == ~"42";
+    assert span.char_span(s) == (4, 6);
+    assert re.find_span_at(s, 8).get().char_span(s) == (7, 8);
+    assert re.spans(s).map(|sp| (sp.start, sp.end)) == ~[(6, 8), (9, 10)];
+    assert re.find_span("x").is_none();
+}
+
+#[test]
+fn test_literal_alternation() {
+    let re = Regex::new("GET|POST|PUT|DELETE").get();
+    assert re.literals.is_some();
+    assert re.find("x PUT y") == Some((2, 5));
+    assert re.is_full_match("DELETE");
+    assert !re.is_match("get");
+    assert re.find_suffix("POST GET") == Some((5, 8));
+    let re = Regex::new_anchored("ab|a").get();
+    assert re.find("ab") == Some((0, 2));
+    assert re.find("xab") == None;
+    assert Regex::new("(?i)get|post").get().literals.is_none();
+    assert Regex::new_word("get|post").get().literals.is_none();
+    let re = Regex::new_bytes("\u00e9t\u00e9|\\xff").get();
+    assert re.literals.is_some();
+    assert re.find_bytes(~[0x00, 0xc3, 0xa9, 0x74, 0xc3, 0xa9]) ==
+        Some((1, 6));
+    assert re.find_bytes(~[0xc3, 0xbf, 0xff]) == Some((2, 3));
+}
+
+#[test]
+fn test_long_lookahead() {
+    // Too long for the backtracker, so the Pike VM runs the lookaheads.
+    let n = 1000000;
+    let s = str::from_bytes(vec::from_elem(n, 'a' as u8));
+    let re = Regex::new("a(?=b)").get();
+    assert !re.is_match(s);
+    assert re.find(s + "b") == Some((n - 1, n));
+    let re = Regex::new("(a)(?!a)").get();
+    assert re.exec(s, 0, false, false) ==
+        Some(~[Some(n - 1), Some(n), Some(n - 1), Some(n)]);
+}
+
+#[test]
+fn test_match_cache() {
+    let re = Regex::new("(a+)b|c").get();
+    let cache = re.cache();
+    let lines = ["xaab", "zzz", "c", "ab", "", "aaa"];
+    for uint::range(0, 3) |_i| {
+        for lines.each |line| {
+            assert re.is_match_with(&cache, *line) == re.is_match(*line);
+            assert re.find_with(&cache, *line) == re.find(*line);
+        }
+    }
+    // each_match takes the fast paths, and scan the machine, with one
+    // cache for all their searches.
+    for ["[a-z]+", "GET|POST", "a(?=b)", "x*"].each |p| {
+        let re = Regex::new(*p).get();
+        let s = "GET ab xab POST";
+        let mut found = ~[];
+        for re.each_match(s) |start, end| {
+            found.push((start, end));
+        }
+        assert re.scan(s).matches == found;
+    }
+    let re = Regex::new("a(?=b)").get();
+    let cache = re.cache();
+    assert re.find_with(&cache, "aab") == Some((1, 2));
+    assert re.find_with(&cache, "ab aab") == Some((0, 1));
+    assert cache.machine.is_none() && cache.backtrack.is_some();
+    // The fast paths are taken too.
+    for ["GET|POST", "(a+)b", "[a-z]+\\.com"].each |p| {
+        let re = Regex::new_anchored(*p).get();
+        let cache = re.cache();
+        for ["POST /", "aab", "ab.com", "x"].each |line| {
+            assert re.find_with(&cache, *line) == re.find(*line);
+            assert re.is_match_with(&cache, *line) == re.is_match(*line);
+        }
+        assert cache.machine.is_none() && cache.backtrack.is_none();
+    }
+}
+
+#[test]
+fn test_required_literal_scan() {
+    let re = Regex::new("[a-z]+@[a-z]+\\.com").get();
+    assert re.required == Some(str::to_bytes(".com"));
+    assert re.find("mail bob@example.com now") == Some((5, 20));
+    assert re.find("mail bob@example.org now") == None;
+    assert re.find_at("a@b.com c@d.com", 8) == Some((8, 15));
+    assert re.find_at("a@b.com c@d.com", 9) == None;
+    assert re.windowed;
+    let text = "x@y.org\nno mail\nbob@example.com, al@b.com\nc@d.com";
+    assert re.find(text) == Some((16, 31));
+    assert re.is_match(text) && !re.is_match("x@y.org\nb.com");
+    assert re.find_at(text, 17) == Some((17, 31));
+    let found = re.spans(text).map(|span| (span.start, span.end));
+    assert found == ~[(16, 31), (33, 41), (42, 49)];
+    assert re.replace_all(text, "_") == ~"x@y.org\nno mail\n_, _\n_";
+    assert Regex::new("foo|bar").get().required.is_none();
+    // Matches that may span lines, or end at the end of the text, are
+    // searched for in all of it.
+    let re = Regex::new("[a-z\\n]+\\.com").get();
+    assert !re.windowed;
+    assert re.find("x\na.com") == Some((0, 7));
+    let re = Regex::new("[a-z]+\\.com$").get();
+    assert !re.windowed;
+    assert re.find("a.com\nb.com") == Some((6, 11));
+    // In bytes mode, the literal is made of the bytes the pattern names.
+    let re = Regex::new_bytes("[a-z]*\u00e9+x").get();
+    assert re.required == Some(~[0xc3, 0xa9]);
+    assert re.find_bytes(~[0x61, 0xc3, 0xa9, 0x78]) == Some((0, 4));
+    let re = Regex::new_bytes("[a-z]+\\xff").get();
+    assert re.required == Some(~[0xff]);
+    assert re.find_bytes(~[0x0a, 0x61, 0xff, 0x0a]) == Some((1, 3));
+}
+
+#[test]
+fn test_captures() {
+    let re = Regex::new("(?P<key>[a-z]+)=([0-9]+)?").get();
+    let caps = re.captures("x: port=80").get();
+    assert caps.len() == 3;
+    assert caps.at(0) == Some(~"port=80");
+    assert caps.name("key") == Some(~"port");
+    assert caps.pos(2).get().start == 8;
+    assert caps.name("x").is_none();
+    assert caps.expand("$2/${key}$$ $3$x") == ~"80/port$ ";
+    let mut t = ~"> ";
+    caps.expand_into("$key", &mut t);
+    assert t == ~"> port";
+    let caps = re.captures("a=").get();
+    assert caps.at(2).is_none();
+    assert caps.expand("[$2]") == ~"[]";
+    assert caps.expand("[$18446744073709551617]") == ~"[]";
+    assert caps.expand("[${99999999999999999999}]") == ~"[]";
+    assert re.captures("=1").is_none();
+    assert re.replace_all_expanded("a=1 b=2", "$2:$key") == ~"1:a 2:b";
+}
+
+#[test]
+fn test_find_lines() {
+    let re = Regex::new("^[a-z]+$").get();
+    assert re.find_lines("ab\n12\ncd\n") == ~[(1, 0, 2), (3, 6, 8)];
+    let re = Regex::new("x*").get();
+    assert re.find_lines("a\n") == ~[(1, 0, 0)];
+    assert re.find_lines("a\n\n") == ~[(1, 0, 0), (2, 2, 2)];
+    assert re.find_lines("") == ~[(1, 0, 0)];
+    let config = Config::new();
+    config.multi_line = true;
+    let re = Regex::with_config("^[0-9]+$", &config).get();
+    let mut found = ~[];
+    for re.each_match("ab\n12\n3") |start, end| {
+        found.push((start, end));
+    }
+    assert found == ~[(3, 5), (6, 7)];
+    assert !Regex::new("^[0-9]+$").get().is_match("ab\n12");
+}
+
+#[test]
+fn test_shortest_match() {
+    fn test(re: &str, s: &str, expected: Option<uint>) {
+        assert Regex::new(re).get().shortest_match(s) == expected;
+    }
+    test("a+", "baaa", Some(2));
+    test("ab|a", "xab", Some(2));
+    test("x*", "abc", Some(0));
+    test("(a)(b)c", "zabc", Some(4));
+    test("ab", "aab", Some(3));
+    test("ab", "ba", None);
+    test("a(?=b)", "aab", Some(2));
+    let re = Regex::new_anchored("a+").get();
+    assert re.shortest_match("aa") == Some(1);
+    assert re.shortest_match("baa") == None;
+}
+
+#[test]
+fn test_word() {
+    let re = Regex::new_word("cat|dog").get();
+    assert re.find("concat dog") == Some((7, 10));
+    assert !re.is_match("cats");
+    let re = Regex::new_word("\\+1").get();
+    assert re.find("x+1 +1") == Some((4, 6));
+    let re = Regex::new_word("c\\+\\+").get();
+    assert re.find("c++ ok") == Some((0, 3));
+    assert !re.is_match("c++x");
+    let re = Regex::new("\\bis\\b").get();
+    assert re.find("this is") == Some((5, 7));
+    let re = Regex::new("\\Bis").get();
+    assert re.find("is this") == Some((5, 7));
+}
+
+#[test]
+fn test_empty_match() {
+    fn test(re: &str, s: &str, expected: &[Option<uint>]) {
+        let re = Regex::new(re).get();
+        assert re.exec(s, 0, false, false) == Some(vec::from_slice(expected));
+        let m = Machine::new(re.prog);
+        let caps = do str::byte_slice(s) |b| { m.run(b, 0, false, false) };
+        assert caps == Some(vec::from_slice(expected));
+    }
+    test("(a*)*", "b", [Some(0), Some(0), Some(0), Some(0)]);
+    test("(a*)*", "aa", [Some(0), Some(2), Some(0), Some(2)]);
+    test("(a*)+", "b", [Some(0), Some(0), Some(0), Some(0)]);
+    test("(a*|b)*", "b", [Some(0), Some(0), Some(0), Some(0)]);
+    test("(|a)+", "aa", [Some(0), Some(0), Some(0), Some(0)]);
+    test("(|a)*", "aa", [Some(0), Some(0), Some(0), Some(0)]);
+    test("()*", "x", [Some(0), Some(0), Some(0), Some(0)]);
+    test("(a|)*", "aa", [Some(0), Some(2), Some(1), Some(2)]);
+    test("(?:a*)*b", "aab", [Some(0), Some(3)]);
+    test("(\\b)*x", "x", [Some(0), Some(1), Some(0), Some(0)]);
+}
+
+#[test]
+fn test_captures_len() {
+    let re = Regex::new("(a)(?P<b>b(c))").get();
+    assert re.captures_len() == 4;
+    assert re.capture_names() == ~[None, None, Some(~"b"), None];
+    assert re.capture_span(0) == Some((0, 14));
+    assert re.capture_span(2) == Some((3, 14));
+    assert re.capture_span(4).is_none();
+}
+
+#[test]
+fn test_split() {
+    let re = Regex::new(",").get();
+    assert re.split("a,b,,c") == ~[~"a", ~"b", ~"", ~"c"];
+    assert re.split("") == ~[~""];
+    assert re.split_quoted("a,\"b,c\",d", '"') == ~[~"a", ~"\"b,c\"", ~"d"];
+    assert re.split_quoted("\"x\"\"y,z\",w", '"') == ~[~"\"x\"\"y,z\"", ~"w"];
+    let re = Regex::new("\t").get();
+    assert re.split_quoted("a\t'b\tc'", '\'') == ~[~"a", ~"'b\tc'"];
+}
+
+#[test]
+fn test_limits() {
+    let config = Config::new();
+    config.nest_limit = 2;
+    assert Regex::with_config("((a))", &config).is_ok();
+    assert Regex::with_config("(((a)))", &config).is_err();
+    let config = Config::new();
+    config.size_limit = 10;
+    assert Regex::with_config("abc", &config).is_ok();
+    assert Regex::with_config("abcdefghijk", &config).is_err();
+}
+
+#[test]
+fn test_replace_all() {
+    let re = Regex::new("a*").get();
+    assert re.replace_all("baaac", "-") == ~"-b-c-";
+    let re = Regex::new("ab").get();
+    assert re.replace_all("xabyab", "") == ~"xy";
+}
+
+#[test]
+fn test_replace_all_preserving_case() {
+    let re = Regex::new("http|HTTP|Http|hTtP").get();
+    assert re.replace_all_preserving_case("HTTP", "https") == ~"HTTPS";
+    assert re.replace_all_preserving_case("http", "HTTPS") == ~"https";
+    assert re.replace_all_preserving_case("Http", "https") == ~"Https";
+    assert re.replace_all_preserving_case("hTtP", "https") == ~"https";
+    let re = Regex::new("(?i)\u00e9t\u00e9").get();
+    assert re.replace_all_preserving_case("\u00c9T\u00c9", "\u00e9t\u00e9")
+        == ~"\u00c9T\u00c9";
+    assert re.replace_all_preserving_case("\u00c9t\u00e9", "\u00c9T\u00c9")
+        == ~"\u00c9t\u00e9";
+    let tables = CaseTables::new();
+    assert transfer_case(&tables, "AB", "k\u212a") == ~"K\u212a";
+    assert transfer_case(&tables, "abc", "\u039c\u03a3") ==
+        ~"\u03bc\u03c3";
+    assert transfer_case(&tables, "ABC", "\u00b5\u03c2") ==
+        ~"\u039c\u03a3";
+    assert transfer_case(&tables, "Abc", "\u01c6x") == ~"\u01c5x";
+}
+
+#[test]
+fn test_unicode() {
+    let re = Regex::new("\\p{Greek}+").get();
+    assert re.find("abc \u03b1\u03b2\u03b3 def") == Some((4, 10));
+    let re = Regex::new("(?i)\u03c3").get();
+    assert re.is_full_match("\u03a3");
+    assert re.is_full_match("\u03c2");
+    let re = Regex::new("a.c").get();
+    assert re.is_full_match("a\u00e9c");
+    assert !re.is_match("a\nc");
+    let config = Config::new();
+    config.unicode = false;
+    assert Regex::with_config("\\pL", &config).is_err();
+    let re = Regex::with_config("(?i)k", &config).get();
+    assert re.is_match("K");
+    assert !re.is_match("\u212a");
+    assert Regex::with_config("(?u)\\pL", &config).is_ok();
+    let (major, _, _) = unicode_version();
+    assert major >= 14;
+}
+
+#[test]
+fn test_bytes() {
+    let re = Regex::new_bytes("a.c").get();
+    assert re.is_match_bytes(~[0x61, 0xff, 0x63]);
+    assert !re.is_match_bytes(~[0x61, 0x0a, 0x63]);
+    let re = Regex::new_bytes("[^a]+").get();
+    assert re.find_bytes(~[0x61, 0x80, 0xfe, 0x61]) == Some((1, 3));
+    let re = Regex::new_bytes("\u00e9").get();
+    assert re.find_bytes(~[0x00, 0xc3, 0xa9]) == Some((1, 3));
+    assert !re.is_match_bytes(~[0xe9]);
+    assert Regex::new_bytes("\\pL").is_err();
+    assert Regex::new_bytes("[\u00e9]").is_err();
+    let re = Regex::new_bytes("\\xff").get();
+    assert re.find_bytes(~[0xc3, 0xbf, 0xff]) == Some((2, 3));
+    let re = Regex::new_bytes("[\\x80-\\xff]+").get();
+    assert re.find_bytes(~[0x61, 0x80, 0xe9, 0xff, 0x7f]) == Some((1, 4));
+    let re = Regex::new_bytes("\\x{e9}|\u00e9").get();
+    assert re.find_bytes(~[0xc3, 0xa9]) == Some((0, 2));
+    assert re.find_bytes(~[0x00, 0xe9]) == Some((1, 2));
+    assert Regex::new_bytes("[\\x{100}]").is_err();
+    let re = Regex::new_bytes("(?i)k").get();
+    assert re.is_match_bytes(~[0x4b]);
+}
+
+#[test]
+fn test_rescan() {
+    fn test(re: &str, s: &str, t: &str, start: uint, old_end: uint,
+            new_end: uint) {
+        let re = Regex::new(re).get();
+        let old = re.scan(s);
+        let new = re.rescan(&old, t, start, old_end, new_end);
+        let fresh = re.scan(t);
+        assert new.matches == fresh.matches;
+    }
+    test("a(bc)?", "abxabcab", "abcabcab", 2, 3, 3);
+    test("ab", "ab ab ab ab", "ab abab ab", 5, 6, 5);
+    test("ab", "ab ab ab ab", "ab a ab ab", 2, 5, 4);
+    test("x*", "axxb", "axxxb", 2, 2, 3);
+    test("ab", "ab ab", "ab abab", 5, 5, 7);
+    // The assertions look at the char after the match.
+    test("abc\\b|b", "abcd", "abc ", 3, 4, 4);
+    test("ab$|a", "abc", "ab", 2, 3, 2);
+    let re = Regex::new("a(bc)?").get();
+    let s = "abc abc abc abc";
+    let t = "abc ab abc abc";
+    let new = re.rescan(&re.scan(s), t, 6, 7, 6);
+    assert new.matches == ~[(0, 3), (4, 6), (7, 10), (11, 14)];
+}
+
+#[test]
+fn test_time_limit() {
+    let config = Config::new();
+    config.time_limit = Some(0);
+    assert Regex::with_config("(?i)\\pL", &config).is_err();
+    config.time_limit = Some(60000);
+    assert Regex::with_config("(?i)\\pL", &config).is_ok();
+}
+
+#[test]
+fn test_default_config() {
+    // The default is shared by the tests running alongside, so the one
+    // set here must not change what they compile: it only sets a time
+    // limit none of them comes near.
+    let config = Config::new();
+    config.time_limit = Some(3600000);
+    assert set_default_config(&config);
+    assert !set_default_config(&Config::new());
+    assert Config::default().time_limit == Some(3600000);
+    assert Config::new().time_limit.is_none();
+    // Other tasks see it too.
+    let child = do task::try {
+        (Config::default().time_limit, set_default_config(&Config::new()))
+    };
+    assert child.get() == (Some(3600000), false);
+    assert Regex::new("abc").is_ok();
+}
+
+#[test]
+fn test_from_prog() {
+    for ["a+(b|c)", "x(?=y)", "(?i)k\\b", "GET|POST"].each |p| {
+        let re = Regex::new(*p).get();
+        let bytes = io::with_bytes_writer(|w| re.prog.encode(w));
+        let prog = io::with_bytes_reader(bytes, |r| Prog::decode(r)).get();
+        let loaded = Regex::from_prog(prog).get();
+        assert loaded.captures_len() == re.captures_len();
+        for ["aab", "ac xy", "K k", "POST GET", ""].each |s| {
+            for uint::range(0, s.len() + 1) |start| {
+                assert loaded.exec(*s, start, false, false) ==
+                    re.exec(*s, start, false, false);
+                assert loaded.exec(*s, start, true, false) ==
+                    re.exec(*s, start, true, false);
+            }
+        }
+    }
+    let re = Regex::new("ab").get();
+    assert Regex::from_prog(copy *re.rprog.get()).is_err();
+}
+
+#[test]
+fn test_memory_usage() {
+    let a = Regex::new("a").get();
+    let b = Regex::new("(abc|def)*").get();
+    assert a.memory_usage() > 0;
+    assert a.memory_usage() < b.memory_usage();
+    let a = Regex::new("[a-z]").get();
+    let b = Regex::new("\\pL").get();
+    assert b.memory_usage() >
+        a.memory_usage() + 100 * sys::size_of::<(char, char)>();
+    let a = Regex::new("(a)").get();
+    let b = Regex::new("(?P<name>a)").get();
+    assert b.memory_usage() == a.memory_usage() + 4;
+}
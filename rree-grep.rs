@@ -0,0 +1,126 @@
+// A grep front end to the crate, printing the lines of files, or of the
+// standard input, that match a pattern.
+//
+//     rree-grep [-i] [-n] [-v] [-o] PATTERN [FILE...]
+//
+// -i matches case insensitively, -n prints line numbers, -v prints the
+// lines that do not match, and -o prints only the matched parts of lines.
+// The exit status is 0 if a line was printed, 1 if none was and 2 on
+// errors, as with grep.
+
+extern mod std;
+extern mod rree;
+
+use io::{Reader, ReaderUtil, WriterUtil};
+use rree::regex::Regex;
+
+struct Options {
+    mut ignore_case: bool,
+    mut line_numbers: bool,
+    mut invert: bool,
+    mut only_matching: bool
+}
+
+fn usage() {
+    io::stderr().write_line(~"usage: rree-grep [-i] [-n] [-v] [-o] " +
+                            "PATTERN [FILE...]");
+    os::set_exit_status(2);
+}
+
+// Print the selected lines of r, prefixed by name if given. Return
+// whether any line was printed.
+fn grep(re: &Regex, opts: &Options, r: @Reader, name: Option<~str>)
+    -> bool {
+    let mut found = false;
+    let mut n = 0u;
+    for r.each_line |line| {
+        n += 1;
+        let mut prefix = match name {
+            Some(ref name) => copy *name + ~":",
+            None => ~""
+        };
+        if opts.line_numbers {
+            prefix += fmt!("%u:", n);
+        }
+        if opts.only_matching {
+            if opts.invert {
+                again;
+            }
+            for re.each_match(line) |start, end| {
+                if start != end {
+                    io::println(prefix + str::slice(line, start, end));
+                    found = true;
+                }
+            }
+        } else if re.is_match(line) != opts.invert {
+            io::println(prefix + line);
+            found = true;
+        }
+    }
+    return found;
+}
+
+fn main() {
+    let args = os::args();
+    let opts = Options {
+        ignore_case: false,
+        line_numbers: false,
+        invert: false,
+        only_matching: false
+    };
+    let mut i = 1;
+    while i < args.len() && args[i].starts_with("-") && args[i].len() > 1 {
+        for str::each_char(str::view(args[i], 1, args[i].len())) |c| {
+            match c {
+                'i' => opts.ignore_case = true,
+                'n' => opts.line_numbers = true,
+                'v' => opts.invert = true,
+                'o' => opts.only_matching = true,
+                _ => return usage()
+            }
+        }
+        i += 1;
+    }
+    if i == args.len() {
+        return usage();
+    }
+    let pattern = if opts.ignore_case {
+        ~"(?i)" + args[i]
+    } else {
+        copy args[i]
+    };
+    let re = match Regex::new(pattern) {
+        Ok(re) => re,
+        Err(e) => {
+            io::stderr().write_line(fmt!("rree-grep: invalid pattern: %?",
+                                         e));
+            os::set_exit_status(2);
+            return;
+        }
+    };
+    let files = vec::slice(args, i + 1, args.len());
+    let mut found = false;
+    let mut failed = false;
+    if files.is_empty() {
+        found = grep(&re, &opts, io::stdin(), None);
+    }
+    for files.each |file| {
+        match io::file_reader(&Path(*file)) {
+            Ok(r) => {
+                let name = if files.len() > 1 {
+                    Some(copy *file)
+                } else {
+                    None
+                };
+                if grep(&re, &opts, r, name) {
+                    found = true;
+                }
+            }
+            Err(e) => {
+                io::stderr().write_line(~"rree-grep: " + e);
+                failed = true;
+            }
+        }
+    }
+    os::set_exit_status(if failed { 2 } else if found { 0 } else { 1 });
+}
@@ -0,0 +1,547 @@
+use result::{Result, Ok, Err};
+use parse::Error;
+use regex::{Regex, Config, next_search};
+use literals::LiteralSet;
+use std::sort;
+
+enum SetKind {
+    Patterns(~[Regex]),
+    // Strings matched exactly as given, without parsing.
+    Literals(@LiteralPatterns)
+}
+
+// The literals of a set, with a trie of them all, so that one scan of a
+// text finds every literal in it.
+struct LiteralPatterns {
+    literals: ~[~str],
+    trie: LiteralSet,
+    // The number the trie reports each literal by, which is that of the
+    // first literal equal to it.
+    ids: ~[uint]
+}
+
+impl LiteralPatterns {
+    static fn new(literals: &[&str]) -> LiteralPatterns {
+        let bytes = literals.map(|s| str::to_bytes(*s));
+        let trie = LiteralSet::new(bytes);
+        let ids = do bytes.map |b| {
+            let mut id = None;
+            for trie.each_at(*b, 0) |k, end| {
+                if end == b.len() {
+                    id = Some(k);
+                }
+            }
+            id.get()
+        };
+        LiteralPatterns {
+            literals: literals.map(|s| s.to_str()),
+            trie: trie,
+            ids: ids
+        }
+    }
+    // The numbers of the literals found in s, in order. If first is set,
+    // only the first one found is.
+    fn found(&self, s: &str, first: bool) -> ~[uint] {
+        let mut hit = vec::from_elem(self.literals.len(), false);
+        let mut any = false;
+        do str::byte_slice(s) |b| {
+            for uint::range(0, b.len() + 1) |pos| {
+                for self.trie.each_at(b, pos) |k, _| {
+                    hit[k] = true;
+                    any = true;
+                    if first {
+                        break;
+                    }
+                }
+                if first && any {
+                    break;
+                }
+            }
+        }
+        let mut found = ~[];
+        for self.ids.eachi |i, k| {
+            if hit[*k] {
+                found.push(i);
+                if first {
+                    break;
+                }
+            }
+        }
+        return found;
+    }
+    // Like RegexSet::find_at.
+    fn find_at(&self, s: &str, start: uint) -> Option<MultiMatch> {
+        let mut best: Option<MultiMatch> = None;
+        do str::byte_slice(s) |b| {
+            for uint::range(start, b.len() + 1) |pos| {
+                for self.trie.each_at(b, pos) |k, end| {
+                    let better = match best {
+                        Some(ref m) => k < m.pattern,
+                        None => true
+                    };
+                    if better {
+                        best = Some(MultiMatch {
+                            pattern: k,
+                            start: pos,
+                            end: end,
+                            captures: None
+                        });
+                    }
+                }
+                if best.is_some() {
+                    break;
+                }
+            }
+        }
+        return best;
+    }
+    // Heap bytes held by the literals and the trie.
+    fn memory_usage(&self) -> uint {
+        let mut size = self.trie.memory_usage();
+        size += self.literals.len() * sys::size_of::<~str>();
+        for self.literals.each |literal| {
+            size += literal.len();
+        }
+        return size + self.ids.len() * sys::size_of::<uint>();
+    }
+}
+
+// A match of one of the patterns of a set, as reported by every search
+// of a set.
+struct MultiMatch {
+    // The number of the pattern.
+    pattern: uint,
+    start: uint,
+    end: uint,
+    // The capture slots, as Regex::exec returns them, unless the set is
+    // of literals, which have no groups.
+    captures: Option<~[Option<uint>]>
+}
+
+// How often the patterns of a set were found, for finding rules that
+// never match or match too much.
+struct HitCounts {
+    // Number of searches counted.
+    searches: uint,
+    // Number of searches each pattern was found by.
+    hits: ~[uint]
+}
+
+impl HitCounts {
+    // The patterns no search has found.
+    fn unused(&self) -> ~[uint] {
+        let mut unused = ~[];
+        for self.hits.eachi |i, n| {
+            if *n == 0 {
+                unused.push(i);
+            }
+        }
+        return unused;
+    }
+}
+
+// A set of patterns searched for together, reporting which of them match.
+// Patterns are numbered in the order given.
+struct RegexSet {
+    kind: SetKind,
+    // Hits counted since count_hits was called, if it was.
+    mut counts: Option<HitCounts>
+}
+
+impl RegexSet {
+    static fn new(patterns: &[&str]) -> Result<RegexSet, Error> {
+        RegexSet::with_config(patterns, &Config::default())
+    }
+    // Like new, compiling every pattern with config. Patterns can still
+    // override its flags inline, as with (?u) when Unicode is off.
+    static fn with_config(patterns: &[&str], config: &Config)
+        -> Result<RegexSet, Error> {
+        let mut res = ~[];
+        for patterns.each |pattern| {
+            match Regex::with_config(*pattern, config) {
+                Ok(re) => res.push(re),
+                Err(e) => return Err(e)
+            }
+        }
+        return Ok(RegexSet {kind: Patterns(res), counts: None});
+    }
+    // A set matching any of literals exactly. No char of them is special,
+    // so user input can be given as is.
+    static fn from_literals(literals: &[&str]) -> RegexSet {
+        RegexSet {
+            kind: Literals(@LiteralPatterns::new(literals)),
+            counts: None
+        }
+    }
+    fn len(&self) -> uint {
+        match self.kind {
+            Patterns(res) => res.len(),
+            Literals(lits) => lits.literals.len()
+        }
+    }
+    // Heap bytes held by the compiled patterns, as Regex::memory_usage
+    // counts them.
+    fn memory_usage(&self) -> uint {
+        match self.kind {
+            Patterns(res) => {
+                let mut size = res.len() * sys::size_of::<Regex>();
+                for res.each |re| {
+                    size += re.memory_usage();
+                }
+                size
+            }
+            Literals(lits) => {
+                sys::size_of::<LiteralPatterns>() + lits.memory_usage()
+            }
+        }
+    }
+    // Start counting hits from zero. Each search counts the patterns it
+    // reports once, however many matches of them it reports: all the
+    // matching ones for matches, those of the matches found for find_at,
+    // each_match, overlapping and tokenize, but only the first one for
+    // is_match, which stops there.
+    fn count_hits(&self) {
+        self.counts = Some(HitCounts {
+            searches: 0,
+            hits: vec::from_elem(self.len(), 0u)
+        });
+    }
+    // A snapshot of the counts, if hits are counted.
+    fn hit_counts(&self) -> Option<HitCounts> {
+        copy self.counts
+    }
+    fn record(&self, found: &[uint]) {
+        match self.counts {
+            Some(ref mut counts) => {
+                counts.searches += 1;
+                for found.each |i| {
+                    counts.hits[*i] += 1;
+                }
+            }
+            None => {}
+        }
+    }
+    // Like record, for the patterns of matches, which may repeat.
+    fn record_patterns(&self, patterns: &[uint]) {
+        if self.counts.is_none() {
+            return;
+        }
+        let mut hit = vec::from_elem(self.len(), false);
+        for patterns.each |i| {
+            hit[*i] = true;
+        }
+        let mut found = ~[];
+        for hit.eachi |i, h| {
+            if *h {
+                found.push(i);
+            }
+        }
+        self.record(found);
+    }
+    // The leftmost match of pattern i at or after start, or only at start
+    // if anchored is set.
+    fn find_one(&self, i: uint, s: &str, start: uint, anchored: bool)
+        -> Option<MultiMatch> {
+        match self.kind {
+            Patterns(res) => {
+                match res[i].exec(s, start, anchored, false) {
+                    Some(caps) => Some(MultiMatch {
+                        pattern: i,
+                        start: caps[0].get(),
+                        end: caps[1].get(),
+                        captures: Some(caps)
+                    }),
+                    None => None
+                }
+            }
+            Literals(lits) => {
+                let literal: &str = lits.literals[i];
+                let found = if anchored {
+                    if str::view(s, start, s.len()).starts_with(literal) {
+                        Some(start)
+                    } else {
+                        None
+                    }
+                } else {
+                    str::find_str_from(s, literal, start)
+                };
+                match found {
+                    Some(pos) => Some(MultiMatch {
+                        pattern: i,
+                        start: pos,
+                        end: pos + literal.len(),
+                        captures: None
+                    }),
+                    None => None
+                }
+            }
+        }
+    }
+    // The leftmost match of any pattern at or after start. Of matches
+    // starting at the same place, the one of the first pattern wins.
+    fn find_at(&self, s: &str, start: uint) -> Option<MultiMatch> {
+        let m = self.leftmost(s, start);
+        match m {
+            Some(ref m) => self.record([m.pattern]),
+            None => self.record([])
+        }
+        return m;
+    }
+    // Like find_at, without counting hits.
+    fn leftmost(&self, s: &str, start: uint) -> Option<MultiMatch> {
+        let res = match self.kind {
+            Patterns(res) => res,
+            Literals(lits) => return lits.find_at(s, start)
+        };
+        let mut best: Option<MultiMatch> = None;
+        for uint::range(0, res.len()) |i| {
+            match self.find_one(i, s, start, false) {
+                Some(m) => {
+                    let better = match best {
+                        Some(ref b) => m.start < b.start,
+                        None => true
+                    };
+                    if better {
+                        best = Some(m);
+                    }
+                }
+                None => {}
+            }
+        }
+        return best;
+    }
+    // Call f with each successive non-overlapping match in s, as found by
+    // find_at. Empty matches abutting a preceding match are skipped.
+    fn each_match(&self, s: &str, f: fn(&MultiMatch) -> bool) {
+        let mut pos = 0;
+        let mut prev_end = None;
+        let mut patterns = ~[];
+        loop {
+            let m = match self.leftmost(s, pos) {
+                Some(m) => m,
+                None => break
+            };
+            if m.start != m.end || prev_end != Some(m.start) {
+                patterns.push(m.pattern);
+                if !f(&m) {
+                    break;
+                }
+                prev_end = Some(m.end);
+            }
+            match next_search(s, m.start, m.end) {
+                Some(next) => pos = next,
+                None => break
+            }
+        }
+        self.record_patterns(patterns);
+    }
+    // The matches of every pattern, each pattern's being found as by
+    // Regex::each_match, so that matches of different patterns may
+    // overlap. They are ordered by start, then by pattern.
+    fn overlapping(&self, s: &str) -> ~[MultiMatch] {
+        let mut matches = ~[];
+        for uint::range(0, self.len()) |i| {
+            let mut pos = 0;
+            let mut prev_end = None;
+            loop {
+                let m = match self.find_one(i, s, pos, false) {
+                    Some(m) => m,
+                    None => break
+                };
+                let next = next_search(s, m.start, m.end);
+                if m.start != m.end || prev_end != Some(m.start) {
+                    prev_end = Some(m.end);
+                    matches.push(m);
+                }
+                match next {
+                    Some(next) => pos = next,
+                    None => break
+                }
+            }
+        }
+        self.record_patterns(matches.map(|m| m.pattern));
+        return sort::merge_sort(|a, b| {
+            a.start < b.start || (a.start == b.start && a.pattern <= b.pattern)
+        }, matches);
+    }
+    // Split s into tokens, as a lexer does: at each position, the pattern
+    // with the longest match there makes the next token, the first one
+    // winning ties. Return the position where no pattern matches, or only
+    // the empty string, as an error.
+    fn tokenize(&self, s: &str) -> Result<~[MultiMatch], uint> {
+        let mut tokens = ~[];
+        let mut pos = 0;
+        while pos < s.len() {
+            let mut best: Option<MultiMatch> = None;
+            for uint::range(0, self.len()) |i| {
+                match self.find_one(i, s, pos, true) {
+                    Some(m) => {
+                        let better = match best {
+                            Some(ref b) => m.end > b.end,
+                            None => m.end > pos
+                        };
+                        if better {
+                            best = Some(m);
+                        }
+                    }
+                    None => {}
+                }
+            }
+            match best {
+                Some(m) => {
+                    pos = m.end;
+                    tokens.push(m);
+                }
+                None => {
+                    self.record_patterns(tokens.map(|m| m.pattern));
+                    return Err(pos);
+                }
+            }
+        }
+        self.record_patterns(tokens.map(|m| m.pattern));
+        return Ok(tokens);
+    }
+    // The numbers of the patterns matching somewhere in s, in order.
+    fn matches(&self, s: &str) -> ~[uint] {
+        let found = match self.kind {
+            Patterns(res) => {
+                let mut found = ~[];
+                for res.eachi |i, re| {
+                    if re.is_match(s) {
+                        found.push(i);
+                    }
+                }
+                found
+            }
+            Literals(lits) => lits.found(s, false)
+        };
+        self.record(found);
+        return found;
+    }
+    fn is_match(&self, s: &str) -> bool {
+        let found = match self.kind {
+            Patterns(res) => {
+                match vec::position(res, |re| re.is_match(s)) {
+                    Some(i) => ~[i],
+                    None => ~[]
+                }
+            }
+            Literals(lits) => lits.found(s, true)
+        };
+        self.record(found);
+        return found.is_not_empty();
+    }
+}
+
+#[test]
+fn test_set() {
+    let set = RegexSet::new(["a+b", "[0-9]", "x|y"]).get();
+    assert set.len() == 3;
+    assert set.matches("aab 1") == ~[0, 1];
+    assert set.matches("y") == ~[2];
+    assert !set.is_match("zzz");
+    assert RegexSet::new(["a", "("]).is_err();
+}
+
+#[test]
+fn test_set_config() {
+    let config = Config::new();
+    config.unicode = false;
+    let set = RegexSet::with_config(["(?i)k", "(?iu)k", "[0-9]+"],
+                                    &config).get();
+    assert set.matches("\u212a") == ~[1];
+    assert set.matches("K 1") == ~[0, 1, 2];
+    assert RegexSet::with_config(["a", "\\pL"], &config).is_err();
+    assert RegexSet::with_config(["(?u)\\pL"], &config).is_ok();
+}
+
+#[test]
+fn test_multi_match() {
+    fn spans(matches: &[MultiMatch]) -> ~[(uint, uint, uint)] {
+        matches.map(|m| (m.pattern, m.start, m.end))
+    }
+    let set = RegexSet::new(["[a-z]+", "[0-9]+", "b(c)"]).get();
+    let m = set.find_at("12 abc", 0).get();
+    assert (m.pattern, m.start, m.end) == (1, 0, 2);
+    assert m.captures == Some(~[Some(0), Some(2)]);
+    let mut found = ~[];
+    for set.each_match("ab 12") |m| {
+        found.push(copy *m);
+    }
+    assert spans(found) == ~[(0, 0, 2), (1, 3, 5)];
+    assert spans(set.overlapping("abc1")) ==
+        ~[(0, 0, 3), (2, 1, 3), (1, 3, 4)];
+    let m = set.overlapping("bc")[1];
+    assert m.captures == Some(~[Some(0), Some(2), Some(1), Some(2)]);
+    let set = RegexSet::from_literals(["if", "=", "=="]);
+    assert spans(set.overlapping("a==b")) ==
+        ~[(1, 1, 2), (2, 1, 3), (1, 2, 3)];
+    let lexer = RegexSet::new(["if", "[a-z]+", "==?", " +"]).get();
+    assert spans(lexer.tokenize("if iff == x").get()) ==
+        ~[(0, 0, 2), (3, 2, 3), (1, 3, 6), (3, 6, 7), (2, 7, 9), (3, 9, 10),
+          (1, 10, 11)];
+    match lexer.tokenize("if 1") {
+        Err(pos) => assert pos == 3,
+        Ok(_) => fail
+    }
+}
+
+#[test]
+fn test_hit_counts() {
+    let set = RegexSet::from_literals(["a", "b", "c"]);
+    set.matches("ab");
+    assert set.hit_counts().is_none();
+    set.count_hits();
+    set.matches("ab");
+    set.matches("b");
+    assert set.is_match("abc");
+    assert !set.is_match("x");
+    let counts = set.hit_counts().get();
+    assert counts.searches == 4;
+    assert counts.hits == ~[2, 2, 0];
+    assert counts.unused() == ~[2];
+    set.count_hits();
+    assert set.hit_counts().get().searches == 0;
+    let set = RegexSet::new(["[a-z]+", "[0-9]+", "!"]).get();
+    set.count_hits();
+    assert set.find_at("12 ab", 0).get().pattern == 1;
+    for set.each_match("ab 12 cd") |_m| {}
+    assert set.overlapping("a1").len() == 2;
+    assert set.tokenize("ab12").is_ok();
+    assert set.tokenize("ab!?").is_err();
+    let counts = set.hit_counts().get();
+    assert counts.searches == 5;
+    assert counts.hits == ~[4, 4, 1];
+}
+
+#[test]
+fn test_from_literals() {
+    let set = RegexSet::from_literals(["a.b", "(", "foo"]);
+    assert set.len() == 3;
+    assert set.matches("a.b(") == ~[0, 1];
+    assert set.matches("axb") == ~[];
+    assert set.is_match("food");
+    assert !set.is_match("fo");
+    let set = RegexSet::from_literals(["ab", "b", "abc", "b", ""]);
+    assert set.matches("xabc") == ~[0, 1, 2, 3, 4];
+    assert set.matches("") == ~[4];
+    let set = RegexSet::from_literals(["bc", "abcd", "b", "bc"]);
+    assert set.matches("abc") == ~[0, 2, 3];
+    let m = set.find_at("abcd", 0).get();
+    assert (m.pattern, m.start, m.end) == (1, 0, 4);
+    let m = set.find_at("abcd", 1).get();
+    assert (m.pattern, m.start, m.end) == (0, 1, 3);
+    assert set.find_at("abcd", 3).is_none();
+}
+
+#[test]
+fn test_set_memory_usage() {
+    let small = RegexSet::new(["a"]).get();
+    let large = RegexSet::new(["a", "(abc|def)*"]).get();
+    assert small.memory_usage() > 0;
+    assert small.memory_usage() < large.memory_usage();
+    let small = RegexSet::from_literals(["a"]);
+    let large = RegexSet::from_literals(["a", "abcdef"]);
+    assert small.memory_usage() > 0;
+    assert small.memory_usage() < large.memory_usage();
+}
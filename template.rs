@@ -0,0 +1,239 @@
+use result::{Result, Ok, Err};
+use parse::{Error, InvalidNamedCapture, Parser, is_word_char};
+
+// A replacement template is text with references to capture groups,
+// written $name or ${name}, where name is a group name or number.
+// $$ stands for a literal $.
+enum Piece {
+    Text(~str),
+    Ref(~str)
+}
+
+// Parse the reference following a $, returning the name and the length
+// consumed.
+fn parse_ref(t: &str) -> Option<(~str, uint)> {
+    let braced = t.starts_with("{");
+    let mut name = ~"";
+    let mut u = if braced { str::view(t, 1, t.len()) } else { t };
+    while u.is_not_empty() {
+        let (c, v) = str::view_shift_char(u);
+        if !is_word_char(c) {
+            break;
+        }
+        str::push_char(&mut name, c);
+        u = v;
+    }
+    if name.is_empty() {
+        return None;
+    }
+    if braced {
+        if !u.starts_with("}") {
+            return None;
+        }
+        u = str::view(u, 1, u.len());
+    }
+    return Some((name, t.len() - u.len()));
+}
+
+// A $ that does not start a valid reference is taken literally.
+fn parse_template(s: &str) -> ~[Piece] {
+    let mut pieces = ~[];
+    let mut text = ~"";
+    let mut t = s;
+    while t.is_not_empty() {
+        let (c, u) = str::view_shift_char(t);
+        t = u;
+        if c != '$' {
+            str::push_char(&mut text, c);
+        } else if t.starts_with("$") {
+            str::push_char(&mut text, '$');
+            t = str::view(t, 1, t.len());
+        } else {
+            match parse_ref(t) {
+                Some((name, len)) => {
+                    if text.is_not_empty() {
+                        pieces.push(Text(text));
+                        text = ~"";
+                    }
+                    pieces.push(Ref(name));
+                    t = str::view(t, len, t.len());
+                }
+                None => {
+                    str::push_char(&mut text, '$');
+                }
+            }
+        }
+    }
+    if text.is_not_empty() {
+        pieces.push(Text(text));
+    }
+    return pieces;
+}
+
+// References are always written braced, so that a renamed reference is
+// never run together with the text following it.
+fn template_to_str(pieces: &[Piece]) -> ~str {
+    let mut t = ~"";
+    for pieces.each |piece| {
+        match *piece {
+            Text(text) => {
+                t += str::replace(text, "$", "$$");
+            }
+            Ref(name) => {
+                t += ~"${" + name + ~"}";
+            }
+        }
+    }
+    return t;
+}
+
+// The number of the group a reference refers to, given the group names
+// indexed by number.
+fn group_index(name: &str, names: &[Option<~str>]) -> Option<uint> {
+    if str::all(name, char::is_digit) {
+        // Numbers are read digit by digit, giving up once past the last
+        // group, so that huge ones do not wrap around to a group.
+        let mut i = 0;
+        for str::each_char(name) |c| {
+            i = i * 10 + (c as uint - '0' as uint);
+            if i >= names.len() {
+                return None;
+            }
+        }
+        return Some(i);
+    }
+    for names.eachi |i, n| {
+        match *n {
+            Some(ref n) if str::eq_slice(*n, name) => return Some(i),
+            _ => {}
+        }
+    }
+    return None;
+}
+
+// Append pieces to dst, with each reference replaced by the text of s its
+// group matched, given the capture slots and group names of a match.
+// References to unknown or unset groups stand for the empty string.
+fn expand_pieces(pieces: &[Piece], s: &str, caps: &[Option<uint>],
+                 names: &[Option<~str>], dst: &mut ~str) {
+    for pieces.each |piece| {
+        match *piece {
+            Text(text) => {
+                str::push_str(dst, text);
+            }
+            Ref(name) => {
+                let i = match group_index(name, names) {
+                    Some(i) if i < caps.len() / 2 => i,
+                    _ => again
+                };
+                match (caps[2 * i], caps[2 * i + 1]) {
+                    (Some(start), Some(end)) => {
+                        str::push_str(dst, str::view(s, start, end));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn find_rename(renames: &[(~str, ~str)], name: &str) -> Option<~str> {
+    for renames.each |r| {
+        let (old, new) = copy *r;
+        if str::eq_slice(old, name) {
+            return Some(new);
+        }
+    }
+    return None;
+}
+
+fn rename_name(renames: &[(~str, ~str)], name: &str) -> ~str {
+    match find_rename(renames, name) {
+        Some(new) => new,
+        None => name.to_str()
+    }
+}
+
+fn rename_template(pieces: &[Piece], renames: &[(~str, ~str)]) -> ~[Piece] {
+    do pieces.map |piece| {
+        match *piece {
+            Text(text) => Text(copy text),
+            Ref(name) => Ref(rename_name(renames, name))
+        }
+    }
+}
+
+// Rename groups in a pattern and a replacement template together, so
+// that the template still refers to the same groups. Numbered references
+// are left alone.
+fn rename_groups(pattern: &str, template: &str, renames: &[(~str, ~str)])
+    -> Result<(~str, ~str), Error> {
+    for renames.each |r| {
+        let (_, new) = copy *r;
+        if new.is_empty() || !str::all(new, is_word_char) {
+            return Err(InvalidNamedCapture);
+        }
+    }
+    let p = Parser::new();
+    match p.parse(pattern) {
+        Ok(_) => {}
+        Err(e) => return Err(e)
+    }
+    // The new names are spliced into the pattern as written, after the
+    // (?P< of each group, so that the rest of it is left as is.
+    // The renamed pattern must not have two groups of one name, as after
+    // renaming a to b in (?P<a>x)(?P<b>y), or the template could no longer
+    // tell them apart.
+    let mut t = ~"";
+    let mut last = 0;
+    let mut names = ~[];
+    for p.groups.each |group| {
+        match group.name {
+            Some(ref name) => {
+                let new = rename_name(renames, *name);
+                if names.any(|n| str::eq_slice(*n, new)) {
+                    return Err(InvalidNamedCapture);
+                }
+                let start = group.start + 4;
+                str::push_str(&mut t, str::view(pattern, last, start));
+                str::push_str(&mut t, new);
+                names.push(new);
+                last = start + name.len();
+            }
+            None => {}
+        }
+    }
+    str::push_str(&mut t, str::view(pattern, last, pattern.len()));
+    let pieces = rename_template(parse_template(template), renames);
+    return Ok((t, template_to_str(pieces)));
+}
+
+#[test]
+fn test_parse_template() {
+    fn test(s: &str, t: &str) {
+        assert template_to_str(parse_template(s)) == t.to_str();
+    }
+    test("", "");
+    test("abc", "abc");
+    test("$1", "${1}");
+    test("$name-${x}y", "${name}-${x}y");
+    test("$$1", "$$1");
+    test("$ ${ ${x", "$$ $${ $${x");
+}
+
+#[test]
+fn test_rename_groups() {
+    let renames = ~[(~"a", ~"first"), (~"b", ~"a")];
+    let (p, t) = rename_groups("(?P<a>x)(?P<b>y)(z)", "$b.$a.$3",
+                               renames).get();
+    assert p == ~"(?P<first>x)(?P<a>y)(z)";
+    assert t == ~"${a}.${first}.${3}";
+    let (p, _) = rename_groups("(?ix)(?P<a>[a-z]+) # word\n(?P<b>.)", "",
+                               renames).get();
+    assert p == ~"(?ix)(?P<first>[a-z]+) # word\n(?P<a>.)";
+    assert rename_groups("(?P<a>x)", "", ~[(~"a", ~"")]).is_err();
+    assert rename_groups("(?P<a>x)", "", ~[(~"a", ~"b c")]).is_err();
+    assert rename_groups("(?P<a>x)(?P<b>y)", "$b", ~[(~"a", ~"b")])
+        .is_err();
+    assert rename_groups("(?P<a>x)(?P<a>y)", "", ~[]).is_err();
+}
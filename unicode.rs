@@ -0,0 +1,5594 @@
+// Generated by etc/unicode.pl. Do not edit.
+
+const unicode_version: (uint, uint, uint) = (14, 0, 0);
+
+fn category_c() -> ~[(char, char)] {
+    ~[
+        ('\u0000', '\u001f'), ('\u007f', '\u009f'), ('\u00ad', '\u00ad'),
+        ('\u0600', '\u0605'), ('\u061c', '\u061c'), ('\u06dd', '\u06dd'),
+        ('\u070f', '\u070f'), ('\u0890', '\u0891'), ('\u08e2', '\u08e2'),
+        ('\u180e', '\u180e'), ('\u200b', '\u200f'), ('\u202a', '\u202e'),
+        ('\u2060', '\u2064'), ('\u2066', '\u206f'), ('\ue000', '\uf8ff'),
+        ('\ufeff', '\ufeff'), ('\ufff9', '\ufffb'), ('\U000110bd', '\U000110bd'),
+        ('\U000110cd', '\U000110cd'), ('\U00013430', '\U00013438'), ('\U0001bca0', '\U0001bca3'),
+        ('\U0001d173', '\U0001d17a'), ('\U000e0001', '\U000e0001'), ('\U000e0020', '\U000e007f'),
+        ('\U000f0000', '\U000ffffd'), ('\U00100000', '\U0010fffd')
+    ]
+}
+
+fn category_cc() -> ~[(char, char)] {
+    ~[
+        ('\u0000', '\u001f'), ('\u007f', '\u009f')
+    ]
+}
+
+fn category_cf() -> ~[(char, char)] {
+    ~[
+        ('\u00ad', '\u00ad'), ('\u0600', '\u0605'), ('\u061c', '\u061c'),
+        ('\u06dd', '\u06dd'), ('\u070f', '\u070f'), ('\u0890', '\u0891'),
+        ('\u08e2', '\u08e2'), ('\u180e', '\u180e'), ('\u200b', '\u200f'),
+        ('\u202a', '\u202e'), ('\u2060', '\u2064'), ('\u2066', '\u206f'),
+        ('\ufeff', '\ufeff'), ('\ufff9', '\ufffb'), ('\U000110bd', '\U000110bd'),
+        ('\U000110cd', '\U000110cd'), ('\U00013430', '\U00013438'), ('\U0001bca0', '\U0001bca3'),
+        ('\U0001d173', '\U0001d17a'), ('\U000e0001', '\U000e0001'), ('\U000e0020', '\U000e007f')
+    ]
+}
+
+fn category_co() -> ~[(char, char)] {
+    ~[
+        ('\ue000', '\uf8ff'), ('\U000f0000', '\U000ffffd'), ('\U00100000', '\U0010fffd')
+    ]
+}
+
+fn category_l() -> ~[(char, char)] {
+    ~[
+        ('\u0041', '\u005a'), ('\u0061', '\u007a'), ('\u00aa', '\u00aa'),
+        ('\u00b5', '\u00b5'), ('\u00ba', '\u00ba'), ('\u00c0', '\u00d6'),
+        ('\u00d8', '\u00f6'), ('\u00f8', '\u02c1'), ('\u02c6', '\u02d1'),
+        ('\u02e0', '\u02e4'), ('\u02ec', '\u02ec'), ('\u02ee', '\u02ee'),
+        ('\u0370', '\u0374'), ('\u0376', '\u0377'), ('\u037a', '\u037d'),
+        ('\u037f', '\u037f'), ('\u0386', '\u0386'), ('\u0388', '\u038a'),
+        ('\u038c', '\u038c'), ('\u038e', '\u03a1'), ('\u03a3', '\u03f5'),
+        ('\u03f7', '\u0481'), ('\u048a', '\u052f'), ('\u0531', '\u0556'),
+        ('\u0559', '\u0559'), ('\u0560', '\u0588'), ('\u05d0', '\u05ea'),
+        ('\u05ef', '\u05f2'), ('\u0620', '\u064a'), ('\u066e', '\u066f'),
+        ('\u0671', '\u06d3'), ('\u06d5', '\u06d5'), ('\u06e5', '\u06e6'),
+        ('\u06ee', '\u06ef'), ('\u06fa', '\u06fc'), ('\u06ff', '\u06ff'),
+        ('\u0710', '\u0710'), ('\u0712', '\u072f'), ('\u074d', '\u07a5'),
+        ('\u07b1', '\u07b1'), ('\u07ca', '\u07ea'), ('\u07f4', '\u07f5'),
+        ('\u07fa', '\u07fa'), ('\u0800', '\u0815'), ('\u081a', '\u081a'),
+        ('\u0824', '\u0824'), ('\u0828', '\u0828'), ('\u0840', '\u0858'),
+        ('\u0860', '\u086a'), ('\u0870', '\u0887'), ('\u0889', '\u088e'),
+        ('\u08a0', '\u08c9'), ('\u0904', '\u0939'), ('\u093d', '\u093d'),
+        ('\u0950', '\u0950'), ('\u0958', '\u0961'), ('\u0971', '\u0980'),
+        ('\u0985', '\u098c'), ('\u098f', '\u0990'), ('\u0993', '\u09a8'),
+        ('\u09aa', '\u09b0'), ('\u09b2', '\u09b2'), ('\u09b6', '\u09b9'),
+        ('\u09bd', '\u09bd'), ('\u09ce', '\u09ce'), ('\u09dc', '\u09dd'),
+        ('\u09df', '\u09e1'), ('\u09f0', '\u09f1'), ('\u09fc', '\u09fc'),
+        ('\u0a05', '\u0a0a'), ('\u0a0f', '\u0a10'), ('\u0a13', '\u0a28'),
+        ('\u0a2a', '\u0a30'), ('\u0a32', '\u0a33'), ('\u0a35', '\u0a36'),
+        ('\u0a38', '\u0a39'), ('\u0a59', '\u0a5c'), ('\u0a5e', '\u0a5e'),
+        ('\u0a72', '\u0a74'), ('\u0a85', '\u0a8d'), ('\u0a8f', '\u0a91'),
+        ('\u0a93', '\u0aa8'), ('\u0aaa', '\u0ab0'), ('\u0ab2', '\u0ab3'),
+        ('\u0ab5', '\u0ab9'), ('\u0abd', '\u0abd'), ('\u0ad0', '\u0ad0'),
+        ('\u0ae0', '\u0ae1'), ('\u0af9', '\u0af9'), ('\u0b05', '\u0b0c'),
+        ('\u0b0f', '\u0b10'), ('\u0b13', '\u0b28'), ('\u0b2a', '\u0b30'),
+        ('\u0b32', '\u0b33'), ('\u0b35', '\u0b39'), ('\u0b3d', '\u0b3d'),
+        ('\u0b5c', '\u0b5d'), ('\u0b5f', '\u0b61'), ('\u0b71', '\u0b71'),
+        ('\u0b83', '\u0b83'), ('\u0b85', '\u0b8a'), ('\u0b8e', '\u0b90'),
+        ('\u0b92', '\u0b95'), ('\u0b99', '\u0b9a'), ('\u0b9c', '\u0b9c'),
+        ('\u0b9e', '\u0b9f'), ('\u0ba3', '\u0ba4'), ('\u0ba8', '\u0baa'),
+        ('\u0bae', '\u0bb9'), ('\u0bd0', '\u0bd0'), ('\u0c05', '\u0c0c'),
+        ('\u0c0e', '\u0c10'), ('\u0c12', '\u0c28'), ('\u0c2a', '\u0c39'),
+        ('\u0c3d', '\u0c3d'), ('\u0c58', '\u0c5a'), ('\u0c5d', '\u0c5d'),
+        ('\u0c60', '\u0c61'), ('\u0c80', '\u0c80'), ('\u0c85', '\u0c8c'),
+        ('\u0c8e', '\u0c90'), ('\u0c92', '\u0ca8'), ('\u0caa', '\u0cb3'),
+        ('\u0cb5', '\u0cb9'), ('\u0cbd', '\u0cbd'), ('\u0cdd', '\u0cde'),
+        ('\u0ce0', '\u0ce1'), ('\u0cf1', '\u0cf2'), ('\u0d04', '\u0d0c'),
+        ('\u0d0e', '\u0d10'), ('\u0d12', '\u0d3a'), ('\u0d3d', '\u0d3d'),
+        ('\u0d4e', '\u0d4e'), ('\u0d54', '\u0d56'), ('\u0d5f', '\u0d61'),
+        ('\u0d7a', '\u0d7f'), ('\u0d85', '\u0d96'), ('\u0d9a', '\u0db1'),
+        ('\u0db3', '\u0dbb'), ('\u0dbd', '\u0dbd'), ('\u0dc0', '\u0dc6'),
+        ('\u0e01', '\u0e30'), ('\u0e32', '\u0e33'), ('\u0e40', '\u0e46'),
+        ('\u0e81', '\u0e82'), ('\u0e84', '\u0e84'), ('\u0e86', '\u0e8a'),
+        ('\u0e8c', '\u0ea3'), ('\u0ea5', '\u0ea5'), ('\u0ea7', '\u0eb0'),
+        ('\u0eb2', '\u0eb3'), ('\u0ebd', '\u0ebd'), ('\u0ec0', '\u0ec4'),
+        ('\u0ec6', '\u0ec6'), ('\u0edc', '\u0edf'), ('\u0f00', '\u0f00'),
+        ('\u0f40', '\u0f47'), ('\u0f49', '\u0f6c'), ('\u0f88', '\u0f8c'),
+        ('\u1000', '\u102a'), ('\u103f', '\u103f'), ('\u1050', '\u1055'),
+        ('\u105a', '\u105d'), ('\u1061', '\u1061'), ('\u1065', '\u1066'),
+        ('\u106e', '\u1070'), ('\u1075', '\u1081'), ('\u108e', '\u108e'),
+        ('\u10a0', '\u10c5'), ('\u10c7', '\u10c7'), ('\u10cd', '\u10cd'),
+        ('\u10d0', '\u10fa'), ('\u10fc', '\u1248'), ('\u124a', '\u124d'),
+        ('\u1250', '\u1256'), ('\u1258', '\u1258'), ('\u125a', '\u125d'),
+        ('\u1260', '\u1288'), ('\u128a', '\u128d'), ('\u1290', '\u12b0'),
+        ('\u12b2', '\u12b5'), ('\u12b8', '\u12be'), ('\u12c0', '\u12c0'),
+        ('\u12c2', '\u12c5'), ('\u12c8', '\u12d6'), ('\u12d8', '\u1310'),
+        ('\u1312', '\u1315'), ('\u1318', '\u135a'), ('\u1380', '\u138f'),
+        ('\u13a0', '\u13f5'), ('\u13f8', '\u13fd'), ('\u1401', '\u166c'),
+        ('\u166f', '\u167f'), ('\u1681', '\u169a'), ('\u16a0', '\u16ea'),
+        ('\u16f1', '\u16f8'), ('\u1700', '\u1711'), ('\u171f', '\u1731'),
+        ('\u1740', '\u1751'), ('\u1760', '\u176c'), ('\u176e', '\u1770'),
+        ('\u1780', '\u17b3'), ('\u17d7', '\u17d7'), ('\u17dc', '\u17dc'),
+        ('\u1820', '\u1878'), ('\u1880', '\u1884'), ('\u1887', '\u18a8'),
+        ('\u18aa', '\u18aa'), ('\u18b0', '\u18f5'), ('\u1900', '\u191e'),
+        ('\u1950', '\u196d'), ('\u1970', '\u1974'), ('\u1980', '\u19ab'),
+        ('\u19b0', '\u19c9'), ('\u1a00', '\u1a16'), ('\u1a20', '\u1a54'),
+        ('\u1aa7', '\u1aa7'), ('\u1b05', '\u1b33'), ('\u1b45', '\u1b4c'),
+        ('\u1b83', '\u1ba0'), ('\u1bae', '\u1baf'), ('\u1bba', '\u1be5'),
+        ('\u1c00', '\u1c23'), ('\u1c4d', '\u1c4f'), ('\u1c5a', '\u1c7d'),
+        ('\u1c80', '\u1c88'), ('\u1c90', '\u1cba'), ('\u1cbd', '\u1cbf'),
+        ('\u1ce9', '\u1cec'), ('\u1cee', '\u1cf3'), ('\u1cf5', '\u1cf6'),
+        ('\u1cfa', '\u1cfa'), ('\u1d00', '\u1dbf'), ('\u1e00', '\u1f15'),
+        ('\u1f18', '\u1f1d'), ('\u1f20', '\u1f45'), ('\u1f48', '\u1f4d'),
+        ('\u1f50', '\u1f57'), ('\u1f59', '\u1f59'), ('\u1f5b', '\u1f5b'),
+        ('\u1f5d', '\u1f5d'), ('\u1f5f', '\u1f7d'), ('\u1f80', '\u1fb4'),
+        ('\u1fb6', '\u1fbc'), ('\u1fbe', '\u1fbe'), ('\u1fc2', '\u1fc4'),
+        ('\u1fc6', '\u1fcc'), ('\u1fd0', '\u1fd3'), ('\u1fd6', '\u1fdb'),
+        ('\u1fe0', '\u1fec'), ('\u1ff2', '\u1ff4'), ('\u1ff6', '\u1ffc'),
+        ('\u2071', '\u2071'), ('\u207f', '\u207f'), ('\u2090', '\u209c'),
+        ('\u2102', '\u2102'), ('\u2107', '\u2107'), ('\u210a', '\u2113'),
+        ('\u2115', '\u2115'), ('\u2119', '\u211d'), ('\u2124', '\u2124'),
+        ('\u2126', '\u2126'), ('\u2128', '\u2128'), ('\u212a', '\u212d'),
+        ('\u212f', '\u2139'), ('\u213c', '\u213f'), ('\u2145', '\u2149'),
+        ('\u214e', '\u214e'), ('\u2183', '\u2184'), ('\u2c00', '\u2ce4'),
+        ('\u2ceb', '\u2cee'), ('\u2cf2', '\u2cf3'), ('\u2d00', '\u2d25'),
+        ('\u2d27', '\u2d27'), ('\u2d2d', '\u2d2d'), ('\u2d30', '\u2d67'),
+        ('\u2d6f', '\u2d6f'), ('\u2d80', '\u2d96'), ('\u2da0', '\u2da6'),
+        ('\u2da8', '\u2dae'), ('\u2db0', '\u2db6'), ('\u2db8', '\u2dbe'),
+        ('\u2dc0', '\u2dc6'), ('\u2dc8', '\u2dce'), ('\u2dd0', '\u2dd6'),
+        ('\u2dd8', '\u2dde'), ('\u2e2f', '\u2e2f'), ('\u3005', '\u3006'),
+        ('\u3031', '\u3035'), ('\u303b', '\u303c'), ('\u3041', '\u3096'),
+        ('\u309d', '\u309f'), ('\u30a1', '\u30fa'), ('\u30fc', '\u30ff'),
+        ('\u3105', '\u312f'), ('\u3131', '\u318e'), ('\u31a0', '\u31bf'),
+        ('\u31f0', '\u31ff'), ('\u3400', '\u4dbf'), ('\u4e00', '\ua48c'),
+        ('\ua4d0', '\ua4fd'), ('\ua500', '\ua60c'), ('\ua610', '\ua61f'),
+        ('\ua62a', '\ua62b'), ('\ua640', '\ua66e'), ('\ua67f', '\ua69d'),
+        ('\ua6a0', '\ua6e5'), ('\ua717', '\ua71f'), ('\ua722', '\ua788'),
+        ('\ua78b', '\ua7ca'), ('\ua7d0', '\ua7d1'), ('\ua7d3', '\ua7d3'),
+        ('\ua7d5', '\ua7d9'), ('\ua7f2', '\ua801'), ('\ua803', '\ua805'),
+        ('\ua807', '\ua80a'), ('\ua80c', '\ua822'), ('\ua840', '\ua873'),
+        ('\ua882', '\ua8b3'), ('\ua8f2', '\ua8f7'), ('\ua8fb', '\ua8fb'),
+        ('\ua8fd', '\ua8fe'), ('\ua90a', '\ua925'), ('\ua930', '\ua946'),
+        ('\ua960', '\ua97c'), ('\ua984', '\ua9b2'), ('\ua9cf', '\ua9cf'),
+        ('\ua9e0', '\ua9e4'), ('\ua9e6', '\ua9ef'), ('\ua9fa', '\ua9fe'),
+        ('\uaa00', '\uaa28'), ('\uaa40', '\uaa42'), ('\uaa44', '\uaa4b'),
+        ('\uaa60', '\uaa76'), ('\uaa7a', '\uaa7a'), ('\uaa7e', '\uaaaf'),
+        ('\uaab1', '\uaab1'), ('\uaab5', '\uaab6'), ('\uaab9', '\uaabd'),
+        ('\uaac0', '\uaac0'), ('\uaac2', '\uaac2'), ('\uaadb', '\uaadd'),
+        ('\uaae0', '\uaaea'), ('\uaaf2', '\uaaf4'), ('\uab01', '\uab06'),
+        ('\uab09', '\uab0e'), ('\uab11', '\uab16'), ('\uab20', '\uab26'),
+        ('\uab28', '\uab2e'), ('\uab30', '\uab5a'), ('\uab5c', '\uab69'),
+        ('\uab70', '\uabe2'), ('\uac00', '\ud7a3'), ('\ud7b0', '\ud7c6'),
+        ('\ud7cb', '\ud7fb'), ('\uf900', '\ufa6d'), ('\ufa70', '\ufad9'),
+        ('\ufb00', '\ufb06'), ('\ufb13', '\ufb17'), ('\ufb1d', '\ufb1d'),
+        ('\ufb1f', '\ufb28'), ('\ufb2a', '\ufb36'), ('\ufb38', '\ufb3c'),
+        ('\ufb3e', '\ufb3e'), ('\ufb40', '\ufb41'), ('\ufb43', '\ufb44'),
+        ('\ufb46', '\ufbb1'), ('\ufbd3', '\ufd3d'), ('\ufd50', '\ufd8f'),
+        ('\ufd92', '\ufdc7'), ('\ufdf0', '\ufdfb'), ('\ufe70', '\ufe74'),
+        ('\ufe76', '\ufefc'), ('\uff21', '\uff3a'), ('\uff41', '\uff5a'),
+        ('\uff66', '\uffbe'), ('\uffc2', '\uffc7'), ('\uffca', '\uffcf'),
+        ('\uffd2', '\uffd7'), ('\uffda', '\uffdc'), ('\U00010000', '\U0001000b'),
+        ('\U0001000d', '\U00010026'), ('\U00010028', '\U0001003a'), ('\U0001003c', '\U0001003d'),
+        ('\U0001003f', '\U0001004d'), ('\U00010050', '\U0001005d'), ('\U00010080', '\U000100fa'),
+        ('\U00010280', '\U0001029c'), ('\U000102a0', '\U000102d0'), ('\U00010300', '\U0001031f'),
+        ('\U0001032d', '\U00010340'), ('\U00010342', '\U00010349'), ('\U00010350', '\U00010375'),
+        ('\U00010380', '\U0001039d'), ('\U000103a0', '\U000103c3'), ('\U000103c8', '\U000103cf'),
+        ('\U00010400', '\U0001049d'), ('\U000104b0', '\U000104d3'), ('\U000104d8', '\U000104fb'),
+        ('\U00010500', '\U00010527'), ('\U00010530', '\U00010563'), ('\U00010570', '\U0001057a'),
+        ('\U0001057c', '\U0001058a'), ('\U0001058c', '\U00010592'), ('\U00010594', '\U00010595'),
+        ('\U00010597', '\U000105a1'), ('\U000105a3', '\U000105b1'), ('\U000105b3', '\U000105b9'),
+        ('\U000105bb', '\U000105bc'), ('\U00010600', '\U00010736'), ('\U00010740', '\U00010755'),
+        ('\U00010760', '\U00010767'), ('\U00010780', '\U00010785'), ('\U00010787', '\U000107b0'),
+        ('\U000107b2', '\U000107ba'), ('\U00010800', '\U00010805'), ('\U00010808', '\U00010808'),
+        ('\U0001080a', '\U00010835'), ('\U00010837', '\U00010838'), ('\U0001083c', '\U0001083c'),
+        ('\U0001083f', '\U00010855'), ('\U00010860', '\U00010876'), ('\U00010880', '\U0001089e'),
+        ('\U000108e0', '\U000108f2'), ('\U000108f4', '\U000108f5'), ('\U00010900', '\U00010915'),
+        ('\U00010920', '\U00010939'), ('\U00010980', '\U000109b7'), ('\U000109be', '\U000109bf'),
+        ('\U00010a00', '\U00010a00'), ('\U00010a10', '\U00010a13'), ('\U00010a15', '\U00010a17'),
+        ('\U00010a19', '\U00010a35'), ('\U00010a60', '\U00010a7c'), ('\U00010a80', '\U00010a9c'),
+        ('\U00010ac0', '\U00010ac7'), ('\U00010ac9', '\U00010ae4'), ('\U00010b00', '\U00010b35'),
+        ('\U00010b40', '\U00010b55'), ('\U00010b60', '\U00010b72'), ('\U00010b80', '\U00010b91'),
+        ('\U00010c00', '\U00010c48'), ('\U00010c80', '\U00010cb2'), ('\U00010cc0', '\U00010cf2'),
+        ('\U00010d00', '\U00010d23'), ('\U00010e80', '\U00010ea9'), ('\U00010eb0', '\U00010eb1'),
+        ('\U00010f00', '\U00010f1c'), ('\U00010f27', '\U00010f27'), ('\U00010f30', '\U00010f45'),
+        ('\U00010f70', '\U00010f81'), ('\U00010fb0', '\U00010fc4'), ('\U00010fe0', '\U00010ff6'),
+        ('\U00011003', '\U00011037'), ('\U00011071', '\U00011072'), ('\U00011075', '\U00011075'),
+        ('\U00011083', '\U000110af'), ('\U000110d0', '\U000110e8'), ('\U00011103', '\U00011126'),
+        ('\U00011144', '\U00011144'), ('\U00011147', '\U00011147'), ('\U00011150', '\U00011172'),
+        ('\U00011176', '\U00011176'), ('\U00011183', '\U000111b2'), ('\U000111c1', '\U000111c4'),
+        ('\U000111da', '\U000111da'), ('\U000111dc', '\U000111dc'), ('\U00011200', '\U00011211'),
+        ('\U00011213', '\U0001122b'), ('\U00011280', '\U00011286'), ('\U00011288', '\U00011288'),
+        ('\U0001128a', '\U0001128d'), ('\U0001128f', '\U0001129d'), ('\U0001129f', '\U000112a8'),
+        ('\U000112b0', '\U000112de'), ('\U00011305', '\U0001130c'), ('\U0001130f', '\U00011310'),
+        ('\U00011313', '\U00011328'), ('\U0001132a', '\U00011330'), ('\U00011332', '\U00011333'),
+        ('\U00011335', '\U00011339'), ('\U0001133d', '\U0001133d'), ('\U00011350', '\U00011350'),
+        ('\U0001135d', '\U00011361'), ('\U00011400', '\U00011434'), ('\U00011447', '\U0001144a'),
+        ('\U0001145f', '\U00011461'), ('\U00011480', '\U000114af'), ('\U000114c4', '\U000114c5'),
+        ('\U000114c7', '\U000114c7'), ('\U00011580', '\U000115ae'), ('\U000115d8', '\U000115db'),
+        ('\U00011600', '\U0001162f'), ('\U00011644', '\U00011644'), ('\U00011680', '\U000116aa'),
+        ('\U000116b8', '\U000116b8'), ('\U00011700', '\U0001171a'), ('\U00011740', '\U00011746'),
+        ('\U00011800', '\U0001182b'), ('\U000118a0', '\U000118df'), ('\U000118ff', '\U00011906'),
+        ('\U00011909', '\U00011909'), ('\U0001190c', '\U00011913'), ('\U00011915', '\U00011916'),
+        ('\U00011918', '\U0001192f'), ('\U0001193f', '\U0001193f'), ('\U00011941', '\U00011941'),
+        ('\U000119a0', '\U000119a7'), ('\U000119aa', '\U000119d0'), ('\U000119e1', '\U000119e1'),
+        ('\U000119e3', '\U000119e3'), ('\U00011a00', '\U00011a00'), ('\U00011a0b', '\U00011a32'),
+        ('\U00011a3a', '\U00011a3a'), ('\U00011a50', '\U00011a50'), ('\U00011a5c', '\U00011a89'),
+        ('\U00011a9d', '\U00011a9d'), ('\U00011ab0', '\U00011af8'), ('\U00011c00', '\U00011c08'),
+        ('\U00011c0a', '\U00011c2e'), ('\U00011c40', '\U00011c40'), ('\U00011c72', '\U00011c8f'),
+        ('\U00011d00', '\U00011d06'), ('\U00011d08', '\U00011d09'), ('\U00011d0b', '\U00011d30'),
+        ('\U00011d46', '\U00011d46'), ('\U00011d60', '\U00011d65'), ('\U00011d67', '\U00011d68'),
+        ('\U00011d6a', '\U00011d89'), ('\U00011d98', '\U00011d98'), ('\U00011ee0', '\U00011ef2'),
+        ('\U00011fb0', '\U00011fb0'), ('\U00012000', '\U00012399'), ('\U00012480', '\U00012543'),
+        ('\U00012f90', '\U00012ff0'), ('\U00013000', '\U0001342e'), ('\U00014400', '\U00014646'),
+        ('\U00016800', '\U00016a38'), ('\U00016a40', '\U00016a5e'), ('\U00016a70', '\U00016abe'),
+        ('\U00016ad0', '\U00016aed'), ('\U00016b00', '\U00016b2f'), ('\U00016b40', '\U00016b43'),
+        ('\U00016b63', '\U00016b77'), ('\U00016b7d', '\U00016b8f'), ('\U00016e40', '\U00016e7f'),
+        ('\U00016f00', '\U00016f4a'), ('\U00016f50', '\U00016f50'), ('\U00016f93', '\U00016f9f'),
+        ('\U00016fe0', '\U00016fe1'), ('\U00016fe3', '\U00016fe3'), ('\U00017000', '\U000187f7'),
+        ('\U00018800', '\U00018cd5'), ('\U00018d00', '\U00018d08'), ('\U0001aff0', '\U0001aff3'),
+        ('\U0001aff5', '\U0001affb'), ('\U0001affd', '\U0001affe'), ('\U0001b000', '\U0001b122'),
+        ('\U0001b150', '\U0001b152'), ('\U0001b164', '\U0001b167'), ('\U0001b170', '\U0001b2fb'),
+        ('\U0001bc00', '\U0001bc6a'), ('\U0001bc70', '\U0001bc7c'), ('\U0001bc80', '\U0001bc88'),
+        ('\U0001bc90', '\U0001bc99'), ('\U0001d400', '\U0001d454'), ('\U0001d456', '\U0001d49c'),
+        ('\U0001d49e', '\U0001d49f'), ('\U0001d4a2', '\U0001d4a2'), ('\U0001d4a5', '\U0001d4a6'),
+        ('\U0001d4a9', '\U0001d4ac'), ('\U0001d4ae', '\U0001d4b9'), ('\U0001d4bb', '\U0001d4bb'),
+        ('\U0001d4bd', '\U0001d4c3'), ('\U0001d4c5', '\U0001d505'), ('\U0001d507', '\U0001d50a'),
+        ('\U0001d50d', '\U0001d514'), ('\U0001d516', '\U0001d51c'), ('\U0001d51e', '\U0001d539'),
+        ('\U0001d53b', '\U0001d53e'), ('\U0001d540', '\U0001d544'), ('\U0001d546', '\U0001d546'),
+        ('\U0001d54a', '\U0001d550'), ('\U0001d552', '\U0001d6a5'), ('\U0001d6a8', '\U0001d6c0'),
+        ('\U0001d6c2', '\U0001d6da'), ('\U0001d6dc', '\U0001d6fa'), ('\U0001d6fc', '\U0001d714'),
+        ('\U0001d716', '\U0001d734'), ('\U0001d736', '\U0001d74e'), ('\U0001d750', '\U0001d76e'),
+        ('\U0001d770', '\U0001d788'), ('\U0001d78a', '\U0001d7a8'), ('\U0001d7aa', '\U0001d7c2'),
+        ('\U0001d7c4', '\U0001d7cb'), ('\U0001df00', '\U0001df1e'), ('\U0001e100', '\U0001e12c'),
+        ('\U0001e137', '\U0001e13d'), ('\U0001e14e', '\U0001e14e'), ('\U0001e290', '\U0001e2ad'),
+        ('\U0001e2c0', '\U0001e2eb'), ('\U0001e7e0', '\U0001e7e6'), ('\U0001e7e8', '\U0001e7eb'),
+        ('\U0001e7ed', '\U0001e7ee'), ('\U0001e7f0', '\U0001e7fe'), ('\U0001e800', '\U0001e8c4'),
+        ('\U0001e900', '\U0001e943'), ('\U0001e94b', '\U0001e94b'), ('\U0001ee00', '\U0001ee03'),
+        ('\U0001ee05', '\U0001ee1f'), ('\U0001ee21', '\U0001ee22'), ('\U0001ee24', '\U0001ee24'),
+        ('\U0001ee27', '\U0001ee27'), ('\U0001ee29', '\U0001ee32'), ('\U0001ee34', '\U0001ee37'),
+        ('\U0001ee39', '\U0001ee39'), ('\U0001ee3b', '\U0001ee3b'), ('\U0001ee42', '\U0001ee42'),
+        ('\U0001ee47', '\U0001ee47'), ('\U0001ee49', '\U0001ee49'), ('\U0001ee4b', '\U0001ee4b'),
+        ('\U0001ee4d', '\U0001ee4f'), ('\U0001ee51', '\U0001ee52'), ('\U0001ee54', '\U0001ee54'),
+        ('\U0001ee57', '\U0001ee57'), ('\U0001ee59', '\U0001ee59'), ('\U0001ee5b', '\U0001ee5b'),
+        ('\U0001ee5d', '\U0001ee5d'), ('\U0001ee5f', '\U0001ee5f'), ('\U0001ee61', '\U0001ee62'),
+        ('\U0001ee64', '\U0001ee64'), ('\U0001ee67', '\U0001ee6a'), ('\U0001ee6c', '\U0001ee72'),
+        ('\U0001ee74', '\U0001ee77'), ('\U0001ee79', '\U0001ee7c'), ('\U0001ee7e', '\U0001ee7e'),
+        ('\U0001ee80', '\U0001ee89'), ('\U0001ee8b', '\U0001ee9b'), ('\U0001eea1', '\U0001eea3'),
+        ('\U0001eea5', '\U0001eea9'), ('\U0001eeab', '\U0001eebb'), ('\U00020000', '\U0002a6df'),
+        ('\U0002a700', '\U0002b738'), ('\U0002b740', '\U0002b81d'), ('\U0002b820', '\U0002cea1'),
+        ('\U0002ceb0', '\U0002ebe0'), ('\U0002f800', '\U0002fa1d'), ('\U00030000', '\U0003134a')
+    ]
+}
+
+fn category_ll() -> ~[(char, char)] {
+    ~[
+        ('\u0061', '\u007a'), ('\u00b5', '\u00b5'), ('\u00df', '\u00f6'),
+        ('\u00f8', '\u00ff'), ('\u0101', '\u0101'), ('\u0103', '\u0103'),
+        ('\u0105', '\u0105'), ('\u0107', '\u0107'), ('\u0109', '\u0109'),
+        ('\u010b', '\u010b'), ('\u010d', '\u010d'), ('\u010f', '\u010f'),
+        ('\u0111', '\u0111'), ('\u0113', '\u0113'), ('\u0115', '\u0115'),
+        ('\u0117', '\u0117'), ('\u0119', '\u0119'), ('\u011b', '\u011b'),
+        ('\u011d', '\u011d'), ('\u011f', '\u011f'), ('\u0121', '\u0121'),
+        ('\u0123', '\u0123'), ('\u0125', '\u0125'), ('\u0127', '\u0127'),
+        ('\u0129', '\u0129'), ('\u012b', '\u012b'), ('\u012d', '\u012d'),
+        ('\u012f', '\u012f'), ('\u0131', '\u0131'), ('\u0133', '\u0133'),
+        ('\u0135', '\u0135'), ('\u0137', '\u0138'), ('\u013a', '\u013a'),
+        ('\u013c', '\u013c'), ('\u013e', '\u013e'), ('\u0140', '\u0140'),
+        ('\u0142', '\u0142'), ('\u0144', '\u0144'), ('\u0146', '\u0146'),
+        ('\u0148', '\u0149'), ('\u014b', '\u014b'), ('\u014d', '\u014d'),
+        ('\u014f', '\u014f'), ('\u0151', '\u0151'), ('\u0153', '\u0153'),
+        ('\u0155', '\u0155'), ('\u0157', '\u0157'), ('\u0159', '\u0159'),
+        ('\u015b', '\u015b'), ('\u015d', '\u015d'), ('\u015f', '\u015f'),
+        ('\u0161', '\u0161'), ('\u0163', '\u0163'), ('\u0165', '\u0165'),
+        ('\u0167', '\u0167'), ('\u0169', '\u0169'), ('\u016b', '\u016b'),
+        ('\u016d', '\u016d'), ('\u016f', '\u016f'), ('\u0171', '\u0171'),
+        ('\u0173', '\u0173'), ('\u0175', '\u0175'), ('\u0177', '\u0177'),
+        ('\u017a', '\u017a'), ('\u017c', '\u017c'), ('\u017e', '\u0180'),
+        ('\u0183', '\u0183'), ('\u0185', '\u0185'), ('\u0188', '\u0188'),
+        ('\u018c', '\u018d'), ('\u0192', '\u0192'), ('\u0195', '\u0195'),
+        ('\u0199', '\u019b'), ('\u019e', '\u019e'), ('\u01a1', '\u01a1'),
+        ('\u01a3', '\u01a3'), ('\u01a5', '\u01a5'), ('\u01a8', '\u01a8'),
+        ('\u01aa', '\u01ab'), ('\u01ad', '\u01ad'), ('\u01b0', '\u01b0'),
+        ('\u01b4', '\u01b4'), ('\u01b6', '\u01b6'), ('\u01b9', '\u01ba'),
+        ('\u01bd', '\u01bf'), ('\u01c6', '\u01c6'), ('\u01c9', '\u01c9'),
+        ('\u01cc', '\u01cc'), ('\u01ce', '\u01ce'), ('\u01d0', '\u01d0'),
+        ('\u01d2', '\u01d2'), ('\u01d4', '\u01d4'), ('\u01d6', '\u01d6'),
+        ('\u01d8', '\u01d8'), ('\u01da', '\u01da'), ('\u01dc', '\u01dd'),
+        ('\u01df', '\u01df'), ('\u01e1', '\u01e1'), ('\u01e3', '\u01e3'),
+        ('\u01e5', '\u01e5'), ('\u01e7', '\u01e7'), ('\u01e9', '\u01e9'),
+        ('\u01eb', '\u01eb'), ('\u01ed', '\u01ed'), ('\u01ef', '\u01f0'),
+        ('\u01f3', '\u01f3'), ('\u01f5', '\u01f5'), ('\u01f9', '\u01f9'),
+        ('\u01fb', '\u01fb'), ('\u01fd', '\u01fd'), ('\u01ff', '\u01ff'),
+        ('\u0201', '\u0201'), ('\u0203', '\u0203'), ('\u0205', '\u0205'),
+        ('\u0207', '\u0207'), ('\u0209', '\u0209'), ('\u020b', '\u020b'),
+        ('\u020d', '\u020d'), ('\u020f', '\u020f'), ('\u0211', '\u0211'),
+        ('\u0213', '\u0213'), ('\u0215', '\u0215'), ('\u0217', '\u0217'),
+        ('\u0219', '\u0219'), ('\u021b', '\u021b'), ('\u021d', '\u021d'),
+        ('\u021f', '\u021f'), ('\u0221', '\u0221'), ('\u0223', '\u0223'),
+        ('\u0225', '\u0225'), ('\u0227', '\u0227'), ('\u0229', '\u0229'),
+        ('\u022b', '\u022b'), ('\u022d', '\u022d'), ('\u022f', '\u022f'),
+        ('\u0231', '\u0231'), ('\u0233', '\u0239'), ('\u023c', '\u023c'),
+        ('\u023f', '\u0240'), ('\u0242', '\u0242'), ('\u0247', '\u0247'),
+        ('\u0249', '\u0249'), ('\u024b', '\u024b'), ('\u024d', '\u024d'),
+        ('\u024f', '\u0293'), ('\u0295', '\u02af'), ('\u0371', '\u0371'),
+        ('\u0373', '\u0373'), ('\u0377', '\u0377'), ('\u037b', '\u037d'),
+        ('\u0390', '\u0390'), ('\u03ac', '\u03ce'), ('\u03d0', '\u03d1'),
+        ('\u03d5', '\u03d7'), ('\u03d9', '\u03d9'), ('\u03db', '\u03db'),
+        ('\u03dd', '\u03dd'), ('\u03df', '\u03df'), ('\u03e1', '\u03e1'),
+        ('\u03e3', '\u03e3'), ('\u03e5', '\u03e5'), ('\u03e7', '\u03e7'),
+        ('\u03e9', '\u03e9'), ('\u03eb', '\u03eb'), ('\u03ed', '\u03ed'),
+        ('\u03ef', '\u03f3'), ('\u03f5', '\u03f5'), ('\u03f8', '\u03f8'),
+        ('\u03fb', '\u03fc'), ('\u0430', '\u045f'), ('\u0461', '\u0461'),
+        ('\u0463', '\u0463'), ('\u0465', '\u0465'), ('\u0467', '\u0467'),
+        ('\u0469', '\u0469'), ('\u046b', '\u046b'), ('\u046d', '\u046d'),
+        ('\u046f', '\u046f'), ('\u0471', '\u0471'), ('\u0473', '\u0473'),
+        ('\u0475', '\u0475'), ('\u0477', '\u0477'), ('\u0479', '\u0479'),
+        ('\u047b', '\u047b'), ('\u047d', '\u047d'), ('\u047f', '\u047f'),
+        ('\u0481', '\u0481'), ('\u048b', '\u048b'), ('\u048d', '\u048d'),
+        ('\u048f', '\u048f'), ('\u0491', '\u0491'), ('\u0493', '\u0493'),
+        ('\u0495', '\u0495'), ('\u0497', '\u0497'), ('\u0499', '\u0499'),
+        ('\u049b', '\u049b'), ('\u049d', '\u049d'), ('\u049f', '\u049f'),
+        ('\u04a1', '\u04a1'), ('\u04a3', '\u04a3'), ('\u04a5', '\u04a5'),
+        ('\u04a7', '\u04a7'), ('\u04a9', '\u04a9'), ('\u04ab', '\u04ab'),
+        ('\u04ad', '\u04ad'), ('\u04af', '\u04af'), ('\u04b1', '\u04b1'),
+        ('\u04b3', '\u04b3'), ('\u04b5', '\u04b5'), ('\u04b7', '\u04b7'),
+        ('\u04b9', '\u04b9'), ('\u04bb', '\u04bb'), ('\u04bd', '\u04bd'),
+        ('\u04bf', '\u04bf'), ('\u04c2', '\u04c2'), ('\u04c4', '\u04c4'),
+        ('\u04c6', '\u04c6'), ('\u04c8', '\u04c8'), ('\u04ca', '\u04ca'),
+        ('\u04cc', '\u04cc'), ('\u04ce', '\u04cf'), ('\u04d1', '\u04d1'),
+        ('\u04d3', '\u04d3'), ('\u04d5', '\u04d5'), ('\u04d7', '\u04d7'),
+        ('\u04d9', '\u04d9'), ('\u04db', '\u04db'), ('\u04dd', '\u04dd'),
+        ('\u04df', '\u04df'), ('\u04e1', '\u04e1'), ('\u04e3', '\u04e3'),
+        ('\u04e5', '\u04e5'), ('\u04e7', '\u04e7'), ('\u04e9', '\u04e9'),
+        ('\u04eb', '\u04eb'), ('\u04ed', '\u04ed'), ('\u04ef', '\u04ef'),
+        ('\u04f1', '\u04f1'), ('\u04f3', '\u04f3'), ('\u04f5', '\u04f5'),
+        ('\u04f7', '\u04f7'), ('\u04f9', '\u04f9'), ('\u04fb', '\u04fb'),
+        ('\u04fd', '\u04fd'), ('\u04ff', '\u04ff'), ('\u0501', '\u0501'),
+        ('\u0503', '\u0503'), ('\u0505', '\u0505'), ('\u0507', '\u0507'),
+        ('\u0509', '\u0509'), ('\u050b', '\u050b'), ('\u050d', '\u050d'),
+        ('\u050f', '\u050f'), ('\u0511', '\u0511'), ('\u0513', '\u0513'),
+        ('\u0515', '\u0515'), ('\u0517', '\u0517'), ('\u0519', '\u0519'),
+        ('\u051b', '\u051b'), ('\u051d', '\u051d'), ('\u051f', '\u051f'),
+        ('\u0521', '\u0521'), ('\u0523', '\u0523'), ('\u0525', '\u0525'),
+        ('\u0527', '\u0527'), ('\u0529', '\u0529'), ('\u052b', '\u052b'),
+        ('\u052d', '\u052d'), ('\u052f', '\u052f'), ('\u0560', '\u0588'),
+        ('\u10d0', '\u10fa'), ('\u10fd', '\u10ff'), ('\u13f8', '\u13fd'),
+        ('\u1c80', '\u1c88'), ('\u1d00', '\u1d2b'), ('\u1d6b', '\u1d77'),
+        ('\u1d79', '\u1d9a'), ('\u1e01', '\u1e01'), ('\u1e03', '\u1e03'),
+        ('\u1e05', '\u1e05'), ('\u1e07', '\u1e07'), ('\u1e09', '\u1e09'),
+        ('\u1e0b', '\u1e0b'), ('\u1e0d', '\u1e0d'), ('\u1e0f', '\u1e0f'),
+        ('\u1e11', '\u1e11'), ('\u1e13', '\u1e13'), ('\u1e15', '\u1e15'),
+        ('\u1e17', '\u1e17'), ('\u1e19', '\u1e19'), ('\u1e1b', '\u1e1b'),
+        ('\u1e1d', '\u1e1d'), ('\u1e1f', '\u1e1f'), ('\u1e21', '\u1e21'),
+        ('\u1e23', '\u1e23'), ('\u1e25', '\u1e25'), ('\u1e27', '\u1e27'),
+        ('\u1e29', '\u1e29'), ('\u1e2b', '\u1e2b'), ('\u1e2d', '\u1e2d'),
+        ('\u1e2f', '\u1e2f'), ('\u1e31', '\u1e31'), ('\u1e33', '\u1e33'),
+        ('\u1e35', '\u1e35'), ('\u1e37', '\u1e37'), ('\u1e39', '\u1e39'),
+        ('\u1e3b', '\u1e3b'), ('\u1e3d', '\u1e3d'), ('\u1e3f', '\u1e3f'),
+        ('\u1e41', '\u1e41'), ('\u1e43', '\u1e43'), ('\u1e45', '\u1e45'),
+        ('\u1e47', '\u1e47'), ('\u1e49', '\u1e49'), ('\u1e4b', '\u1e4b'),
+        ('\u1e4d', '\u1e4d'), ('\u1e4f', '\u1e4f'), ('\u1e51', '\u1e51'),
+        ('\u1e53', '\u1e53'), ('\u1e55', '\u1e55'), ('\u1e57', '\u1e57'),
+        ('\u1e59', '\u1e59'), ('\u1e5b', '\u1e5b'), ('\u1e5d', '\u1e5d'),
+        ('\u1e5f', '\u1e5f'), ('\u1e61', '\u1e61'), ('\u1e63', '\u1e63'),
+        ('\u1e65', '\u1e65'), ('\u1e67', '\u1e67'), ('\u1e69', '\u1e69'),
+        ('\u1e6b', '\u1e6b'), ('\u1e6d', '\u1e6d'), ('\u1e6f', '\u1e6f'),
+        ('\u1e71', '\u1e71'), ('\u1e73', '\u1e73'), ('\u1e75', '\u1e75'),
+        ('\u1e77', '\u1e77'), ('\u1e79', '\u1e79'), ('\u1e7b', '\u1e7b'),
+        ('\u1e7d', '\u1e7d'), ('\u1e7f', '\u1e7f'), ('\u1e81', '\u1e81'),
+        ('\u1e83', '\u1e83'), ('\u1e85', '\u1e85'), ('\u1e87', '\u1e87'),
+        ('\u1e89', '\u1e89'), ('\u1e8b', '\u1e8b'), ('\u1e8d', '\u1e8d'),
+        ('\u1e8f', '\u1e8f'), ('\u1e91', '\u1e91'), ('\u1e93', '\u1e93'),
+        ('\u1e95', '\u1e9d'), ('\u1e9f', '\u1e9f'), ('\u1ea1', '\u1ea1'),
+        ('\u1ea3', '\u1ea3'), ('\u1ea5', '\u1ea5'), ('\u1ea7', '\u1ea7'),
+        ('\u1ea9', '\u1ea9'), ('\u1eab', '\u1eab'), ('\u1ead', '\u1ead'),
+        ('\u1eaf', '\u1eaf'), ('\u1eb1', '\u1eb1'), ('\u1eb3', '\u1eb3'),
+        ('\u1eb5', '\u1eb5'), ('\u1eb7', '\u1eb7'), ('\u1eb9', '\u1eb9'),
+        ('\u1ebb', '\u1ebb'), ('\u1ebd', '\u1ebd'), ('\u1ebf', '\u1ebf'),
+        ('\u1ec1', '\u1ec1'), ('\u1ec3', '\u1ec3'), ('\u1ec5', '\u1ec5'),
+        ('\u1ec7', '\u1ec7'), ('\u1ec9', '\u1ec9'), ('\u1ecb', '\u1ecb'),
+        ('\u1ecd', '\u1ecd'), ('\u1ecf', '\u1ecf'), ('\u1ed1', '\u1ed1'),
+        ('\u1ed3', '\u1ed3'), ('\u1ed5', '\u1ed5'), ('\u1ed7', '\u1ed7'),
+        ('\u1ed9', '\u1ed9'), ('\u1edb', '\u1edb'), ('\u1edd', '\u1edd'),
+        ('\u1edf', '\u1edf'), ('\u1ee1', '\u1ee1'), ('\u1ee3', '\u1ee3'),
+        ('\u1ee5', '\u1ee5'), ('\u1ee7', '\u1ee7'), ('\u1ee9', '\u1ee9'),
+        ('\u1eeb', '\u1eeb'), ('\u1eed', '\u1eed'), ('\u1eef', '\u1eef'),
+        ('\u1ef1', '\u1ef1'), ('\u1ef3', '\u1ef3'), ('\u1ef5', '\u1ef5'),
+        ('\u1ef7', '\u1ef7'), ('\u1ef9', '\u1ef9'), ('\u1efb', '\u1efb'),
+        ('\u1efd', '\u1efd'), ('\u1eff', '\u1f07'), ('\u1f10', '\u1f15'),
+        ('\u1f20', '\u1f27'), ('\u1f30', '\u1f37'), ('\u1f40', '\u1f45'),
+        ('\u1f50', '\u1f57'), ('\u1f60', '\u1f67'), ('\u1f70', '\u1f7d'),
+        ('\u1f80', '\u1f87'), ('\u1f90', '\u1f97'), ('\u1fa0', '\u1fa7'),
+        ('\u1fb0', '\u1fb4'), ('\u1fb6', '\u1fb7'), ('\u1fbe', '\u1fbe'),
+        ('\u1fc2', '\u1fc4'), ('\u1fc6', '\u1fc7'), ('\u1fd0', '\u1fd3'),
+        ('\u1fd6', '\u1fd7'), ('\u1fe0', '\u1fe7'), ('\u1ff2', '\u1ff4'),
+        ('\u1ff6', '\u1ff7'), ('\u210a', '\u210a'), ('\u210e', '\u210f'),
+        ('\u2113', '\u2113'), ('\u212f', '\u212f'), ('\u2134', '\u2134'),
+        ('\u2139', '\u2139'), ('\u213c', '\u213d'), ('\u2146', '\u2149'),
+        ('\u214e', '\u214e'), ('\u2184', '\u2184'), ('\u2c30', '\u2c5f'),
+        ('\u2c61', '\u2c61'), ('\u2c65', '\u2c66'), ('\u2c68', '\u2c68'),
+        ('\u2c6a', '\u2c6a'), ('\u2c6c', '\u2c6c'), ('\u2c71', '\u2c71'),
+        ('\u2c73', '\u2c74'), ('\u2c76', '\u2c7b'), ('\u2c81', '\u2c81'),
+        ('\u2c83', '\u2c83'), ('\u2c85', '\u2c85'), ('\u2c87', '\u2c87'),
+        ('\u2c89', '\u2c89'), ('\u2c8b', '\u2c8b'), ('\u2c8d', '\u2c8d'),
+        ('\u2c8f', '\u2c8f'), ('\u2c91', '\u2c91'), ('\u2c93', '\u2c93'),
+        ('\u2c95', '\u2c95'), ('\u2c97', '\u2c97'), ('\u2c99', '\u2c99'),
+        ('\u2c9b', '\u2c9b'), ('\u2c9d', '\u2c9d'), ('\u2c9f', '\u2c9f'),
+        ('\u2ca1', '\u2ca1'), ('\u2ca3', '\u2ca3'), ('\u2ca5', '\u2ca5'),
+        ('\u2ca7', '\u2ca7'), ('\u2ca9', '\u2ca9'), ('\u2cab', '\u2cab'),
+        ('\u2cad', '\u2cad'), ('\u2caf', '\u2caf'), ('\u2cb1', '\u2cb1'),
+        ('\u2cb3', '\u2cb3'), ('\u2cb5', '\u2cb5'), ('\u2cb7', '\u2cb7'),
+        ('\u2cb9', '\u2cb9'), ('\u2cbb', '\u2cbb'), ('\u2cbd', '\u2cbd'),
+        ('\u2cbf', '\u2cbf'), ('\u2cc1', '\u2cc1'), ('\u2cc3', '\u2cc3'),
+        ('\u2cc5', '\u2cc5'), ('\u2cc7', '\u2cc7'), ('\u2cc9', '\u2cc9'),
+        ('\u2ccb', '\u2ccb'), ('\u2ccd', '\u2ccd'), ('\u2ccf', '\u2ccf'),
+        ('\u2cd1', '\u2cd1'), ('\u2cd3', '\u2cd3'), ('\u2cd5', '\u2cd5'),
+        ('\u2cd7', '\u2cd7'), ('\u2cd9', '\u2cd9'), ('\u2cdb', '\u2cdb'),
+        ('\u2cdd', '\u2cdd'), ('\u2cdf', '\u2cdf'), ('\u2ce1', '\u2ce1'),
+        ('\u2ce3', '\u2ce4'), ('\u2cec', '\u2cec'), ('\u2cee', '\u2cee'),
+        ('\u2cf3', '\u2cf3'), ('\u2d00', '\u2d25'), ('\u2d27', '\u2d27'),
+        ('\u2d2d', '\u2d2d'), ('\ua641', '\ua641'), ('\ua643', '\ua643'),
+        ('\ua645', '\ua645'), ('\ua647', '\ua647'), ('\ua649', '\ua649'),
+        ('\ua64b', '\ua64b'), ('\ua64d', '\ua64d'), ('\ua64f', '\ua64f'),
+        ('\ua651', '\ua651'), ('\ua653', '\ua653'), ('\ua655', '\ua655'),
+        ('\ua657', '\ua657'), ('\ua659', '\ua659'), ('\ua65b', '\ua65b'),
+        ('\ua65d', '\ua65d'), ('\ua65f', '\ua65f'), ('\ua661', '\ua661'),
+        ('\ua663', '\ua663'), ('\ua665', '\ua665'), ('\ua667', '\ua667'),
+        ('\ua669', '\ua669'), ('\ua66b', '\ua66b'), ('\ua66d', '\ua66d'),
+        ('\ua681', '\ua681'), ('\ua683', '\ua683'), ('\ua685', '\ua685'),
+        ('\ua687', '\ua687'), ('\ua689', '\ua689'), ('\ua68b', '\ua68b'),
+        ('\ua68d', '\ua68d'), ('\ua68f', '\ua68f'), ('\ua691', '\ua691'),
+        ('\ua693', '\ua693'), ('\ua695', '\ua695'), ('\ua697', '\ua697'),
+        ('\ua699', '\ua699'), ('\ua69b', '\ua69b'), ('\ua723', '\ua723'),
+        ('\ua725', '\ua725'), ('\ua727', '\ua727'), ('\ua729', '\ua729'),
+        ('\ua72b', '\ua72b'), ('\ua72d', '\ua72d'), ('\ua72f', '\ua731'),
+        ('\ua733', '\ua733'), ('\ua735', '\ua735'), ('\ua737', '\ua737'),
+        ('\ua739', '\ua739'), ('\ua73b', '\ua73b'), ('\ua73d', '\ua73d'),
+        ('\ua73f', '\ua73f'), ('\ua741', '\ua741'), ('\ua743', '\ua743'),
+        ('\ua745', '\ua745'), ('\ua747', '\ua747'), ('\ua749', '\ua749'),
+        ('\ua74b', '\ua74b'), ('\ua74d', '\ua74d'), ('\ua74f', '\ua74f'),
+        ('\ua751', '\ua751'), ('\ua753', '\ua753'), ('\ua755', '\ua755'),
+        ('\ua757', '\ua757'), ('\ua759', '\ua759'), ('\ua75b', '\ua75b'),
+        ('\ua75d', '\ua75d'), ('\ua75f', '\ua75f'), ('\ua761', '\ua761'),
+        ('\ua763', '\ua763'), ('\ua765', '\ua765'), ('\ua767', '\ua767'),
+        ('\ua769', '\ua769'), ('\ua76b', '\ua76b'), ('\ua76d', '\ua76d'),
+        ('\ua76f', '\ua76f'), ('\ua771', '\ua778'), ('\ua77a', '\ua77a'),
+        ('\ua77c', '\ua77c'), ('\ua77f', '\ua77f'), ('\ua781', '\ua781'),
+        ('\ua783', '\ua783'), ('\ua785', '\ua785'), ('\ua787', '\ua787'),
+        ('\ua78c', '\ua78c'), ('\ua78e', '\ua78e'), ('\ua791', '\ua791'),
+        ('\ua793', '\ua795'), ('\ua797', '\ua797'), ('\ua799', '\ua799'),
+        ('\ua79b', '\ua79b'), ('\ua79d', '\ua79d'), ('\ua79f', '\ua79f'),
+        ('\ua7a1', '\ua7a1'), ('\ua7a3', '\ua7a3'), ('\ua7a5', '\ua7a5'),
+        ('\ua7a7', '\ua7a7'), ('\ua7a9', '\ua7a9'), ('\ua7af', '\ua7af'),
+        ('\ua7b5', '\ua7b5'), ('\ua7b7', '\ua7b7'), ('\ua7b9', '\ua7b9'),
+        ('\ua7bb', '\ua7bb'), ('\ua7bd', '\ua7bd'), ('\ua7bf', '\ua7bf'),
+        ('\ua7c1', '\ua7c1'), ('\ua7c3', '\ua7c3'), ('\ua7c8', '\ua7c8'),
+        ('\ua7ca', '\ua7ca'), ('\ua7d1', '\ua7d1'), ('\ua7d3', '\ua7d3'),
+        ('\ua7d5', '\ua7d5'), ('\ua7d7', '\ua7d7'), ('\ua7d9', '\ua7d9'),
+        ('\ua7f6', '\ua7f6'), ('\ua7fa', '\ua7fa'), ('\uab30', '\uab5a'),
+        ('\uab60', '\uab68'), ('\uab70', '\uabbf'), ('\ufb00', '\ufb06'),
+        ('\ufb13', '\ufb17'), ('\uff41', '\uff5a'), ('\U00010428', '\U0001044f'),
+        ('\U000104d8', '\U000104fb'), ('\U00010597', '\U000105a1'), ('\U000105a3', '\U000105b1'),
+        ('\U000105b3', '\U000105b9'), ('\U000105bb', '\U000105bc'), ('\U00010cc0', '\U00010cf2'),
+        ('\U000118c0', '\U000118df'), ('\U00016e60', '\U00016e7f'), ('\U0001d41a', '\U0001d433'),
+        ('\U0001d44e', '\U0001d454'), ('\U0001d456', '\U0001d467'), ('\U0001d482', '\U0001d49b'),
+        ('\U0001d4b6', '\U0001d4b9'), ('\U0001d4bb', '\U0001d4bb'), ('\U0001d4bd', '\U0001d4c3'),
+        ('\U0001d4c5', '\U0001d4cf'), ('\U0001d4ea', '\U0001d503'), ('\U0001d51e', '\U0001d537'),
+        ('\U0001d552', '\U0001d56b'), ('\U0001d586', '\U0001d59f'), ('\U0001d5ba', '\U0001d5d3'),
+        ('\U0001d5ee', '\U0001d607'), ('\U0001d622', '\U0001d63b'), ('\U0001d656', '\U0001d66f'),
+        ('\U0001d68a', '\U0001d6a5'), ('\U0001d6c2', '\U0001d6da'), ('\U0001d6dc', '\U0001d6e1'),
+        ('\U0001d6fc', '\U0001d714'), ('\U0001d716', '\U0001d71b'), ('\U0001d736', '\U0001d74e'),
+        ('\U0001d750', '\U0001d755'), ('\U0001d770', '\U0001d788'), ('\U0001d78a', '\U0001d78f'),
+        ('\U0001d7aa', '\U0001d7c2'), ('\U0001d7c4', '\U0001d7c9'), ('\U0001d7cb', '\U0001d7cb'),
+        ('\U0001df00', '\U0001df09'), ('\U0001df0b', '\U0001df1e'), ('\U0001e922', '\U0001e943')
+    ]
+}
+
+fn category_lm() -> ~[(char, char)] {
+    ~[
+        ('\u02b0', '\u02c1'), ('\u02c6', '\u02d1'), ('\u02e0', '\u02e4'),
+        ('\u02ec', '\u02ec'), ('\u02ee', '\u02ee'), ('\u0374', '\u0374'),
+        ('\u037a', '\u037a'), ('\u0559', '\u0559'), ('\u0640', '\u0640'),
+        ('\u06e5', '\u06e6'), ('\u07f4', '\u07f5'), ('\u07fa', '\u07fa'),
+        ('\u081a', '\u081a'), ('\u0824', '\u0824'), ('\u0828', '\u0828'),
+        ('\u08c9', '\u08c9'), ('\u0971', '\u0971'), ('\u0e46', '\u0e46'),
+        ('\u0ec6', '\u0ec6'), ('\u10fc', '\u10fc'), ('\u17d7', '\u17d7'),
+        ('\u1843', '\u1843'), ('\u1aa7', '\u1aa7'), ('\u1c78', '\u1c7d'),
+        ('\u1d2c', '\u1d6a'), ('\u1d78', '\u1d78'), ('\u1d9b', '\u1dbf'),
+        ('\u2071', '\u2071'), ('\u207f', '\u207f'), ('\u2090', '\u209c'),
+        ('\u2c7c', '\u2c7d'), ('\u2d6f', '\u2d6f'), ('\u2e2f', '\u2e2f'),
+        ('\u3005', '\u3005'), ('\u3031', '\u3035'), ('\u303b', '\u303b'),
+        ('\u309d', '\u309e'), ('\u30fc', '\u30fe'), ('\ua015', '\ua015'),
+        ('\ua4f8', '\ua4fd'), ('\ua60c', '\ua60c'), ('\ua67f', '\ua67f'),
+        ('\ua69c', '\ua69d'), ('\ua717', '\ua71f'), ('\ua770', '\ua770'),
+        ('\ua788', '\ua788'), ('\ua7f2', '\ua7f4'), ('\ua7f8', '\ua7f9'),
+        ('\ua9cf', '\ua9cf'), ('\ua9e6', '\ua9e6'), ('\uaa70', '\uaa70'),
+        ('\uaadd', '\uaadd'), ('\uaaf3', '\uaaf4'), ('\uab5c', '\uab5f'),
+        ('\uab69', '\uab69'), ('\uff70', '\uff70'), ('\uff9e', '\uff9f'),
+        ('\U00010780', '\U00010785'), ('\U00010787', '\U000107b0'), ('\U000107b2', '\U000107ba'),
+        ('\U00016b40', '\U00016b43'), ('\U00016f93', '\U00016f9f'), ('\U00016fe0', '\U00016fe1'),
+        ('\U00016fe3', '\U00016fe3'), ('\U0001aff0', '\U0001aff3'), ('\U0001aff5', '\U0001affb'),
+        ('\U0001affd', '\U0001affe'), ('\U0001e137', '\U0001e13d'), ('\U0001e94b', '\U0001e94b')
+    ]
+}
+
+fn category_lo() -> ~[(char, char)] {
+    ~[
+        ('\u00aa', '\u00aa'), ('\u00ba', '\u00ba'), ('\u01bb', '\u01bb'),
+        ('\u01c0', '\u01c3'), ('\u0294', '\u0294'), ('\u05d0', '\u05ea'),
+        ('\u05ef', '\u05f2'), ('\u0620', '\u063f'), ('\u0641', '\u064a'),
+        ('\u066e', '\u066f'), ('\u0671', '\u06d3'), ('\u06d5', '\u06d5'),
+        ('\u06ee', '\u06ef'), ('\u06fa', '\u06fc'), ('\u06ff', '\u06ff'),
+        ('\u0710', '\u0710'), ('\u0712', '\u072f'), ('\u074d', '\u07a5'),
+        ('\u07b1', '\u07b1'), ('\u07ca', '\u07ea'), ('\u0800', '\u0815'),
+        ('\u0840', '\u0858'), ('\u0860', '\u086a'), ('\u0870', '\u0887'),
+        ('\u0889', '\u088e'), ('\u08a0', '\u08c8'), ('\u0904', '\u0939'),
+        ('\u093d', '\u093d'), ('\u0950', '\u0950'), ('\u0958', '\u0961'),
+        ('\u0972', '\u0980'), ('\u0985', '\u098c'), ('\u098f', '\u0990'),
+        ('\u0993', '\u09a8'), ('\u09aa', '\u09b0'), ('\u09b2', '\u09b2'),
+        ('\u09b6', '\u09b9'), ('\u09bd', '\u09bd'), ('\u09ce', '\u09ce'),
+        ('\u09dc', '\u09dd'), ('\u09df', '\u09e1'), ('\u09f0', '\u09f1'),
+        ('\u09fc', '\u09fc'), ('\u0a05', '\u0a0a'), ('\u0a0f', '\u0a10'),
+        ('\u0a13', '\u0a28'), ('\u0a2a', '\u0a30'), ('\u0a32', '\u0a33'),
+        ('\u0a35', '\u0a36'), ('\u0a38', '\u0a39'), ('\u0a59', '\u0a5c'),
+        ('\u0a5e', '\u0a5e'), ('\u0a72', '\u0a74'), ('\u0a85', '\u0a8d'),
+        ('\u0a8f', '\u0a91'), ('\u0a93', '\u0aa8'), ('\u0aaa', '\u0ab0'),
+        ('\u0ab2', '\u0ab3'), ('\u0ab5', '\u0ab9'), ('\u0abd', '\u0abd'),
+        ('\u0ad0', '\u0ad0'), ('\u0ae0', '\u0ae1'), ('\u0af9', '\u0af9'),
+        ('\u0b05', '\u0b0c'), ('\u0b0f', '\u0b10'), ('\u0b13', '\u0b28'),
+        ('\u0b2a', '\u0b30'), ('\u0b32', '\u0b33'), ('\u0b35', '\u0b39'),
+        ('\u0b3d', '\u0b3d'), ('\u0b5c', '\u0b5d'), ('\u0b5f', '\u0b61'),
+        ('\u0b71', '\u0b71'), ('\u0b83', '\u0b83'), ('\u0b85', '\u0b8a'),
+        ('\u0b8e', '\u0b90'), ('\u0b92', '\u0b95'), ('\u0b99', '\u0b9a'),
+        ('\u0b9c', '\u0b9c'), ('\u0b9e', '\u0b9f'), ('\u0ba3', '\u0ba4'),
+        ('\u0ba8', '\u0baa'), ('\u0bae', '\u0bb9'), ('\u0bd0', '\u0bd0'),
+        ('\u0c05', '\u0c0c'), ('\u0c0e', '\u0c10'), ('\u0c12', '\u0c28'),
+        ('\u0c2a', '\u0c39'), ('\u0c3d', '\u0c3d'), ('\u0c58', '\u0c5a'),
+        ('\u0c5d', '\u0c5d'), ('\u0c60', '\u0c61'), ('\u0c80', '\u0c80'),
+        ('\u0c85', '\u0c8c'), ('\u0c8e', '\u0c90'), ('\u0c92', '\u0ca8'),
+        ('\u0caa', '\u0cb3'), ('\u0cb5', '\u0cb9'), ('\u0cbd', '\u0cbd'),
+        ('\u0cdd', '\u0cde'), ('\u0ce0', '\u0ce1'), ('\u0cf1', '\u0cf2'),
+        ('\u0d04', '\u0d0c'), ('\u0d0e', '\u0d10'), ('\u0d12', '\u0d3a'),
+        ('\u0d3d', '\u0d3d'), ('\u0d4e', '\u0d4e'), ('\u0d54', '\u0d56'),
+        ('\u0d5f', '\u0d61'), ('\u0d7a', '\u0d7f'), ('\u0d85', '\u0d96'),
+        ('\u0d9a', '\u0db1'), ('\u0db3', '\u0dbb'), ('\u0dbd', '\u0dbd'),
+        ('\u0dc0', '\u0dc6'), ('\u0e01', '\u0e30'), ('\u0e32', '\u0e33'),
+        ('\u0e40', '\u0e45'), ('\u0e81', '\u0e82'), ('\u0e84', '\u0e84'),
+        ('\u0e86', '\u0e8a'), ('\u0e8c', '\u0ea3'), ('\u0ea5', '\u0ea5'),
+        ('\u0ea7', '\u0eb0'), ('\u0eb2', '\u0eb3'), ('\u0ebd', '\u0ebd'),
+        ('\u0ec0', '\u0ec4'), ('\u0edc', '\u0edf'), ('\u0f00', '\u0f00'),
+        ('\u0f40', '\u0f47'), ('\u0f49', '\u0f6c'), ('\u0f88', '\u0f8c'),
+        ('\u1000', '\u102a'), ('\u103f', '\u103f'), ('\u1050', '\u1055'),
+        ('\u105a', '\u105d'), ('\u1061', '\u1061'), ('\u1065', '\u1066'),
+        ('\u106e', '\u1070'), ('\u1075', '\u1081'), ('\u108e', '\u108e'),
+        ('\u1100', '\u1248'), ('\u124a', '\u124d'), ('\u1250', '\u1256'),
+        ('\u1258', '\u1258'), ('\u125a', '\u125d'), ('\u1260', '\u1288'),
+        ('\u128a', '\u128d'), ('\u1290', '\u12b0'), ('\u12b2', '\u12b5'),
+        ('\u12b8', '\u12be'), ('\u12c0', '\u12c0'), ('\u12c2', '\u12c5'),
+        ('\u12c8', '\u12d6'), ('\u12d8', '\u1310'), ('\u1312', '\u1315'),
+        ('\u1318', '\u135a'), ('\u1380', '\u138f'), ('\u1401', '\u166c'),
+        ('\u166f', '\u167f'), ('\u1681', '\u169a'), ('\u16a0', '\u16ea'),
+        ('\u16f1', '\u16f8'), ('\u1700', '\u1711'), ('\u171f', '\u1731'),
+        ('\u1740', '\u1751'), ('\u1760', '\u176c'), ('\u176e', '\u1770'),
+        ('\u1780', '\u17b3'), ('\u17dc', '\u17dc'), ('\u1820', '\u1842'),
+        ('\u1844', '\u1878'), ('\u1880', '\u1884'), ('\u1887', '\u18a8'),
+        ('\u18aa', '\u18aa'), ('\u18b0', '\u18f5'), ('\u1900', '\u191e'),
+        ('\u1950', '\u196d'), ('\u1970', '\u1974'), ('\u1980', '\u19ab'),
+        ('\u19b0', '\u19c9'), ('\u1a00', '\u1a16'), ('\u1a20', '\u1a54'),
+        ('\u1b05', '\u1b33'), ('\u1b45', '\u1b4c'), ('\u1b83', '\u1ba0'),
+        ('\u1bae', '\u1baf'), ('\u1bba', '\u1be5'), ('\u1c00', '\u1c23'),
+        ('\u1c4d', '\u1c4f'), ('\u1c5a', '\u1c77'), ('\u1ce9', '\u1cec'),
+        ('\u1cee', '\u1cf3'), ('\u1cf5', '\u1cf6'), ('\u1cfa', '\u1cfa'),
+        ('\u2135', '\u2138'), ('\u2d30', '\u2d67'), ('\u2d80', '\u2d96'),
+        ('\u2da0', '\u2da6'), ('\u2da8', '\u2dae'), ('\u2db0', '\u2db6'),
+        ('\u2db8', '\u2dbe'), ('\u2dc0', '\u2dc6'), ('\u2dc8', '\u2dce'),
+        ('\u2dd0', '\u2dd6'), ('\u2dd8', '\u2dde'), ('\u3006', '\u3006'),
+        ('\u303c', '\u303c'), ('\u3041', '\u3096'), ('\u309f', '\u309f'),
+        ('\u30a1', '\u30fa'), ('\u30ff', '\u30ff'), ('\u3105', '\u312f'),
+        ('\u3131', '\u318e'), ('\u31a0', '\u31bf'), ('\u31f0', '\u31ff'),
+        ('\u3400', '\u4dbf'), ('\u4e00', '\ua014'), ('\ua016', '\ua48c'),
+        ('\ua4d0', '\ua4f7'), ('\ua500', '\ua60b'), ('\ua610', '\ua61f'),
+        ('\ua62a', '\ua62b'), ('\ua66e', '\ua66e'), ('\ua6a0', '\ua6e5'),
+        ('\ua78f', '\ua78f'), ('\ua7f7', '\ua7f7'), ('\ua7fb', '\ua801'),
+        ('\ua803', '\ua805'), ('\ua807', '\ua80a'), ('\ua80c', '\ua822'),
+        ('\ua840', '\ua873'), ('\ua882', '\ua8b3'), ('\ua8f2', '\ua8f7'),
+        ('\ua8fb', '\ua8fb'), ('\ua8fd', '\ua8fe'), ('\ua90a', '\ua925'),
+        ('\ua930', '\ua946'), ('\ua960', '\ua97c'), ('\ua984', '\ua9b2'),
+        ('\ua9e0', '\ua9e4'), ('\ua9e7', '\ua9ef'), ('\ua9fa', '\ua9fe'),
+        ('\uaa00', '\uaa28'), ('\uaa40', '\uaa42'), ('\uaa44', '\uaa4b'),
+        ('\uaa60', '\uaa6f'), ('\uaa71', '\uaa76'), ('\uaa7a', '\uaa7a'),
+        ('\uaa7e', '\uaaaf'), ('\uaab1', '\uaab1'), ('\uaab5', '\uaab6'),
+        ('\uaab9', '\uaabd'), ('\uaac0', '\uaac0'), ('\uaac2', '\uaac2'),
+        ('\uaadb', '\uaadc'), ('\uaae0', '\uaaea'), ('\uaaf2', '\uaaf2'),
+        ('\uab01', '\uab06'), ('\uab09', '\uab0e'), ('\uab11', '\uab16'),
+        ('\uab20', '\uab26'), ('\uab28', '\uab2e'), ('\uabc0', '\uabe2'),
+        ('\uac00', '\ud7a3'), ('\ud7b0', '\ud7c6'), ('\ud7cb', '\ud7fb'),
+        ('\uf900', '\ufa6d'), ('\ufa70', '\ufad9'), ('\ufb1d', '\ufb1d'),
+        ('\ufb1f', '\ufb28'), ('\ufb2a', '\ufb36'), ('\ufb38', '\ufb3c'),
+        ('\ufb3e', '\ufb3e'), ('\ufb40', '\ufb41'), ('\ufb43', '\ufb44'),
+        ('\ufb46', '\ufbb1'), ('\ufbd3', '\ufd3d'), ('\ufd50', '\ufd8f'),
+        ('\ufd92', '\ufdc7'), ('\ufdf0', '\ufdfb'), ('\ufe70', '\ufe74'),
+        ('\ufe76', '\ufefc'), ('\uff66', '\uff6f'), ('\uff71', '\uff9d'),
+        ('\uffa0', '\uffbe'), ('\uffc2', '\uffc7'), ('\uffca', '\uffcf'),
+        ('\uffd2', '\uffd7'), ('\uffda', '\uffdc'), ('\U00010000', '\U0001000b'),
+        ('\U0001000d', '\U00010026'), ('\U00010028', '\U0001003a'), ('\U0001003c', '\U0001003d'),
+        ('\U0001003f', '\U0001004d'), ('\U00010050', '\U0001005d'), ('\U00010080', '\U000100fa'),
+        ('\U00010280', '\U0001029c'), ('\U000102a0', '\U000102d0'), ('\U00010300', '\U0001031f'),
+        ('\U0001032d', '\U00010340'), ('\U00010342', '\U00010349'), ('\U00010350', '\U00010375'),
+        ('\U00010380', '\U0001039d'), ('\U000103a0', '\U000103c3'), ('\U000103c8', '\U000103cf'),
+        ('\U00010450', '\U0001049d'), ('\U00010500', '\U00010527'), ('\U00010530', '\U00010563'),
+        ('\U00010600', '\U00010736'), ('\U00010740', '\U00010755'), ('\U00010760', '\U00010767'),
+        ('\U00010800', '\U00010805'), ('\U00010808', '\U00010808'), ('\U0001080a', '\U00010835'),
+        ('\U00010837', '\U00010838'), ('\U0001083c', '\U0001083c'), ('\U0001083f', '\U00010855'),
+        ('\U00010860', '\U00010876'), ('\U00010880', '\U0001089e'), ('\U000108e0', '\U000108f2'),
+        ('\U000108f4', '\U000108f5'), ('\U00010900', '\U00010915'), ('\U00010920', '\U00010939'),
+        ('\U00010980', '\U000109b7'), ('\U000109be', '\U000109bf'), ('\U00010a00', '\U00010a00'),
+        ('\U00010a10', '\U00010a13'), ('\U00010a15', '\U00010a17'), ('\U00010a19', '\U00010a35'),
+        ('\U00010a60', '\U00010a7c'), ('\U00010a80', '\U00010a9c'), ('\U00010ac0', '\U00010ac7'),
+        ('\U00010ac9', '\U00010ae4'), ('\U00010b00', '\U00010b35'), ('\U00010b40', '\U00010b55'),
+        ('\U00010b60', '\U00010b72'), ('\U00010b80', '\U00010b91'), ('\U00010c00', '\U00010c48'),
+        ('\U00010d00', '\U00010d23'), ('\U00010e80', '\U00010ea9'), ('\U00010eb0', '\U00010eb1'),
+        ('\U00010f00', '\U00010f1c'), ('\U00010f27', '\U00010f27'), ('\U00010f30', '\U00010f45'),
+        ('\U00010f70', '\U00010f81'), ('\U00010fb0', '\U00010fc4'), ('\U00010fe0', '\U00010ff6'),
+        ('\U00011003', '\U00011037'), ('\U00011071', '\U00011072'), ('\U00011075', '\U00011075'),
+        ('\U00011083', '\U000110af'), ('\U000110d0', '\U000110e8'), ('\U00011103', '\U00011126'),
+        ('\U00011144', '\U00011144'), ('\U00011147', '\U00011147'), ('\U00011150', '\U00011172'),
+        ('\U00011176', '\U00011176'), ('\U00011183', '\U000111b2'), ('\U000111c1', '\U000111c4'),
+        ('\U000111da', '\U000111da'), ('\U000111dc', '\U000111dc'), ('\U00011200', '\U00011211'),
+        ('\U00011213', '\U0001122b'), ('\U00011280', '\U00011286'), ('\U00011288', '\U00011288'),
+        ('\U0001128a', '\U0001128d'), ('\U0001128f', '\U0001129d'), ('\U0001129f', '\U000112a8'),
+        ('\U000112b0', '\U000112de'), ('\U00011305', '\U0001130c'), ('\U0001130f', '\U00011310'),
+        ('\U00011313', '\U00011328'), ('\U0001132a', '\U00011330'), ('\U00011332', '\U00011333'),
+        ('\U00011335', '\U00011339'), ('\U0001133d', '\U0001133d'), ('\U00011350', '\U00011350'),
+        ('\U0001135d', '\U00011361'), ('\U00011400', '\U00011434'), ('\U00011447', '\U0001144a'),
+        ('\U0001145f', '\U00011461'), ('\U00011480', '\U000114af'), ('\U000114c4', '\U000114c5'),
+        ('\U000114c7', '\U000114c7'), ('\U00011580', '\U000115ae'), ('\U000115d8', '\U000115db'),
+        ('\U00011600', '\U0001162f'), ('\U00011644', '\U00011644'), ('\U00011680', '\U000116aa'),
+        ('\U000116b8', '\U000116b8'), ('\U00011700', '\U0001171a'), ('\U00011740', '\U00011746'),
+        ('\U00011800', '\U0001182b'), ('\U000118ff', '\U00011906'), ('\U00011909', '\U00011909'),
+        ('\U0001190c', '\U00011913'), ('\U00011915', '\U00011916'), ('\U00011918', '\U0001192f'),
+        ('\U0001193f', '\U0001193f'), ('\U00011941', '\U00011941'), ('\U000119a0', '\U000119a7'),
+        ('\U000119aa', '\U000119d0'), ('\U000119e1', '\U000119e1'), ('\U000119e3', '\U000119e3'),
+        ('\U00011a00', '\U00011a00'), ('\U00011a0b', '\U00011a32'), ('\U00011a3a', '\U00011a3a'),
+        ('\U00011a50', '\U00011a50'), ('\U00011a5c', '\U00011a89'), ('\U00011a9d', '\U00011a9d'),
+        ('\U00011ab0', '\U00011af8'), ('\U00011c00', '\U00011c08'), ('\U00011c0a', '\U00011c2e'),
+        ('\U00011c40', '\U00011c40'), ('\U00011c72', '\U00011c8f'), ('\U00011d00', '\U00011d06'),
+        ('\U00011d08', '\U00011d09'), ('\U00011d0b', '\U00011d30'), ('\U00011d46', '\U00011d46'),
+        ('\U00011d60', '\U00011d65'), ('\U00011d67', '\U00011d68'), ('\U00011d6a', '\U00011d89'),
+        ('\U00011d98', '\U00011d98'), ('\U00011ee0', '\U00011ef2'), ('\U00011fb0', '\U00011fb0'),
+        ('\U00012000', '\U00012399'), ('\U00012480', '\U00012543'), ('\U00012f90', '\U00012ff0'),
+        ('\U00013000', '\U0001342e'), ('\U00014400', '\U00014646'), ('\U00016800', '\U00016a38'),
+        ('\U00016a40', '\U00016a5e'), ('\U00016a70', '\U00016abe'), ('\U00016ad0', '\U00016aed'),
+        ('\U00016b00', '\U00016b2f'), ('\U00016b63', '\U00016b77'), ('\U00016b7d', '\U00016b8f'),
+        ('\U00016f00', '\U00016f4a'), ('\U00016f50', '\U00016f50'), ('\U00017000', '\U000187f7'),
+        ('\U00018800', '\U00018cd5'), ('\U00018d00', '\U00018d08'), ('\U0001b000', '\U0001b122'),
+        ('\U0001b150', '\U0001b152'), ('\U0001b164', '\U0001b167'), ('\U0001b170', '\U0001b2fb'),
+        ('\U0001bc00', '\U0001bc6a'), ('\U0001bc70', '\U0001bc7c'), ('\U0001bc80', '\U0001bc88'),
+        ('\U0001bc90', '\U0001bc99'), ('\U0001df0a', '\U0001df0a'), ('\U0001e100', '\U0001e12c'),
+        ('\U0001e14e', '\U0001e14e'), ('\U0001e290', '\U0001e2ad'), ('\U0001e2c0', '\U0001e2eb'),
+        ('\U0001e7e0', '\U0001e7e6'), ('\U0001e7e8', '\U0001e7eb'), ('\U0001e7ed', '\U0001e7ee'),
+        ('\U0001e7f0', '\U0001e7fe'), ('\U0001e800', '\U0001e8c4'), ('\U0001ee00', '\U0001ee03'),
+        ('\U0001ee05', '\U0001ee1f'), ('\U0001ee21', '\U0001ee22'), ('\U0001ee24', '\U0001ee24'),
+        ('\U0001ee27', '\U0001ee27'), ('\U0001ee29', '\U0001ee32'), ('\U0001ee34', '\U0001ee37'),
+        ('\U0001ee39', '\U0001ee39'), ('\U0001ee3b', '\U0001ee3b'), ('\U0001ee42', '\U0001ee42'),
+        ('\U0001ee47', '\U0001ee47'), ('\U0001ee49', '\U0001ee49'), ('\U0001ee4b', '\U0001ee4b'),
+        ('\U0001ee4d', '\U0001ee4f'), ('\U0001ee51', '\U0001ee52'), ('\U0001ee54', '\U0001ee54'),
+        ('\U0001ee57', '\U0001ee57'), ('\U0001ee59', '\U0001ee59'), ('\U0001ee5b', '\U0001ee5b'),
+        ('\U0001ee5d', '\U0001ee5d'), ('\U0001ee5f', '\U0001ee5f'), ('\U0001ee61', '\U0001ee62'),
+        ('\U0001ee64', '\U0001ee64'), ('\U0001ee67', '\U0001ee6a'), ('\U0001ee6c', '\U0001ee72'),
+        ('\U0001ee74', '\U0001ee77'), ('\U0001ee79', '\U0001ee7c'), ('\U0001ee7e', '\U0001ee7e'),
+        ('\U0001ee80', '\U0001ee89'), ('\U0001ee8b', '\U0001ee9b'), ('\U0001eea1', '\U0001eea3'),
+        ('\U0001eea5', '\U0001eea9'), ('\U0001eeab', '\U0001eebb'), ('\U00020000', '\U0002a6df'),
+        ('\U0002a700', '\U0002b738'), ('\U0002b740', '\U0002b81d'), ('\U0002b820', '\U0002cea1'),
+        ('\U0002ceb0', '\U0002ebe0'), ('\U0002f800', '\U0002fa1d'), ('\U00030000', '\U0003134a')
+    ]
+}
+
+fn category_lt() -> ~[(char, char)] {
+    ~[
+        ('\u01c5', '\u01c5'), ('\u01c8', '\u01c8'), ('\u01cb', '\u01cb'),
+        ('\u01f2', '\u01f2'), ('\u1f88', '\u1f8f'), ('\u1f98', '\u1f9f'),
+        ('\u1fa8', '\u1faf'), ('\u1fbc', '\u1fbc'), ('\u1fcc', '\u1fcc'),
+        ('\u1ffc', '\u1ffc')
+    ]
+}
+
+fn category_lu() -> ~[(char, char)] {
+    ~[
+        ('\u0041', '\u005a'), ('\u00c0', '\u00d6'), ('\u00d8', '\u00de'),
+        ('\u0100', '\u0100'), ('\u0102', '\u0102'), ('\u0104', '\u0104'),
+        ('\u0106', '\u0106'), ('\u0108', '\u0108'), ('\u010a', '\u010a'),
+        ('\u010c', '\u010c'), ('\u010e', '\u010e'), ('\u0110', '\u0110'),
+        ('\u0112', '\u0112'), ('\u0114', '\u0114'), ('\u0116', '\u0116'),
+        ('\u0118', '\u0118'), ('\u011a', '\u011a'), ('\u011c', '\u011c'),
+        ('\u011e', '\u011e'), ('\u0120', '\u0120'), ('\u0122', '\u0122'),
+        ('\u0124', '\u0124'), ('\u0126', '\u0126'), ('\u0128', '\u0128'),
+        ('\u012a', '\u012a'), ('\u012c', '\u012c'), ('\u012e', '\u012e'),
+        ('\u0130', '\u0130'), ('\u0132', '\u0132'), ('\u0134', '\u0134'),
+        ('\u0136', '\u0136'), ('\u0139', '\u0139'), ('\u013b', '\u013b'),
+        ('\u013d', '\u013d'), ('\u013f', '\u013f'), ('\u0141', '\u0141'),
+        ('\u0143', '\u0143'), ('\u0145', '\u0145'), ('\u0147', '\u0147'),
+        ('\u014a', '\u014a'), ('\u014c', '\u014c'), ('\u014e', '\u014e'),
+        ('\u0150', '\u0150'), ('\u0152', '\u0152'), ('\u0154', '\u0154'),
+        ('\u0156', '\u0156'), ('\u0158', '\u0158'), ('\u015a', '\u015a'),
+        ('\u015c', '\u015c'), ('\u015e', '\u015e'), ('\u0160', '\u0160'),
+        ('\u0162', '\u0162'), ('\u0164', '\u0164'), ('\u0166', '\u0166'),
+        ('\u0168', '\u0168'), ('\u016a', '\u016a'), ('\u016c', '\u016c'),
+        ('\u016e', '\u016e'), ('\u0170', '\u0170'), ('\u0172', '\u0172'),
+        ('\u0174', '\u0174'), ('\u0176', '\u0176'), ('\u0178', '\u0179'),
+        ('\u017b', '\u017b'), ('\u017d', '\u017d'), ('\u0181', '\u0182'),
+        ('\u0184', '\u0184'), ('\u0186', '\u0187'), ('\u0189', '\u018b'),
+        ('\u018e', '\u0191'), ('\u0193', '\u0194'), ('\u0196', '\u0198'),
+        ('\u019c', '\u019d'), ('\u019f', '\u01a0'), ('\u01a2', '\u01a2'),
+        ('\u01a4', '\u01a4'), ('\u01a6', '\u01a7'), ('\u01a9', '\u01a9'),
+        ('\u01ac', '\u01ac'), ('\u01ae', '\u01af'), ('\u01b1', '\u01b3'),
+        ('\u01b5', '\u01b5'), ('\u01b7', '\u01b8'), ('\u01bc', '\u01bc'),
+        ('\u01c4', '\u01c4'), ('\u01c7', '\u01c7'), ('\u01ca', '\u01ca'),
+        ('\u01cd', '\u01cd'), ('\u01cf', '\u01cf'), ('\u01d1', '\u01d1'),
+        ('\u01d3', '\u01d3'), ('\u01d5', '\u01d5'), ('\u01d7', '\u01d7'),
+        ('\u01d9', '\u01d9'), ('\u01db', '\u01db'), ('\u01de', '\u01de'),
+        ('\u01e0', '\u01e0'), ('\u01e2', '\u01e2'), ('\u01e4', '\u01e4'),
+        ('\u01e6', '\u01e6'), ('\u01e8', '\u01e8'), ('\u01ea', '\u01ea'),
+        ('\u01ec', '\u01ec'), ('\u01ee', '\u01ee'), ('\u01f1', '\u01f1'),
+        ('\u01f4', '\u01f4'), ('\u01f6', '\u01f8'), ('\u01fa', '\u01fa'),
+        ('\u01fc', '\u01fc'), ('\u01fe', '\u01fe'), ('\u0200', '\u0200'),
+        ('\u0202', '\u0202'), ('\u0204', '\u0204'), ('\u0206', '\u0206'),
+        ('\u0208', '\u0208'), ('\u020a', '\u020a'), ('\u020c', '\u020c'),
+        ('\u020e', '\u020e'), ('\u0210', '\u0210'), ('\u0212', '\u0212'),
+        ('\u0214', '\u0214'), ('\u0216', '\u0216'), ('\u0218', '\u0218'),
+        ('\u021a', '\u021a'), ('\u021c', '\u021c'), ('\u021e', '\u021e'),
+        ('\u0220', '\u0220'), ('\u0222', '\u0222'), ('\u0224', '\u0224'),
+        ('\u0226', '\u0226'), ('\u0228', '\u0228'), ('\u022a', '\u022a'),
+        ('\u022c', '\u022c'), ('\u022e', '\u022e'), ('\u0230', '\u0230'),
+        ('\u0232', '\u0232'), ('\u023a', '\u023b'), ('\u023d', '\u023e'),
+        ('\u0241', '\u0241'), ('\u0243', '\u0246'), ('\u0248', '\u0248'),
+        ('\u024a', '\u024a'), ('\u024c', '\u024c'), ('\u024e', '\u024e'),
+        ('\u0370', '\u0370'), ('\u0372', '\u0372'), ('\u0376', '\u0376'),
+        ('\u037f', '\u037f'), ('\u0386', '\u0386'), ('\u0388', '\u038a'),
+        ('\u038c', '\u038c'), ('\u038e', '\u038f'), ('\u0391', '\u03a1'),
+        ('\u03a3', '\u03ab'), ('\u03cf', '\u03cf'), ('\u03d2', '\u03d4'),
+        ('\u03d8', '\u03d8'), ('\u03da', '\u03da'), ('\u03dc', '\u03dc'),
+        ('\u03de', '\u03de'), ('\u03e0', '\u03e0'), ('\u03e2', '\u03e2'),
+        ('\u03e4', '\u03e4'), ('\u03e6', '\u03e6'), ('\u03e8', '\u03e8'),
+        ('\u03ea', '\u03ea'), ('\u03ec', '\u03ec'), ('\u03ee', '\u03ee'),
+        ('\u03f4', '\u03f4'), ('\u03f7', '\u03f7'), ('\u03f9', '\u03fa'),
+        ('\u03fd', '\u042f'), ('\u0460', '\u0460'), ('\u0462', '\u0462'),
+        ('\u0464', '\u0464'), ('\u0466', '\u0466'), ('\u0468', '\u0468'),
+        ('\u046a', '\u046a'), ('\u046c', '\u046c'), ('\u046e', '\u046e'),
+        ('\u0470', '\u0470'), ('\u0472', '\u0472'), ('\u0474', '\u0474'),
+        ('\u0476', '\u0476'), ('\u0478', '\u0478'), ('\u047a', '\u047a'),
+        ('\u047c', '\u047c'), ('\u047e', '\u047e'), ('\u0480', '\u0480'),
+        ('\u048a', '\u048a'), ('\u048c', '\u048c'), ('\u048e', '\u048e'),
+        ('\u0490', '\u0490'), ('\u0492', '\u0492'), ('\u0494', '\u0494'),
+        ('\u0496', '\u0496'), ('\u0498', '\u0498'), ('\u049a', '\u049a'),
+        ('\u049c', '\u049c'), ('\u049e', '\u049e'), ('\u04a0', '\u04a0'),
+        ('\u04a2', '\u04a2'), ('\u04a4', '\u04a4'), ('\u04a6', '\u04a6'),
+        ('\u04a8', '\u04a8'), ('\u04aa', '\u04aa'), ('\u04ac', '\u04ac'),
+        ('\u04ae', '\u04ae'), ('\u04b0', '\u04b0'), ('\u04b2', '\u04b2'),
+        ('\u04b4', '\u04b4'), ('\u04b6', '\u04b6'), ('\u04b8', '\u04b8'),
+        ('\u04ba', '\u04ba'), ('\u04bc', '\u04bc'), ('\u04be', '\u04be'),
+        ('\u04c0', '\u04c1'), ('\u04c3', '\u04c3'), ('\u04c5', '\u04c5'),
+        ('\u04c7', '\u04c7'), ('\u04c9', '\u04c9'), ('\u04cb', '\u04cb'),
+        ('\u04cd', '\u04cd'), ('\u04d0', '\u04d0'), ('\u04d2', '\u04d2'),
+        ('\u04d4', '\u04d4'), ('\u04d6', '\u04d6'), ('\u04d8', '\u04d8'),
+        ('\u04da', '\u04da'), ('\u04dc', '\u04dc'), ('\u04de', '\u04de'),
+        ('\u04e0', '\u04e0'), ('\u04e2', '\u04e2'), ('\u04e4', '\u04e4'),
+        ('\u04e6', '\u04e6'), ('\u04e8', '\u04e8'), ('\u04ea', '\u04ea'),
+        ('\u04ec', '\u04ec'), ('\u04ee', '\u04ee'), ('\u04f0', '\u04f0'),
+        ('\u04f2', '\u04f2'), ('\u04f4', '\u04f4'), ('\u04f6', '\u04f6'),
+        ('\u04f8', '\u04f8'), ('\u04fa', '\u04fa'), ('\u04fc', '\u04fc'),
+        ('\u04fe', '\u04fe'), ('\u0500', '\u0500'), ('\u0502', '\u0502'),
+        ('\u0504', '\u0504'), ('\u0506', '\u0506'), ('\u0508', '\u0508'),
+        ('\u050a', '\u050a'), ('\u050c', '\u050c'), ('\u050e', '\u050e'),
+        ('\u0510', '\u0510'), ('\u0512', '\u0512'), ('\u0514', '\u0514'),
+        ('\u0516', '\u0516'), ('\u0518', '\u0518'), ('\u051a', '\u051a'),
+        ('\u051c', '\u051c'), ('\u051e', '\u051e'), ('\u0520', '\u0520'),
+        ('\u0522', '\u0522'), ('\u0524', '\u0524'), ('\u0526', '\u0526'),
+        ('\u0528', '\u0528'), ('\u052a', '\u052a'), ('\u052c', '\u052c'),
+        ('\u052e', '\u052e'), ('\u0531', '\u0556'), ('\u10a0', '\u10c5'),
+        ('\u10c7', '\u10c7'), ('\u10cd', '\u10cd'), ('\u13a0', '\u13f5'),
+        ('\u1c90', '\u1cba'), ('\u1cbd', '\u1cbf'), ('\u1e00', '\u1e00'),
+        ('\u1e02', '\u1e02'), ('\u1e04', '\u1e04'), ('\u1e06', '\u1e06'),
+        ('\u1e08', '\u1e08'), ('\u1e0a', '\u1e0a'), ('\u1e0c', '\u1e0c'),
+        ('\u1e0e', '\u1e0e'), ('\u1e10', '\u1e10'), ('\u1e12', '\u1e12'),
+        ('\u1e14', '\u1e14'), ('\u1e16', '\u1e16'), ('\u1e18', '\u1e18'),
+        ('\u1e1a', '\u1e1a'), ('\u1e1c', '\u1e1c'), ('\u1e1e', '\u1e1e'),
+        ('\u1e20', '\u1e20'), ('\u1e22', '\u1e22'), ('\u1e24', '\u1e24'),
+        ('\u1e26', '\u1e26'), ('\u1e28', '\u1e28'), ('\u1e2a', '\u1e2a'),
+        ('\u1e2c', '\u1e2c'), ('\u1e2e', '\u1e2e'), ('\u1e30', '\u1e30'),
+        ('\u1e32', '\u1e32'), ('\u1e34', '\u1e34'), ('\u1e36', '\u1e36'),
+        ('\u1e38', '\u1e38'), ('\u1e3a', '\u1e3a'), ('\u1e3c', '\u1e3c'),
+        ('\u1e3e', '\u1e3e'), ('\u1e40', '\u1e40'), ('\u1e42', '\u1e42'),
+        ('\u1e44', '\u1e44'), ('\u1e46', '\u1e46'), ('\u1e48', '\u1e48'),
+        ('\u1e4a', '\u1e4a'), ('\u1e4c', '\u1e4c'), ('\u1e4e', '\u1e4e'),
+        ('\u1e50', '\u1e50'), ('\u1e52', '\u1e52'), ('\u1e54', '\u1e54'),
+        ('\u1e56', '\u1e56'), ('\u1e58', '\u1e58'), ('\u1e5a', '\u1e5a'),
+        ('\u1e5c', '\u1e5c'), ('\u1e5e', '\u1e5e'), ('\u1e60', '\u1e60'),
+        ('\u1e62', '\u1e62'), ('\u1e64', '\u1e64'), ('\u1e66', '\u1e66'),
+        ('\u1e68', '\u1e68'), ('\u1e6a', '\u1e6a'), ('\u1e6c', '\u1e6c'),
+        ('\u1e6e', '\u1e6e'), ('\u1e70', '\u1e70'), ('\u1e72', '\u1e72'),
+        ('\u1e74', '\u1e74'), ('\u1e76', '\u1e76'), ('\u1e78', '\u1e78'),
+        ('\u1e7a', '\u1e7a'), ('\u1e7c', '\u1e7c'), ('\u1e7e', '\u1e7e'),
+        ('\u1e80', '\u1e80'), ('\u1e82', '\u1e82'), ('\u1e84', '\u1e84'),
+        ('\u1e86', '\u1e86'), ('\u1e88', '\u1e88'), ('\u1e8a', '\u1e8a'),
+        ('\u1e8c', '\u1e8c'), ('\u1e8e', '\u1e8e'), ('\u1e90', '\u1e90'),
+        ('\u1e92', '\u1e92'), ('\u1e94', '\u1e94'), ('\u1e9e', '\u1e9e'),
+        ('\u1ea0', '\u1ea0'), ('\u1ea2', '\u1ea2'), ('\u1ea4', '\u1ea4'),
+        ('\u1ea6', '\u1ea6'), ('\u1ea8', '\u1ea8'), ('\u1eaa', '\u1eaa'),
+        ('\u1eac', '\u1eac'), ('\u1eae', '\u1eae'), ('\u1eb0', '\u1eb0'),
+        ('\u1eb2', '\u1eb2'), ('\u1eb4', '\u1eb4'), ('\u1eb6', '\u1eb6'),
+        ('\u1eb8', '\u1eb8'), ('\u1eba', '\u1eba'), ('\u1ebc', '\u1ebc'),
+        ('\u1ebe', '\u1ebe'), ('\u1ec0', '\u1ec0'), ('\u1ec2', '\u1ec2'),
+        ('\u1ec4', '\u1ec4'), ('\u1ec6', '\u1ec6'), ('\u1ec8', '\u1ec8'),
+        ('\u1eca', '\u1eca'), ('\u1ecc', '\u1ecc'), ('\u1ece', '\u1ece'),
+        ('\u1ed0', '\u1ed0'), ('\u1ed2', '\u1ed2'), ('\u1ed4', '\u1ed4'),
+        ('\u1ed6', '\u1ed6'), ('\u1ed8', '\u1ed8'), ('\u1eda', '\u1eda'),
+        ('\u1edc', '\u1edc'), ('\u1ede', '\u1ede'), ('\u1ee0', '\u1ee0'),
+        ('\u1ee2', '\u1ee2'), ('\u1ee4', '\u1ee4'), ('\u1ee6', '\u1ee6'),
+        ('\u1ee8', '\u1ee8'), ('\u1eea', '\u1eea'), ('\u1eec', '\u1eec'),
+        ('\u1eee', '\u1eee'), ('\u1ef0', '\u1ef0'), ('\u1ef2', '\u1ef2'),
+        ('\u1ef4', '\u1ef4'), ('\u1ef6', '\u1ef6'), ('\u1ef8', '\u1ef8'),
+        ('\u1efa', '\u1efa'), ('\u1efc', '\u1efc'), ('\u1efe', '\u1efe'),
+        ('\u1f08', '\u1f0f'), ('\u1f18', '\u1f1d'), ('\u1f28', '\u1f2f'),
+        ('\u1f38', '\u1f3f'), ('\u1f48', '\u1f4d'), ('\u1f59', '\u1f59'),
+        ('\u1f5b', '\u1f5b'), ('\u1f5d', '\u1f5d'), ('\u1f5f', '\u1f5f'),
+        ('\u1f68', '\u1f6f'), ('\u1fb8', '\u1fbb'), ('\u1fc8', '\u1fcb'),
+        ('\u1fd8', '\u1fdb'), ('\u1fe8', '\u1fec'), ('\u1ff8', '\u1ffb'),
+        ('\u2102', '\u2102'), ('\u2107', '\u2107'), ('\u210b', '\u210d'),
+        ('\u2110', '\u2112'), ('\u2115', '\u2115'), ('\u2119', '\u211d'),
+        ('\u2124', '\u2124'), ('\u2126', '\u2126'), ('\u2128', '\u2128'),
+        ('\u212a', '\u212d'), ('\u2130', '\u2133'), ('\u213e', '\u213f'),
+        ('\u2145', '\u2145'), ('\u2183', '\u2183'), ('\u2c00', '\u2c2f'),
+        ('\u2c60', '\u2c60'), ('\u2c62', '\u2c64'), ('\u2c67', '\u2c67'),
+        ('\u2c69', '\u2c69'), ('\u2c6b', '\u2c6b'), ('\u2c6d', '\u2c70'),
+        ('\u2c72', '\u2c72'), ('\u2c75', '\u2c75'), ('\u2c7e', '\u2c80'),
+        ('\u2c82', '\u2c82'), ('\u2c84', '\u2c84'), ('\u2c86', '\u2c86'),
+        ('\u2c88', '\u2c88'), ('\u2c8a', '\u2c8a'), ('\u2c8c', '\u2c8c'),
+        ('\u2c8e', '\u2c8e'), ('\u2c90', '\u2c90'), ('\u2c92', '\u2c92'),
+        ('\u2c94', '\u2c94'), ('\u2c96', '\u2c96'), ('\u2c98', '\u2c98'),
+        ('\u2c9a', '\u2c9a'), ('\u2c9c', '\u2c9c'), ('\u2c9e', '\u2c9e'),
+        ('\u2ca0', '\u2ca0'), ('\u2ca2', '\u2ca2'), ('\u2ca4', '\u2ca4'),
+        ('\u2ca6', '\u2ca6'), ('\u2ca8', '\u2ca8'), ('\u2caa', '\u2caa'),
+        ('\u2cac', '\u2cac'), ('\u2cae', '\u2cae'), ('\u2cb0', '\u2cb0'),
+        ('\u2cb2', '\u2cb2'), ('\u2cb4', '\u2cb4'), ('\u2cb6', '\u2cb6'),
+        ('\u2cb8', '\u2cb8'), ('\u2cba', '\u2cba'), ('\u2cbc', '\u2cbc'),
+        ('\u2cbe', '\u2cbe'), ('\u2cc0', '\u2cc0'), ('\u2cc2', '\u2cc2'),
+        ('\u2cc4', '\u2cc4'), ('\u2cc6', '\u2cc6'), ('\u2cc8', '\u2cc8'),
+        ('\u2cca', '\u2cca'), ('\u2ccc', '\u2ccc'), ('\u2cce', '\u2cce'),
+        ('\u2cd0', '\u2cd0'), ('\u2cd2', '\u2cd2'), ('\u2cd4', '\u2cd4'),
+        ('\u2cd6', '\u2cd6'), ('\u2cd8', '\u2cd8'), ('\u2cda', '\u2cda'),
+        ('\u2cdc', '\u2cdc'), ('\u2cde', '\u2cde'), ('\u2ce0', '\u2ce0'),
+        ('\u2ce2', '\u2ce2'), ('\u2ceb', '\u2ceb'), ('\u2ced', '\u2ced'),
+        ('\u2cf2', '\u2cf2'), ('\ua640', '\ua640'), ('\ua642', '\ua642'),
+        ('\ua644', '\ua644'), ('\ua646', '\ua646'), ('\ua648', '\ua648'),
+        ('\ua64a', '\ua64a'), ('\ua64c', '\ua64c'), ('\ua64e', '\ua64e'),
+        ('\ua650', '\ua650'), ('\ua652', '\ua652'), ('\ua654', '\ua654'),
+        ('\ua656', '\ua656'), ('\ua658', '\ua658'), ('\ua65a', '\ua65a'),
+        ('\ua65c', '\ua65c'), ('\ua65e', '\ua65e'), ('\ua660', '\ua660'),
+        ('\ua662', '\ua662'), ('\ua664', '\ua664'), ('\ua666', '\ua666'),
+        ('\ua668', '\ua668'), ('\ua66a', '\ua66a'), ('\ua66c', '\ua66c'),
+        ('\ua680', '\ua680'), ('\ua682', '\ua682'), ('\ua684', '\ua684'),
+        ('\ua686', '\ua686'), ('\ua688', '\ua688'), ('\ua68a', '\ua68a'),
+        ('\ua68c', '\ua68c'), ('\ua68e', '\ua68e'), ('\ua690', '\ua690'),
+        ('\ua692', '\ua692'), ('\ua694', '\ua694'), ('\ua696', '\ua696'),
+        ('\ua698', '\ua698'), ('\ua69a', '\ua69a'), ('\ua722', '\ua722'),
+        ('\ua724', '\ua724'), ('\ua726', '\ua726'), ('\ua728', '\ua728'),
+        ('\ua72a', '\ua72a'), ('\ua72c', '\ua72c'), ('\ua72e', '\ua72e'),
+        ('\ua732', '\ua732'), ('\ua734', '\ua734'), ('\ua736', '\ua736'),
+        ('\ua738', '\ua738'), ('\ua73a', '\ua73a'), ('\ua73c', '\ua73c'),
+        ('\ua73e', '\ua73e'), ('\ua740', '\ua740'), ('\ua742', '\ua742'),
+        ('\ua744', '\ua744'), ('\ua746', '\ua746'), ('\ua748', '\ua748'),
+        ('\ua74a', '\ua74a'), ('\ua74c', '\ua74c'), ('\ua74e', '\ua74e'),
+        ('\ua750', '\ua750'), ('\ua752', '\ua752'), ('\ua754', '\ua754'),
+        ('\ua756', '\ua756'), ('\ua758', '\ua758'), ('\ua75a', '\ua75a'),
+        ('\ua75c', '\ua75c'), ('\ua75e', '\ua75e'), ('\ua760', '\ua760'),
+        ('\ua762', '\ua762'), ('\ua764', '\ua764'), ('\ua766', '\ua766'),
+        ('\ua768', '\ua768'), ('\ua76a', '\ua76a'), ('\ua76c', '\ua76c'),
+        ('\ua76e', '\ua76e'), ('\ua779', '\ua779'), ('\ua77b', '\ua77b'),
+        ('\ua77d', '\ua77e'), ('\ua780', '\ua780'), ('\ua782', '\ua782'),
+        ('\ua784', '\ua784'), ('\ua786', '\ua786'), ('\ua78b', '\ua78b'),
+        ('\ua78d', '\ua78d'), ('\ua790', '\ua790'), ('\ua792', '\ua792'),
+        ('\ua796', '\ua796'), ('\ua798', '\ua798'), ('\ua79a', '\ua79a'),
+        ('\ua79c', '\ua79c'), ('\ua79e', '\ua79e'), ('\ua7a0', '\ua7a0'),
+        ('\ua7a2', '\ua7a2'), ('\ua7a4', '\ua7a4'), ('\ua7a6', '\ua7a6'),
+        ('\ua7a8', '\ua7a8'), ('\ua7aa', '\ua7ae'), ('\ua7b0', '\ua7b4'),
+        ('\ua7b6', '\ua7b6'), ('\ua7b8', '\ua7b8'), ('\ua7ba', '\ua7ba'),
+        ('\ua7bc', '\ua7bc'), ('\ua7be', '\ua7be'), ('\ua7c0', '\ua7c0'),
+        ('\ua7c2', '\ua7c2'), ('\ua7c4', '\ua7c7'), ('\ua7c9', '\ua7c9'),
+        ('\ua7d0', '\ua7d0'), ('\ua7d6', '\ua7d6'), ('\ua7d8', '\ua7d8'),
+        ('\ua7f5', '\ua7f5'), ('\uff21', '\uff3a'), ('\U00010400', '\U00010427'),
+        ('\U000104b0', '\U000104d3'), ('\U00010570', '\U0001057a'), ('\U0001057c', '\U0001058a'),
+        ('\U0001058c', '\U00010592'), ('\U00010594', '\U00010595'), ('\U00010c80', '\U00010cb2'),
+        ('\U000118a0', '\U000118bf'), ('\U00016e40', '\U00016e5f'), ('\U0001d400', '\U0001d419'),
+        ('\U0001d434', '\U0001d44d'), ('\U0001d468', '\U0001d481'), ('\U0001d49c', '\U0001d49c'),
+        ('\U0001d49e', '\U0001d49f'), ('\U0001d4a2', '\U0001d4a2'), ('\U0001d4a5', '\U0001d4a6'),
+        ('\U0001d4a9', '\U0001d4ac'), ('\U0001d4ae', '\U0001d4b5'), ('\U0001d4d0', '\U0001d4e9'),
+        ('\U0001d504', '\U0001d505'), ('\U0001d507', '\U0001d50a'), ('\U0001d50d', '\U0001d514'),
+        ('\U0001d516', '\U0001d51c'), ('\U0001d538', '\U0001d539'), ('\U0001d53b', '\U0001d53e'),
+        ('\U0001d540', '\U0001d544'), ('\U0001d546', '\U0001d546'), ('\U0001d54a', '\U0001d550'),
+        ('\U0001d56c', '\U0001d585'), ('\U0001d5a0', '\U0001d5b9'), ('\U0001d5d4', '\U0001d5ed'),
+        ('\U0001d608', '\U0001d621'), ('\U0001d63c', '\U0001d655'), ('\U0001d670', '\U0001d689'),
+        ('\U0001d6a8', '\U0001d6c0'), ('\U0001d6e2', '\U0001d6fa'), ('\U0001d71c', '\U0001d734'),
+        ('\U0001d756', '\U0001d76e'), ('\U0001d790', '\U0001d7a8'), ('\U0001d7ca', '\U0001d7ca'),
+        ('\U0001e900', '\U0001e921')
+    ]
+}
+
+fn category_m() -> ~[(char, char)] {
+    ~[
+        ('\u0300', '\u036f'), ('\u0483', '\u0489'), ('\u0591', '\u05bd'),
+        ('\u05bf', '\u05bf'), ('\u05c1', '\u05c2'), ('\u05c4', '\u05c5'),
+        ('\u05c7', '\u05c7'), ('\u0610', '\u061a'), ('\u064b', '\u065f'),
+        ('\u0670', '\u0670'), ('\u06d6', '\u06dc'), ('\u06df', '\u06e4'),
+        ('\u06e7', '\u06e8'), ('\u06ea', '\u06ed'), ('\u0711', '\u0711'),
+        ('\u0730', '\u074a'), ('\u07a6', '\u07b0'), ('\u07eb', '\u07f3'),
+        ('\u07fd', '\u07fd'), ('\u0816', '\u0819'), ('\u081b', '\u0823'),
+        ('\u0825', '\u0827'), ('\u0829', '\u082d'), ('\u0859', '\u085b'),
+        ('\u0898', '\u089f'), ('\u08ca', '\u08e1'), ('\u08e3', '\u0903'),
+        ('\u093a', '\u093c'), ('\u093e', '\u094f'), ('\u0951', '\u0957'),
+        ('\u0962', '\u0963'), ('\u0981', '\u0983'), ('\u09bc', '\u09bc'),
+        ('\u09be', '\u09c4'), ('\u09c7', '\u09c8'), ('\u09cb', '\u09cd'),
+        ('\u09d7', '\u09d7'), ('\u09e2', '\u09e3'), ('\u09fe', '\u09fe'),
+        ('\u0a01', '\u0a03'), ('\u0a3c', '\u0a3c'), ('\u0a3e', '\u0a42'),
+        ('\u0a47', '\u0a48'), ('\u0a4b', '\u0a4d'), ('\u0a51', '\u0a51'),
+        ('\u0a70', '\u0a71'), ('\u0a75', '\u0a75'), ('\u0a81', '\u0a83'),
+        ('\u0abc', '\u0abc'), ('\u0abe', '\u0ac5'), ('\u0ac7', '\u0ac9'),
+        ('\u0acb', '\u0acd'), ('\u0ae2', '\u0ae3'), ('\u0afa', '\u0aff'),
+        ('\u0b01', '\u0b03'), ('\u0b3c', '\u0b3c'), ('\u0b3e', '\u0b44'),
+        ('\u0b47', '\u0b48'), ('\u0b4b', '\u0b4d'), ('\u0b55', '\u0b57'),
+        ('\u0b62', '\u0b63'), ('\u0b82', '\u0b82'), ('\u0bbe', '\u0bc2'),
+        ('\u0bc6', '\u0bc8'), ('\u0bca', '\u0bcd'), ('\u0bd7', '\u0bd7'),
+        ('\u0c00', '\u0c04'), ('\u0c3c', '\u0c3c'), ('\u0c3e', '\u0c44'),
+        ('\u0c46', '\u0c48'), ('\u0c4a', '\u0c4d'), ('\u0c55', '\u0c56'),
+        ('\u0c62', '\u0c63'), ('\u0c81', '\u0c83'), ('\u0cbc', '\u0cbc'),
+        ('\u0cbe', '\u0cc4'), ('\u0cc6', '\u0cc8'), ('\u0cca', '\u0ccd'),
+        ('\u0cd5', '\u0cd6'), ('\u0ce2', '\u0ce3'), ('\u0d00', '\u0d03'),
+        ('\u0d3b', '\u0d3c'), ('\u0d3e', '\u0d44'), ('\u0d46', '\u0d48'),
+        ('\u0d4a', '\u0d4d'), ('\u0d57', '\u0d57'), ('\u0d62', '\u0d63'),
+        ('\u0d81', '\u0d83'), ('\u0dca', '\u0dca'), ('\u0dcf', '\u0dd4'),
+        ('\u0dd6', '\u0dd6'), ('\u0dd8', '\u0ddf'), ('\u0df2', '\u0df3'),
+        ('\u0e31', '\u0e31'), ('\u0e34', '\u0e3a'), ('\u0e47', '\u0e4e'),
+        ('\u0eb1', '\u0eb1'), ('\u0eb4', '\u0ebc'), ('\u0ec8', '\u0ecd'),
+        ('\u0f18', '\u0f19'), ('\u0f35', '\u0f35'), ('\u0f37', '\u0f37'),
+        ('\u0f39', '\u0f39'), ('\u0f3e', '\u0f3f'), ('\u0f71', '\u0f84'),
+        ('\u0f86', '\u0f87'), ('\u0f8d', '\u0f97'), ('\u0f99', '\u0fbc'),
+        ('\u0fc6', '\u0fc6'), ('\u102b', '\u103e'), ('\u1056', '\u1059'),
+        ('\u105e', '\u1060'), ('\u1062', '\u1064'), ('\u1067', '\u106d'),
+        ('\u1071', '\u1074'), ('\u1082', '\u108d'), ('\u108f', '\u108f'),
+        ('\u109a', '\u109d'), ('\u135d', '\u135f'), ('\u1712', '\u1715'),
+        ('\u1732', '\u1734'), ('\u1752', '\u1753'), ('\u1772', '\u1773'),
+        ('\u17b4', '\u17d3'), ('\u17dd', '\u17dd'), ('\u180b', '\u180d'),
+        ('\u180f', '\u180f'), ('\u1885', '\u1886'), ('\u18a9', '\u18a9'),
+        ('\u1920', '\u192b'), ('\u1930', '\u193b'), ('\u1a17', '\u1a1b'),
+        ('\u1a55', '\u1a5e'), ('\u1a60', '\u1a7c'), ('\u1a7f', '\u1a7f'),
+        ('\u1ab0', '\u1ace'), ('\u1b00', '\u1b04'), ('\u1b34', '\u1b44'),
+        ('\u1b6b', '\u1b73'), ('\u1b80', '\u1b82'), ('\u1ba1', '\u1bad'),
+        ('\u1be6', '\u1bf3'), ('\u1c24', '\u1c37'), ('\u1cd0', '\u1cd2'),
+        ('\u1cd4', '\u1ce8'), ('\u1ced', '\u1ced'), ('\u1cf4', '\u1cf4'),
+        ('\u1cf7', '\u1cf9'), ('\u1dc0', '\u1dff'), ('\u20d0', '\u20f0'),
+        ('\u2cef', '\u2cf1'), ('\u2d7f', '\u2d7f'), ('\u2de0', '\u2dff'),
+        ('\u302a', '\u302f'), ('\u3099', '\u309a'), ('\ua66f', '\ua672'),
+        ('\ua674', '\ua67d'), ('\ua69e', '\ua69f'), ('\ua6f0', '\ua6f1'),
+        ('\ua802', '\ua802'), ('\ua806', '\ua806'), ('\ua80b', '\ua80b'),
+        ('\ua823', '\ua827'), ('\ua82c', '\ua82c'), ('\ua880', '\ua881'),
+        ('\ua8b4', '\ua8c5'), ('\ua8e0', '\ua8f1'), ('\ua8ff', '\ua8ff'),
+        ('\ua926', '\ua92d'), ('\ua947', '\ua953'), ('\ua980', '\ua983'),
+        ('\ua9b3', '\ua9c0'), ('\ua9e5', '\ua9e5'), ('\uaa29', '\uaa36'),
+        ('\uaa43', '\uaa43'), ('\uaa4c', '\uaa4d'), ('\uaa7b', '\uaa7d'),
+        ('\uaab0', '\uaab0'), ('\uaab2', '\uaab4'), ('\uaab7', '\uaab8'),
+        ('\uaabe', '\uaabf'), ('\uaac1', '\uaac1'), ('\uaaeb', '\uaaef'),
+        ('\uaaf5', '\uaaf6'), ('\uabe3', '\uabea'), ('\uabec', '\uabed'),
+        ('\ufb1e', '\ufb1e'), ('\ufe00', '\ufe0f'), ('\ufe20', '\ufe2f'),
+        ('\U000101fd', '\U000101fd'), ('\U000102e0', '\U000102e0'), ('\U00010376', '\U0001037a'),
+        ('\U00010a01', '\U00010a03'), ('\U00010a05', '\U00010a06'), ('\U00010a0c', '\U00010a0f'),
+        ('\U00010a38', '\U00010a3a'), ('\U00010a3f', '\U00010a3f'), ('\U00010ae5', '\U00010ae6'),
+        ('\U00010d24', '\U00010d27'), ('\U00010eab', '\U00010eac'), ('\U00010f46', '\U00010f50'),
+        ('\U00010f82', '\U00010f85'), ('\U00011000', '\U00011002'), ('\U00011038', '\U00011046'),
+        ('\U00011070', '\U00011070'), ('\U00011073', '\U00011074'), ('\U0001107f', '\U00011082'),
+        ('\U000110b0', '\U000110ba'), ('\U000110c2', '\U000110c2'), ('\U00011100', '\U00011102'),
+        ('\U00011127', '\U00011134'), ('\U00011145', '\U00011146'), ('\U00011173', '\U00011173'),
+        ('\U00011180', '\U00011182'), ('\U000111b3', '\U000111c0'), ('\U000111c9', '\U000111cc'),
+        ('\U000111ce', '\U000111cf'), ('\U0001122c', '\U00011237'), ('\U0001123e', '\U0001123e'),
+        ('\U000112df', '\U000112ea'), ('\U00011300', '\U00011303'), ('\U0001133b', '\U0001133c'),
+        ('\U0001133e', '\U00011344'), ('\U00011347', '\U00011348'), ('\U0001134b', '\U0001134d'),
+        ('\U00011357', '\U00011357'), ('\U00011362', '\U00011363'), ('\U00011366', '\U0001136c'),
+        ('\U00011370', '\U00011374'), ('\U00011435', '\U00011446'), ('\U0001145e', '\U0001145e'),
+        ('\U000114b0', '\U000114c3'), ('\U000115af', '\U000115b5'), ('\U000115b8', '\U000115c0'),
+        ('\U000115dc', '\U000115dd'), ('\U00011630', '\U00011640'), ('\U000116ab', '\U000116b7'),
+        ('\U0001171d', '\U0001172b'), ('\U0001182c', '\U0001183a'), ('\U00011930', '\U00011935'),
+        ('\U00011937', '\U00011938'), ('\U0001193b', '\U0001193e'), ('\U00011940', '\U00011940'),
+        ('\U00011942', '\U00011943'), ('\U000119d1', '\U000119d7'), ('\U000119da', '\U000119e0'),
+        ('\U000119e4', '\U000119e4'), ('\U00011a01', '\U00011a0a'), ('\U00011a33', '\U00011a39'),
+        ('\U00011a3b', '\U00011a3e'), ('\U00011a47', '\U00011a47'), ('\U00011a51', '\U00011a5b'),
+        ('\U00011a8a', '\U00011a99'), ('\U00011c2f', '\U00011c36'), ('\U00011c38', '\U00011c3f'),
+        ('\U00011c92', '\U00011ca7'), ('\U00011ca9', '\U00011cb6'), ('\U00011d31', '\U00011d36'),
+        ('\U00011d3a', '\U00011d3a'), ('\U00011d3c', '\U00011d3d'), ('\U00011d3f', '\U00011d45'),
+        ('\U00011d47', '\U00011d47'), ('\U00011d8a', '\U00011d8e'), ('\U00011d90', '\U00011d91'),
+        ('\U00011d93', '\U00011d97'), ('\U00011ef3', '\U00011ef6'), ('\U00016af0', '\U00016af4'),
+        ('\U00016b30', '\U00016b36'), ('\U00016f4f', '\U00016f4f'), ('\U00016f51', '\U00016f87'),
+        ('\U00016f8f', '\U00016f92'), ('\U00016fe4', '\U00016fe4'), ('\U00016ff0', '\U00016ff1'),
+        ('\U0001bc9d', '\U0001bc9e'), ('\U0001cf00', '\U0001cf2d'), ('\U0001cf30', '\U0001cf46'),
+        ('\U0001d165', '\U0001d169'), ('\U0001d16d', '\U0001d172'), ('\U0001d17b', '\U0001d182'),
+        ('\U0001d185', '\U0001d18b'), ('\U0001d1aa', '\U0001d1ad'), ('\U0001d242', '\U0001d244'),
+        ('\U0001da00', '\U0001da36'), ('\U0001da3b', '\U0001da6c'), ('\U0001da75', '\U0001da75'),
+        ('\U0001da84', '\U0001da84'), ('\U0001da9b', '\U0001da9f'), ('\U0001daa1', '\U0001daaf'),
+        ('\U0001e000', '\U0001e006'), ('\U0001e008', '\U0001e018'), ('\U0001e01b', '\U0001e021'),
+        ('\U0001e023', '\U0001e024'), ('\U0001e026', '\U0001e02a'), ('\U0001e130', '\U0001e136'),
+        ('\U0001e2ae', '\U0001e2ae'), ('\U0001e2ec', '\U0001e2ef'), ('\U0001e8d0', '\U0001e8d6'),
+        ('\U0001e944', '\U0001e94a'), ('\U000e0100', '\U000e01ef')
+    ]
+}
+
+fn category_mc() -> ~[(char, char)] {
+    ~[
+        ('\u0903', '\u0903'), ('\u093b', '\u093b'), ('\u093e', '\u0940'),
+        ('\u0949', '\u094c'), ('\u094e', '\u094f'), ('\u0982', '\u0983'),
+        ('\u09be', '\u09c0'), ('\u09c7', '\u09c8'), ('\u09cb', '\u09cc'),
+        ('\u09d7', '\u09d7'), ('\u0a03', '\u0a03'), ('\u0a3e', '\u0a40'),
+        ('\u0a83', '\u0a83'), ('\u0abe', '\u0ac0'), ('\u0ac9', '\u0ac9'),
+        ('\u0acb', '\u0acc'), ('\u0b02', '\u0b03'), ('\u0b3e', '\u0b3e'),
+        ('\u0b40', '\u0b40'), ('\u0b47', '\u0b48'), ('\u0b4b', '\u0b4c'),
+        ('\u0b57', '\u0b57'), ('\u0bbe', '\u0bbf'), ('\u0bc1', '\u0bc2'),
+        ('\u0bc6', '\u0bc8'), ('\u0bca', '\u0bcc'), ('\u0bd7', '\u0bd7'),
+        ('\u0c01', '\u0c03'), ('\u0c41', '\u0c44'), ('\u0c82', '\u0c83'),
+        ('\u0cbe', '\u0cbe'), ('\u0cc0', '\u0cc4'), ('\u0cc7', '\u0cc8'),
+        ('\u0cca', '\u0ccb'), ('\u0cd5', '\u0cd6'), ('\u0d02', '\u0d03'),
+        ('\u0d3e', '\u0d40'), ('\u0d46', '\u0d48'), ('\u0d4a', '\u0d4c'),
+        ('\u0d57', '\u0d57'), ('\u0d82', '\u0d83'), ('\u0dcf', '\u0dd1'),
+        ('\u0dd8', '\u0ddf'), ('\u0df2', '\u0df3'), ('\u0f3e', '\u0f3f'),
+        ('\u0f7f', '\u0f7f'), ('\u102b', '\u102c'), ('\u1031', '\u1031'),
+        ('\u1038', '\u1038'), ('\u103b', '\u103c'), ('\u1056', '\u1057'),
+        ('\u1062', '\u1064'), ('\u1067', '\u106d'), ('\u1083', '\u1084'),
+        ('\u1087', '\u108c'), ('\u108f', '\u108f'), ('\u109a', '\u109c'),
+        ('\u1715', '\u1715'), ('\u1734', '\u1734'), ('\u17b6', '\u17b6'),
+        ('\u17be', '\u17c5'), ('\u17c7', '\u17c8'), ('\u1923', '\u1926'),
+        ('\u1929', '\u192b'), ('\u1930', '\u1931'), ('\u1933', '\u1938'),
+        ('\u1a19', '\u1a1a'), ('\u1a55', '\u1a55'), ('\u1a57', '\u1a57'),
+        ('\u1a61', '\u1a61'), ('\u1a63', '\u1a64'), ('\u1a6d', '\u1a72'),
+        ('\u1b04', '\u1b04'), ('\u1b35', '\u1b35'), ('\u1b3b', '\u1b3b'),
+        ('\u1b3d', '\u1b41'), ('\u1b43', '\u1b44'), ('\u1b82', '\u1b82'),
+        ('\u1ba1', '\u1ba1'), ('\u1ba6', '\u1ba7'), ('\u1baa', '\u1baa'),
+        ('\u1be7', '\u1be7'), ('\u1bea', '\u1bec'), ('\u1bee', '\u1bee'),
+        ('\u1bf2', '\u1bf3'), ('\u1c24', '\u1c2b'), ('\u1c34', '\u1c35'),
+        ('\u1ce1', '\u1ce1'), ('\u1cf7', '\u1cf7'), ('\u302e', '\u302f'),
+        ('\ua823', '\ua824'), ('\ua827', '\ua827'), ('\ua880', '\ua881'),
+        ('\ua8b4', '\ua8c3'), ('\ua952', '\ua953'), ('\ua983', '\ua983'),
+        ('\ua9b4', '\ua9b5'), ('\ua9ba', '\ua9bb'), ('\ua9be', '\ua9c0'),
+        ('\uaa2f', '\uaa30'), ('\uaa33', '\uaa34'), ('\uaa4d', '\uaa4d'),
+        ('\uaa7b', '\uaa7b'), ('\uaa7d', '\uaa7d'), ('\uaaeb', '\uaaeb'),
+        ('\uaaee', '\uaaef'), ('\uaaf5', '\uaaf5'), ('\uabe3', '\uabe4'),
+        ('\uabe6', '\uabe7'), ('\uabe9', '\uabea'), ('\uabec', '\uabec'),
+        ('\U00011000', '\U00011000'), ('\U00011002', '\U00011002'), ('\U00011082', '\U00011082'),
+        ('\U000110b0', '\U000110b2'), ('\U000110b7', '\U000110b8'), ('\U0001112c', '\U0001112c'),
+        ('\U00011145', '\U00011146'), ('\U00011182', '\U00011182'), ('\U000111b3', '\U000111b5'),
+        ('\U000111bf', '\U000111c0'), ('\U000111ce', '\U000111ce'), ('\U0001122c', '\U0001122e'),
+        ('\U00011232', '\U00011233'), ('\U00011235', '\U00011235'), ('\U000112e0', '\U000112e2'),
+        ('\U00011302', '\U00011303'), ('\U0001133e', '\U0001133f'), ('\U00011341', '\U00011344'),
+        ('\U00011347', '\U00011348'), ('\U0001134b', '\U0001134d'), ('\U00011357', '\U00011357'),
+        ('\U00011362', '\U00011363'), ('\U00011435', '\U00011437'), ('\U00011440', '\U00011441'),
+        ('\U00011445', '\U00011445'), ('\U000114b0', '\U000114b2'), ('\U000114b9', '\U000114b9'),
+        ('\U000114bb', '\U000114be'), ('\U000114c1', '\U000114c1'), ('\U000115af', '\U000115b1'),
+        ('\U000115b8', '\U000115bb'), ('\U000115be', '\U000115be'), ('\U00011630', '\U00011632'),
+        ('\U0001163b', '\U0001163c'), ('\U0001163e', '\U0001163e'), ('\U000116ac', '\U000116ac'),
+        ('\U000116ae', '\U000116af'), ('\U000116b6', '\U000116b6'), ('\U00011720', '\U00011721'),
+        ('\U00011726', '\U00011726'), ('\U0001182c', '\U0001182e'), ('\U00011838', '\U00011838'),
+        ('\U00011930', '\U00011935'), ('\U00011937', '\U00011938'), ('\U0001193d', '\U0001193d'),
+        ('\U00011940', '\U00011940'), ('\U00011942', '\U00011942'), ('\U000119d1', '\U000119d3'),
+        ('\U000119dc', '\U000119df'), ('\U000119e4', '\U000119e4'), ('\U00011a39', '\U00011a39'),
+        ('\U00011a57', '\U00011a58'), ('\U00011a97', '\U00011a97'), ('\U00011c2f', '\U00011c2f'),
+        ('\U00011c3e', '\U00011c3e'), ('\U00011ca9', '\U00011ca9'), ('\U00011cb1', '\U00011cb1'),
+        ('\U00011cb4', '\U00011cb4'), ('\U00011d8a', '\U00011d8e'), ('\U00011d93', '\U00011d94'),
+        ('\U00011d96', '\U00011d96'), ('\U00011ef5', '\U00011ef6'), ('\U00016f51', '\U00016f87'),
+        ('\U00016ff0', '\U00016ff1'), ('\U0001d165', '\U0001d166'), ('\U0001d16d', '\U0001d172')
+    ]
+}
+
+fn category_me() -> ~[(char, char)] {
+    ~[
+        ('\u0488', '\u0489'), ('\u1abe', '\u1abe'), ('\u20dd', '\u20e0'),
+        ('\u20e2', '\u20e4'), ('\ua670', '\ua672')
+    ]
+}
+
+fn category_mn() -> ~[(char, char)] {
+    ~[
+        ('\u0300', '\u036f'), ('\u0483', '\u0487'), ('\u0591', '\u05bd'),
+        ('\u05bf', '\u05bf'), ('\u05c1', '\u05c2'), ('\u05c4', '\u05c5'),
+        ('\u05c7', '\u05c7'), ('\u0610', '\u061a'), ('\u064b', '\u065f'),
+        ('\u0670', '\u0670'), ('\u06d6', '\u06dc'), ('\u06df', '\u06e4'),
+        ('\u06e7', '\u06e8'), ('\u06ea', '\u06ed'), ('\u0711', '\u0711'),
+        ('\u0730', '\u074a'), ('\u07a6', '\u07b0'), ('\u07eb', '\u07f3'),
+        ('\u07fd', '\u07fd'), ('\u0816', '\u0819'), ('\u081b', '\u0823'),
+        ('\u0825', '\u0827'), ('\u0829', '\u082d'), ('\u0859', '\u085b'),
+        ('\u0898', '\u089f'), ('\u08ca', '\u08e1'), ('\u08e3', '\u0902'),
+        ('\u093a', '\u093a'), ('\u093c', '\u093c'), ('\u0941', '\u0948'),
+        ('\u094d', '\u094d'), ('\u0951', '\u0957'), ('\u0962', '\u0963'),
+        ('\u0981', '\u0981'), ('\u09bc', '\u09bc'), ('\u09c1', '\u09c4'),
+        ('\u09cd', '\u09cd'), ('\u09e2', '\u09e3'), ('\u09fe', '\u09fe'),
+        ('\u0a01', '\u0a02'), ('\u0a3c', '\u0a3c'), ('\u0a41', '\u0a42'),
+        ('\u0a47', '\u0a48'), ('\u0a4b', '\u0a4d'), ('\u0a51', '\u0a51'),
+        ('\u0a70', '\u0a71'), ('\u0a75', '\u0a75'), ('\u0a81', '\u0a82'),
+        ('\u0abc', '\u0abc'), ('\u0ac1', '\u0ac5'), ('\u0ac7', '\u0ac8'),
+        ('\u0acd', '\u0acd'), ('\u0ae2', '\u0ae3'), ('\u0afa', '\u0aff'),
+        ('\u0b01', '\u0b01'), ('\u0b3c', '\u0b3c'), ('\u0b3f', '\u0b3f'),
+        ('\u0b41', '\u0b44'), ('\u0b4d', '\u0b4d'), ('\u0b55', '\u0b56'),
+        ('\u0b62', '\u0b63'), ('\u0b82', '\u0b82'), ('\u0bc0', '\u0bc0'),
+        ('\u0bcd', '\u0bcd'), ('\u0c00', '\u0c00'), ('\u0c04', '\u0c04'),
+        ('\u0c3c', '\u0c3c'), ('\u0c3e', '\u0c40'), ('\u0c46', '\u0c48'),
+        ('\u0c4a', '\u0c4d'), ('\u0c55', '\u0c56'), ('\u0c62', '\u0c63'),
+        ('\u0c81', '\u0c81'), ('\u0cbc', '\u0cbc'), ('\u0cbf', '\u0cbf'),
+        ('\u0cc6', '\u0cc6'), ('\u0ccc', '\u0ccd'), ('\u0ce2', '\u0ce3'),
+        ('\u0d00', '\u0d01'), ('\u0d3b', '\u0d3c'), ('\u0d41', '\u0d44'),
+        ('\u0d4d', '\u0d4d'), ('\u0d62', '\u0d63'), ('\u0d81', '\u0d81'),
+        ('\u0dca', '\u0dca'), ('\u0dd2', '\u0dd4'), ('\u0dd6', '\u0dd6'),
+        ('\u0e31', '\u0e31'), ('\u0e34', '\u0e3a'), ('\u0e47', '\u0e4e'),
+        ('\u0eb1', '\u0eb1'), ('\u0eb4', '\u0ebc'), ('\u0ec8', '\u0ecd'),
+        ('\u0f18', '\u0f19'), ('\u0f35', '\u0f35'), ('\u0f37', '\u0f37'),
+        ('\u0f39', '\u0f39'), ('\u0f71', '\u0f7e'), ('\u0f80', '\u0f84'),
+        ('\u0f86', '\u0f87'), ('\u0f8d', '\u0f97'), ('\u0f99', '\u0fbc'),
+        ('\u0fc6', '\u0fc6'), ('\u102d', '\u1030'), ('\u1032', '\u1037'),
+        ('\u1039', '\u103a'), ('\u103d', '\u103e'), ('\u1058', '\u1059'),
+        ('\u105e', '\u1060'), ('\u1071', '\u1074'), ('\u1082', '\u1082'),
+        ('\u1085', '\u1086'), ('\u108d', '\u108d'), ('\u109d', '\u109d'),
+        ('\u135d', '\u135f'), ('\u1712', '\u1714'), ('\u1732', '\u1733'),
+        ('\u1752', '\u1753'), ('\u1772', '\u1773'), ('\u17b4', '\u17b5'),
+        ('\u17b7', '\u17bd'), ('\u17c6', '\u17c6'), ('\u17c9', '\u17d3'),
+        ('\u17dd', '\u17dd'), ('\u180b', '\u180d'), ('\u180f', '\u180f'),
+        ('\u1885', '\u1886'), ('\u18a9', '\u18a9'), ('\u1920', '\u1922'),
+        ('\u1927', '\u1928'), ('\u1932', '\u1932'), ('\u1939', '\u193b'),
+        ('\u1a17', '\u1a18'), ('\u1a1b', '\u1a1b'), ('\u1a56', '\u1a56'),
+        ('\u1a58', '\u1a5e'), ('\u1a60', '\u1a60'), ('\u1a62', '\u1a62'),
+        ('\u1a65', '\u1a6c'), ('\u1a73', '\u1a7c'), ('\u1a7f', '\u1a7f'),
+        ('\u1ab0', '\u1abd'), ('\u1abf', '\u1ace'), ('\u1b00', '\u1b03'),
+        ('\u1b34', '\u1b34'), ('\u1b36', '\u1b3a'), ('\u1b3c', '\u1b3c'),
+        ('\u1b42', '\u1b42'), ('\u1b6b', '\u1b73'), ('\u1b80', '\u1b81'),
+        ('\u1ba2', '\u1ba5'), ('\u1ba8', '\u1ba9'), ('\u1bab', '\u1bad'),
+        ('\u1be6', '\u1be6'), ('\u1be8', '\u1be9'), ('\u1bed', '\u1bed'),
+        ('\u1bef', '\u1bf1'), ('\u1c2c', '\u1c33'), ('\u1c36', '\u1c37'),
+        ('\u1cd0', '\u1cd2'), ('\u1cd4', '\u1ce0'), ('\u1ce2', '\u1ce8'),
+        ('\u1ced', '\u1ced'), ('\u1cf4', '\u1cf4'), ('\u1cf8', '\u1cf9'),
+        ('\u1dc0', '\u1dff'), ('\u20d0', '\u20dc'), ('\u20e1', '\u20e1'),
+        ('\u20e5', '\u20f0'), ('\u2cef', '\u2cf1'), ('\u2d7f', '\u2d7f'),
+        ('\u2de0', '\u2dff'), ('\u302a', '\u302d'), ('\u3099', '\u309a'),
+        ('\ua66f', '\ua66f'), ('\ua674', '\ua67d'), ('\ua69e', '\ua69f'),
+        ('\ua6f0', '\ua6f1'), ('\ua802', '\ua802'), ('\ua806', '\ua806'),
+        ('\ua80b', '\ua80b'), ('\ua825', '\ua826'), ('\ua82c', '\ua82c'),
+        ('\ua8c4', '\ua8c5'), ('\ua8e0', '\ua8f1'), ('\ua8ff', '\ua8ff'),
+        ('\ua926', '\ua92d'), ('\ua947', '\ua951'), ('\ua980', '\ua982'),
+        ('\ua9b3', '\ua9b3'), ('\ua9b6', '\ua9b9'), ('\ua9bc', '\ua9bd'),
+        ('\ua9e5', '\ua9e5'), ('\uaa29', '\uaa2e'), ('\uaa31', '\uaa32'),
+        ('\uaa35', '\uaa36'), ('\uaa43', '\uaa43'), ('\uaa4c', '\uaa4c'),
+        ('\uaa7c', '\uaa7c'), ('\uaab0', '\uaab0'), ('\uaab2', '\uaab4'),
+        ('\uaab7', '\uaab8'), ('\uaabe', '\uaabf'), ('\uaac1', '\uaac1'),
+        ('\uaaec', '\uaaed'), ('\uaaf6', '\uaaf6'), ('\uabe5', '\uabe5'),
+        ('\uabe8', '\uabe8'), ('\uabed', '\uabed'), ('\ufb1e', '\ufb1e'),
+        ('\ufe00', '\ufe0f'), ('\ufe20', '\ufe2f'), ('\U000101fd', '\U000101fd'),
+        ('\U000102e0', '\U000102e0'), ('\U00010376', '\U0001037a'), ('\U00010a01', '\U00010a03'),
+        ('\U00010a05', '\U00010a06'), ('\U00010a0c', '\U00010a0f'), ('\U00010a38', '\U00010a3a'),
+        ('\U00010a3f', '\U00010a3f'), ('\U00010ae5', '\U00010ae6'), ('\U00010d24', '\U00010d27'),
+        ('\U00010eab', '\U00010eac'), ('\U00010f46', '\U00010f50'), ('\U00010f82', '\U00010f85'),
+        ('\U00011001', '\U00011001'), ('\U00011038', '\U00011046'), ('\U00011070', '\U00011070'),
+        ('\U00011073', '\U00011074'), ('\U0001107f', '\U00011081'), ('\U000110b3', '\U000110b6'),
+        ('\U000110b9', '\U000110ba'), ('\U000110c2', '\U000110c2'), ('\U00011100', '\U00011102'),
+        ('\U00011127', '\U0001112b'), ('\U0001112d', '\U00011134'), ('\U00011173', '\U00011173'),
+        ('\U00011180', '\U00011181'), ('\U000111b6', '\U000111be'), ('\U000111c9', '\U000111cc'),
+        ('\U000111cf', '\U000111cf'), ('\U0001122f', '\U00011231'), ('\U00011234', '\U00011234'),
+        ('\U00011236', '\U00011237'), ('\U0001123e', '\U0001123e'), ('\U000112df', '\U000112df'),
+        ('\U000112e3', '\U000112ea'), ('\U00011300', '\U00011301'), ('\U0001133b', '\U0001133c'),
+        ('\U00011340', '\U00011340'), ('\U00011366', '\U0001136c'), ('\U00011370', '\U00011374'),
+        ('\U00011438', '\U0001143f'), ('\U00011442', '\U00011444'), ('\U00011446', '\U00011446'),
+        ('\U0001145e', '\U0001145e'), ('\U000114b3', '\U000114b8'), ('\U000114ba', '\U000114ba'),
+        ('\U000114bf', '\U000114c0'), ('\U000114c2', '\U000114c3'), ('\U000115b2', '\U000115b5'),
+        ('\U000115bc', '\U000115bd'), ('\U000115bf', '\U000115c0'), ('\U000115dc', '\U000115dd'),
+        ('\U00011633', '\U0001163a'), ('\U0001163d', '\U0001163d'), ('\U0001163f', '\U00011640'),
+        ('\U000116ab', '\U000116ab'), ('\U000116ad', '\U000116ad'), ('\U000116b0', '\U000116b5'),
+        ('\U000116b7', '\U000116b7'), ('\U0001171d', '\U0001171f'), ('\U00011722', '\U00011725'),
+        ('\U00011727', '\U0001172b'), ('\U0001182f', '\U00011837'), ('\U00011839', '\U0001183a'),
+        ('\U0001193b', '\U0001193c'), ('\U0001193e', '\U0001193e'), ('\U00011943', '\U00011943'),
+        ('\U000119d4', '\U000119d7'), ('\U000119da', '\U000119db'), ('\U000119e0', '\U000119e0'),
+        ('\U00011a01', '\U00011a0a'), ('\U00011a33', '\U00011a38'), ('\U00011a3b', '\U00011a3e'),
+        ('\U00011a47', '\U00011a47'), ('\U00011a51', '\U00011a56'), ('\U00011a59', '\U00011a5b'),
+        ('\U00011a8a', '\U00011a96'), ('\U00011a98', '\U00011a99'), ('\U00011c30', '\U00011c36'),
+        ('\U00011c38', '\U00011c3d'), ('\U00011c3f', '\U00011c3f'), ('\U00011c92', '\U00011ca7'),
+        ('\U00011caa', '\U00011cb0'), ('\U00011cb2', '\U00011cb3'), ('\U00011cb5', '\U00011cb6'),
+        ('\U00011d31', '\U00011d36'), ('\U00011d3a', '\U00011d3a'), ('\U00011d3c', '\U00011d3d'),
+        ('\U00011d3f', '\U00011d45'), ('\U00011d47', '\U00011d47'), ('\U00011d90', '\U00011d91'),
+        ('\U00011d95', '\U00011d95'), ('\U00011d97', '\U00011d97'), ('\U00011ef3', '\U00011ef4'),
+        ('\U00016af0', '\U00016af4'), ('\U00016b30', '\U00016b36'), ('\U00016f4f', '\U00016f4f'),
+        ('\U00016f8f', '\U00016f92'), ('\U00016fe4', '\U00016fe4'), ('\U0001bc9d', '\U0001bc9e'),
+        ('\U0001cf00', '\U0001cf2d'), ('\U0001cf30', '\U0001cf46'), ('\U0001d167', '\U0001d169'),
+        ('\U0001d17b', '\U0001d182'), ('\U0001d185', '\U0001d18b'), ('\U0001d1aa', '\U0001d1ad'),
+        ('\U0001d242', '\U0001d244'), ('\U0001da00', '\U0001da36'), ('\U0001da3b', '\U0001da6c'),
+        ('\U0001da75', '\U0001da75'), ('\U0001da84', '\U0001da84'), ('\U0001da9b', '\U0001da9f'),
+        ('\U0001daa1', '\U0001daaf'), ('\U0001e000', '\U0001e006'), ('\U0001e008', '\U0001e018'),
+        ('\U0001e01b', '\U0001e021'), ('\U0001e023', '\U0001e024'), ('\U0001e026', '\U0001e02a'),
+        ('\U0001e130', '\U0001e136'), ('\U0001e2ae', '\U0001e2ae'), ('\U0001e2ec', '\U0001e2ef'),
+        ('\U0001e8d0', '\U0001e8d6'), ('\U0001e944', '\U0001e94a'), ('\U000e0100', '\U000e01ef')
+    ]
+}
+
+fn category_n() -> ~[(char, char)] {
+    ~[
+        ('\u0030', '\u0039'), ('\u00b2', '\u00b3'), ('\u00b9', '\u00b9'),
+        ('\u00bc', '\u00be'), ('\u0660', '\u0669'), ('\u06f0', '\u06f9'),
+        ('\u07c0', '\u07c9'), ('\u0966', '\u096f'), ('\u09e6', '\u09ef'),
+        ('\u09f4', '\u09f9'), ('\u0a66', '\u0a6f'), ('\u0ae6', '\u0aef'),
+        ('\u0b66', '\u0b6f'), ('\u0b72', '\u0b77'), ('\u0be6', '\u0bf2'),
+        ('\u0c66', '\u0c6f'), ('\u0c78', '\u0c7e'), ('\u0ce6', '\u0cef'),
+        ('\u0d58', '\u0d5e'), ('\u0d66', '\u0d78'), ('\u0de6', '\u0def'),
+        ('\u0e50', '\u0e59'), ('\u0ed0', '\u0ed9'), ('\u0f20', '\u0f33'),
+        ('\u1040', '\u1049'), ('\u1090', '\u1099'), ('\u1369', '\u137c'),
+        ('\u16ee', '\u16f0'), ('\u17e0', '\u17e9'), ('\u17f0', '\u17f9'),
+        ('\u1810', '\u1819'), ('\u1946', '\u194f'), ('\u19d0', '\u19da'),
+        ('\u1a80', '\u1a89'), ('\u1a90', '\u1a99'), ('\u1b50', '\u1b59'),
+        ('\u1bb0', '\u1bb9'), ('\u1c40', '\u1c49'), ('\u1c50', '\u1c59'),
+        ('\u2070', '\u2070'), ('\u2074', '\u2079'), ('\u2080', '\u2089'),
+        ('\u2150', '\u2182'), ('\u2185', '\u2189'), ('\u2460', '\u249b'),
+        ('\u24ea', '\u24ff'), ('\u2776', '\u2793'), ('\u2cfd', '\u2cfd'),
+        ('\u3007', '\u3007'), ('\u3021', '\u3029'), ('\u3038', '\u303a'),
+        ('\u3192', '\u3195'), ('\u3220', '\u3229'), ('\u3248', '\u324f'),
+        ('\u3251', '\u325f'), ('\u3280', '\u3289'), ('\u32b1', '\u32bf'),
+        ('\ua620', '\ua629'), ('\ua6e6', '\ua6ef'), ('\ua830', '\ua835'),
+        ('\ua8d0', '\ua8d9'), ('\ua900', '\ua909'), ('\ua9d0', '\ua9d9'),
+        ('\ua9f0', '\ua9f9'), ('\uaa50', '\uaa59'), ('\uabf0', '\uabf9'),
+        ('\uff10', '\uff19'), ('\U00010107', '\U00010133'), ('\U00010140', '\U00010178'),
+        ('\U0001018a', '\U0001018b'), ('\U000102e1', '\U000102fb'), ('\U00010320', '\U00010323'),
+        ('\U00010341', '\U00010341'), ('\U0001034a', '\U0001034a'), ('\U000103d1', '\U000103d5'),
+        ('\U000104a0', '\U000104a9'), ('\U00010858', '\U0001085f'), ('\U00010879', '\U0001087f'),
+        ('\U000108a7', '\U000108af'), ('\U000108fb', '\U000108ff'), ('\U00010916', '\U0001091b'),
+        ('\U000109bc', '\U000109bd'), ('\U000109c0', '\U000109cf'), ('\U000109d2', '\U000109ff'),
+        ('\U00010a40', '\U00010a48'), ('\U00010a7d', '\U00010a7e'), ('\U00010a9d', '\U00010a9f'),
+        ('\U00010aeb', '\U00010aef'), ('\U00010b58', '\U00010b5f'), ('\U00010b78', '\U00010b7f'),
+        ('\U00010ba9', '\U00010baf'), ('\U00010cfa', '\U00010cff'), ('\U00010d30', '\U00010d39'),
+        ('\U00010e60', '\U00010e7e'), ('\U00010f1d', '\U00010f26'), ('\U00010f51', '\U00010f54'),
+        ('\U00010fc5', '\U00010fcb'), ('\U00011052', '\U0001106f'), ('\U000110f0', '\U000110f9'),
+        ('\U00011136', '\U0001113f'), ('\U000111d0', '\U000111d9'), ('\U000111e1', '\U000111f4'),
+        ('\U000112f0', '\U000112f9'), ('\U00011450', '\U00011459'), ('\U000114d0', '\U000114d9'),
+        ('\U00011650', '\U00011659'), ('\U000116c0', '\U000116c9'), ('\U00011730', '\U0001173b'),
+        ('\U000118e0', '\U000118f2'), ('\U00011950', '\U00011959'), ('\U00011c50', '\U00011c6c'),
+        ('\U00011d50', '\U00011d59'), ('\U00011da0', '\U00011da9'), ('\U00011fc0', '\U00011fd4'),
+        ('\U00012400', '\U0001246e'), ('\U00016a60', '\U00016a69'), ('\U00016ac0', '\U00016ac9'),
+        ('\U00016b50', '\U00016b59'), ('\U00016b5b', '\U00016b61'), ('\U00016e80', '\U00016e96'),
+        ('\U0001d2e0', '\U0001d2f3'), ('\U0001d360', '\U0001d378'), ('\U0001d7ce', '\U0001d7ff'),
+        ('\U0001e140', '\U0001e149'), ('\U0001e2f0', '\U0001e2f9'), ('\U0001e8c7', '\U0001e8cf'),
+        ('\U0001e950', '\U0001e959'), ('\U0001ec71', '\U0001ecab'), ('\U0001ecad', '\U0001ecaf'),
+        ('\U0001ecb1', '\U0001ecb4'), ('\U0001ed01', '\U0001ed2d'), ('\U0001ed2f', '\U0001ed3d'),
+        ('\U0001f100', '\U0001f10c'), ('\U0001fbf0', '\U0001fbf9')
+    ]
+}
+
+fn category_nd() -> ~[(char, char)] {
+    ~[
+        ('\u0030', '\u0039'), ('\u0660', '\u0669'), ('\u06f0', '\u06f9'),
+        ('\u07c0', '\u07c9'), ('\u0966', '\u096f'), ('\u09e6', '\u09ef'),
+        ('\u0a66', '\u0a6f'), ('\u0ae6', '\u0aef'), ('\u0b66', '\u0b6f'),
+        ('\u0be6', '\u0bef'), ('\u0c66', '\u0c6f'), ('\u0ce6', '\u0cef'),
+        ('\u0d66', '\u0d6f'), ('\u0de6', '\u0def'), ('\u0e50', '\u0e59'),
+        ('\u0ed0', '\u0ed9'), ('\u0f20', '\u0f29'), ('\u1040', '\u1049'),
+        ('\u1090', '\u1099'), ('\u17e0', '\u17e9'), ('\u1810', '\u1819'),
+        ('\u1946', '\u194f'), ('\u19d0', '\u19d9'), ('\u1a80', '\u1a89'),
+        ('\u1a90', '\u1a99'), ('\u1b50', '\u1b59'), ('\u1bb0', '\u1bb9'),
+        ('\u1c40', '\u1c49'), ('\u1c50', '\u1c59'), ('\ua620', '\ua629'),
+        ('\ua8d0', '\ua8d9'), ('\ua900', '\ua909'), ('\ua9d0', '\ua9d9'),
+        ('\ua9f0', '\ua9f9'), ('\uaa50', '\uaa59'), ('\uabf0', '\uabf9'),
+        ('\uff10', '\uff19'), ('\U000104a0', '\U000104a9'), ('\U00010d30', '\U00010d39'),
+        ('\U00011066', '\U0001106f'), ('\U000110f0', '\U000110f9'), ('\U00011136', '\U0001113f'),
+        ('\U000111d0', '\U000111d9'), ('\U000112f0', '\U000112f9'), ('\U00011450', '\U00011459'),
+        ('\U000114d0', '\U000114d9'), ('\U00011650', '\U00011659'), ('\U000116c0', '\U000116c9'),
+        ('\U00011730', '\U00011739'), ('\U000118e0', '\U000118e9'), ('\U00011950', '\U00011959'),
+        ('\U00011c50', '\U00011c59'), ('\U00011d50', '\U00011d59'), ('\U00011da0', '\U00011da9'),
+        ('\U00016a60', '\U00016a69'), ('\U00016ac0', '\U00016ac9'), ('\U00016b50', '\U00016b59'),
+        ('\U0001d7ce', '\U0001d7ff'), ('\U0001e140', '\U0001e149'), ('\U0001e2f0', '\U0001e2f9'),
+        ('\U0001e950', '\U0001e959'), ('\U0001fbf0', '\U0001fbf9')
+    ]
+}
+
+fn category_nl() -> ~[(char, char)] {
+    ~[
+        ('\u16ee', '\u16f0'), ('\u2160', '\u2182'), ('\u2185', '\u2188'),
+        ('\u3007', '\u3007'), ('\u3021', '\u3029'), ('\u3038', '\u303a'),
+        ('\ua6e6', '\ua6ef'), ('\U00010140', '\U00010174'), ('\U00010341', '\U00010341'),
+        ('\U0001034a', '\U0001034a'), ('\U000103d1', '\U000103d5'), ('\U00012400', '\U0001246e')
+    ]
+}
+
+fn category_no() -> ~[(char, char)] {
+    ~[
+        ('\u00b2', '\u00b3'), ('\u00b9', '\u00b9'), ('\u00bc', '\u00be'),
+        ('\u09f4', '\u09f9'), ('\u0b72', '\u0b77'), ('\u0bf0', '\u0bf2'),
+        ('\u0c78', '\u0c7e'), ('\u0d58', '\u0d5e'), ('\u0d70', '\u0d78'),
+        ('\u0f2a', '\u0f33'), ('\u1369', '\u137c'), ('\u17f0', '\u17f9'),
+        ('\u19da', '\u19da'), ('\u2070', '\u2070'), ('\u2074', '\u2079'),
+        ('\u2080', '\u2089'), ('\u2150', '\u215f'), ('\u2189', '\u2189'),
+        ('\u2460', '\u249b'), ('\u24ea', '\u24ff'), ('\u2776', '\u2793'),
+        ('\u2cfd', '\u2cfd'), ('\u3192', '\u3195'), ('\u3220', '\u3229'),
+        ('\u3248', '\u324f'), ('\u3251', '\u325f'), ('\u3280', '\u3289'),
+        ('\u32b1', '\u32bf'), ('\ua830', '\ua835'), ('\U00010107', '\U00010133'),
+        ('\U00010175', '\U00010178'), ('\U0001018a', '\U0001018b'), ('\U000102e1', '\U000102fb'),
+        ('\U00010320', '\U00010323'), ('\U00010858', '\U0001085f'), ('\U00010879', '\U0001087f'),
+        ('\U000108a7', '\U000108af'), ('\U000108fb', '\U000108ff'), ('\U00010916', '\U0001091b'),
+        ('\U000109bc', '\U000109bd'), ('\U000109c0', '\U000109cf'), ('\U000109d2', '\U000109ff'),
+        ('\U00010a40', '\U00010a48'), ('\U00010a7d', '\U00010a7e'), ('\U00010a9d', '\U00010a9f'),
+        ('\U00010aeb', '\U00010aef'), ('\U00010b58', '\U00010b5f'), ('\U00010b78', '\U00010b7f'),
+        ('\U00010ba9', '\U00010baf'), ('\U00010cfa', '\U00010cff'), ('\U00010e60', '\U00010e7e'),
+        ('\U00010f1d', '\U00010f26'), ('\U00010f51', '\U00010f54'), ('\U00010fc5', '\U00010fcb'),
+        ('\U00011052', '\U00011065'), ('\U000111e1', '\U000111f4'), ('\U0001173a', '\U0001173b'),
+        ('\U000118ea', '\U000118f2'), ('\U00011c5a', '\U00011c6c'), ('\U00011fc0', '\U00011fd4'),
+        ('\U00016b5b', '\U00016b61'), ('\U00016e80', '\U00016e96'), ('\U0001d2e0', '\U0001d2f3'),
+        ('\U0001d360', '\U0001d378'), ('\U0001e8c7', '\U0001e8cf'), ('\U0001ec71', '\U0001ecab'),
+        ('\U0001ecad', '\U0001ecaf'), ('\U0001ecb1', '\U0001ecb4'), ('\U0001ed01', '\U0001ed2d'),
+        ('\U0001ed2f', '\U0001ed3d'), ('\U0001f100', '\U0001f10c')
+    ]
+}
+
+fn category_p() -> ~[(char, char)] {
+    ~[
+        ('\u0021', '\u0023'), ('\u0025', '\u002a'), ('\u002c', '\u002f'),
+        ('\u003a', '\u003b'), ('\u003f', '\u0040'), ('\u005b', '\u005d'),
+        ('\u005f', '\u005f'), ('\u007b', '\u007b'), ('\u007d', '\u007d'),
+        ('\u00a1', '\u00a1'), ('\u00a7', '\u00a7'), ('\u00ab', '\u00ab'),
+        ('\u00b6', '\u00b7'), ('\u00bb', '\u00bb'), ('\u00bf', '\u00bf'),
+        ('\u037e', '\u037e'), ('\u0387', '\u0387'), ('\u055a', '\u055f'),
+        ('\u0589', '\u058a'), ('\u05be', '\u05be'), ('\u05c0', '\u05c0'),
+        ('\u05c3', '\u05c3'), ('\u05c6', '\u05c6'), ('\u05f3', '\u05f4'),
+        ('\u0609', '\u060a'), ('\u060c', '\u060d'), ('\u061b', '\u061b'),
+        ('\u061d', '\u061f'), ('\u066a', '\u066d'), ('\u06d4', '\u06d4'),
+        ('\u0700', '\u070d'), ('\u07f7', '\u07f9'), ('\u0830', '\u083e'),
+        ('\u085e', '\u085e'), ('\u0964', '\u0965'), ('\u0970', '\u0970'),
+        ('\u09fd', '\u09fd'), ('\u0a76', '\u0a76'), ('\u0af0', '\u0af0'),
+        ('\u0c77', '\u0c77'), ('\u0c84', '\u0c84'), ('\u0df4', '\u0df4'),
+        ('\u0e4f', '\u0e4f'), ('\u0e5a', '\u0e5b'), ('\u0f04', '\u0f12'),
+        ('\u0f14', '\u0f14'), ('\u0f3a', '\u0f3d'), ('\u0f85', '\u0f85'),
+        ('\u0fd0', '\u0fd4'), ('\u0fd9', '\u0fda'), ('\u104a', '\u104f'),
+        ('\u10fb', '\u10fb'), ('\u1360', '\u1368'), ('\u1400', '\u1400'),
+        ('\u166e', '\u166e'), ('\u169b', '\u169c'), ('\u16eb', '\u16ed'),
+        ('\u1735', '\u1736'), ('\u17d4', '\u17d6'), ('\u17d8', '\u17da'),
+        ('\u1800', '\u180a'), ('\u1944', '\u1945'), ('\u1a1e', '\u1a1f'),
+        ('\u1aa0', '\u1aa6'), ('\u1aa8', '\u1aad'), ('\u1b5a', '\u1b60'),
+        ('\u1b7d', '\u1b7e'), ('\u1bfc', '\u1bff'), ('\u1c3b', '\u1c3f'),
+        ('\u1c7e', '\u1c7f'), ('\u1cc0', '\u1cc7'), ('\u1cd3', '\u1cd3'),
+        ('\u2010', '\u2027'), ('\u2030', '\u2043'), ('\u2045', '\u2051'),
+        ('\u2053', '\u205e'), ('\u207d', '\u207e'), ('\u208d', '\u208e'),
+        ('\u2308', '\u230b'), ('\u2329', '\u232a'), ('\u2768', '\u2775'),
+        ('\u27c5', '\u27c6'), ('\u27e6', '\u27ef'), ('\u2983', '\u2998'),
+        ('\u29d8', '\u29db'), ('\u29fc', '\u29fd'), ('\u2cf9', '\u2cfc'),
+        ('\u2cfe', '\u2cff'), ('\u2d70', '\u2d70'), ('\u2e00', '\u2e2e'),
+        ('\u2e30', '\u2e4f'), ('\u2e52', '\u2e5d'), ('\u3001', '\u3003'),
+        ('\u3008', '\u3011'), ('\u3014', '\u301f'), ('\u3030', '\u3030'),
+        ('\u303d', '\u303d'), ('\u30a0', '\u30a0'), ('\u30fb', '\u30fb'),
+        ('\ua4fe', '\ua4ff'), ('\ua60d', '\ua60f'), ('\ua673', '\ua673'),
+        ('\ua67e', '\ua67e'), ('\ua6f2', '\ua6f7'), ('\ua874', '\ua877'),
+        ('\ua8ce', '\ua8cf'), ('\ua8f8', '\ua8fa'), ('\ua8fc', '\ua8fc'),
+        ('\ua92e', '\ua92f'), ('\ua95f', '\ua95f'), ('\ua9c1', '\ua9cd'),
+        ('\ua9de', '\ua9df'), ('\uaa5c', '\uaa5f'), ('\uaade', '\uaadf'),
+        ('\uaaf0', '\uaaf1'), ('\uabeb', '\uabeb'), ('\ufd3e', '\ufd3f'),
+        ('\ufe10', '\ufe19'), ('\ufe30', '\ufe52'), ('\ufe54', '\ufe61'),
+        ('\ufe63', '\ufe63'), ('\ufe68', '\ufe68'), ('\ufe6a', '\ufe6b'),
+        ('\uff01', '\uff03'), ('\uff05', '\uff0a'), ('\uff0c', '\uff0f'),
+        ('\uff1a', '\uff1b'), ('\uff1f', '\uff20'), ('\uff3b', '\uff3d'),
+        ('\uff3f', '\uff3f'), ('\uff5b', '\uff5b'), ('\uff5d', '\uff5d'),
+        ('\uff5f', '\uff65'), ('\U00010100', '\U00010102'), ('\U0001039f', '\U0001039f'),
+        ('\U000103d0', '\U000103d0'), ('\U0001056f', '\U0001056f'), ('\U00010857', '\U00010857'),
+        ('\U0001091f', '\U0001091f'), ('\U0001093f', '\U0001093f'), ('\U00010a50', '\U00010a58'),
+        ('\U00010a7f', '\U00010a7f'), ('\U00010af0', '\U00010af6'), ('\U00010b39', '\U00010b3f'),
+        ('\U00010b99', '\U00010b9c'), ('\U00010ead', '\U00010ead'), ('\U00010f55', '\U00010f59'),
+        ('\U00010f86', '\U00010f89'), ('\U00011047', '\U0001104d'), ('\U000110bb', '\U000110bc'),
+        ('\U000110be', '\U000110c1'), ('\U00011140', '\U00011143'), ('\U00011174', '\U00011175'),
+        ('\U000111c5', '\U000111c8'), ('\U000111cd', '\U000111cd'), ('\U000111db', '\U000111db'),
+        ('\U000111dd', '\U000111df'), ('\U00011238', '\U0001123d'), ('\U000112a9', '\U000112a9'),
+        ('\U0001144b', '\U0001144f'), ('\U0001145a', '\U0001145b'), ('\U0001145d', '\U0001145d'),
+        ('\U000114c6', '\U000114c6'), ('\U000115c1', '\U000115d7'), ('\U00011641', '\U00011643'),
+        ('\U00011660', '\U0001166c'), ('\U000116b9', '\U000116b9'), ('\U0001173c', '\U0001173e'),
+        ('\U0001183b', '\U0001183b'), ('\U00011944', '\U00011946'), ('\U000119e2', '\U000119e2'),
+        ('\U00011a3f', '\U00011a46'), ('\U00011a9a', '\U00011a9c'), ('\U00011a9e', '\U00011aa2'),
+        ('\U00011c41', '\U00011c45'), ('\U00011c70', '\U00011c71'), ('\U00011ef7', '\U00011ef8'),
+        ('\U00011fff', '\U00011fff'), ('\U00012470', '\U00012474'), ('\U00012ff1', '\U00012ff2'),
+        ('\U00016a6e', '\U00016a6f'), ('\U00016af5', '\U00016af5'), ('\U00016b37', '\U00016b3b'),
+        ('\U00016b44', '\U00016b44'), ('\U00016e97', '\U00016e9a'), ('\U00016fe2', '\U00016fe2'),
+        ('\U0001bc9f', '\U0001bc9f'), ('\U0001da87', '\U0001da8b'), ('\U0001e95e', '\U0001e95f')
+    ]
+}
+
+fn category_pc() -> ~[(char, char)] {
+    ~[
+        ('\u005f', '\u005f'), ('\u203f', '\u2040'), ('\u2054', '\u2054'),
+        ('\ufe33', '\ufe34'), ('\ufe4d', '\ufe4f'), ('\uff3f', '\uff3f')
+    ]
+}
+
+fn category_pd() -> ~[(char, char)] {
+    ~[
+        ('\u002d', '\u002d'), ('\u058a', '\u058a'), ('\u05be', '\u05be'),
+        ('\u1400', '\u1400'), ('\u1806', '\u1806'), ('\u2010', '\u2015'),
+        ('\u2e17', '\u2e17'), ('\u2e1a', '\u2e1a'), ('\u2e3a', '\u2e3b'),
+        ('\u2e40', '\u2e40'), ('\u2e5d', '\u2e5d'), ('\u301c', '\u301c'),
+        ('\u3030', '\u3030'), ('\u30a0', '\u30a0'), ('\ufe31', '\ufe32'),
+        ('\ufe58', '\ufe58'), ('\ufe63', '\ufe63'), ('\uff0d', '\uff0d'),
+        ('\U00010ead', '\U00010ead')
+    ]
+}
+
+fn category_pe() -> ~[(char, char)] {
+    ~[
+        ('\u0029', '\u0029'), ('\u005d', '\u005d'), ('\u007d', '\u007d'),
+        ('\u0f3b', '\u0f3b'), ('\u0f3d', '\u0f3d'), ('\u169c', '\u169c'),
+        ('\u2046', '\u2046'), ('\u207e', '\u207e'), ('\u208e', '\u208e'),
+        ('\u2309', '\u2309'), ('\u230b', '\u230b'), ('\u232a', '\u232a'),
+        ('\u2769', '\u2769'), ('\u276b', '\u276b'), ('\u276d', '\u276d'),
+        ('\u276f', '\u276f'), ('\u2771', '\u2771'), ('\u2773', '\u2773'),
+        ('\u2775', '\u2775'), ('\u27c6', '\u27c6'), ('\u27e7', '\u27e7'),
+        ('\u27e9', '\u27e9'), ('\u27eb', '\u27eb'), ('\u27ed', '\u27ed'),
+        ('\u27ef', '\u27ef'), ('\u2984', '\u2984'), ('\u2986', '\u2986'),
+        ('\u2988', '\u2988'), ('\u298a', '\u298a'), ('\u298c', '\u298c'),
+        ('\u298e', '\u298e'), ('\u2990', '\u2990'), ('\u2992', '\u2992'),
+        ('\u2994', '\u2994'), ('\u2996', '\u2996'), ('\u2998', '\u2998'),
+        ('\u29d9', '\u29d9'), ('\u29db', '\u29db'), ('\u29fd', '\u29fd'),
+        ('\u2e23', '\u2e23'), ('\u2e25', '\u2e25'), ('\u2e27', '\u2e27'),
+        ('\u2e29', '\u2e29'), ('\u2e56', '\u2e56'), ('\u2e58', '\u2e58'),
+        ('\u2e5a', '\u2e5a'), ('\u2e5c', '\u2e5c'), ('\u3009', '\u3009'),
+        ('\u300b', '\u300b'), ('\u300d', '\u300d'), ('\u300f', '\u300f'),
+        ('\u3011', '\u3011'), ('\u3015', '\u3015'), ('\u3017', '\u3017'),
+        ('\u3019', '\u3019'), ('\u301b', '\u301b'), ('\u301e', '\u301f'),
+        ('\ufd3e', '\ufd3e'), ('\ufe18', '\ufe18'), ('\ufe36', '\ufe36'),
+        ('\ufe38', '\ufe38'), ('\ufe3a', '\ufe3a'), ('\ufe3c', '\ufe3c'),
+        ('\ufe3e', '\ufe3e'), ('\ufe40', '\ufe40'), ('\ufe42', '\ufe42'),
+        ('\ufe44', '\ufe44'), ('\ufe48', '\ufe48'), ('\ufe5a', '\ufe5a'),
+        ('\ufe5c', '\ufe5c'), ('\ufe5e', '\ufe5e'), ('\uff09', '\uff09'),
+        ('\uff3d', '\uff3d'), ('\uff5d', '\uff5d'), ('\uff60', '\uff60'),
+        ('\uff63', '\uff63')
+    ]
+}
+
+fn category_pf() -> ~[(char, char)] {
+    ~[
+        ('\u00bb', '\u00bb'), ('\u2019', '\u2019'), ('\u201d', '\u201d'),
+        ('\u203a', '\u203a'), ('\u2e03', '\u2e03'), ('\u2e05', '\u2e05'),
+        ('\u2e0a', '\u2e0a'), ('\u2e0d', '\u2e0d'), ('\u2e1d', '\u2e1d'),
+        ('\u2e21', '\u2e21')
+    ]
+}
+
+fn category_pi() -> ~[(char, char)] {
+    ~[
+        ('\u00ab', '\u00ab'), ('\u2018', '\u2018'), ('\u201b', '\u201c'),
+        ('\u201f', '\u201f'), ('\u2039', '\u2039'), ('\u2e02', '\u2e02'),
+        ('\u2e04', '\u2e04'), ('\u2e09', '\u2e09'), ('\u2e0c', '\u2e0c'),
+        ('\u2e1c', '\u2e1c'), ('\u2e20', '\u2e20')
+    ]
+}
+
+fn category_po() -> ~[(char, char)] {
+    ~[
+        ('\u0021', '\u0023'), ('\u0025', '\u0027'), ('\u002a', '\u002a'),
+        ('\u002c', '\u002c'), ('\u002e', '\u002f'), ('\u003a', '\u003b'),
+        ('\u003f', '\u0040'), ('\u005c', '\u005c'), ('\u00a1', '\u00a1'),
+        ('\u00a7', '\u00a7'), ('\u00b6', '\u00b7'), ('\u00bf', '\u00bf'),
+        ('\u037e', '\u037e'), ('\u0387', '\u0387'), ('\u055a', '\u055f'),
+        ('\u0589', '\u0589'), ('\u05c0', '\u05c0'), ('\u05c3', '\u05c3'),
+        ('\u05c6', '\u05c6'), ('\u05f3', '\u05f4'), ('\u0609', '\u060a'),
+        ('\u060c', '\u060d'), ('\u061b', '\u061b'), ('\u061d', '\u061f'),
+        ('\u066a', '\u066d'), ('\u06d4', '\u06d4'), ('\u0700', '\u070d'),
+        ('\u07f7', '\u07f9'), ('\u0830', '\u083e'), ('\u085e', '\u085e'),
+        ('\u0964', '\u0965'), ('\u0970', '\u0970'), ('\u09fd', '\u09fd'),
+        ('\u0a76', '\u0a76'), ('\u0af0', '\u0af0'), ('\u0c77', '\u0c77'),
+        ('\u0c84', '\u0c84'), ('\u0df4', '\u0df4'), ('\u0e4f', '\u0e4f'),
+        ('\u0e5a', '\u0e5b'), ('\u0f04', '\u0f12'), ('\u0f14', '\u0f14'),
+        ('\u0f85', '\u0f85'), ('\u0fd0', '\u0fd4'), ('\u0fd9', '\u0fda'),
+        ('\u104a', '\u104f'), ('\u10fb', '\u10fb'), ('\u1360', '\u1368'),
+        ('\u166e', '\u166e'), ('\u16eb', '\u16ed'), ('\u1735', '\u1736'),
+        ('\u17d4', '\u17d6'), ('\u17d8', '\u17da'), ('\u1800', '\u1805'),
+        ('\u1807', '\u180a'), ('\u1944', '\u1945'), ('\u1a1e', '\u1a1f'),
+        ('\u1aa0', '\u1aa6'), ('\u1aa8', '\u1aad'), ('\u1b5a', '\u1b60'),
+        ('\u1b7d', '\u1b7e'), ('\u1bfc', '\u1bff'), ('\u1c3b', '\u1c3f'),
+        ('\u1c7e', '\u1c7f'), ('\u1cc0', '\u1cc7'), ('\u1cd3', '\u1cd3'),
+        ('\u2016', '\u2017'), ('\u2020', '\u2027'), ('\u2030', '\u2038'),
+        ('\u203b', '\u203e'), ('\u2041', '\u2043'), ('\u2047', '\u2051'),
+        ('\u2053', '\u2053'), ('\u2055', '\u205e'), ('\u2cf9', '\u2cfc'),
+        ('\u2cfe', '\u2cff'), ('\u2d70', '\u2d70'), ('\u2e00', '\u2e01'),
+        ('\u2e06', '\u2e08'), ('\u2e0b', '\u2e0b'), ('\u2e0e', '\u2e16'),
+        ('\u2e18', '\u2e19'), ('\u2e1b', '\u2e1b'), ('\u2e1e', '\u2e1f'),
+        ('\u2e2a', '\u2e2e'), ('\u2e30', '\u2e39'), ('\u2e3c', '\u2e3f'),
+        ('\u2e41', '\u2e41'), ('\u2e43', '\u2e4f'), ('\u2e52', '\u2e54'),
+        ('\u3001', '\u3003'), ('\u303d', '\u303d'), ('\u30fb', '\u30fb'),
+        ('\ua4fe', '\ua4ff'), ('\ua60d', '\ua60f'), ('\ua673', '\ua673'),
+        ('\ua67e', '\ua67e'), ('\ua6f2', '\ua6f7'), ('\ua874', '\ua877'),
+        ('\ua8ce', '\ua8cf'), ('\ua8f8', '\ua8fa'), ('\ua8fc', '\ua8fc'),
+        ('\ua92e', '\ua92f'), ('\ua95f', '\ua95f'), ('\ua9c1', '\ua9cd'),
+        ('\ua9de', '\ua9df'), ('\uaa5c', '\uaa5f'), ('\uaade', '\uaadf'),
+        ('\uaaf0', '\uaaf1'), ('\uabeb', '\uabeb'), ('\ufe10', '\ufe16'),
+        ('\ufe19', '\ufe19'), ('\ufe30', '\ufe30'), ('\ufe45', '\ufe46'),
+        ('\ufe49', '\ufe4c'), ('\ufe50', '\ufe52'), ('\ufe54', '\ufe57'),
+        ('\ufe5f', '\ufe61'), ('\ufe68', '\ufe68'), ('\ufe6a', '\ufe6b'),
+        ('\uff01', '\uff03'), ('\uff05', '\uff07'), ('\uff0a', '\uff0a'),
+        ('\uff0c', '\uff0c'), ('\uff0e', '\uff0f'), ('\uff1a', '\uff1b'),
+        ('\uff1f', '\uff20'), ('\uff3c', '\uff3c'), ('\uff61', '\uff61'),
+        ('\uff64', '\uff65'), ('\U00010100', '\U00010102'), ('\U0001039f', '\U0001039f'),
+        ('\U000103d0', '\U000103d0'), ('\U0001056f', '\U0001056f'), ('\U00010857', '\U00010857'),
+        ('\U0001091f', '\U0001091f'), ('\U0001093f', '\U0001093f'), ('\U00010a50', '\U00010a58'),
+        ('\U00010a7f', '\U00010a7f'), ('\U00010af0', '\U00010af6'), ('\U00010b39', '\U00010b3f'),
+        ('\U00010b99', '\U00010b9c'), ('\U00010f55', '\U00010f59'), ('\U00010f86', '\U00010f89'),
+        ('\U00011047', '\U0001104d'), ('\U000110bb', '\U000110bc'), ('\U000110be', '\U000110c1'),
+        ('\U00011140', '\U00011143'), ('\U00011174', '\U00011175'), ('\U000111c5', '\U000111c8'),
+        ('\U000111cd', '\U000111cd'), ('\U000111db', '\U000111db'), ('\U000111dd', '\U000111df'),
+        ('\U00011238', '\U0001123d'), ('\U000112a9', '\U000112a9'), ('\U0001144b', '\U0001144f'),
+        ('\U0001145a', '\U0001145b'), ('\U0001145d', '\U0001145d'), ('\U000114c6', '\U000114c6'),
+        ('\U000115c1', '\U000115d7'), ('\U00011641', '\U00011643'), ('\U00011660', '\U0001166c'),
+        ('\U000116b9', '\U000116b9'), ('\U0001173c', '\U0001173e'), ('\U0001183b', '\U0001183b'),
+        ('\U00011944', '\U00011946'), ('\U000119e2', '\U000119e2'), ('\U00011a3f', '\U00011a46'),
+        ('\U00011a9a', '\U00011a9c'), ('\U00011a9e', '\U00011aa2'), ('\U00011c41', '\U00011c45'),
+        ('\U00011c70', '\U00011c71'), ('\U00011ef7', '\U00011ef8'), ('\U00011fff', '\U00011fff'),
+        ('\U00012470', '\U00012474'), ('\U00012ff1', '\U00012ff2'), ('\U00016a6e', '\U00016a6f'),
+        ('\U00016af5', '\U00016af5'), ('\U00016b37', '\U00016b3b'), ('\U00016b44', '\U00016b44'),
+        ('\U00016e97', '\U00016e9a'), ('\U00016fe2', '\U00016fe2'), ('\U0001bc9f', '\U0001bc9f'),
+        ('\U0001da87', '\U0001da8b'), ('\U0001e95e', '\U0001e95f')
+    ]
+}
+
+fn category_ps() -> ~[(char, char)] {
+    ~[
+        ('\u0028', '\u0028'), ('\u005b', '\u005b'), ('\u007b', '\u007b'),
+        ('\u0f3a', '\u0f3a'), ('\u0f3c', '\u0f3c'), ('\u169b', '\u169b'),
+        ('\u201a', '\u201a'), ('\u201e', '\u201e'), ('\u2045', '\u2045'),
+        ('\u207d', '\u207d'), ('\u208d', '\u208d'), ('\u2308', '\u2308'),
+        ('\u230a', '\u230a'), ('\u2329', '\u2329'), ('\u2768', '\u2768'),
+        ('\u276a', '\u276a'), ('\u276c', '\u276c'), ('\u276e', '\u276e'),
+        ('\u2770', '\u2770'), ('\u2772', '\u2772'), ('\u2774', '\u2774'),
+        ('\u27c5', '\u27c5'), ('\u27e6', '\u27e6'), ('\u27e8', '\u27e8'),
+        ('\u27ea', '\u27ea'), ('\u27ec', '\u27ec'), ('\u27ee', '\u27ee'),
+        ('\u2983', '\u2983'), ('\u2985', '\u2985'), ('\u2987', '\u2987'),
+        ('\u2989', '\u2989'), ('\u298b', '\u298b'), ('\u298d', '\u298d'),
+        ('\u298f', '\u298f'), ('\u2991', '\u2991'), ('\u2993', '\u2993'),
+        ('\u2995', '\u2995'), ('\u2997', '\u2997'), ('\u29d8', '\u29d8'),
+        ('\u29da', '\u29da'), ('\u29fc', '\u29fc'), ('\u2e22', '\u2e22'),
+        ('\u2e24', '\u2e24'), ('\u2e26', '\u2e26'), ('\u2e28', '\u2e28'),
+        ('\u2e42', '\u2e42'), ('\u2e55', '\u2e55'), ('\u2e57', '\u2e57'),
+        ('\u2e59', '\u2e59'), ('\u2e5b', '\u2e5b'), ('\u3008', '\u3008'),
+        ('\u300a', '\u300a'), ('\u300c', '\u300c'), ('\u300e', '\u300e'),
+        ('\u3010', '\u3010'), ('\u3014', '\u3014'), ('\u3016', '\u3016'),
+        ('\u3018', '\u3018'), ('\u301a', '\u301a'), ('\u301d', '\u301d'),
+        ('\ufd3f', '\ufd3f'), ('\ufe17', '\ufe17'), ('\ufe35', '\ufe35'),
+        ('\ufe37', '\ufe37'), ('\ufe39', '\ufe39'), ('\ufe3b', '\ufe3b'),
+        ('\ufe3d', '\ufe3d'), ('\ufe3f', '\ufe3f'), ('\ufe41', '\ufe41'),
+        ('\ufe43', '\ufe43'), ('\ufe47', '\ufe47'), ('\ufe59', '\ufe59'),
+        ('\ufe5b', '\ufe5b'), ('\ufe5d', '\ufe5d'), ('\uff08', '\uff08'),
+        ('\uff3b', '\uff3b'), ('\uff5b', '\uff5b'), ('\uff5f', '\uff5f'),
+        ('\uff62', '\uff62')
+    ]
+}
+
+fn category_s() -> ~[(char, char)] {
+    ~[
+        ('\u0024', '\u0024'), ('\u002b', '\u002b'), ('\u003c', '\u003e'),
+        ('\u005e', '\u005e'), ('\u0060', '\u0060'), ('\u007c', '\u007c'),
+        ('\u007e', '\u007e'), ('\u00a2', '\u00a6'), ('\u00a8', '\u00a9'),
+        ('\u00ac', '\u00ac'), ('\u00ae', '\u00b1'), ('\u00b4', '\u00b4'),
+        ('\u00b8', '\u00b8'), ('\u00d7', '\u00d7'), ('\u00f7', '\u00f7'),
+        ('\u02c2', '\u02c5'), ('\u02d2', '\u02df'), ('\u02e5', '\u02eb'),
+        ('\u02ed', '\u02ed'), ('\u02ef', '\u02ff'), ('\u0375', '\u0375'),
+        ('\u0384', '\u0385'), ('\u03f6', '\u03f6'), ('\u0482', '\u0482'),
+        ('\u058d', '\u058f'), ('\u0606', '\u0608'), ('\u060b', '\u060b'),
+        ('\u060e', '\u060f'), ('\u06de', '\u06de'), ('\u06e9', '\u06e9'),
+        ('\u06fd', '\u06fe'), ('\u07f6', '\u07f6'), ('\u07fe', '\u07ff'),
+        ('\u0888', '\u0888'), ('\u09f2', '\u09f3'), ('\u09fa', '\u09fb'),
+        ('\u0af1', '\u0af1'), ('\u0b70', '\u0b70'), ('\u0bf3', '\u0bfa'),
+        ('\u0c7f', '\u0c7f'), ('\u0d4f', '\u0d4f'), ('\u0d79', '\u0d79'),
+        ('\u0e3f', '\u0e3f'), ('\u0f01', '\u0f03'), ('\u0f13', '\u0f13'),
+        ('\u0f15', '\u0f17'), ('\u0f1a', '\u0f1f'), ('\u0f34', '\u0f34'),
+        ('\u0f36', '\u0f36'), ('\u0f38', '\u0f38'), ('\u0fbe', '\u0fc5'),
+        ('\u0fc7', '\u0fcc'), ('\u0fce', '\u0fcf'), ('\u0fd5', '\u0fd8'),
+        ('\u109e', '\u109f'), ('\u1390', '\u1399'), ('\u166d', '\u166d'),
+        ('\u17db', '\u17db'), ('\u1940', '\u1940'), ('\u19de', '\u19ff'),
+        ('\u1b61', '\u1b6a'), ('\u1b74', '\u1b7c'), ('\u1fbd', '\u1fbd'),
+        ('\u1fbf', '\u1fc1'), ('\u1fcd', '\u1fcf'), ('\u1fdd', '\u1fdf'),
+        ('\u1fed', '\u1fef'), ('\u1ffd', '\u1ffe'), ('\u2044', '\u2044'),
+        ('\u2052', '\u2052'), ('\u207a', '\u207c'), ('\u208a', '\u208c'),
+        ('\u20a0', '\u20c0'), ('\u2100', '\u2101'), ('\u2103', '\u2106'),
+        ('\u2108', '\u2109'), ('\u2114', '\u2114'), ('\u2116', '\u2118'),
+        ('\u211e', '\u2123'), ('\u2125', '\u2125'), ('\u2127', '\u2127'),
+        ('\u2129', '\u2129'), ('\u212e', '\u212e'), ('\u213a', '\u213b'),
+        ('\u2140', '\u2144'), ('\u214a', '\u214d'), ('\u214f', '\u214f'),
+        ('\u218a', '\u218b'), ('\u2190', '\u2307'), ('\u230c', '\u2328'),
+        ('\u232b', '\u2426'), ('\u2440', '\u244a'), ('\u249c', '\u24e9'),
+        ('\u2500', '\u2767'), ('\u2794', '\u27c4'), ('\u27c7', '\u27e5'),
+        ('\u27f0', '\u2982'), ('\u2999', '\u29d7'), ('\u29dc', '\u29fb'),
+        ('\u29fe', '\u2b73'), ('\u2b76', '\u2b95'), ('\u2b97', '\u2bff'),
+        ('\u2ce5', '\u2cea'), ('\u2e50', '\u2e51'), ('\u2e80', '\u2e99'),
+        ('\u2e9b', '\u2ef3'), ('\u2f00', '\u2fd5'), ('\u2ff0', '\u2ffb'),
+        ('\u3004', '\u3004'), ('\u3012', '\u3013'), ('\u3020', '\u3020'),
+        ('\u3036', '\u3037'), ('\u303e', '\u303f'), ('\u309b', '\u309c'),
+        ('\u3190', '\u3191'), ('\u3196', '\u319f'), ('\u31c0', '\u31e3'),
+        ('\u3200', '\u321e'), ('\u322a', '\u3247'), ('\u3250', '\u3250'),
+        ('\u3260', '\u327f'), ('\u328a', '\u32b0'), ('\u32c0', '\u33ff'),
+        ('\u4dc0', '\u4dff'), ('\ua490', '\ua4c6'), ('\ua700', '\ua716'),
+        ('\ua720', '\ua721'), ('\ua789', '\ua78a'), ('\ua828', '\ua82b'),
+        ('\ua836', '\ua839'), ('\uaa77', '\uaa79'), ('\uab5b', '\uab5b'),
+        ('\uab6a', '\uab6b'), ('\ufb29', '\ufb29'), ('\ufbb2', '\ufbc2'),
+        ('\ufd40', '\ufd4f'), ('\ufdcf', '\ufdcf'), ('\ufdfc', '\ufdff'),
+        ('\ufe62', '\ufe62'), ('\ufe64', '\ufe66'), ('\ufe69', '\ufe69'),
+        ('\uff04', '\uff04'), ('\uff0b', '\uff0b'), ('\uff1c', '\uff1e'),
+        ('\uff3e', '\uff3e'), ('\uff40', '\uff40'), ('\uff5c', '\uff5c'),
+        ('\uff5e', '\uff5e'), ('\uffe0', '\uffe6'), ('\uffe8', '\uffee'),
+        ('\ufffc', '\ufffd'), ('\U00010137', '\U0001013f'), ('\U00010179', '\U00010189'),
+        ('\U0001018c', '\U0001018e'), ('\U00010190', '\U0001019c'), ('\U000101a0', '\U000101a0'),
+        ('\U000101d0', '\U000101fc'), ('\U00010877', '\U00010878'), ('\U00010ac8', '\U00010ac8'),
+        ('\U0001173f', '\U0001173f'), ('\U00011fd5', '\U00011ff1'), ('\U00016b3c', '\U00016b3f'),
+        ('\U00016b45', '\U00016b45'), ('\U0001bc9c', '\U0001bc9c'), ('\U0001cf50', '\U0001cfc3'),
+        ('\U0001d000', '\U0001d0f5'), ('\U0001d100', '\U0001d126'), ('\U0001d129', '\U0001d164'),
+        ('\U0001d16a', '\U0001d16c'), ('\U0001d183', '\U0001d184'), ('\U0001d18c', '\U0001d1a9'),
+        ('\U0001d1ae', '\U0001d1ea'), ('\U0001d200', '\U0001d241'), ('\U0001d245', '\U0001d245'),
+        ('\U0001d300', '\U0001d356'), ('\U0001d6c1', '\U0001d6c1'), ('\U0001d6db', '\U0001d6db'),
+        ('\U0001d6fb', '\U0001d6fb'), ('\U0001d715', '\U0001d715'), ('\U0001d735', '\U0001d735'),
+        ('\U0001d74f', '\U0001d74f'), ('\U0001d76f', '\U0001d76f'), ('\U0001d789', '\U0001d789'),
+        ('\U0001d7a9', '\U0001d7a9'), ('\U0001d7c3', '\U0001d7c3'), ('\U0001d800', '\U0001d9ff'),
+        ('\U0001da37', '\U0001da3a'), ('\U0001da6d', '\U0001da74'), ('\U0001da76', '\U0001da83'),
+        ('\U0001da85', '\U0001da86'), ('\U0001e14f', '\U0001e14f'), ('\U0001e2ff', '\U0001e2ff'),
+        ('\U0001ecac', '\U0001ecac'), ('\U0001ecb0', '\U0001ecb0'), ('\U0001ed2e', '\U0001ed2e'),
+        ('\U0001eef0', '\U0001eef1'), ('\U0001f000', '\U0001f02b'), ('\U0001f030', '\U0001f093'),
+        ('\U0001f0a0', '\U0001f0ae'), ('\U0001f0b1', '\U0001f0bf'), ('\U0001f0c1', '\U0001f0cf'),
+        ('\U0001f0d1', '\U0001f0f5'), ('\U0001f10d', '\U0001f1ad'), ('\U0001f1e6', '\U0001f202'),
+        ('\U0001f210', '\U0001f23b'), ('\U0001f240', '\U0001f248'), ('\U0001f250', '\U0001f251'),
+        ('\U0001f260', '\U0001f265'), ('\U0001f300', '\U0001f6d7'), ('\U0001f6dd', '\U0001f6ec'),
+        ('\U0001f6f0', '\U0001f6fc'), ('\U0001f700', '\U0001f773'), ('\U0001f780', '\U0001f7d8'),
+        ('\U0001f7e0', '\U0001f7eb'), ('\U0001f7f0', '\U0001f7f0'), ('\U0001f800', '\U0001f80b'),
+        ('\U0001f810', '\U0001f847'), ('\U0001f850', '\U0001f859'), ('\U0001f860', '\U0001f887'),
+        ('\U0001f890', '\U0001f8ad'), ('\U0001f8b0', '\U0001f8b1'), ('\U0001f900', '\U0001fa53'),
+        ('\U0001fa60', '\U0001fa6d'), ('\U0001fa70', '\U0001fa74'), ('\U0001fa78', '\U0001fa7c'),
+        ('\U0001fa80', '\U0001fa86'), ('\U0001fa90', '\U0001faac'), ('\U0001fab0', '\U0001faba'),
+        ('\U0001fac0', '\U0001fac5'), ('\U0001fad0', '\U0001fad9'), ('\U0001fae0', '\U0001fae7'),
+        ('\U0001faf0', '\U0001faf6'), ('\U0001fb00', '\U0001fb92'), ('\U0001fb94', '\U0001fbca')
+    ]
+}
+
+fn category_sc() -> ~[(char, char)] {
+    ~[
+        ('\u0024', '\u0024'), ('\u00a2', '\u00a5'), ('\u058f', '\u058f'),
+        ('\u060b', '\u060b'), ('\u07fe', '\u07ff'), ('\u09f2', '\u09f3'),
+        ('\u09fb', '\u09fb'), ('\u0af1', '\u0af1'), ('\u0bf9', '\u0bf9'),
+        ('\u0e3f', '\u0e3f'), ('\u17db', '\u17db'), ('\u20a0', '\u20c0'),
+        ('\ua838', '\ua838'), ('\ufdfc', '\ufdfc'), ('\ufe69', '\ufe69'),
+        ('\uff04', '\uff04'), ('\uffe0', '\uffe1'), ('\uffe5', '\uffe6'),
+        ('\U00011fdd', '\U00011fe0'), ('\U0001e2ff', '\U0001e2ff'), ('\U0001ecb0', '\U0001ecb0')
+    ]
+}
+
+fn category_sk() -> ~[(char, char)] {
+    ~[
+        ('\u005e', '\u005e'), ('\u0060', '\u0060'), ('\u00a8', '\u00a8'),
+        ('\u00af', '\u00af'), ('\u00b4', '\u00b4'), ('\u00b8', '\u00b8'),
+        ('\u02c2', '\u02c5'), ('\u02d2', '\u02df'), ('\u02e5', '\u02eb'),
+        ('\u02ed', '\u02ed'), ('\u02ef', '\u02ff'), ('\u0375', '\u0375'),
+        ('\u0384', '\u0385'), ('\u0888', '\u0888'), ('\u1fbd', '\u1fbd'),
+        ('\u1fbf', '\u1fc1'), ('\u1fcd', '\u1fcf'), ('\u1fdd', '\u1fdf'),
+        ('\u1fed', '\u1fef'), ('\u1ffd', '\u1ffe'), ('\u309b', '\u309c'),
+        ('\ua700', '\ua716'), ('\ua720', '\ua721'), ('\ua789', '\ua78a'),
+        ('\uab5b', '\uab5b'), ('\uab6a', '\uab6b'), ('\ufbb2', '\ufbc2'),
+        ('\uff3e', '\uff3e'), ('\uff40', '\uff40'), ('\uffe3', '\uffe3'),
+        ('\U0001f3fb', '\U0001f3ff')
+    ]
+}
+
+fn category_sm() -> ~[(char, char)] {
+    ~[
+        ('\u002b', '\u002b'), ('\u003c', '\u003e'), ('\u007c', '\u007c'),
+        ('\u007e', '\u007e'), ('\u00ac', '\u00ac'), ('\u00b1', '\u00b1'),
+        ('\u00d7', '\u00d7'), ('\u00f7', '\u00f7'), ('\u03f6', '\u03f6'),
+        ('\u0606', '\u0608'), ('\u2044', '\u2044'), ('\u2052', '\u2052'),
+        ('\u207a', '\u207c'), ('\u208a', '\u208c'), ('\u2118', '\u2118'),
+        ('\u2140', '\u2144'), ('\u214b', '\u214b'), ('\u2190', '\u2194'),
+        ('\u219a', '\u219b'), ('\u21a0', '\u21a0'), ('\u21a3', '\u21a3'),
+        ('\u21a6', '\u21a6'), ('\u21ae', '\u21ae'), ('\u21ce', '\u21cf'),
+        ('\u21d2', '\u21d2'), ('\u21d4', '\u21d4'), ('\u21f4', '\u22ff'),
+        ('\u2320', '\u2321'), ('\u237c', '\u237c'), ('\u239b', '\u23b3'),
+        ('\u23dc', '\u23e1'), ('\u25b7', '\u25b7'), ('\u25c1', '\u25c1'),
+        ('\u25f8', '\u25ff'), ('\u266f', '\u266f'), ('\u27c0', '\u27c4'),
+        ('\u27c7', '\u27e5'), ('\u27f0', '\u27ff'), ('\u2900', '\u2982'),
+        ('\u2999', '\u29d7'), ('\u29dc', '\u29fb'), ('\u29fe', '\u2aff'),
+        ('\u2b30', '\u2b44'), ('\u2b47', '\u2b4c'), ('\ufb29', '\ufb29'),
+        ('\ufe62', '\ufe62'), ('\ufe64', '\ufe66'), ('\uff0b', '\uff0b'),
+        ('\uff1c', '\uff1e'), ('\uff5c', '\uff5c'), ('\uff5e', '\uff5e'),
+        ('\uffe2', '\uffe2'), ('\uffe9', '\uffec'), ('\U0001d6c1', '\U0001d6c1'),
+        ('\U0001d6db', '\U0001d6db'), ('\U0001d6fb', '\U0001d6fb'), ('\U0001d715', '\U0001d715'),
+        ('\U0001d735', '\U0001d735'), ('\U0001d74f', '\U0001d74f'), ('\U0001d76f', '\U0001d76f'),
+        ('\U0001d789', '\U0001d789'), ('\U0001d7a9', '\U0001d7a9'), ('\U0001d7c3', '\U0001d7c3'),
+        ('\U0001eef0', '\U0001eef1')
+    ]
+}
+
+fn category_so() -> ~[(char, char)] {
+    ~[
+        ('\u00a6', '\u00a6'), ('\u00a9', '\u00a9'), ('\u00ae', '\u00ae'),
+        ('\u00b0', '\u00b0'), ('\u0482', '\u0482'), ('\u058d', '\u058e'),
+        ('\u060e', '\u060f'), ('\u06de', '\u06de'), ('\u06e9', '\u06e9'),
+        ('\u06fd', '\u06fe'), ('\u07f6', '\u07f6'), ('\u09fa', '\u09fa'),
+        ('\u0b70', '\u0b70'), ('\u0bf3', '\u0bf8'), ('\u0bfa', '\u0bfa'),
+        ('\u0c7f', '\u0c7f'), ('\u0d4f', '\u0d4f'), ('\u0d79', '\u0d79'),
+        ('\u0f01', '\u0f03'), ('\u0f13', '\u0f13'), ('\u0f15', '\u0f17'),
+        ('\u0f1a', '\u0f1f'), ('\u0f34', '\u0f34'), ('\u0f36', '\u0f36'),
+        ('\u0f38', '\u0f38'), ('\u0fbe', '\u0fc5'), ('\u0fc7', '\u0fcc'),
+        ('\u0fce', '\u0fcf'), ('\u0fd5', '\u0fd8'), ('\u109e', '\u109f'),
+        ('\u1390', '\u1399'), ('\u166d', '\u166d'), ('\u1940', '\u1940'),
+        ('\u19de', '\u19ff'), ('\u1b61', '\u1b6a'), ('\u1b74', '\u1b7c'),
+        ('\u2100', '\u2101'), ('\u2103', '\u2106'), ('\u2108', '\u2109'),
+        ('\u2114', '\u2114'), ('\u2116', '\u2117'), ('\u211e', '\u2123'),
+        ('\u2125', '\u2125'), ('\u2127', '\u2127'), ('\u2129', '\u2129'),
+        ('\u212e', '\u212e'), ('\u213a', '\u213b'), ('\u214a', '\u214a'),
+        ('\u214c', '\u214d'), ('\u214f', '\u214f'), ('\u218a', '\u218b'),
+        ('\u2195', '\u2199'), ('\u219c', '\u219f'), ('\u21a1', '\u21a2'),
+        ('\u21a4', '\u21a5'), ('\u21a7', '\u21ad'), ('\u21af', '\u21cd'),
+        ('\u21d0', '\u21d1'), ('\u21d3', '\u21d3'), ('\u21d5', '\u21f3'),
+        ('\u2300', '\u2307'), ('\u230c', '\u231f'), ('\u2322', '\u2328'),
+        ('\u232b', '\u237b'), ('\u237d', '\u239a'), ('\u23b4', '\u23db'),
+        ('\u23e2', '\u2426'), ('\u2440', '\u244a'), ('\u249c', '\u24e9'),
+        ('\u2500', '\u25b6'), ('\u25b8', '\u25c0'), ('\u25c2', '\u25f7'),
+        ('\u2600', '\u266e'), ('\u2670', '\u2767'), ('\u2794', '\u27bf'),
+        ('\u2800', '\u28ff'), ('\u2b00', '\u2b2f'), ('\u2b45', '\u2b46'),
+        ('\u2b4d', '\u2b73'), ('\u2b76', '\u2b95'), ('\u2b97', '\u2bff'),
+        ('\u2ce5', '\u2cea'), ('\u2e50', '\u2e51'), ('\u2e80', '\u2e99'),
+        ('\u2e9b', '\u2ef3'), ('\u2f00', '\u2fd5'), ('\u2ff0', '\u2ffb'),
+        ('\u3004', '\u3004'), ('\u3012', '\u3013'), ('\u3020', '\u3020'),
+        ('\u3036', '\u3037'), ('\u303e', '\u303f'), ('\u3190', '\u3191'),
+        ('\u3196', '\u319f'), ('\u31c0', '\u31e3'), ('\u3200', '\u321e'),
+        ('\u322a', '\u3247'), ('\u3250', '\u3250'), ('\u3260', '\u327f'),
+        ('\u328a', '\u32b0'), ('\u32c0', '\u33ff'), ('\u4dc0', '\u4dff'),
+        ('\ua490', '\ua4c6'), ('\ua828', '\ua82b'), ('\ua836', '\ua837'),
+        ('\ua839', '\ua839'), ('\uaa77', '\uaa79'), ('\ufd40', '\ufd4f'),
+        ('\ufdcf', '\ufdcf'), ('\ufdfd', '\ufdff'), ('\uffe4', '\uffe4'),
+        ('\uffe8', '\uffe8'), ('\uffed', '\uffee'), ('\ufffc', '\ufffd'),
+        ('\U00010137', '\U0001013f'), ('\U00010179', '\U00010189'), ('\U0001018c', '\U0001018e'),
+        ('\U00010190', '\U0001019c'), ('\U000101a0', '\U000101a0'), ('\U000101d0', '\U000101fc'),
+        ('\U00010877', '\U00010878'), ('\U00010ac8', '\U00010ac8'), ('\U0001173f', '\U0001173f'),
+        ('\U00011fd5', '\U00011fdc'), ('\U00011fe1', '\U00011ff1'), ('\U00016b3c', '\U00016b3f'),
+        ('\U00016b45', '\U00016b45'), ('\U0001bc9c', '\U0001bc9c'), ('\U0001cf50', '\U0001cfc3'),
+        ('\U0001d000', '\U0001d0f5'), ('\U0001d100', '\U0001d126'), ('\U0001d129', '\U0001d164'),
+        ('\U0001d16a', '\U0001d16c'), ('\U0001d183', '\U0001d184'), ('\U0001d18c', '\U0001d1a9'),
+        ('\U0001d1ae', '\U0001d1ea'), ('\U0001d200', '\U0001d241'), ('\U0001d245', '\U0001d245'),
+        ('\U0001d300', '\U0001d356'), ('\U0001d800', '\U0001d9ff'), ('\U0001da37', '\U0001da3a'),
+        ('\U0001da6d', '\U0001da74'), ('\U0001da76', '\U0001da83'), ('\U0001da85', '\U0001da86'),
+        ('\U0001e14f', '\U0001e14f'), ('\U0001ecac', '\U0001ecac'), ('\U0001ed2e', '\U0001ed2e'),
+        ('\U0001f000', '\U0001f02b'), ('\U0001f030', '\U0001f093'), ('\U0001f0a0', '\U0001f0ae'),
+        ('\U0001f0b1', '\U0001f0bf'), ('\U0001f0c1', '\U0001f0cf'), ('\U0001f0d1', '\U0001f0f5'),
+        ('\U0001f10d', '\U0001f1ad'), ('\U0001f1e6', '\U0001f202'), ('\U0001f210', '\U0001f23b'),
+        ('\U0001f240', '\U0001f248'), ('\U0001f250', '\U0001f251'), ('\U0001f260', '\U0001f265'),
+        ('\U0001f300', '\U0001f3fa'), ('\U0001f400', '\U0001f6d7'), ('\U0001f6dd', '\U0001f6ec'),
+        ('\U0001f6f0', '\U0001f6fc'), ('\U0001f700', '\U0001f773'), ('\U0001f780', '\U0001f7d8'),
+        ('\U0001f7e0', '\U0001f7eb'), ('\U0001f7f0', '\U0001f7f0'), ('\U0001f800', '\U0001f80b'),
+        ('\U0001f810', '\U0001f847'), ('\U0001f850', '\U0001f859'), ('\U0001f860', '\U0001f887'),
+        ('\U0001f890', '\U0001f8ad'), ('\U0001f8b0', '\U0001f8b1'), ('\U0001f900', '\U0001fa53'),
+        ('\U0001fa60', '\U0001fa6d'), ('\U0001fa70', '\U0001fa74'), ('\U0001fa78', '\U0001fa7c'),
+        ('\U0001fa80', '\U0001fa86'), ('\U0001fa90', '\U0001faac'), ('\U0001fab0', '\U0001faba'),
+        ('\U0001fac0', '\U0001fac5'), ('\U0001fad0', '\U0001fad9'), ('\U0001fae0', '\U0001fae7'),
+        ('\U0001faf0', '\U0001faf6'), ('\U0001fb00', '\U0001fb92'), ('\U0001fb94', '\U0001fbca')
+    ]
+}
+
+fn category_z() -> ~[(char, char)] {
+    ~[
+        ('\u0020', '\u0020'), ('\u00a0', '\u00a0'), ('\u1680', '\u1680'),
+        ('\u2000', '\u200a'), ('\u2028', '\u2029'), ('\u202f', '\u202f'),
+        ('\u205f', '\u205f'), ('\u3000', '\u3000')
+    ]
+}
+
+fn category_zl() -> ~[(char, char)] {
+    ~[
+        ('\u2028', '\u2028')
+    ]
+}
+
+fn category_zp() -> ~[(char, char)] {
+    ~[
+        ('\u2029', '\u2029')
+    ]
+}
+
+fn category_zs() -> ~[(char, char)] {
+    ~[
+        ('\u0020', '\u0020'), ('\u00a0', '\u00a0'), ('\u1680', '\u1680'),
+        ('\u2000', '\u200a'), ('\u202f', '\u202f'), ('\u205f', '\u205f'),
+        ('\u3000', '\u3000')
+    ]
+}
+
+fn script_adlam() -> ~[(char, char)] {
+    ~[
+        ('\U0001e900', '\U0001e94b'), ('\U0001e950', '\U0001e959'), ('\U0001e95e', '\U0001e95f')
+    ]
+}
+
+fn script_caucasian_albanian() -> ~[(char, char)] {
+    ~[
+        ('\U00010530', '\U00010563'), ('\U0001056f', '\U0001056f')
+    ]
+}
+
+fn script_ahom() -> ~[(char, char)] {
+    ~[
+        ('\U00011700', '\U0001171a'), ('\U0001171d', '\U0001172b'), ('\U00011730', '\U00011746')
+    ]
+}
+
+fn script_arabic() -> ~[(char, char)] {
+    ~[
+        ('\u0600', '\u0604'), ('\u0606', '\u060b'), ('\u060d', '\u061a'),
+        ('\u061c', '\u061e'), ('\u0620', '\u063f'), ('\u0641', '\u064a'),
+        ('\u0656', '\u066f'), ('\u0671', '\u06dc'), ('\u06de', '\u06ff'),
+        ('\u0750', '\u077f'), ('\u0870', '\u088e'), ('\u0890', '\u0891'),
+        ('\u0898', '\u08e1'), ('\u08e3', '\u08ff'), ('\ufb50', '\ufbc2'),
+        ('\ufbd3', '\ufd3d'), ('\ufd40', '\ufd8f'), ('\ufd92', '\ufdc7'),
+        ('\ufdcf', '\ufdcf'), ('\ufdf0', '\ufdff'), ('\ufe70', '\ufe74'),
+        ('\ufe76', '\ufefc'), ('\U00010e60', '\U00010e7e'), ('\U0001ee00', '\U0001ee03'),
+        ('\U0001ee05', '\U0001ee1f'), ('\U0001ee21', '\U0001ee22'), ('\U0001ee24', '\U0001ee24'),
+        ('\U0001ee27', '\U0001ee27'), ('\U0001ee29', '\U0001ee32'), ('\U0001ee34', '\U0001ee37'),
+        ('\U0001ee39', '\U0001ee39'), ('\U0001ee3b', '\U0001ee3b'), ('\U0001ee42', '\U0001ee42'),
+        ('\U0001ee47', '\U0001ee47'), ('\U0001ee49', '\U0001ee49'), ('\U0001ee4b', '\U0001ee4b'),
+        ('\U0001ee4d', '\U0001ee4f'), ('\U0001ee51', '\U0001ee52'), ('\U0001ee54', '\U0001ee54'),
+        ('\U0001ee57', '\U0001ee57'), ('\U0001ee59', '\U0001ee59'), ('\U0001ee5b', '\U0001ee5b'),
+        ('\U0001ee5d', '\U0001ee5d'), ('\U0001ee5f', '\U0001ee5f'), ('\U0001ee61', '\U0001ee62'),
+        ('\U0001ee64', '\U0001ee64'), ('\U0001ee67', '\U0001ee6a'), ('\U0001ee6c', '\U0001ee72'),
+        ('\U0001ee74', '\U0001ee77'), ('\U0001ee79', '\U0001ee7c'), ('\U0001ee7e', '\U0001ee7e'),
+        ('\U0001ee80', '\U0001ee89'), ('\U0001ee8b', '\U0001ee9b'), ('\U0001eea1', '\U0001eea3'),
+        ('\U0001eea5', '\U0001eea9'), ('\U0001eeab', '\U0001eebb'), ('\U0001eef0', '\U0001eef1')
+    ]
+}
+
+fn script_imperial_aramaic() -> ~[(char, char)] {
+    ~[
+        ('\U00010840', '\U00010855'), ('\U00010857', '\U0001085f')
+    ]
+}
+
+fn script_armenian() -> ~[(char, char)] {
+    ~[
+        ('\u0531', '\u0556'), ('\u0559', '\u058a'), ('\u058d', '\u058f'),
+        ('\ufb13', '\ufb17')
+    ]
+}
+
+fn script_avestan() -> ~[(char, char)] {
+    ~[
+        ('\U00010b00', '\U00010b35'), ('\U00010b39', '\U00010b3f')
+    ]
+}
+
+fn script_balinese() -> ~[(char, char)] {
+    ~[
+        ('\u1b00', '\u1b4c'), ('\u1b50', '\u1b7e')
+    ]
+}
+
+fn script_bamum() -> ~[(char, char)] {
+    ~[
+        ('\ua6a0', '\ua6f7'), ('\U00016800', '\U00016a38')
+    ]
+}
+
+fn script_bassa_vah() -> ~[(char, char)] {
+    ~[
+        ('\U00016ad0', '\U00016aed'), ('\U00016af0', '\U00016af5')
+    ]
+}
+
+fn script_batak() -> ~[(char, char)] {
+    ~[
+        ('\u1bc0', '\u1bf3'), ('\u1bfc', '\u1bff')
+    ]
+}
+
+fn script_bengali() -> ~[(char, char)] {
+    ~[
+        ('\u0980', '\u0983'), ('\u0985', '\u098c'), ('\u098f', '\u0990'),
+        ('\u0993', '\u09a8'), ('\u09aa', '\u09b0'), ('\u09b2', '\u09b2'),
+        ('\u09b6', '\u09b9'), ('\u09bc', '\u09c4'), ('\u09c7', '\u09c8'),
+        ('\u09cb', '\u09ce'), ('\u09d7', '\u09d7'), ('\u09dc', '\u09dd'),
+        ('\u09df', '\u09e3'), ('\u09e6', '\u09fe')
+    ]
+}
+
+fn script_bhaiksuki() -> ~[(char, char)] {
+    ~[
+        ('\U00011c00', '\U00011c08'), ('\U00011c0a', '\U00011c36'), ('\U00011c38', '\U00011c45'),
+        ('\U00011c50', '\U00011c6c')
+    ]
+}
+
+fn script_bopomofo() -> ~[(char, char)] {
+    ~[
+        ('\u02ea', '\u02eb'), ('\u3105', '\u312f'), ('\u31a0', '\u31bf')
+    ]
+}
+
+fn script_brahmi() -> ~[(char, char)] {
+    ~[
+        ('\U00011000', '\U0001104d'), ('\U00011052', '\U00011075'), ('\U0001107f', '\U0001107f')
+    ]
+}
+
+fn script_braille() -> ~[(char, char)] {
+    ~[
+        ('\u2800', '\u28ff')
+    ]
+}
+
+fn script_buginese() -> ~[(char, char)] {
+    ~[
+        ('\u1a00', '\u1a1b'), ('\u1a1e', '\u1a1f')
+    ]
+}
+
+fn script_buhid() -> ~[(char, char)] {
+    ~[
+        ('\u1740', '\u1753')
+    ]
+}
+
+fn script_chakma() -> ~[(char, char)] {
+    ~[
+        ('\U00011100', '\U00011134'), ('\U00011136', '\U00011147')
+    ]
+}
+
+fn script_canadian_aboriginal() -> ~[(char, char)] {
+    ~[
+        ('\u1400', '\u167f'), ('\u18b0', '\u18f5'), ('\U00011ab0', '\U00011abf')
+    ]
+}
+
+fn script_carian() -> ~[(char, char)] {
+    ~[
+        ('\U000102a0', '\U000102d0')
+    ]
+}
+
+fn script_cham() -> ~[(char, char)] {
+    ~[
+        ('\uaa00', '\uaa36'), ('\uaa40', '\uaa4d'), ('\uaa50', '\uaa59'),
+        ('\uaa5c', '\uaa5f')
+    ]
+}
+
+fn script_cherokee() -> ~[(char, char)] {
+    ~[
+        ('\u13a0', '\u13f5'), ('\u13f8', '\u13fd'), ('\uab70', '\uabbf')
+    ]
+}
+
+fn script_chorasmian() -> ~[(char, char)] {
+    ~[
+        ('\U00010fb0', '\U00010fcb')
+    ]
+}
+
+fn script_coptic() -> ~[(char, char)] {
+    ~[
+        ('\u03e2', '\u03ef'), ('\u2c80', '\u2cf3'), ('\u2cf9', '\u2cff')
+    ]
+}
+
+fn script_cypro_minoan() -> ~[(char, char)] {
+    ~[
+        ('\U00012f90', '\U00012ff2')
+    ]
+}
+
+fn script_cypriot() -> ~[(char, char)] {
+    ~[
+        ('\U00010800', '\U00010805'), ('\U00010808', '\U00010808'), ('\U0001080a', '\U00010835'),
+        ('\U00010837', '\U00010838'), ('\U0001083c', '\U0001083c'), ('\U0001083f', '\U0001083f')
+    ]
+}
+
+fn script_cyrillic() -> ~[(char, char)] {
+    ~[
+        ('\u0400', '\u0484'), ('\u0487', '\u052f'), ('\u1c80', '\u1c88'),
+        ('\u1d2b', '\u1d2b'), ('\u1d78', '\u1d78'), ('\u2de0', '\u2dff'),
+        ('\ua640', '\ua69f'), ('\ufe2e', '\ufe2f')
+    ]
+}
+
+fn script_devanagari() -> ~[(char, char)] {
+    ~[
+        ('\u0900', '\u0950'), ('\u0955', '\u0963'), ('\u0966', '\u097f'),
+        ('\ua8e0', '\ua8ff')
+    ]
+}
+
+fn script_dives_akuru() -> ~[(char, char)] {
+    ~[
+        ('\U00011900', '\U00011906'), ('\U00011909', '\U00011909'), ('\U0001190c', '\U00011913'),
+        ('\U00011915', '\U00011916'), ('\U00011918', '\U00011935'), ('\U00011937', '\U00011938'),
+        ('\U0001193b', '\U00011946'), ('\U00011950', '\U00011959')
+    ]
+}
+
+fn script_dogra() -> ~[(char, char)] {
+    ~[
+        ('\U00011800', '\U0001183b')
+    ]
+}
+
+fn script_deseret() -> ~[(char, char)] {
+    ~[
+        ('\U00010400', '\U0001044f')
+    ]
+}
+
+fn script_duployan() -> ~[(char, char)] {
+    ~[
+        ('\U0001bc00', '\U0001bc6a'), ('\U0001bc70', '\U0001bc7c'), ('\U0001bc80', '\U0001bc88'),
+        ('\U0001bc90', '\U0001bc99'), ('\U0001bc9c', '\U0001bc9f')
+    ]
+}
+
+fn script_egyptian_hieroglyphs() -> ~[(char, char)] {
+    ~[
+        ('\U00013000', '\U0001342e'), ('\U00013430', '\U00013438')
+    ]
+}
+
+fn script_elbasan() -> ~[(char, char)] {
+    ~[
+        ('\U00010500', '\U00010527')
+    ]
+}
+
+fn script_elymaic() -> ~[(char, char)] {
+    ~[
+        ('\U00010fe0', '\U00010ff6')
+    ]
+}
+
+fn script_ethiopic() -> ~[(char, char)] {
+    ~[
+        ('\u1200', '\u1248'), ('\u124a', '\u124d'), ('\u1250', '\u1256'),
+        ('\u1258', '\u1258'), ('\u125a', '\u125d'), ('\u1260', '\u1288'),
+        ('\u128a', '\u128d'), ('\u1290', '\u12b0'), ('\u12b2', '\u12b5'),
+        ('\u12b8', '\u12be'), ('\u12c0', '\u12c0'), ('\u12c2', '\u12c5'),
+        ('\u12c8', '\u12d6'), ('\u12d8', '\u1310'), ('\u1312', '\u1315'),
+        ('\u1318', '\u135a'), ('\u135d', '\u137c'), ('\u1380', '\u1399'),
+        ('\u2d80', '\u2d96'), ('\u2da0', '\u2da6'), ('\u2da8', '\u2dae'),
+        ('\u2db0', '\u2db6'), ('\u2db8', '\u2dbe'), ('\u2dc0', '\u2dc6'),
+        ('\u2dc8', '\u2dce'), ('\u2dd0', '\u2dd6'), ('\u2dd8', '\u2dde'),
+        ('\uab01', '\uab06'), ('\uab09', '\uab0e'), ('\uab11', '\uab16'),
+        ('\uab20', '\uab26'), ('\uab28', '\uab2e'), ('\U0001e7e0', '\U0001e7e6'),
+        ('\U0001e7e8', '\U0001e7eb'), ('\U0001e7ed', '\U0001e7ee'), ('\U0001e7f0', '\U0001e7fe')
+    ]
+}
+
+fn script_georgian() -> ~[(char, char)] {
+    ~[
+        ('\u10a0', '\u10c5'), ('\u10c7', '\u10c7'), ('\u10cd', '\u10cd'),
+        ('\u10d0', '\u10fa'), ('\u10fc', '\u10ff'), ('\u1c90', '\u1cba'),
+        ('\u1cbd', '\u1cbf'), ('\u2d00', '\u2d25'), ('\u2d27', '\u2d27'),
+        ('\u2d2d', '\u2d2d')
+    ]
+}
+
+fn script_glagolitic() -> ~[(char, char)] {
+    ~[
+        ('\u2c00', '\u2c5f'), ('\U0001e000', '\U0001e006'), ('\U0001e008', '\U0001e018'),
+        ('\U0001e01b', '\U0001e021'), ('\U0001e023', '\U0001e024'), ('\U0001e026', '\U0001e02a')
+    ]
+}
+
+fn script_gunjala_gondi() -> ~[(char, char)] {
+    ~[
+        ('\U00011d60', '\U00011d65'), ('\U00011d67', '\U00011d68'), ('\U00011d6a', '\U00011d8e'),
+        ('\U00011d90', '\U00011d91'), ('\U00011d93', '\U00011d98'), ('\U00011da0', '\U00011da9')
+    ]
+}
+
+fn script_masaram_gondi() -> ~[(char, char)] {
+    ~[
+        ('\U00011d00', '\U00011d06'), ('\U00011d08', '\U00011d09'), ('\U00011d0b', '\U00011d36'),
+        ('\U00011d3a', '\U00011d3a'), ('\U00011d3c', '\U00011d3d'), ('\U00011d3f', '\U00011d47'),
+        ('\U00011d50', '\U00011d59')
+    ]
+}
+
+fn script_gothic() -> ~[(char, char)] {
+    ~[
+        ('\U00010330', '\U0001034a')
+    ]
+}
+
+fn script_grantha() -> ~[(char, char)] {
+    ~[
+        ('\U00011300', '\U00011303'), ('\U00011305', '\U0001130c'), ('\U0001130f', '\U00011310'),
+        ('\U00011313', '\U00011328'), ('\U0001132a', '\U00011330'), ('\U00011332', '\U00011333'),
+        ('\U00011335', '\U00011339'), ('\U0001133c', '\U00011344'), ('\U00011347', '\U00011348'),
+        ('\U0001134b', '\U0001134d'), ('\U00011350', '\U00011350'), ('\U00011357', '\U00011357'),
+        ('\U0001135d', '\U00011363'), ('\U00011366', '\U0001136c'), ('\U00011370', '\U00011374')
+    ]
+}
+
+fn script_greek() -> ~[(char, char)] {
+    ~[
+        ('\u0370', '\u0373'), ('\u0375', '\u0377'), ('\u037a', '\u037d'),
+        ('\u037f', '\u037f'), ('\u0384', '\u0384'), ('\u0386', '\u0386'),
+        ('\u0388', '\u038a'), ('\u038c', '\u038c'), ('\u038e', '\u03a1'),
+        ('\u03a3', '\u03e1'), ('\u03f0', '\u03ff'), ('\u1d26', '\u1d2a'),
+        ('\u1d5d', '\u1d61'), ('\u1d66', '\u1d6a'), ('\u1dbf', '\u1dbf'),
+        ('\u1f00', '\u1f15'), ('\u1f18', '\u1f1d'), ('\u1f20', '\u1f45'),
+        ('\u1f48', '\u1f4d'), ('\u1f50', '\u1f57'), ('\u1f59', '\u1f59'),
+        ('\u1f5b', '\u1f5b'), ('\u1f5d', '\u1f5d'), ('\u1f5f', '\u1f7d'),
+        ('\u1f80', '\u1fb4'), ('\u1fb6', '\u1fc4'), ('\u1fc6', '\u1fd3'),
+        ('\u1fd6', '\u1fdb'), ('\u1fdd', '\u1fef'), ('\u1ff2', '\u1ff4'),
+        ('\u1ff6', '\u1ffe'), ('\u2126', '\u2126'), ('\uab65', '\uab65'),
+        ('\U00010140', '\U0001018e'), ('\U000101a0', '\U000101a0'), ('\U0001d200', '\U0001d245')
+    ]
+}
+
+fn script_gujarati() -> ~[(char, char)] {
+    ~[
+        ('\u0a81', '\u0a83'), ('\u0a85', '\u0a8d'), ('\u0a8f', '\u0a91'),
+        ('\u0a93', '\u0aa8'), ('\u0aaa', '\u0ab0'), ('\u0ab2', '\u0ab3'),
+        ('\u0ab5', '\u0ab9'), ('\u0abc', '\u0ac5'), ('\u0ac7', '\u0ac9'),
+        ('\u0acb', '\u0acd'), ('\u0ad0', '\u0ad0'), ('\u0ae0', '\u0ae3'),
+        ('\u0ae6', '\u0af1'), ('\u0af9', '\u0aff')
+    ]
+}
+
+fn script_gurmukhi() -> ~[(char, char)] {
+    ~[
+        ('\u0a01', '\u0a03'), ('\u0a05', '\u0a0a'), ('\u0a0f', '\u0a10'),
+        ('\u0a13', '\u0a28'), ('\u0a2a', '\u0a30'), ('\u0a32', '\u0a33'),
+        ('\u0a35', '\u0a36'), ('\u0a38', '\u0a39'), ('\u0a3c', '\u0a3c'),
+        ('\u0a3e', '\u0a42'), ('\u0a47', '\u0a48'), ('\u0a4b', '\u0a4d'),
+        ('\u0a51', '\u0a51'), ('\u0a59', '\u0a5c'), ('\u0a5e', '\u0a5e'),
+        ('\u0a66', '\u0a76')
+    ]
+}
+
+fn script_hangul() -> ~[(char, char)] {
+    ~[
+        ('\u1100', '\u11ff'), ('\u302e', '\u302f'), ('\u3131', '\u318e'),
+        ('\u3200', '\u321e'), ('\u3260', '\u327e'), ('\ua960', '\ua97c'),
+        ('\uac00', '\ud7a3'), ('\ud7b0', '\ud7c6'), ('\ud7cb', '\ud7fb'),
+        ('\uffa0', '\uffbe'), ('\uffc2', '\uffc7'), ('\uffca', '\uffcf'),
+        ('\uffd2', '\uffd7'), ('\uffda', '\uffdc')
+    ]
+}
+
+fn script_han() -> ~[(char, char)] {
+    ~[
+        ('\u2e80', '\u2e99'), ('\u2e9b', '\u2ef3'), ('\u2f00', '\u2fd5'),
+        ('\u3005', '\u3005'), ('\u3007', '\u3007'), ('\u3021', '\u3029'),
+        ('\u3038', '\u303b'), ('\u3400', '\u4dbf'), ('\u4e00', '\u9fff'),
+        ('\uf900', '\ufa6d'), ('\ufa70', '\ufad9'), ('\U00016fe2', '\U00016fe3'),
+        ('\U00016ff0', '\U00016ff1'), ('\U00020000', '\U0002a6df'), ('\U0002a700', '\U0002b738'),
+        ('\U0002b740', '\U0002b81d'), ('\U0002b820', '\U0002cea1'), ('\U0002ceb0', '\U0002ebe0'),
+        ('\U0002f800', '\U0002fa1d'), ('\U00030000', '\U0003134a')
+    ]
+}
+
+fn script_hanunoo() -> ~[(char, char)] {
+    ~[
+        ('\u1720', '\u1734')
+    ]
+}
+
+fn script_hatran() -> ~[(char, char)] {
+    ~[
+        ('\U000108e0', '\U000108f2'), ('\U000108f4', '\U000108f5'), ('\U000108fb', '\U000108ff')
+    ]
+}
+
+fn script_hebrew() -> ~[(char, char)] {
+    ~[
+        ('\u0591', '\u05c7'), ('\u05d0', '\u05ea'), ('\u05ef', '\u05f4'),
+        ('\ufb1d', '\ufb36'), ('\ufb38', '\ufb3c'), ('\ufb3e', '\ufb3e'),
+        ('\ufb40', '\ufb41'), ('\ufb43', '\ufb44'), ('\ufb46', '\ufb4f')
+    ]
+}
+
+fn script_hiragana() -> ~[(char, char)] {
+    ~[
+        ('\u3041', '\u3096'), ('\u309d', '\u309f'), ('\U0001b001', '\U0001b11f'),
+        ('\U0001b150', '\U0001b152'), ('\U0001f200', '\U0001f200')
+    ]
+}
+
+fn script_anatolian_hieroglyphs() -> ~[(char, char)] {
+    ~[
+        ('\U00014400', '\U00014646')
+    ]
+}
+
+fn script_pahawh_hmong() -> ~[(char, char)] {
+    ~[
+        ('\U00016b00', '\U00016b45'), ('\U00016b50', '\U00016b59'), ('\U00016b5b', '\U00016b61'),
+        ('\U00016b63', '\U00016b77'), ('\U00016b7d', '\U00016b8f')
+    ]
+}
+
+fn script_nyiakeng_puachue_hmong() -> ~[(char, char)] {
+    ~[
+        ('\U0001e100', '\U0001e12c'), ('\U0001e130', '\U0001e13d'), ('\U0001e140', '\U0001e149'),
+        ('\U0001e14e', '\U0001e14f')
+    ]
+}
+
+fn script_old_hungarian() -> ~[(char, char)] {
+    ~[
+        ('\U00010c80', '\U00010cb2'), ('\U00010cc0', '\U00010cf2'), ('\U00010cfa', '\U00010cff')
+    ]
+}
+
+fn script_old_italic() -> ~[(char, char)] {
+    ~[
+        ('\U00010300', '\U00010323'), ('\U0001032d', '\U0001032f')
+    ]
+}
+
+fn script_javanese() -> ~[(char, char)] {
+    ~[
+        ('\ua980', '\ua9cd'), ('\ua9d0', '\ua9d9'), ('\ua9de', '\ua9df')
+    ]
+}
+
+fn script_kayah_li() -> ~[(char, char)] {
+    ~[
+        ('\ua900', '\ua92d'), ('\ua92f', '\ua92f')
+    ]
+}
+
+fn script_katakana() -> ~[(char, char)] {
+    ~[
+        ('\u30a1', '\u30fa'), ('\u30fd', '\u30ff'), ('\u31f0', '\u31ff'),
+        ('\u32d0', '\u32fe'), ('\u3300', '\u3357'), ('\uff66', '\uff6f'),
+        ('\uff71', '\uff9d'), ('\U0001aff0', '\U0001aff3'), ('\U0001aff5', '\U0001affb'),
+        ('\U0001affd', '\U0001affe'), ('\U0001b000', '\U0001b000'), ('\U0001b120', '\U0001b122'),
+        ('\U0001b164', '\U0001b167')
+    ]
+}
+
+fn script_kharoshthi() -> ~[(char, char)] {
+    ~[
+        ('\U00010a00', '\U00010a03'), ('\U00010a05', '\U00010a06'), ('\U00010a0c', '\U00010a13'),
+        ('\U00010a15', '\U00010a17'), ('\U00010a19', '\U00010a35'), ('\U00010a38', '\U00010a3a'),
+        ('\U00010a3f', '\U00010a48'), ('\U00010a50', '\U00010a58')
+    ]
+}
+
+fn script_khmer() -> ~[(char, char)] {
+    ~[
+        ('\u1780', '\u17dd'), ('\u17e0', '\u17e9'), ('\u17f0', '\u17f9'),
+        ('\u19e0', '\u19ff')
+    ]
+}
+
+fn script_khojki() -> ~[(char, char)] {
+    ~[
+        ('\U00011200', '\U00011211'), ('\U00011213', '\U0001123e')
+    ]
+}
+
+fn script_khitan_small_script() -> ~[(char, char)] {
+    ~[
+        ('\U00016fe4', '\U00016fe4'), ('\U00018b00', '\U00018cd5')
+    ]
+}
+
+fn script_kannada() -> ~[(char, char)] {
+    ~[
+        ('\u0c80', '\u0c8c'), ('\u0c8e', '\u0c90'), ('\u0c92', '\u0ca8'),
+        ('\u0caa', '\u0cb3'), ('\u0cb5', '\u0cb9'), ('\u0cbc', '\u0cc4'),
+        ('\u0cc6', '\u0cc8'), ('\u0cca', '\u0ccd'), ('\u0cd5', '\u0cd6'),
+        ('\u0cdd', '\u0cde'), ('\u0ce0', '\u0ce3'), ('\u0ce6', '\u0cef'),
+        ('\u0cf1', '\u0cf2')
+    ]
+}
+
+fn script_kaithi() -> ~[(char, char)] {
+    ~[
+        ('\U00011080', '\U000110c2'), ('\U000110cd', '\U000110cd')
+    ]
+}
+
+fn script_tai_tham() -> ~[(char, char)] {
+    ~[
+        ('\u1a20', '\u1a5e'), ('\u1a60', '\u1a7c'), ('\u1a7f', '\u1a89'),
+        ('\u1a90', '\u1a99'), ('\u1aa0', '\u1aad')
+    ]
+}
+
+fn script_lao() -> ~[(char, char)] {
+    ~[
+        ('\u0e81', '\u0e82'), ('\u0e84', '\u0e84'), ('\u0e86', '\u0e8a'),
+        ('\u0e8c', '\u0ea3'), ('\u0ea5', '\u0ea5'), ('\u0ea7', '\u0ebd'),
+        ('\u0ec0', '\u0ec4'), ('\u0ec6', '\u0ec6'), ('\u0ec8', '\u0ecd'),
+        ('\u0ed0', '\u0ed9'), ('\u0edc', '\u0edf')
+    ]
+}
+
+fn script_latin() -> ~[(char, char)] {
+    ~[
+        ('\u0041', '\u005a'), ('\u0061', '\u007a'), ('\u00aa', '\u00aa'),
+        ('\u00ba', '\u00ba'), ('\u00c0', '\u00d6'), ('\u00d8', '\u00f6'),
+        ('\u00f8', '\u02b8'), ('\u02e0', '\u02e4'), ('\u1d00', '\u1d25'),
+        ('\u1d2c', '\u1d5c'), ('\u1d62', '\u1d65'), ('\u1d6b', '\u1d77'),
+        ('\u1d79', '\u1dbe'), ('\u1e00', '\u1eff'), ('\u2071', '\u2071'),
+        ('\u207f', '\u207f'), ('\u2090', '\u209c'), ('\u212a', '\u212b'),
+        ('\u2132', '\u2132'), ('\u214e', '\u214e'), ('\u2160', '\u2188'),
+        ('\u2c60', '\u2c7f'), ('\ua722', '\ua787'), ('\ua78b', '\ua7ca'),
+        ('\ua7d0', '\ua7d1'), ('\ua7d3', '\ua7d3'), ('\ua7d5', '\ua7d9'),
+        ('\ua7f2', '\ua7ff'), ('\uab30', '\uab5a'), ('\uab5c', '\uab64'),
+        ('\uab66', '\uab69'), ('\ufb00', '\ufb06'), ('\uff21', '\uff3a'),
+        ('\uff41', '\uff5a'), ('\U00010780', '\U00010785'), ('\U00010787', '\U000107b0'),
+        ('\U000107b2', '\U000107ba'), ('\U0001df00', '\U0001df1e')
+    ]
+}
+
+fn script_lepcha() -> ~[(char, char)] {
+    ~[
+        ('\u1c00', '\u1c37'), ('\u1c3b', '\u1c49'), ('\u1c4d', '\u1c4f')
+    ]
+}
+
+fn script_limbu() -> ~[(char, char)] {
+    ~[
+        ('\u1900', '\u191e'), ('\u1920', '\u192b'), ('\u1930', '\u193b'),
+        ('\u1940', '\u1940'), ('\u1944', '\u194f')
+    ]
+}
+
+fn script_linear_a() -> ~[(char, char)] {
+    ~[
+        ('\U00010600', '\U00010736'), ('\U00010740', '\U00010755'), ('\U00010760', '\U00010767')
+    ]
+}
+
+fn script_linear_b() -> ~[(char, char)] {
+    ~[
+        ('\U00010000', '\U0001000b'), ('\U0001000d', '\U00010026'), ('\U00010028', '\U0001003a'),
+        ('\U0001003c', '\U0001003d'), ('\U0001003f', '\U0001004d'), ('\U00010050', '\U0001005d'),
+        ('\U00010080', '\U000100fa')
+    ]
+}
+
+fn script_lisu() -> ~[(char, char)] {
+    ~[
+        ('\ua4d0', '\ua4ff'), ('\U00011fb0', '\U00011fb0')
+    ]
+}
+
+fn script_lycian() -> ~[(char, char)] {
+    ~[
+        ('\U00010280', '\U0001029c')
+    ]
+}
+
+fn script_lydian() -> ~[(char, char)] {
+    ~[
+        ('\U00010920', '\U00010939'), ('\U0001093f', '\U0001093f')
+    ]
+}
+
+fn script_mahajani() -> ~[(char, char)] {
+    ~[
+        ('\U00011150', '\U00011176')
+    ]
+}
+
+fn script_makasar() -> ~[(char, char)] {
+    ~[
+        ('\U00011ee0', '\U00011ef8')
+    ]
+}
+
+fn script_mandaic() -> ~[(char, char)] {
+    ~[
+        ('\u0840', '\u085b'), ('\u085e', '\u085e')
+    ]
+}
+
+fn script_manichaean() -> ~[(char, char)] {
+    ~[
+        ('\U00010ac0', '\U00010ae6'), ('\U00010aeb', '\U00010af6')
+    ]
+}
+
+fn script_marchen() -> ~[(char, char)] {
+    ~[
+        ('\U00011c70', '\U00011c8f'), ('\U00011c92', '\U00011ca7'), ('\U00011ca9', '\U00011cb6')
+    ]
+}
+
+fn script_medefaidrin() -> ~[(char, char)] {
+    ~[
+        ('\U00016e40', '\U00016e9a')
+    ]
+}
+
+fn script_mende_kikakui() -> ~[(char, char)] {
+    ~[
+        ('\U0001e800', '\U0001e8c4'), ('\U0001e8c7', '\U0001e8d6')
+    ]
+}
+
+fn script_meroitic_cursive() -> ~[(char, char)] {
+    ~[
+        ('\U000109a0', '\U000109b7'), ('\U000109bc', '\U000109cf'), ('\U000109d2', '\U000109ff')
+    ]
+}
+
+fn script_meroitic_hieroglyphs() -> ~[(char, char)] {
+    ~[
+        ('\U00010980', '\U0001099f')
+    ]
+}
+
+fn script_malayalam() -> ~[(char, char)] {
+    ~[
+        ('\u0d00', '\u0d0c'), ('\u0d0e', '\u0d10'), ('\u0d12', '\u0d44'),
+        ('\u0d46', '\u0d48'), ('\u0d4a', '\u0d4f'), ('\u0d54', '\u0d63'),
+        ('\u0d66', '\u0d7f')
+    ]
+}
+
+fn script_modi() -> ~[(char, char)] {
+    ~[
+        ('\U00011600', '\U00011644'), ('\U00011650', '\U00011659')
+    ]
+}
+
+fn script_mongolian() -> ~[(char, char)] {
+    ~[
+        ('\u1800', '\u1801'), ('\u1804', '\u1804'), ('\u1806', '\u1819'),
+        ('\u1820', '\u1878'), ('\u1880', '\u18aa'), ('\U00011660', '\U0001166c')
+    ]
+}
+
+fn script_mro() -> ~[(char, char)] {
+    ~[
+        ('\U00016a40', '\U00016a5e'), ('\U00016a60', '\U00016a69'), ('\U00016a6e', '\U00016a6f')
+    ]
+}
+
+fn script_meetei_mayek() -> ~[(char, char)] {
+    ~[
+        ('\uaae0', '\uaaf6'), ('\uabc0', '\uabed'), ('\uabf0', '\uabf9')
+    ]
+}
+
+fn script_multani() -> ~[(char, char)] {
+    ~[
+        ('\U00011280', '\U00011286'), ('\U00011288', '\U00011288'), ('\U0001128a', '\U0001128d'),
+        ('\U0001128f', '\U0001129d'), ('\U0001129f', '\U000112a9')
+    ]
+}
+
+fn script_myanmar() -> ~[(char, char)] {
+    ~[
+        ('\u1000', '\u109f'), ('\ua9e0', '\ua9fe'), ('\uaa60', '\uaa7f')
+    ]
+}
+
+fn script_nandinagari() -> ~[(char, char)] {
+    ~[
+        ('\U000119a0', '\U000119a7'), ('\U000119aa', '\U000119d7'), ('\U000119da', '\U000119e4')
+    ]
+}
+
+fn script_old_north_arabian() -> ~[(char, char)] {
+    ~[
+        ('\U00010a80', '\U00010a9f')
+    ]
+}
+
+fn script_nabataean() -> ~[(char, char)] {
+    ~[
+        ('\U00010880', '\U0001089e'), ('\U000108a7', '\U000108af')
+    ]
+}
+
+fn script_newa() -> ~[(char, char)] {
+    ~[
+        ('\U00011400', '\U0001145b'), ('\U0001145d', '\U00011461')
+    ]
+}
+
+fn script_nko() -> ~[(char, char)] {
+    ~[
+        ('\u07c0', '\u07fa'), ('\u07fd', '\u07ff')
+    ]
+}
+
+fn script_nushu() -> ~[(char, char)] {
+    ~[
+        ('\U00016fe1', '\U00016fe1'), ('\U0001b170', '\U0001b2fb')
+    ]
+}
+
+fn script_ogham() -> ~[(char, char)] {
+    ~[
+        ('\u1680', '\u169c')
+    ]
+}
+
+fn script_ol_chiki() -> ~[(char, char)] {
+    ~[
+        ('\u1c50', '\u1c7f')
+    ]
+}
+
+fn script_old_turkic() -> ~[(char, char)] {
+    ~[
+        ('\U00010c00', '\U00010c48')
+    ]
+}
+
+fn script_oriya() -> ~[(char, char)] {
+    ~[
+        ('\u0b01', '\u0b03'), ('\u0b05', '\u0b0c'), ('\u0b0f', '\u0b10'),
+        ('\u0b13', '\u0b28'), ('\u0b2a', '\u0b30'), ('\u0b32', '\u0b33'),
+        ('\u0b35', '\u0b39'), ('\u0b3c', '\u0b44'), ('\u0b47', '\u0b48'),
+        ('\u0b4b', '\u0b4d'), ('\u0b55', '\u0b57'), ('\u0b5c', '\u0b5d'),
+        ('\u0b5f', '\u0b63'), ('\u0b66', '\u0b77')
+    ]
+}
+
+fn script_osage() -> ~[(char, char)] {
+    ~[
+        ('\U000104b0', '\U000104d3'), ('\U000104d8', '\U000104fb')
+    ]
+}
+
+fn script_osmanya() -> ~[(char, char)] {
+    ~[
+        ('\U00010480', '\U0001049d'), ('\U000104a0', '\U000104a9')
+    ]
+}
+
+fn script_old_uyghur() -> ~[(char, char)] {
+    ~[
+        ('\U00010f70', '\U00010f89')
+    ]
+}
+
+fn script_palmyrene() -> ~[(char, char)] {
+    ~[
+        ('\U00010860', '\U0001087f')
+    ]
+}
+
+fn script_pau_cin_hau() -> ~[(char, char)] {
+    ~[
+        ('\U00011ac0', '\U00011af8')
+    ]
+}
+
+fn script_old_permic() -> ~[(char, char)] {
+    ~[
+        ('\U00010350', '\U0001037a')
+    ]
+}
+
+fn script_phags_pa() -> ~[(char, char)] {
+    ~[
+        ('\ua840', '\ua877')
+    ]
+}
+
+fn script_inscriptional_pahlavi() -> ~[(char, char)] {
+    ~[
+        ('\U00010b60', '\U00010b72'), ('\U00010b78', '\U00010b7f')
+    ]
+}
+
+fn script_psalter_pahlavi() -> ~[(char, char)] {
+    ~[
+        ('\U00010b80', '\U00010b91'), ('\U00010b99', '\U00010b9c'), ('\U00010ba9', '\U00010baf')
+    ]
+}
+
+fn script_phoenician() -> ~[(char, char)] {
+    ~[
+        ('\U00010900', '\U0001091b'), ('\U0001091f', '\U0001091f')
+    ]
+}
+
+fn script_miao() -> ~[(char, char)] {
+    ~[
+        ('\U00016f00', '\U00016f4a'), ('\U00016f4f', '\U00016f87'), ('\U00016f8f', '\U00016f9f')
+    ]
+}
+
+fn script_inscriptional_parthian() -> ~[(char, char)] {
+    ~[
+        ('\U00010b40', '\U00010b55'), ('\U00010b58', '\U00010b5f')
+    ]
+}
+
+fn script_rejang() -> ~[(char, char)] {
+    ~[
+        ('\ua930', '\ua953'), ('\ua95f', '\ua95f')
+    ]
+}
+
+fn script_hanifi_rohingya() -> ~[(char, char)] {
+    ~[
+        ('\U00010d00', '\U00010d27'), ('\U00010d30', '\U00010d39')
+    ]
+}
+
+fn script_runic() -> ~[(char, char)] {
+    ~[
+        ('\u16a0', '\u16ea'), ('\u16ee', '\u16f8')
+    ]
+}
+
+fn script_samaritan() -> ~[(char, char)] {
+    ~[
+        ('\u0800', '\u082d'), ('\u0830', '\u083e')
+    ]
+}
+
+fn script_old_south_arabian() -> ~[(char, char)] {
+    ~[
+        ('\U00010a60', '\U00010a7f')
+    ]
+}
+
+fn script_saurashtra() -> ~[(char, char)] {
+    ~[
+        ('\ua880', '\ua8c5'), ('\ua8ce', '\ua8d9')
+    ]
+}
+
+fn script_signwriting() -> ~[(char, char)] {
+    ~[
+        ('\U0001d800', '\U0001da8b'), ('\U0001da9b', '\U0001da9f'), ('\U0001daa1', '\U0001daaf')
+    ]
+}
+
+fn script_shavian() -> ~[(char, char)] {
+    ~[
+        ('\U00010450', '\U0001047f')
+    ]
+}
+
+fn script_sharada() -> ~[(char, char)] {
+    ~[
+        ('\U00011180', '\U000111df')
+    ]
+}
+
+fn script_siddham() -> ~[(char, char)] {
+    ~[
+        ('\U00011580', '\U000115b5'), ('\U000115b8', '\U000115dd')
+    ]
+}
+
+fn script_khudawadi() -> ~[(char, char)] {
+    ~[
+        ('\U000112b0', '\U000112ea'), ('\U000112f0', '\U000112f9')
+    ]
+}
+
+fn script_sinhala() -> ~[(char, char)] {
+    ~[
+        ('\u0d81', '\u0d83'), ('\u0d85', '\u0d96'), ('\u0d9a', '\u0db1'),
+        ('\u0db3', '\u0dbb'), ('\u0dbd', '\u0dbd'), ('\u0dc0', '\u0dc6'),
+        ('\u0dca', '\u0dca'), ('\u0dcf', '\u0dd4'), ('\u0dd6', '\u0dd6'),
+        ('\u0dd8', '\u0ddf'), ('\u0de6', '\u0def'), ('\u0df2', '\u0df4'),
+        ('\U000111e1', '\U000111f4')
+    ]
+}
+
+fn script_sogdian() -> ~[(char, char)] {
+    ~[
+        ('\U00010f30', '\U00010f59')
+    ]
+}
+
+fn script_old_sogdian() -> ~[(char, char)] {
+    ~[
+        ('\U00010f00', '\U00010f27')
+    ]
+}
+
+fn script_sora_sompeng() -> ~[(char, char)] {
+    ~[
+        ('\U000110d0', '\U000110e8'), ('\U000110f0', '\U000110f9')
+    ]
+}
+
+fn script_soyombo() -> ~[(char, char)] {
+    ~[
+        ('\U00011a50', '\U00011aa2')
+    ]
+}
+
+fn script_sundanese() -> ~[(char, char)] {
+    ~[
+        ('\u1b80', '\u1bbf'), ('\u1cc0', '\u1cc7')
+    ]
+}
+
+fn script_syloti_nagri() -> ~[(char, char)] {
+    ~[
+        ('\ua800', '\ua82c')
+    ]
+}
+
+fn script_syriac() -> ~[(char, char)] {
+    ~[
+        ('\u0700', '\u070d'), ('\u070f', '\u074a'), ('\u074d', '\u074f'),
+        ('\u0860', '\u086a')
+    ]
+}
+
+fn script_tagbanwa() -> ~[(char, char)] {
+    ~[
+        ('\u1760', '\u176c'), ('\u176e', '\u1770'), ('\u1772', '\u1773')
+    ]
+}
+
+fn script_takri() -> ~[(char, char)] {
+    ~[
+        ('\U00011680', '\U000116b9'), ('\U000116c0', '\U000116c9')
+    ]
+}
+
+fn script_tai_le() -> ~[(char, char)] {
+    ~[
+        ('\u1950', '\u196d'), ('\u1970', '\u1974')
+    ]
+}
+
+fn script_new_tai_lue() -> ~[(char, char)] {
+    ~[
+        ('\u1980', '\u19ab'), ('\u19b0', '\u19c9'), ('\u19d0', '\u19da'),
+        ('\u19de', '\u19df')
+    ]
+}
+
+fn script_tamil() -> ~[(char, char)] {
+    ~[
+        ('\u0b82', '\u0b83'), ('\u0b85', '\u0b8a'), ('\u0b8e', '\u0b90'),
+        ('\u0b92', '\u0b95'), ('\u0b99', '\u0b9a'), ('\u0b9c', '\u0b9c'),
+        ('\u0b9e', '\u0b9f'), ('\u0ba3', '\u0ba4'), ('\u0ba8', '\u0baa'),
+        ('\u0bae', '\u0bb9'), ('\u0bbe', '\u0bc2'), ('\u0bc6', '\u0bc8'),
+        ('\u0bca', '\u0bcd'), ('\u0bd0', '\u0bd0'), ('\u0bd7', '\u0bd7'),
+        ('\u0be6', '\u0bfa'), ('\U00011fc0', '\U00011ff1'), ('\U00011fff', '\U00011fff')
+    ]
+}
+
+fn script_tangut() -> ~[(char, char)] {
+    ~[
+        ('\U00016fe0', '\U00016fe0'), ('\U00017000', '\U000187f7'), ('\U00018800', '\U00018aff'),
+        ('\U00018d00', '\U00018d08')
+    ]
+}
+
+fn script_tai_viet() -> ~[(char, char)] {
+    ~[
+        ('\uaa80', '\uaac2'), ('\uaadb', '\uaadf')
+    ]
+}
+
+fn script_telugu() -> ~[(char, char)] {
+    ~[
+        ('\u0c00', '\u0c0c'), ('\u0c0e', '\u0c10'), ('\u0c12', '\u0c28'),
+        ('\u0c2a', '\u0c39'), ('\u0c3c', '\u0c44'), ('\u0c46', '\u0c48'),
+        ('\u0c4a', '\u0c4d'), ('\u0c55', '\u0c56'), ('\u0c58', '\u0c5a'),
+        ('\u0c5d', '\u0c5d'), ('\u0c60', '\u0c63'), ('\u0c66', '\u0c6f'),
+        ('\u0c77', '\u0c7f')
+    ]
+}
+
+fn script_tifinagh() -> ~[(char, char)] {
+    ~[
+        ('\u2d30', '\u2d67'), ('\u2d6f', '\u2d70'), ('\u2d7f', '\u2d7f')
+    ]
+}
+
+fn script_tagalog() -> ~[(char, char)] {
+    ~[
+        ('\u1700', '\u1715'), ('\u171f', '\u171f')
+    ]
+}
+
+fn script_thaana() -> ~[(char, char)] {
+    ~[
+        ('\u0780', '\u07b1')
+    ]
+}
+
+fn script_thai() -> ~[(char, char)] {
+    ~[
+        ('\u0e01', '\u0e3a'), ('\u0e40', '\u0e5b')
+    ]
+}
+
+fn script_tibetan() -> ~[(char, char)] {
+    ~[
+        ('\u0f00', '\u0f47'), ('\u0f49', '\u0f6c'), ('\u0f71', '\u0f97'),
+        ('\u0f99', '\u0fbc'), ('\u0fbe', '\u0fcc'), ('\u0fce', '\u0fd4'),
+        ('\u0fd9', '\u0fda')
+    ]
+}
+
+fn script_tirhuta() -> ~[(char, char)] {
+    ~[
+        ('\U00011480', '\U000114c7'), ('\U000114d0', '\U000114d9')
+    ]
+}
+
+fn script_tangsa() -> ~[(char, char)] {
+    ~[
+        ('\U00016a70', '\U00016abe'), ('\U00016ac0', '\U00016ac9')
+    ]
+}
+
+fn script_toto() -> ~[(char, char)] {
+    ~[
+        ('\U0001e290', '\U0001e2ae')
+    ]
+}
+
+fn script_ugaritic() -> ~[(char, char)] {
+    ~[
+        ('\U00010380', '\U0001039d'), ('\U0001039f', '\U0001039f')
+    ]
+}
+
+fn script_vai() -> ~[(char, char)] {
+    ~[
+        ('\ua500', '\ua62b')
+    ]
+}
+
+fn script_vithkuqi() -> ~[(char, char)] {
+    ~[
+        ('\U00010570', '\U0001057a'), ('\U0001057c', '\U0001058a'), ('\U0001058c', '\U00010592'),
+        ('\U00010594', '\U00010595'), ('\U00010597', '\U000105a1'), ('\U000105a3', '\U000105b1'),
+        ('\U000105b3', '\U000105b9'), ('\U000105bb', '\U000105bc')
+    ]
+}
+
+fn script_warang_citi() -> ~[(char, char)] {
+    ~[
+        ('\U000118a0', '\U000118f2'), ('\U000118ff', '\U000118ff')
+    ]
+}
+
+fn script_wancho() -> ~[(char, char)] {
+    ~[
+        ('\U0001e2c0', '\U0001e2f9'), ('\U0001e2ff', '\U0001e2ff')
+    ]
+}
+
+fn script_old_persian() -> ~[(char, char)] {
+    ~[
+        ('\U000103a0', '\U000103c3'), ('\U000103c8', '\U000103d5')
+    ]
+}
+
+fn script_cuneiform() -> ~[(char, char)] {
+    ~[
+        ('\U00012000', '\U00012399'), ('\U00012400', '\U0001246e'), ('\U00012470', '\U00012474'),
+        ('\U00012480', '\U00012543')
+    ]
+}
+
+fn script_yezidi() -> ~[(char, char)] {
+    ~[
+        ('\U00010e80', '\U00010ea9'), ('\U00010eab', '\U00010ead'), ('\U00010eb0', '\U00010eb1')
+    ]
+}
+
+fn script_yi() -> ~[(char, char)] {
+    ~[
+        ('\ua000', '\ua48c'), ('\ua490', '\ua4c6')
+    ]
+}
+
+fn script_zanabazar_square() -> ~[(char, char)] {
+    ~[
+        ('\U00011a00', '\U00011a47')
+    ]
+}
+
+fn script_inherited() -> ~[(char, char)] {
+    ~[
+        ('\u0300', '\u036f'), ('\u0485', '\u0486'), ('\u064b', '\u0655'),
+        ('\u0670', '\u0670'), ('\u0951', '\u0954'), ('\u1ab0', '\u1ace'),
+        ('\u1cd0', '\u1cd2'), ('\u1cd4', '\u1ce0'), ('\u1ce2', '\u1ce8'),
+        ('\u1ced', '\u1ced'), ('\u1cf4', '\u1cf4'), ('\u1cf8', '\u1cf9'),
+        ('\u1dc0', '\u1dff'), ('\u200c', '\u200d'), ('\u20d0', '\u20f0'),
+        ('\u302a', '\u302d'), ('\u3099', '\u309a'), ('\ufe00', '\ufe0f'),
+        ('\ufe20', '\ufe2d'), ('\U000101fd', '\U000101fd'), ('\U000102e0', '\U000102e0'),
+        ('\U0001133b', '\U0001133b'), ('\U0001cf00', '\U0001cf2d'), ('\U0001cf30', '\U0001cf46'),
+        ('\U0001d167', '\U0001d169'), ('\U0001d17b', '\U0001d182'), ('\U0001d185', '\U0001d18b'),
+        ('\U0001d1aa', '\U0001d1ad'), ('\U000e0100', '\U000e01ef')
+    ]
+}
+
+fn script_common() -> ~[(char, char)] {
+    ~[
+        ('\u0000', '\u0040'), ('\u005b', '\u0060'), ('\u007b', '\u00a9'),
+        ('\u00ab', '\u00b9'), ('\u00bb', '\u00bf'), ('\u00d7', '\u00d7'),
+        ('\u00f7', '\u00f7'), ('\u02b9', '\u02df'), ('\u02e5', '\u02e9'),
+        ('\u02ec', '\u02ff'), ('\u0374', '\u0374'), ('\u037e', '\u037e'),
+        ('\u0385', '\u0385'), ('\u0387', '\u0387'), ('\u0605', '\u0605'),
+        ('\u060c', '\u060c'), ('\u061b', '\u061b'), ('\u061f', '\u061f'),
+        ('\u0640', '\u0640'), ('\u06dd', '\u06dd'), ('\u08e2', '\u08e2'),
+        ('\u0964', '\u0965'), ('\u0e3f', '\u0e3f'), ('\u0fd5', '\u0fd8'),
+        ('\u10fb', '\u10fb'), ('\u16eb', '\u16ed'), ('\u1735', '\u1736'),
+        ('\u1802', '\u1803'), ('\u1805', '\u1805'), ('\u1cd3', '\u1cd3'),
+        ('\u1ce1', '\u1ce1'), ('\u1ce9', '\u1cec'), ('\u1cee', '\u1cf3'),
+        ('\u1cf5', '\u1cf7'), ('\u1cfa', '\u1cfa'), ('\u2000', '\u200b'),
+        ('\u200e', '\u2064'), ('\u2066', '\u2070'), ('\u2074', '\u207e'),
+        ('\u2080', '\u208e'), ('\u20a0', '\u20c0'), ('\u2100', '\u2125'),
+        ('\u2127', '\u2129'), ('\u212c', '\u2131'), ('\u2133', '\u214d'),
+        ('\u214f', '\u215f'), ('\u2189', '\u218b'), ('\u2190', '\u2426'),
+        ('\u2440', '\u244a'), ('\u2460', '\u27ff'), ('\u2900', '\u2b73'),
+        ('\u2b76', '\u2b95'), ('\u2b97', '\u2bff'), ('\u2e00', '\u2e5d'),
+        ('\u2ff0', '\u2ffb'), ('\u3000', '\u3004'), ('\u3006', '\u3006'),
+        ('\u3008', '\u3020'), ('\u3030', '\u3037'), ('\u303c', '\u303f'),
+        ('\u309b', '\u309c'), ('\u30a0', '\u30a0'), ('\u30fb', '\u30fc'),
+        ('\u3190', '\u319f'), ('\u31c0', '\u31e3'), ('\u3220', '\u325f'),
+        ('\u327f', '\u32cf'), ('\u32ff', '\u32ff'), ('\u3358', '\u33ff'),
+        ('\u4dc0', '\u4dff'), ('\ua700', '\ua721'), ('\ua788', '\ua78a'),
+        ('\ua830', '\ua839'), ('\ua92e', '\ua92e'), ('\ua9cf', '\ua9cf'),
+        ('\uab5b', '\uab5b'), ('\uab6a', '\uab6b'), ('\ufd3e', '\ufd3f'),
+        ('\ufe10', '\ufe19'), ('\ufe30', '\ufe52'), ('\ufe54', '\ufe66'),
+        ('\ufe68', '\ufe6b'), ('\ufeff', '\ufeff'), ('\uff01', '\uff20'),
+        ('\uff3b', '\uff40'), ('\uff5b', '\uff65'), ('\uff70', '\uff70'),
+        ('\uff9e', '\uff9f'), ('\uffe0', '\uffe6'), ('\uffe8', '\uffee'),
+        ('\ufff9', '\ufffd'), ('\U00010100', '\U00010102'), ('\U00010107', '\U00010133'),
+        ('\U00010137', '\U0001013f'), ('\U00010190', '\U0001019c'), ('\U000101d0', '\U000101fc'),
+        ('\U000102e1', '\U000102fb'), ('\U0001bca0', '\U0001bca3'), ('\U0001cf50', '\U0001cfc3'),
+        ('\U0001d000', '\U0001d0f5'), ('\U0001d100', '\U0001d126'), ('\U0001d129', '\U0001d166'),
+        ('\U0001d16a', '\U0001d17a'), ('\U0001d183', '\U0001d184'), ('\U0001d18c', '\U0001d1a9'),
+        ('\U0001d1ae', '\U0001d1ea'), ('\U0001d2e0', '\U0001d2f3'), ('\U0001d300', '\U0001d356'),
+        ('\U0001d360', '\U0001d378'), ('\U0001d400', '\U0001d454'), ('\U0001d456', '\U0001d49c'),
+        ('\U0001d49e', '\U0001d49f'), ('\U0001d4a2', '\U0001d4a2'), ('\U0001d4a5', '\U0001d4a6'),
+        ('\U0001d4a9', '\U0001d4ac'), ('\U0001d4ae', '\U0001d4b9'), ('\U0001d4bb', '\U0001d4bb'),
+        ('\U0001d4bd', '\U0001d4c3'), ('\U0001d4c5', '\U0001d505'), ('\U0001d507', '\U0001d50a'),
+        ('\U0001d50d', '\U0001d514'), ('\U0001d516', '\U0001d51c'), ('\U0001d51e', '\U0001d539'),
+        ('\U0001d53b', '\U0001d53e'), ('\U0001d540', '\U0001d544'), ('\U0001d546', '\U0001d546'),
+        ('\U0001d54a', '\U0001d550'), ('\U0001d552', '\U0001d6a5'), ('\U0001d6a8', '\U0001d7cb'),
+        ('\U0001d7ce', '\U0001d7ff'), ('\U0001ec71', '\U0001ecb4'), ('\U0001ed01', '\U0001ed3d'),
+        ('\U0001f000', '\U0001f02b'), ('\U0001f030', '\U0001f093'), ('\U0001f0a0', '\U0001f0ae'),
+        ('\U0001f0b1', '\U0001f0bf'), ('\U0001f0c1', '\U0001f0cf'), ('\U0001f0d1', '\U0001f0f5'),
+        ('\U0001f100', '\U0001f1ad'), ('\U0001f1e6', '\U0001f1ff'), ('\U0001f201', '\U0001f202'),
+        ('\U0001f210', '\U0001f23b'), ('\U0001f240', '\U0001f248'), ('\U0001f250', '\U0001f251'),
+        ('\U0001f260', '\U0001f265'), ('\U0001f300', '\U0001f6d7'), ('\U0001f6dd', '\U0001f6ec'),
+        ('\U0001f6f0', '\U0001f6fc'), ('\U0001f700', '\U0001f773'), ('\U0001f780', '\U0001f7d8'),
+        ('\U0001f7e0', '\U0001f7eb'), ('\U0001f7f0', '\U0001f7f0'), ('\U0001f800', '\U0001f80b'),
+        ('\U0001f810', '\U0001f847'), ('\U0001f850', '\U0001f859'), ('\U0001f860', '\U0001f887'),
+        ('\U0001f890', '\U0001f8ad'), ('\U0001f8b0', '\U0001f8b1'), ('\U0001f900', '\U0001fa53'),
+        ('\U0001fa60', '\U0001fa6d'), ('\U0001fa70', '\U0001fa74'), ('\U0001fa78', '\U0001fa7c'),
+        ('\U0001fa80', '\U0001fa86'), ('\U0001fa90', '\U0001faac'), ('\U0001fab0', '\U0001faba'),
+        ('\U0001fac0', '\U0001fac5'), ('\U0001fad0', '\U0001fad9'), ('\U0001fae0', '\U0001fae7'),
+        ('\U0001faf0', '\U0001faf6'), ('\U0001fb00', '\U0001fb92'), ('\U0001fb94', '\U0001fbca'),
+        ('\U0001fbf0', '\U0001fbf9'), ('\U000e0001', '\U000e0001'), ('\U000e0020', '\U000e007f')
+    ]
+}
+
+// Ranges of a general category, such as L or Lu, or of a script,
+// such as Greek.
+fn table(name: &str) -> Option<~[(char, char)]> {
+    match name {
+        "C" => Some(category_c()),
+        "Cc" => Some(category_cc()),
+        "Cf" => Some(category_cf()),
+        "Co" => Some(category_co()),
+        "L" => Some(category_l()),
+        "Ll" => Some(category_ll()),
+        "Lm" => Some(category_lm()),
+        "Lo" => Some(category_lo()),
+        "Lt" => Some(category_lt()),
+        "Lu" => Some(category_lu()),
+        "M" => Some(category_m()),
+        "Mc" => Some(category_mc()),
+        "Me" => Some(category_me()),
+        "Mn" => Some(category_mn()),
+        "N" => Some(category_n()),
+        "Nd" => Some(category_nd()),
+        "Nl" => Some(category_nl()),
+        "No" => Some(category_no()),
+        "P" => Some(category_p()),
+        "Pc" => Some(category_pc()),
+        "Pd" => Some(category_pd()),
+        "Pe" => Some(category_pe()),
+        "Pf" => Some(category_pf()),
+        "Pi" => Some(category_pi()),
+        "Po" => Some(category_po()),
+        "Ps" => Some(category_ps()),
+        "S" => Some(category_s()),
+        "Sc" => Some(category_sc()),
+        "Sk" => Some(category_sk()),
+        "Sm" => Some(category_sm()),
+        "So" => Some(category_so()),
+        "Z" => Some(category_z()),
+        "Zl" => Some(category_zl()),
+        "Zp" => Some(category_zp()),
+        "Zs" => Some(category_zs()),
+        "Adlam" => Some(script_adlam()),
+        "Caucasian_Albanian" => Some(script_caucasian_albanian()),
+        "Ahom" => Some(script_ahom()),
+        "Arabic" => Some(script_arabic()),
+        "Imperial_Aramaic" => Some(script_imperial_aramaic()),
+        "Armenian" => Some(script_armenian()),
+        "Avestan" => Some(script_avestan()),
+        "Balinese" => Some(script_balinese()),
+        "Bamum" => Some(script_bamum()),
+        "Bassa_Vah" => Some(script_bassa_vah()),
+        "Batak" => Some(script_batak()),
+        "Bengali" => Some(script_bengali()),
+        "Bhaiksuki" => Some(script_bhaiksuki()),
+        "Bopomofo" => Some(script_bopomofo()),
+        "Brahmi" => Some(script_brahmi()),
+        "Braille" => Some(script_braille()),
+        "Buginese" => Some(script_buginese()),
+        "Buhid" => Some(script_buhid()),
+        "Chakma" => Some(script_chakma()),
+        "Canadian_Aboriginal" => Some(script_canadian_aboriginal()),
+        "Carian" => Some(script_carian()),
+        "Cham" => Some(script_cham()),
+        "Cherokee" => Some(script_cherokee()),
+        "Chorasmian" => Some(script_chorasmian()),
+        "Coptic" => Some(script_coptic()),
+        "Cypro_Minoan" => Some(script_cypro_minoan()),
+        "Cypriot" => Some(script_cypriot()),
+        "Cyrillic" => Some(script_cyrillic()),
+        "Devanagari" => Some(script_devanagari()),
+        "Dives_Akuru" => Some(script_dives_akuru()),
+        "Dogra" => Some(script_dogra()),
+        "Deseret" => Some(script_deseret()),
+        "Duployan" => Some(script_duployan()),
+        "Egyptian_Hieroglyphs" => Some(script_egyptian_hieroglyphs()),
+        "Elbasan" => Some(script_elbasan()),
+        "Elymaic" => Some(script_elymaic()),
+        "Ethiopic" => Some(script_ethiopic()),
+        "Georgian" => Some(script_georgian()),
+        "Glagolitic" => Some(script_glagolitic()),
+        "Gunjala_Gondi" => Some(script_gunjala_gondi()),
+        "Masaram_Gondi" => Some(script_masaram_gondi()),
+        "Gothic" => Some(script_gothic()),
+        "Grantha" => Some(script_grantha()),
+        "Greek" => Some(script_greek()),
+        "Gujarati" => Some(script_gujarati()),
+        "Gurmukhi" => Some(script_gurmukhi()),
+        "Hangul" => Some(script_hangul()),
+        "Han" => Some(script_han()),
+        "Hanunoo" => Some(script_hanunoo()),
+        "Hatran" => Some(script_hatran()),
+        "Hebrew" => Some(script_hebrew()),
+        "Hiragana" => Some(script_hiragana()),
+        "Anatolian_Hieroglyphs" => Some(script_anatolian_hieroglyphs()),
+        "Pahawh_Hmong" => Some(script_pahawh_hmong()),
+        "Nyiakeng_Puachue_Hmong" => Some(script_nyiakeng_puachue_hmong()),
+        "Old_Hungarian" => Some(script_old_hungarian()),
+        "Old_Italic" => Some(script_old_italic()),
+        "Javanese" => Some(script_javanese()),
+        "Kayah_Li" => Some(script_kayah_li()),
+        "Katakana" => Some(script_katakana()),
+        "Kharoshthi" => Some(script_kharoshthi()),
+        "Khmer" => Some(script_khmer()),
+        "Khojki" => Some(script_khojki()),
+        "Khitan_Small_Script" => Some(script_khitan_small_script()),
+        "Kannada" => Some(script_kannada()),
+        "Kaithi" => Some(script_kaithi()),
+        "Tai_Tham" => Some(script_tai_tham()),
+        "Lao" => Some(script_lao()),
+        "Latin" => Some(script_latin()),
+        "Lepcha" => Some(script_lepcha()),
+        "Limbu" => Some(script_limbu()),
+        "Linear_A" => Some(script_linear_a()),
+        "Linear_B" => Some(script_linear_b()),
+        "Lisu" => Some(script_lisu()),
+        "Lycian" => Some(script_lycian()),
+        "Lydian" => Some(script_lydian()),
+        "Mahajani" => Some(script_mahajani()),
+        "Makasar" => Some(script_makasar()),
+        "Mandaic" => Some(script_mandaic()),
+        "Manichaean" => Some(script_manichaean()),
+        "Marchen" => Some(script_marchen()),
+        "Medefaidrin" => Some(script_medefaidrin()),
+        "Mende_Kikakui" => Some(script_mende_kikakui()),
+        "Meroitic_Cursive" => Some(script_meroitic_cursive()),
+        "Meroitic_Hieroglyphs" => Some(script_meroitic_hieroglyphs()),
+        "Malayalam" => Some(script_malayalam()),
+        "Modi" => Some(script_modi()),
+        "Mongolian" => Some(script_mongolian()),
+        "Mro" => Some(script_mro()),
+        "Meetei_Mayek" => Some(script_meetei_mayek()),
+        "Multani" => Some(script_multani()),
+        "Myanmar" => Some(script_myanmar()),
+        "Nandinagari" => Some(script_nandinagari()),
+        "Old_North_Arabian" => Some(script_old_north_arabian()),
+        "Nabataean" => Some(script_nabataean()),
+        "Newa" => Some(script_newa()),
+        "Nko" => Some(script_nko()),
+        "Nushu" => Some(script_nushu()),
+        "Ogham" => Some(script_ogham()),
+        "Ol_Chiki" => Some(script_ol_chiki()),
+        "Old_Turkic" => Some(script_old_turkic()),
+        "Oriya" => Some(script_oriya()),
+        "Osage" => Some(script_osage()),
+        "Osmanya" => Some(script_osmanya()),
+        "Old_Uyghur" => Some(script_old_uyghur()),
+        "Palmyrene" => Some(script_palmyrene()),
+        "Pau_Cin_Hau" => Some(script_pau_cin_hau()),
+        "Old_Permic" => Some(script_old_permic()),
+        "Phags_Pa" => Some(script_phags_pa()),
+        "Inscriptional_Pahlavi" => Some(script_inscriptional_pahlavi()),
+        "Psalter_Pahlavi" => Some(script_psalter_pahlavi()),
+        "Phoenician" => Some(script_phoenician()),
+        "Miao" => Some(script_miao()),
+        "Inscriptional_Parthian" => Some(script_inscriptional_parthian()),
+        "Rejang" => Some(script_rejang()),
+        "Hanifi_Rohingya" => Some(script_hanifi_rohingya()),
+        "Runic" => Some(script_runic()),
+        "Samaritan" => Some(script_samaritan()),
+        "Old_South_Arabian" => Some(script_old_south_arabian()),
+        "Saurashtra" => Some(script_saurashtra()),
+        "SignWriting" => Some(script_signwriting()),
+        "Shavian" => Some(script_shavian()),
+        "Sharada" => Some(script_sharada()),
+        "Siddham" => Some(script_siddham()),
+        "Khudawadi" => Some(script_khudawadi()),
+        "Sinhala" => Some(script_sinhala()),
+        "Sogdian" => Some(script_sogdian()),
+        "Old_Sogdian" => Some(script_old_sogdian()),
+        "Sora_Sompeng" => Some(script_sora_sompeng()),
+        "Soyombo" => Some(script_soyombo()),
+        "Sundanese" => Some(script_sundanese()),
+        "Syloti_Nagri" => Some(script_syloti_nagri()),
+        "Syriac" => Some(script_syriac()),
+        "Tagbanwa" => Some(script_tagbanwa()),
+        "Takri" => Some(script_takri()),
+        "Tai_Le" => Some(script_tai_le()),
+        "New_Tai_Lue" => Some(script_new_tai_lue()),
+        "Tamil" => Some(script_tamil()),
+        "Tangut" => Some(script_tangut()),
+        "Tai_Viet" => Some(script_tai_viet()),
+        "Telugu" => Some(script_telugu()),
+        "Tifinagh" => Some(script_tifinagh()),
+        "Tagalog" => Some(script_tagalog()),
+        "Thaana" => Some(script_thaana()),
+        "Thai" => Some(script_thai()),
+        "Tibetan" => Some(script_tibetan()),
+        "Tirhuta" => Some(script_tirhuta()),
+        "Tangsa" => Some(script_tangsa()),
+        "Toto" => Some(script_toto()),
+        "Ugaritic" => Some(script_ugaritic()),
+        "Vai" => Some(script_vai()),
+        "Vithkuqi" => Some(script_vithkuqi()),
+        "Warang_Citi" => Some(script_warang_citi()),
+        "Wancho" => Some(script_wancho()),
+        "Old_Persian" => Some(script_old_persian()),
+        "Cuneiform" => Some(script_cuneiform()),
+        "Yezidi" => Some(script_yezidi()),
+        "Yi" => Some(script_yi()),
+        "Zanabazar_Square" => Some(script_zanabazar_square()),
+        "Inherited" => Some(script_inherited()),
+        "Common" => Some(script_common()),
+        _ => None
+    }
+}
+
+// Pairs of each char and the next char in its case folding orbit,
+// sorted by the first.
+fn fold_table() -> ~[(char, char)] {
+    ~[
+        ('\u0041', '\u0061'), ('\u0042', '\u0062'), ('\u0043', '\u0063'),
+        ('\u0044', '\u0064'), ('\u0045', '\u0065'), ('\u0046', '\u0066'),
+        ('\u0047', '\u0067'), ('\u0048', '\u0068'), ('\u0049', '\u0069'),
+        ('\u004a', '\u006a'), ('\u004b', '\u006b'), ('\u004c', '\u006c'),
+        ('\u004d', '\u006d'), ('\u004e', '\u006e'), ('\u004f', '\u006f'),
+        ('\u0050', '\u0070'), ('\u0051', '\u0071'), ('\u0052', '\u0072'),
+        ('\u0053', '\u0073'), ('\u0054', '\u0074'), ('\u0055', '\u0075'),
+        ('\u0056', '\u0076'), ('\u0057', '\u0077'), ('\u0058', '\u0078'),
+        ('\u0059', '\u0079'), ('\u005a', '\u007a'), ('\u0061', '\u0041'),
+        ('\u0062', '\u0042'), ('\u0063', '\u0043'), ('\u0064', '\u0044'),
+        ('\u0065', '\u0045'), ('\u0066', '\u0046'), ('\u0067', '\u0047'),
+        ('\u0068', '\u0048'), ('\u0069', '\u0049'), ('\u006a', '\u004a'),
+        ('\u006b', '\u212a'), ('\u006c', '\u004c'), ('\u006d', '\u004d'),
+        ('\u006e', '\u004e'), ('\u006f', '\u004f'), ('\u0070', '\u0050'),
+        ('\u0071', '\u0051'), ('\u0072', '\u0052'), ('\u0073', '\u017f'),
+        ('\u0074', '\u0054'), ('\u0075', '\u0055'), ('\u0076', '\u0056'),
+        ('\u0077', '\u0057'), ('\u0078', '\u0058'), ('\u0079', '\u0059'),
+        ('\u007a', '\u005a'), ('\u00b5', '\u039c'), ('\u00c0', '\u00e0'),
+        ('\u00c1', '\u00e1'), ('\u00c2', '\u00e2'), ('\u00c3', '\u00e3'),
+        ('\u00c4', '\u00e4'), ('\u00c5', '\u00e5'), ('\u00c6', '\u00e6'),
+        ('\u00c7', '\u00e7'), ('\u00c8', '\u00e8'), ('\u00c9', '\u00e9'),
+        ('\u00ca', '\u00ea'), ('\u00cb', '\u00eb'), ('\u00cc', '\u00ec'),
+        ('\u00cd', '\u00ed'), ('\u00ce', '\u00ee'), ('\u00cf', '\u00ef'),
+        ('\u00d0', '\u00f0'), ('\u00d1', '\u00f1'), ('\u00d2', '\u00f2'),
+        ('\u00d3', '\u00f3'), ('\u00d4', '\u00f4'), ('\u00d5', '\u00f5'),
+        ('\u00d6', '\u00f6'), ('\u00d8', '\u00f8'), ('\u00d9', '\u00f9'),
+        ('\u00da', '\u00fa'), ('\u00db', '\u00fb'), ('\u00dc', '\u00fc'),
+        ('\u00dd', '\u00fd'), ('\u00de', '\u00fe'), ('\u00df', '\u1e9e'),
+        ('\u00e0', '\u00c0'), ('\u00e1', '\u00c1'), ('\u00e2', '\u00c2'),
+        ('\u00e3', '\u00c3'), ('\u00e4', '\u00c4'), ('\u00e5', '\u212b'),
+        ('\u00e6', '\u00c6'), ('\u00e7', '\u00c7'), ('\u00e8', '\u00c8'),
+        ('\u00e9', '\u00c9'), ('\u00ea', '\u00ca'), ('\u00eb', '\u00cb'),
+        ('\u00ec', '\u00cc'), ('\u00ed', '\u00cd'), ('\u00ee', '\u00ce'),
+        ('\u00ef', '\u00cf'), ('\u00f0', '\u00d0'), ('\u00f1', '\u00d1'),
+        ('\u00f2', '\u00d2'), ('\u00f3', '\u00d3'), ('\u00f4', '\u00d4'),
+        ('\u00f5', '\u00d5'), ('\u00f6', '\u00d6'), ('\u00f8', '\u00d8'),
+        ('\u00f9', '\u00d9'), ('\u00fa', '\u00da'), ('\u00fb', '\u00db'),
+        ('\u00fc', '\u00dc'), ('\u00fd', '\u00dd'), ('\u00fe', '\u00de'),
+        ('\u00ff', '\u0178'), ('\u0100', '\u0101'), ('\u0101', '\u0100'),
+        ('\u0102', '\u0103'), ('\u0103', '\u0102'), ('\u0104', '\u0105'),
+        ('\u0105', '\u0104'), ('\u0106', '\u0107'), ('\u0107', '\u0106'),
+        ('\u0108', '\u0109'), ('\u0109', '\u0108'), ('\u010a', '\u010b'),
+        ('\u010b', '\u010a'), ('\u010c', '\u010d'), ('\u010d', '\u010c'),
+        ('\u010e', '\u010f'), ('\u010f', '\u010e'), ('\u0110', '\u0111'),
+        ('\u0111', '\u0110'), ('\u0112', '\u0113'), ('\u0113', '\u0112'),
+        ('\u0114', '\u0115'), ('\u0115', '\u0114'), ('\u0116', '\u0117'),
+        ('\u0117', '\u0116'), ('\u0118', '\u0119'), ('\u0119', '\u0118'),
+        ('\u011a', '\u011b'), ('\u011b', '\u011a'), ('\u011c', '\u011d'),
+        ('\u011d', '\u011c'), ('\u011e', '\u011f'), ('\u011f', '\u011e'),
+        ('\u0120', '\u0121'), ('\u0121', '\u0120'), ('\u0122', '\u0123'),
+        ('\u0123', '\u0122'), ('\u0124', '\u0125'), ('\u0125', '\u0124'),
+        ('\u0126', '\u0127'), ('\u0127', '\u0126'), ('\u0128', '\u0129'),
+        ('\u0129', '\u0128'), ('\u012a', '\u012b'), ('\u012b', '\u012a'),
+        ('\u012c', '\u012d'), ('\u012d', '\u012c'), ('\u012e', '\u012f'),
+        ('\u012f', '\u012e'), ('\u0132', '\u0133'), ('\u0133', '\u0132'),
+        ('\u0134', '\u0135'), ('\u0135', '\u0134'), ('\u0136', '\u0137'),
+        ('\u0137', '\u0136'), ('\u0139', '\u013a'), ('\u013a', '\u0139'),
+        ('\u013b', '\u013c'), ('\u013c', '\u013b'), ('\u013d', '\u013e'),
+        ('\u013e', '\u013d'), ('\u013f', '\u0140'), ('\u0140', '\u013f'),
+        ('\u0141', '\u0142'), ('\u0142', '\u0141'), ('\u0143', '\u0144'),
+        ('\u0144', '\u0143'), ('\u0145', '\u0146'), ('\u0146', '\u0145'),
+        ('\u0147', '\u0148'), ('\u0148', '\u0147'), ('\u014a', '\u014b'),
+        ('\u014b', '\u014a'), ('\u014c', '\u014d'), ('\u014d', '\u014c'),
+        ('\u014e', '\u014f'), ('\u014f', '\u014e'), ('\u0150', '\u0151'),
+        ('\u0151', '\u0150'), ('\u0152', '\u0153'), ('\u0153', '\u0152'),
+        ('\u0154', '\u0155'), ('\u0155', '\u0154'), ('\u0156', '\u0157'),
+        ('\u0157', '\u0156'), ('\u0158', '\u0159'), ('\u0159', '\u0158'),
+        ('\u015a', '\u015b'), ('\u015b', '\u015a'), ('\u015c', '\u015d'),
+        ('\u015d', '\u015c'), ('\u015e', '\u015f'), ('\u015f', '\u015e'),
+        ('\u0160', '\u0161'), ('\u0161', '\u0160'), ('\u0162', '\u0163'),
+        ('\u0163', '\u0162'), ('\u0164', '\u0165'), ('\u0165', '\u0164'),
+        ('\u0166', '\u0167'), ('\u0167', '\u0166'), ('\u0168', '\u0169'),
+        ('\u0169', '\u0168'), ('\u016a', '\u016b'), ('\u016b', '\u016a'),
+        ('\u016c', '\u016d'), ('\u016d', '\u016c'), ('\u016e', '\u016f'),
+        ('\u016f', '\u016e'), ('\u0170', '\u0171'), ('\u0171', '\u0170'),
+        ('\u0172', '\u0173'), ('\u0173', '\u0172'), ('\u0174', '\u0175'),
+        ('\u0175', '\u0174'), ('\u0176', '\u0177'), ('\u0177', '\u0176'),
+        ('\u0178', '\u00ff'), ('\u0179', '\u017a'), ('\u017a', '\u0179'),
+        ('\u017b', '\u017c'), ('\u017c', '\u017b'), ('\u017d', '\u017e'),
+        ('\u017e', '\u017d'), ('\u017f', '\u0053'), ('\u0180', '\u0243'),
+        ('\u0181', '\u0253'), ('\u0182', '\u0183'), ('\u0183', '\u0182'),
+        ('\u0184', '\u0185'), ('\u0185', '\u0184'), ('\u0186', '\u0254'),
+        ('\u0187', '\u0188'), ('\u0188', '\u0187'), ('\u0189', '\u0256'),
+        ('\u018a', '\u0257'), ('\u018b', '\u018c'), ('\u018c', '\u018b'),
+        ('\u018e', '\u01dd'), ('\u018f', '\u0259'), ('\u0190', '\u025b'),
+        ('\u0191', '\u0192'), ('\u0192', '\u0191'), ('\u0193', '\u0260'),
+        ('\u0194', '\u0263'), ('\u0195', '\u01f6'), ('\u0196', '\u0269'),
+        ('\u0197', '\u0268'), ('\u0198', '\u0199'), ('\u0199', '\u0198'),
+        ('\u019a', '\u023d'), ('\u019c', '\u026f'), ('\u019d', '\u0272'),
+        ('\u019e', '\u0220'), ('\u019f', '\u0275'), ('\u01a0', '\u01a1'),
+        ('\u01a1', '\u01a0'), ('\u01a2', '\u01a3'), ('\u01a3', '\u01a2'),
+        ('\u01a4', '\u01a5'), ('\u01a5', '\u01a4'), ('\u01a6', '\u0280'),
+        ('\u01a7', '\u01a8'), ('\u01a8', '\u01a7'), ('\u01a9', '\u0283'),
+        ('\u01ac', '\u01ad'), ('\u01ad', '\u01ac'), ('\u01ae', '\u0288'),
+        ('\u01af', '\u01b0'), ('\u01b0', '\u01af'), ('\u01b1', '\u028a'),
+        ('\u01b2', '\u028b'), ('\u01b3', '\u01b4'), ('\u01b4', '\u01b3'),
+        ('\u01b5', '\u01b6'), ('\u01b6', '\u01b5'), ('\u01b7', '\u0292'),
+        ('\u01b8', '\u01b9'), ('\u01b9', '\u01b8'), ('\u01bc', '\u01bd'),
+        ('\u01bd', '\u01bc'), ('\u01bf', '\u01f7'), ('\u01c4', '\u01c5'),
+        ('\u01c5', '\u01c6'), ('\u01c6', '\u01c4'), ('\u01c7', '\u01c8'),
+        ('\u01c8', '\u01c9'), ('\u01c9', '\u01c7'), ('\u01ca', '\u01cb'),
+        ('\u01cb', '\u01cc'), ('\u01cc', '\u01ca'), ('\u01cd', '\u01ce'),
+        ('\u01ce', '\u01cd'), ('\u01cf', '\u01d0'), ('\u01d0', '\u01cf'),
+        ('\u01d1', '\u01d2'), ('\u01d2', '\u01d1'), ('\u01d3', '\u01d4'),
+        ('\u01d4', '\u01d3'), ('\u01d5', '\u01d6'), ('\u01d6', '\u01d5'),
+        ('\u01d7', '\u01d8'), ('\u01d8', '\u01d7'), ('\u01d9', '\u01da'),
+        ('\u01da', '\u01d9'), ('\u01db', '\u01dc'), ('\u01dc', '\u01db'),
+        ('\u01dd', '\u018e'), ('\u01de', '\u01df'), ('\u01df', '\u01de'),
+        ('\u01e0', '\u01e1'), ('\u01e1', '\u01e0'), ('\u01e2', '\u01e3'),
+        ('\u01e3', '\u01e2'), ('\u01e4', '\u01e5'), ('\u01e5', '\u01e4'),
+        ('\u01e6', '\u01e7'), ('\u01e7', '\u01e6'), ('\u01e8', '\u01e9'),
+        ('\u01e9', '\u01e8'), ('\u01ea', '\u01eb'), ('\u01eb', '\u01ea'),
+        ('\u01ec', '\u01ed'), ('\u01ed', '\u01ec'), ('\u01ee', '\u01ef'),
+        ('\u01ef', '\u01ee'), ('\u01f1', '\u01f2'), ('\u01f2', '\u01f3'),
+        ('\u01f3', '\u01f1'), ('\u01f4', '\u01f5'), ('\u01f5', '\u01f4'),
+        ('\u01f6', '\u0195'), ('\u01f7', '\u01bf'), ('\u01f8', '\u01f9'),
+        ('\u01f9', '\u01f8'), ('\u01fa', '\u01fb'), ('\u01fb', '\u01fa'),
+        ('\u01fc', '\u01fd'), ('\u01fd', '\u01fc'), ('\u01fe', '\u01ff'),
+        ('\u01ff', '\u01fe'), ('\u0200', '\u0201'), ('\u0201', '\u0200'),
+        ('\u0202', '\u0203'), ('\u0203', '\u0202'), ('\u0204', '\u0205'),
+        ('\u0205', '\u0204'), ('\u0206', '\u0207'), ('\u0207', '\u0206'),
+        ('\u0208', '\u0209'), ('\u0209', '\u0208'), ('\u020a', '\u020b'),
+        ('\u020b', '\u020a'), ('\u020c', '\u020d'), ('\u020d', '\u020c'),
+        ('\u020e', '\u020f'), ('\u020f', '\u020e'), ('\u0210', '\u0211'),
+        ('\u0211', '\u0210'), ('\u0212', '\u0213'), ('\u0213', '\u0212'),
+        ('\u0214', '\u0215'), ('\u0215', '\u0214'), ('\u0216', '\u0217'),
+        ('\u0217', '\u0216'), ('\u0218', '\u0219'), ('\u0219', '\u0218'),
+        ('\u021a', '\u021b'), ('\u021b', '\u021a'), ('\u021c', '\u021d'),
+        ('\u021d', '\u021c'), ('\u021e', '\u021f'), ('\u021f', '\u021e'),
+        ('\u0220', '\u019e'), ('\u0222', '\u0223'), ('\u0223', '\u0222'),
+        ('\u0224', '\u0225'), ('\u0225', '\u0224'), ('\u0226', '\u0227'),
+        ('\u0227', '\u0226'), ('\u0228', '\u0229'), ('\u0229', '\u0228'),
+        ('\u022a', '\u022b'), ('\u022b', '\u022a'), ('\u022c', '\u022d'),
+        ('\u022d', '\u022c'), ('\u022e', '\u022f'), ('\u022f', '\u022e'),
+        ('\u0230', '\u0231'), ('\u0231', '\u0230'), ('\u0232', '\u0233'),
+        ('\u0233', '\u0232'), ('\u023a', '\u2c65'), ('\u023b', '\u023c'),
+        ('\u023c', '\u023b'), ('\u023d', '\u019a'), ('\u023e', '\u2c66'),
+        ('\u023f', '\u2c7e'), ('\u0240', '\u2c7f'), ('\u0241', '\u0242'),
+        ('\u0242', '\u0241'), ('\u0243', '\u0180'), ('\u0244', '\u0289'),
+        ('\u0245', '\u028c'), ('\u0246', '\u0247'), ('\u0247', '\u0246'),
+        ('\u0248', '\u0249'), ('\u0249', '\u0248'), ('\u024a', '\u024b'),
+        ('\u024b', '\u024a'), ('\u024c', '\u024d'), ('\u024d', '\u024c'),
+        ('\u024e', '\u024f'), ('\u024f', '\u024e'), ('\u0250', '\u2c6f'),
+        ('\u0251', '\u2c6d'), ('\u0252', '\u2c70'), ('\u0253', '\u0181'),
+        ('\u0254', '\u0186'), ('\u0256', '\u0189'), ('\u0257', '\u018a'),
+        ('\u0259', '\u018f'), ('\u025b', '\u0190'), ('\u025c', '\ua7ab'),
+        ('\u0260', '\u0193'), ('\u0261', '\ua7ac'), ('\u0263', '\u0194'),
+        ('\u0265', '\ua78d'), ('\u0266', '\ua7aa'), ('\u0268', '\u0197'),
+        ('\u0269', '\u0196'), ('\u026a', '\ua7ae'), ('\u026b', '\u2c62'),
+        ('\u026c', '\ua7ad'), ('\u026f', '\u019c'), ('\u0271', '\u2c6e'),
+        ('\u0272', '\u019d'), ('\u0275', '\u019f'), ('\u027d', '\u2c64'),
+        ('\u0280', '\u01a6'), ('\u0282', '\ua7c5'), ('\u0283', '\u01a9'),
+        ('\u0287', '\ua7b1'), ('\u0288', '\u01ae'), ('\u0289', '\u0244'),
+        ('\u028a', '\u01b1'), ('\u028b', '\u01b2'), ('\u028c', '\u0245'),
+        ('\u0292', '\u01b7'), ('\u029d', '\ua7b2'), ('\u029e', '\ua7b0'),
+        ('\u0345', '\u0399'), ('\u0370', '\u0371'), ('\u0371', '\u0370'),
+        ('\u0372', '\u0373'), ('\u0373', '\u0372'), ('\u0376', '\u0377'),
+        ('\u0377', '\u0376'), ('\u037b', '\u03fd'), ('\u037c', '\u03fe'),
+        ('\u037d', '\u03ff'), ('\u037f', '\u03f3'), ('\u0386', '\u03ac'),
+        ('\u0388', '\u03ad'), ('\u0389', '\u03ae'), ('\u038a', '\u03af'),
+        ('\u038c', '\u03cc'), ('\u038e', '\u03cd'), ('\u038f', '\u03ce'),
+        ('\u0391', '\u03b1'), ('\u0392', '\u03b2'), ('\u0393', '\u03b3'),
+        ('\u0394', '\u03b4'), ('\u0395', '\u03b5'), ('\u0396', '\u03b6'),
+        ('\u0397', '\u03b7'), ('\u0398', '\u03b8'), ('\u0399', '\u03b9'),
+        ('\u039a', '\u03ba'), ('\u039b', '\u03bb'), ('\u039c', '\u03bc'),
+        ('\u039d', '\u03bd'), ('\u039e', '\u03be'), ('\u039f', '\u03bf'),
+        ('\u03a0', '\u03c0'), ('\u03a1', '\u03c1'), ('\u03a3', '\u03c2'),
+        ('\u03a4', '\u03c4'), ('\u03a5', '\u03c5'), ('\u03a6', '\u03c6'),
+        ('\u03a7', '\u03c7'), ('\u03a8', '\u03c8'), ('\u03a9', '\u03c9'),
+        ('\u03aa', '\u03ca'), ('\u03ab', '\u03cb'), ('\u03ac', '\u0386'),
+        ('\u03ad', '\u0388'), ('\u03ae', '\u0389'), ('\u03af', '\u038a'),
+        ('\u03b1', '\u0391'), ('\u03b2', '\u03d0'), ('\u03b3', '\u0393'),
+        ('\u03b4', '\u0394'), ('\u03b5', '\u03f5'), ('\u03b6', '\u0396'),
+        ('\u03b7', '\u0397'), ('\u03b8', '\u03d1'), ('\u03b9', '\u1fbe'),
+        ('\u03ba', '\u03f0'), ('\u03bb', '\u039b'), ('\u03bc', '\u00b5'),
+        ('\u03bd', '\u039d'), ('\u03be', '\u039e'), ('\u03bf', '\u039f'),
+        ('\u03c0', '\u03d6'), ('\u03c1', '\u03f1'), ('\u03c2', '\u03c3'),
+        ('\u03c3', '\u03a3'), ('\u03c4', '\u03a4'), ('\u03c5', '\u03a5'),
+        ('\u03c6', '\u03d5'), ('\u03c7', '\u03a7'), ('\u03c8', '\u03a8'),
+        ('\u03c9', '\u2126'), ('\u03ca', '\u03aa'), ('\u03cb', '\u03ab'),
+        ('\u03cc', '\u038c'), ('\u03cd', '\u038e'), ('\u03ce', '\u038f'),
+        ('\u03cf', '\u03d7'), ('\u03d0', '\u0392'), ('\u03d1', '\u03f4'),
+        ('\u03d5', '\u03a6'), ('\u03d6', '\u03a0'), ('\u03d7', '\u03cf'),
+        ('\u03d8', '\u03d9'), ('\u03d9', '\u03d8'), ('\u03da', '\u03db'),
+        ('\u03db', '\u03da'), ('\u03dc', '\u03dd'), ('\u03dd', '\u03dc'),
+        ('\u03de', '\u03df'), ('\u03df', '\u03de'), ('\u03e0', '\u03e1'),
+        ('\u03e1', '\u03e0'), ('\u03e2', '\u03e3'), ('\u03e3', '\u03e2'),
+        ('\u03e4', '\u03e5'), ('\u03e5', '\u03e4'), ('\u03e6', '\u03e7'),
+        ('\u03e7', '\u03e6'), ('\u03e8', '\u03e9'), ('\u03e9', '\u03e8'),
+        ('\u03ea', '\u03eb'), ('\u03eb', '\u03ea'), ('\u03ec', '\u03ed'),
+        ('\u03ed', '\u03ec'), ('\u03ee', '\u03ef'), ('\u03ef', '\u03ee'),
+        ('\u03f0', '\u039a'), ('\u03f1', '\u03a1'), ('\u03f2', '\u03f9'),
+        ('\u03f3', '\u037f'), ('\u03f4', '\u0398'), ('\u03f5', '\u0395'),
+        ('\u03f7', '\u03f8'), ('\u03f8', '\u03f7'), ('\u03f9', '\u03f2'),
+        ('\u03fa', '\u03fb'), ('\u03fb', '\u03fa'), ('\u03fd', '\u037b'),
+        ('\u03fe', '\u037c'), ('\u03ff', '\u037d'), ('\u0400', '\u0450'),
+        ('\u0401', '\u0451'), ('\u0402', '\u0452'), ('\u0403', '\u0453'),
+        ('\u0404', '\u0454'), ('\u0405', '\u0455'), ('\u0406', '\u0456'),
+        ('\u0407', '\u0457'), ('\u0408', '\u0458'), ('\u0409', '\u0459'),
+        ('\u040a', '\u045a'), ('\u040b', '\u045b'), ('\u040c', '\u045c'),
+        ('\u040d', '\u045d'), ('\u040e', '\u045e'), ('\u040f', '\u045f'),
+        ('\u0410', '\u0430'), ('\u0411', '\u0431'), ('\u0412', '\u0432'),
+        ('\u0413', '\u0433'), ('\u0414', '\u0434'), ('\u0415', '\u0435'),
+        ('\u0416', '\u0436'), ('\u0417', '\u0437'), ('\u0418', '\u0438'),
+        ('\u0419', '\u0439'), ('\u041a', '\u043a'), ('\u041b', '\u043b'),
+        ('\u041c', '\u043c'), ('\u041d', '\u043d'), ('\u041e', '\u043e'),
+        ('\u041f', '\u043f'), ('\u0420', '\u0440'), ('\u0421', '\u0441'),
+        ('\u0422', '\u0442'), ('\u0423', '\u0443'), ('\u0424', '\u0444'),
+        ('\u0425', '\u0445'), ('\u0426', '\u0446'), ('\u0427', '\u0447'),
+        ('\u0428', '\u0448'), ('\u0429', '\u0449'), ('\u042a', '\u044a'),
+        ('\u042b', '\u044b'), ('\u042c', '\u044c'), ('\u042d', '\u044d'),
+        ('\u042e', '\u044e'), ('\u042f', '\u044f'), ('\u0430', '\u0410'),
+        ('\u0431', '\u0411'), ('\u0432', '\u1c80'), ('\u0433', '\u0413'),
+        ('\u0434', '\u1c81'), ('\u0435', '\u0415'), ('\u0436', '\u0416'),
+        ('\u0437', '\u0417'), ('\u0438', '\u0418'), ('\u0439', '\u0419'),
+        ('\u043a', '\u041a'), ('\u043b', '\u041b'), ('\u043c', '\u041c'),
+        ('\u043d', '\u041d'), ('\u043e', '\u1c82'), ('\u043f', '\u041f'),
+        ('\u0440', '\u0420'), ('\u0441', '\u1c83'), ('\u0442', '\u1c84'),
+        ('\u0443', '\u0423'), ('\u0444', '\u0424'), ('\u0445', '\u0425'),
+        ('\u0446', '\u0426'), ('\u0447', '\u0427'), ('\u0448', '\u0428'),
+        ('\u0449', '\u0429'), ('\u044a', '\u1c86'), ('\u044b', '\u042b'),
+        ('\u044c', '\u042c'), ('\u044d', '\u042d'), ('\u044e', '\u042e'),
+        ('\u044f', '\u042f'), ('\u0450', '\u0400'), ('\u0451', '\u0401'),
+        ('\u0452', '\u0402'), ('\u0453', '\u0403'), ('\u0454', '\u0404'),
+        ('\u0455', '\u0405'), ('\u0456', '\u0406'), ('\u0457', '\u0407'),
+        ('\u0458', '\u0408'), ('\u0459', '\u0409'), ('\u045a', '\u040a'),
+        ('\u045b', '\u040b'), ('\u045c', '\u040c'), ('\u045d', '\u040d'),
+        ('\u045e', '\u040e'), ('\u045f', '\u040f'), ('\u0460', '\u0461'),
+        ('\u0461', '\u0460'), ('\u0462', '\u0463'), ('\u0463', '\u1c87'),
+        ('\u0464', '\u0465'), ('\u0465', '\u0464'), ('\u0466', '\u0467'),
+        ('\u0467', '\u0466'), ('\u0468', '\u0469'), ('\u0469', '\u0468'),
+        ('\u046a', '\u046b'), ('\u046b', '\u046a'), ('\u046c', '\u046d'),
+        ('\u046d', '\u046c'), ('\u046e', '\u046f'), ('\u046f', '\u046e'),
+        ('\u0470', '\u0471'), ('\u0471', '\u0470'), ('\u0472', '\u0473'),
+        ('\u0473', '\u0472'), ('\u0474', '\u0475'), ('\u0475', '\u0474'),
+        ('\u0476', '\u0477'), ('\u0477', '\u0476'), ('\u0478', '\u0479'),
+        ('\u0479', '\u0478'), ('\u047a', '\u047b'), ('\u047b', '\u047a'),
+        ('\u047c', '\u047d'), ('\u047d', '\u047c'), ('\u047e', '\u047f'),
+        ('\u047f', '\u047e'), ('\u0480', '\u0481'), ('\u0481', '\u0480'),
+        ('\u048a', '\u048b'), ('\u048b', '\u048a'), ('\u048c', '\u048d'),
+        ('\u048d', '\u048c'), ('\u048e', '\u048f'), ('\u048f', '\u048e'),
+        ('\u0490', '\u0491'), ('\u0491', '\u0490'), ('\u0492', '\u0493'),
+        ('\u0493', '\u0492'), ('\u0494', '\u0495'), ('\u0495', '\u0494'),
+        ('\u0496', '\u0497'), ('\u0497', '\u0496'), ('\u0498', '\u0499'),
+        ('\u0499', '\u0498'), ('\u049a', '\u049b'), ('\u049b', '\u049a'),
+        ('\u049c', '\u049d'), ('\u049d', '\u049c'), ('\u049e', '\u049f'),
+        ('\u049f', '\u049e'), ('\u04a0', '\u04a1'), ('\u04a1', '\u04a0'),
+        ('\u04a2', '\u04a3'), ('\u04a3', '\u04a2'), ('\u04a4', '\u04a5'),
+        ('\u04a5', '\u04a4'), ('\u04a6', '\u04a7'), ('\u04a7', '\u04a6'),
+        ('\u04a8', '\u04a9'), ('\u04a9', '\u04a8'), ('\u04aa', '\u04ab'),
+        ('\u04ab', '\u04aa'), ('\u04ac', '\u04ad'), ('\u04ad', '\u04ac'),
+        ('\u04ae', '\u04af'), ('\u04af', '\u04ae'), ('\u04b0', '\u04b1'),
+        ('\u04b1', '\u04b0'), ('\u04b2', '\u04b3'), ('\u04b3', '\u04b2'),
+        ('\u04b4', '\u04b5'), ('\u04b5', '\u04b4'), ('\u04b6', '\u04b7'),
+        ('\u04b7', '\u04b6'), ('\u04b8', '\u04b9'), ('\u04b9', '\u04b8'),
+        ('\u04ba', '\u04bb'), ('\u04bb', '\u04ba'), ('\u04bc', '\u04bd'),
+        ('\u04bd', '\u04bc'), ('\u04be', '\u04bf'), ('\u04bf', '\u04be'),
+        ('\u04c0', '\u04cf'), ('\u04c1', '\u04c2'), ('\u04c2', '\u04c1'),
+        ('\u04c3', '\u04c4'), ('\u04c4', '\u04c3'), ('\u04c5', '\u04c6'),
+        ('\u04c6', '\u04c5'), ('\u04c7', '\u04c8'), ('\u04c8', '\u04c7'),
+        ('\u04c9', '\u04ca'), ('\u04ca', '\u04c9'), ('\u04cb', '\u04cc'),
+        ('\u04cc', '\u04cb'), ('\u04cd', '\u04ce'), ('\u04ce', '\u04cd'),
+        ('\u04cf', '\u04c0'), ('\u04d0', '\u04d1'), ('\u04d1', '\u04d0'),
+        ('\u04d2', '\u04d3'), ('\u04d3', '\u04d2'), ('\u04d4', '\u04d5'),
+        ('\u04d5', '\u04d4'), ('\u04d6', '\u04d7'), ('\u04d7', '\u04d6'),
+        ('\u04d8', '\u04d9'), ('\u04d9', '\u04d8'), ('\u04da', '\u04db'),
+        ('\u04db', '\u04da'), ('\u04dc', '\u04dd'), ('\u04dd', '\u04dc'),
+        ('\u04de', '\u04df'), ('\u04df', '\u04de'), ('\u04e0', '\u04e1'),
+        ('\u04e1', '\u04e0'), ('\u04e2', '\u04e3'), ('\u04e3', '\u04e2'),
+        ('\u04e4', '\u04e5'), ('\u04e5', '\u04e4'), ('\u04e6', '\u04e7'),
+        ('\u04e7', '\u04e6'), ('\u04e8', '\u04e9'), ('\u04e9', '\u04e8'),
+        ('\u04ea', '\u04eb'), ('\u04eb', '\u04ea'), ('\u04ec', '\u04ed'),
+        ('\u04ed', '\u04ec'), ('\u04ee', '\u04ef'), ('\u04ef', '\u04ee'),
+        ('\u04f0', '\u04f1'), ('\u04f1', '\u04f0'), ('\u04f2', '\u04f3'),
+        ('\u04f3', '\u04f2'), ('\u04f4', '\u04f5'), ('\u04f5', '\u04f4'),
+        ('\u04f6', '\u04f7'), ('\u04f7', '\u04f6'), ('\u04f8', '\u04f9'),
+        ('\u04f9', '\u04f8'), ('\u04fa', '\u04fb'), ('\u04fb', '\u04fa'),
+        ('\u04fc', '\u04fd'), ('\u04fd', '\u04fc'), ('\u04fe', '\u04ff'),
+        ('\u04ff', '\u04fe'), ('\u0500', '\u0501'), ('\u0501', '\u0500'),
+        ('\u0502', '\u0503'), ('\u0503', '\u0502'), ('\u0504', '\u0505'),
+        ('\u0505', '\u0504'), ('\u0506', '\u0507'), ('\u0507', '\u0506'),
+        ('\u0508', '\u0509'), ('\u0509', '\u0508'), ('\u050a', '\u050b'),
+        ('\u050b', '\u050a'), ('\u050c', '\u050d'), ('\u050d', '\u050c'),
+        ('\u050e', '\u050f'), ('\u050f', '\u050e'), ('\u0510', '\u0511'),
+        ('\u0511', '\u0510'), ('\u0512', '\u0513'), ('\u0513', '\u0512'),
+        ('\u0514', '\u0515'), ('\u0515', '\u0514'), ('\u0516', '\u0517'),
+        ('\u0517', '\u0516'), ('\u0518', '\u0519'), ('\u0519', '\u0518'),
+        ('\u051a', '\u051b'), ('\u051b', '\u051a'), ('\u051c', '\u051d'),
+        ('\u051d', '\u051c'), ('\u051e', '\u051f'), ('\u051f', '\u051e'),
+        ('\u0520', '\u0521'), ('\u0521', '\u0520'), ('\u0522', '\u0523'),
+        ('\u0523', '\u0522'), ('\u0524', '\u0525'), ('\u0525', '\u0524'),
+        ('\u0526', '\u0527'), ('\u0527', '\u0526'), ('\u0528', '\u0529'),
+        ('\u0529', '\u0528'), ('\u052a', '\u052b'), ('\u052b', '\u052a'),
+        ('\u052c', '\u052d'), ('\u052d', '\u052c'), ('\u052e', '\u052f'),
+        ('\u052f', '\u052e'), ('\u0531', '\u0561'), ('\u0532', '\u0562'),
+        ('\u0533', '\u0563'), ('\u0534', '\u0564'), ('\u0535', '\u0565'),
+        ('\u0536', '\u0566'), ('\u0537', '\u0567'), ('\u0538', '\u0568'),
+        ('\u0539', '\u0569'), ('\u053a', '\u056a'), ('\u053b', '\u056b'),
+        ('\u053c', '\u056c'), ('\u053d', '\u056d'), ('\u053e', '\u056e'),
+        ('\u053f', '\u056f'), ('\u0540', '\u0570'), ('\u0541', '\u0571'),
+        ('\u0542', '\u0572'), ('\u0543', '\u0573'), ('\u0544', '\u0574'),
+        ('\u0545', '\u0575'), ('\u0546', '\u0576'), ('\u0547', '\u0577'),
+        ('\u0548', '\u0578'), ('\u0549', '\u0579'), ('\u054a', '\u057a'),
+        ('\u054b', '\u057b'), ('\u054c', '\u057c'), ('\u054d', '\u057d'),
+        ('\u054e', '\u057e'), ('\u054f', '\u057f'), ('\u0550', '\u0580'),
+        ('\u0551', '\u0581'), ('\u0552', '\u0582'), ('\u0553', '\u0583'),
+        ('\u0554', '\u0584'), ('\u0555', '\u0585'), ('\u0556', '\u0586'),
+        ('\u0561', '\u0531'), ('\u0562', '\u0532'), ('\u0563', '\u0533'),
+        ('\u0564', '\u0534'), ('\u0565', '\u0535'), ('\u0566', '\u0536'),
+        ('\u0567', '\u0537'), ('\u0568', '\u0538'), ('\u0569', '\u0539'),
+        ('\u056a', '\u053a'), ('\u056b', '\u053b'), ('\u056c', '\u053c'),
+        ('\u056d', '\u053d'), ('\u056e', '\u053e'), ('\u056f', '\u053f'),
+        ('\u0570', '\u0540'), ('\u0571', '\u0541'), ('\u0572', '\u0542'),
+        ('\u0573', '\u0543'), ('\u0574', '\u0544'), ('\u0575', '\u0545'),
+        ('\u0576', '\u0546'), ('\u0577', '\u0547'), ('\u0578', '\u0548'),
+        ('\u0579', '\u0549'), ('\u057a', '\u054a'), ('\u057b', '\u054b'),
+        ('\u057c', '\u054c'), ('\u057d', '\u054d'), ('\u057e', '\u054e'),
+        ('\u057f', '\u054f'), ('\u0580', '\u0550'), ('\u0581', '\u0551'),
+        ('\u0582', '\u0552'), ('\u0583', '\u0553'), ('\u0584', '\u0554'),
+        ('\u0585', '\u0555'), ('\u0586', '\u0556'), ('\u10a0', '\u2d00'),
+        ('\u10a1', '\u2d01'), ('\u10a2', '\u2d02'), ('\u10a3', '\u2d03'),
+        ('\u10a4', '\u2d04'), ('\u10a5', '\u2d05'), ('\u10a6', '\u2d06'),
+        ('\u10a7', '\u2d07'), ('\u10a8', '\u2d08'), ('\u10a9', '\u2d09'),
+        ('\u10aa', '\u2d0a'), ('\u10ab', '\u2d0b'), ('\u10ac', '\u2d0c'),
+        ('\u10ad', '\u2d0d'), ('\u10ae', '\u2d0e'), ('\u10af', '\u2d0f'),
+        ('\u10b0', '\u2d10'), ('\u10b1', '\u2d11'), ('\u10b2', '\u2d12'),
+        ('\u10b3', '\u2d13'), ('\u10b4', '\u2d14'), ('\u10b5', '\u2d15'),
+        ('\u10b6', '\u2d16'), ('\u10b7', '\u2d17'), ('\u10b8', '\u2d18'),
+        ('\u10b9', '\u2d19'), ('\u10ba', '\u2d1a'), ('\u10bb', '\u2d1b'),
+        ('\u10bc', '\u2d1c'), ('\u10bd', '\u2d1d'), ('\u10be', '\u2d1e'),
+        ('\u10bf', '\u2d1f'), ('\u10c0', '\u2d20'), ('\u10c1', '\u2d21'),
+        ('\u10c2', '\u2d22'), ('\u10c3', '\u2d23'), ('\u10c4', '\u2d24'),
+        ('\u10c5', '\u2d25'), ('\u10c7', '\u2d27'), ('\u10cd', '\u2d2d'),
+        ('\u10d0', '\u1c90'), ('\u10d1', '\u1c91'), ('\u10d2', '\u1c92'),
+        ('\u10d3', '\u1c93'), ('\u10d4', '\u1c94'), ('\u10d5', '\u1c95'),
+        ('\u10d6', '\u1c96'), ('\u10d7', '\u1c97'), ('\u10d8', '\u1c98'),
+        ('\u10d9', '\u1c99'), ('\u10da', '\u1c9a'), ('\u10db', '\u1c9b'),
+        ('\u10dc', '\u1c9c'), ('\u10dd', '\u1c9d'), ('\u10de', '\u1c9e'),
+        ('\u10df', '\u1c9f'), ('\u10e0', '\u1ca0'), ('\u10e1', '\u1ca1'),
+        ('\u10e2', '\u1ca2'), ('\u10e3', '\u1ca3'), ('\u10e4', '\u1ca4'),
+        ('\u10e5', '\u1ca5'), ('\u10e6', '\u1ca6'), ('\u10e7', '\u1ca7'),
+        ('\u10e8', '\u1ca8'), ('\u10e9', '\u1ca9'), ('\u10ea', '\u1caa'),
+        ('\u10eb', '\u1cab'), ('\u10ec', '\u1cac'), ('\u10ed', '\u1cad'),
+        ('\u10ee', '\u1cae'), ('\u10ef', '\u1caf'), ('\u10f0', '\u1cb0'),
+        ('\u10f1', '\u1cb1'), ('\u10f2', '\u1cb2'), ('\u10f3', '\u1cb3'),
+        ('\u10f4', '\u1cb4'), ('\u10f5', '\u1cb5'), ('\u10f6', '\u1cb6'),
+        ('\u10f7', '\u1cb7'), ('\u10f8', '\u1cb8'), ('\u10f9', '\u1cb9'),
+        ('\u10fa', '\u1cba'), ('\u10fd', '\u1cbd'), ('\u10fe', '\u1cbe'),
+        ('\u10ff', '\u1cbf'), ('\u13a0', '\uab70'), ('\u13a1', '\uab71'),
+        ('\u13a2', '\uab72'), ('\u13a3', '\uab73'), ('\u13a4', '\uab74'),
+        ('\u13a5', '\uab75'), ('\u13a6', '\uab76'), ('\u13a7', '\uab77'),
+        ('\u13a8', '\uab78'), ('\u13a9', '\uab79'), ('\u13aa', '\uab7a'),
+        ('\u13ab', '\uab7b'), ('\u13ac', '\uab7c'), ('\u13ad', '\uab7d'),
+        ('\u13ae', '\uab7e'), ('\u13af', '\uab7f'), ('\u13b0', '\uab80'),
+        ('\u13b1', '\uab81'), ('\u13b2', '\uab82'), ('\u13b3', '\uab83'),
+        ('\u13b4', '\uab84'), ('\u13b5', '\uab85'), ('\u13b6', '\uab86'),
+        ('\u13b7', '\uab87'), ('\u13b8', '\uab88'), ('\u13b9', '\uab89'),
+        ('\u13ba', '\uab8a'), ('\u13bb', '\uab8b'), ('\u13bc', '\uab8c'),
+        ('\u13bd', '\uab8d'), ('\u13be', '\uab8e'), ('\u13bf', '\uab8f'),
+        ('\u13c0', '\uab90'), ('\u13c1', '\uab91'), ('\u13c2', '\uab92'),
+        ('\u13c3', '\uab93'), ('\u13c4', '\uab94'), ('\u13c5', '\uab95'),
+        ('\u13c6', '\uab96'), ('\u13c7', '\uab97'), ('\u13c8', '\uab98'),
+        ('\u13c9', '\uab99'), ('\u13ca', '\uab9a'), ('\u13cb', '\uab9b'),
+        ('\u13cc', '\uab9c'), ('\u13cd', '\uab9d'), ('\u13ce', '\uab9e'),
+        ('\u13cf', '\uab9f'), ('\u13d0', '\uaba0'), ('\u13d1', '\uaba1'),
+        ('\u13d2', '\uaba2'), ('\u13d3', '\uaba3'), ('\u13d4', '\uaba4'),
+        ('\u13d5', '\uaba5'), ('\u13d6', '\uaba6'), ('\u13d7', '\uaba7'),
+        ('\u13d8', '\uaba8'), ('\u13d9', '\uaba9'), ('\u13da', '\uabaa'),
+        ('\u13db', '\uabab'), ('\u13dc', '\uabac'), ('\u13dd', '\uabad'),
+        ('\u13de', '\uabae'), ('\u13df', '\uabaf'), ('\u13e0', '\uabb0'),
+        ('\u13e1', '\uabb1'), ('\u13e2', '\uabb2'), ('\u13e3', '\uabb3'),
+        ('\u13e4', '\uabb4'), ('\u13e5', '\uabb5'), ('\u13e6', '\uabb6'),
+        ('\u13e7', '\uabb7'), ('\u13e8', '\uabb8'), ('\u13e9', '\uabb9'),
+        ('\u13ea', '\uabba'), ('\u13eb', '\uabbb'), ('\u13ec', '\uabbc'),
+        ('\u13ed', '\uabbd'), ('\u13ee', '\uabbe'), ('\u13ef', '\uabbf'),
+        ('\u13f0', '\u13f8'), ('\u13f1', '\u13f9'), ('\u13f2', '\u13fa'),
+        ('\u13f3', '\u13fb'), ('\u13f4', '\u13fc'), ('\u13f5', '\u13fd'),
+        ('\u13f8', '\u13f0'), ('\u13f9', '\u13f1'), ('\u13fa', '\u13f2'),
+        ('\u13fb', '\u13f3'), ('\u13fc', '\u13f4'), ('\u13fd', '\u13f5'),
+        ('\u1c80', '\u0412'), ('\u1c81', '\u0414'), ('\u1c82', '\u041e'),
+        ('\u1c83', '\u0421'), ('\u1c84', '\u1c85'), ('\u1c85', '\u0422'),
+        ('\u1c86', '\u042a'), ('\u1c87', '\u0462'), ('\u1c88', '\ua64a'),
+        ('\u1c90', '\u10d0'), ('\u1c91', '\u10d1'), ('\u1c92', '\u10d2'),
+        ('\u1c93', '\u10d3'), ('\u1c94', '\u10d4'), ('\u1c95', '\u10d5'),
+        ('\u1c96', '\u10d6'), ('\u1c97', '\u10d7'), ('\u1c98', '\u10d8'),
+        ('\u1c99', '\u10d9'), ('\u1c9a', '\u10da'), ('\u1c9b', '\u10db'),
+        ('\u1c9c', '\u10dc'), ('\u1c9d', '\u10dd'), ('\u1c9e', '\u10de'),
+        ('\u1c9f', '\u10df'), ('\u1ca0', '\u10e0'), ('\u1ca1', '\u10e1'),
+        ('\u1ca2', '\u10e2'), ('\u1ca3', '\u10e3'), ('\u1ca4', '\u10e4'),
+        ('\u1ca5', '\u10e5'), ('\u1ca6', '\u10e6'), ('\u1ca7', '\u10e7'),
+        ('\u1ca8', '\u10e8'), ('\u1ca9', '\u10e9'), ('\u1caa', '\u10ea'),
+        ('\u1cab', '\u10eb'), ('\u1cac', '\u10ec'), ('\u1cad', '\u10ed'),
+        ('\u1cae', '\u10ee'), ('\u1caf', '\u10ef'), ('\u1cb0', '\u10f0'),
+        ('\u1cb1', '\u10f1'), ('\u1cb2', '\u10f2'), ('\u1cb3', '\u10f3'),
+        ('\u1cb4', '\u10f4'), ('\u1cb5', '\u10f5'), ('\u1cb6', '\u10f6'),
+        ('\u1cb7', '\u10f7'), ('\u1cb8', '\u10f8'), ('\u1cb9', '\u10f9'),
+        ('\u1cba', '\u10fa'), ('\u1cbd', '\u10fd'), ('\u1cbe', '\u10fe'),
+        ('\u1cbf', '\u10ff'), ('\u1d79', '\ua77d'), ('\u1d7d', '\u2c63'),
+        ('\u1d8e', '\ua7c6'), ('\u1e00', '\u1e01'), ('\u1e01', '\u1e00'),
+        ('\u1e02', '\u1e03'), ('\u1e03', '\u1e02'), ('\u1e04', '\u1e05'),
+        ('\u1e05', '\u1e04'), ('\u1e06', '\u1e07'), ('\u1e07', '\u1e06'),
+        ('\u1e08', '\u1e09'), ('\u1e09', '\u1e08'), ('\u1e0a', '\u1e0b'),
+        ('\u1e0b', '\u1e0a'), ('\u1e0c', '\u1e0d'), ('\u1e0d', '\u1e0c'),
+        ('\u1e0e', '\u1e0f'), ('\u1e0f', '\u1e0e'), ('\u1e10', '\u1e11'),
+        ('\u1e11', '\u1e10'), ('\u1e12', '\u1e13'), ('\u1e13', '\u1e12'),
+        ('\u1e14', '\u1e15'), ('\u1e15', '\u1e14'), ('\u1e16', '\u1e17'),
+        ('\u1e17', '\u1e16'), ('\u1e18', '\u1e19'), ('\u1e19', '\u1e18'),
+        ('\u1e1a', '\u1e1b'), ('\u1e1b', '\u1e1a'), ('\u1e1c', '\u1e1d'),
+        ('\u1e1d', '\u1e1c'), ('\u1e1e', '\u1e1f'), ('\u1e1f', '\u1e1e'),
+        ('\u1e20', '\u1e21'), ('\u1e21', '\u1e20'), ('\u1e22', '\u1e23'),
+        ('\u1e23', '\u1e22'), ('\u1e24', '\u1e25'), ('\u1e25', '\u1e24'),
+        ('\u1e26', '\u1e27'), ('\u1e27', '\u1e26'), ('\u1e28', '\u1e29'),
+        ('\u1e29', '\u1e28'), ('\u1e2a', '\u1e2b'), ('\u1e2b', '\u1e2a'),
+        ('\u1e2c', '\u1e2d'), ('\u1e2d', '\u1e2c'), ('\u1e2e', '\u1e2f'),
+        ('\u1e2f', '\u1e2e'), ('\u1e30', '\u1e31'), ('\u1e31', '\u1e30'),
+        ('\u1e32', '\u1e33'), ('\u1e33', '\u1e32'), ('\u1e34', '\u1e35'),
+        ('\u1e35', '\u1e34'), ('\u1e36', '\u1e37'), ('\u1e37', '\u1e36'),
+        ('\u1e38', '\u1e39'), ('\u1e39', '\u1e38'), ('\u1e3a', '\u1e3b'),
+        ('\u1e3b', '\u1e3a'), ('\u1e3c', '\u1e3d'), ('\u1e3d', '\u1e3c'),
+        ('\u1e3e', '\u1e3f'), ('\u1e3f', '\u1e3e'), ('\u1e40', '\u1e41'),
+        ('\u1e41', '\u1e40'), ('\u1e42', '\u1e43'), ('\u1e43', '\u1e42'),
+        ('\u1e44', '\u1e45'), ('\u1e45', '\u1e44'), ('\u1e46', '\u1e47'),
+        ('\u1e47', '\u1e46'), ('\u1e48', '\u1e49'), ('\u1e49', '\u1e48'),
+        ('\u1e4a', '\u1e4b'), ('\u1e4b', '\u1e4a'), ('\u1e4c', '\u1e4d'),
+        ('\u1e4d', '\u1e4c'), ('\u1e4e', '\u1e4f'), ('\u1e4f', '\u1e4e'),
+        ('\u1e50', '\u1e51'), ('\u1e51', '\u1e50'), ('\u1e52', '\u1e53'),
+        ('\u1e53', '\u1e52'), ('\u1e54', '\u1e55'), ('\u1e55', '\u1e54'),
+        ('\u1e56', '\u1e57'), ('\u1e57', '\u1e56'), ('\u1e58', '\u1e59'),
+        ('\u1e59', '\u1e58'), ('\u1e5a', '\u1e5b'), ('\u1e5b', '\u1e5a'),
+        ('\u1e5c', '\u1e5d'), ('\u1e5d', '\u1e5c'), ('\u1e5e', '\u1e5f'),
+        ('\u1e5f', '\u1e5e'), ('\u1e60', '\u1e61'), ('\u1e61', '\u1e9b'),
+        ('\u1e62', '\u1e63'), ('\u1e63', '\u1e62'), ('\u1e64', '\u1e65'),
+        ('\u1e65', '\u1e64'), ('\u1e66', '\u1e67'), ('\u1e67', '\u1e66'),
+        ('\u1e68', '\u1e69'), ('\u1e69', '\u1e68'), ('\u1e6a', '\u1e6b'),
+        ('\u1e6b', '\u1e6a'), ('\u1e6c', '\u1e6d'), ('\u1e6d', '\u1e6c'),
+        ('\u1e6e', '\u1e6f'), ('\u1e6f', '\u1e6e'), ('\u1e70', '\u1e71'),
+        ('\u1e71', '\u1e70'), ('\u1e72', '\u1e73'), ('\u1e73', '\u1e72'),
+        ('\u1e74', '\u1e75'), ('\u1e75', '\u1e74'), ('\u1e76', '\u1e77'),
+        ('\u1e77', '\u1e76'), ('\u1e78', '\u1e79'), ('\u1e79', '\u1e78'),
+        ('\u1e7a', '\u1e7b'), ('\u1e7b', '\u1e7a'), ('\u1e7c', '\u1e7d'),
+        ('\u1e7d', '\u1e7c'), ('\u1e7e', '\u1e7f'), ('\u1e7f', '\u1e7e'),
+        ('\u1e80', '\u1e81'), ('\u1e81', '\u1e80'), ('\u1e82', '\u1e83'),
+        ('\u1e83', '\u1e82'), ('\u1e84', '\u1e85'), ('\u1e85', '\u1e84'),
+        ('\u1e86', '\u1e87'), ('\u1e87', '\u1e86'), ('\u1e88', '\u1e89'),
+        ('\u1e89', '\u1e88'), ('\u1e8a', '\u1e8b'), ('\u1e8b', '\u1e8a'),
+        ('\u1e8c', '\u1e8d'), ('\u1e8d', '\u1e8c'), ('\u1e8e', '\u1e8f'),
+        ('\u1e8f', '\u1e8e'), ('\u1e90', '\u1e91'), ('\u1e91', '\u1e90'),
+        ('\u1e92', '\u1e93'), ('\u1e93', '\u1e92'), ('\u1e94', '\u1e95'),
+        ('\u1e95', '\u1e94'), ('\u1e9b', '\u1e60'), ('\u1e9e', '\u00df'),
+        ('\u1ea0', '\u1ea1'), ('\u1ea1', '\u1ea0'), ('\u1ea2', '\u1ea3'),
+        ('\u1ea3', '\u1ea2'), ('\u1ea4', '\u1ea5'), ('\u1ea5', '\u1ea4'),
+        ('\u1ea6', '\u1ea7'), ('\u1ea7', '\u1ea6'), ('\u1ea8', '\u1ea9'),
+        ('\u1ea9', '\u1ea8'), ('\u1eaa', '\u1eab'), ('\u1eab', '\u1eaa'),
+        ('\u1eac', '\u1ead'), ('\u1ead', '\u1eac'), ('\u1eae', '\u1eaf'),
+        ('\u1eaf', '\u1eae'), ('\u1eb0', '\u1eb1'), ('\u1eb1', '\u1eb0'),
+        ('\u1eb2', '\u1eb3'), ('\u1eb3', '\u1eb2'), ('\u1eb4', '\u1eb5'),
+        ('\u1eb5', '\u1eb4'), ('\u1eb6', '\u1eb7'), ('\u1eb7', '\u1eb6'),
+        ('\u1eb8', '\u1eb9'), ('\u1eb9', '\u1eb8'), ('\u1eba', '\u1ebb'),
+        ('\u1ebb', '\u1eba'), ('\u1ebc', '\u1ebd'), ('\u1ebd', '\u1ebc'),
+        ('\u1ebe', '\u1ebf'), ('\u1ebf', '\u1ebe'), ('\u1ec0', '\u1ec1'),
+        ('\u1ec1', '\u1ec0'), ('\u1ec2', '\u1ec3'), ('\u1ec3', '\u1ec2'),
+        ('\u1ec4', '\u1ec5'), ('\u1ec5', '\u1ec4'), ('\u1ec6', '\u1ec7'),
+        ('\u1ec7', '\u1ec6'), ('\u1ec8', '\u1ec9'), ('\u1ec9', '\u1ec8'),
+        ('\u1eca', '\u1ecb'), ('\u1ecb', '\u1eca'), ('\u1ecc', '\u1ecd'),
+        ('\u1ecd', '\u1ecc'), ('\u1ece', '\u1ecf'), ('\u1ecf', '\u1ece'),
+        ('\u1ed0', '\u1ed1'), ('\u1ed1', '\u1ed0'), ('\u1ed2', '\u1ed3'),
+        ('\u1ed3', '\u1ed2'), ('\u1ed4', '\u1ed5'), ('\u1ed5', '\u1ed4'),
+        ('\u1ed6', '\u1ed7'), ('\u1ed7', '\u1ed6'), ('\u1ed8', '\u1ed9'),
+        ('\u1ed9', '\u1ed8'), ('\u1eda', '\u1edb'), ('\u1edb', '\u1eda'),
+        ('\u1edc', '\u1edd'), ('\u1edd', '\u1edc'), ('\u1ede', '\u1edf'),
+        ('\u1edf', '\u1ede'), ('\u1ee0', '\u1ee1'), ('\u1ee1', '\u1ee0'),
+        ('\u1ee2', '\u1ee3'), ('\u1ee3', '\u1ee2'), ('\u1ee4', '\u1ee5'),
+        ('\u1ee5', '\u1ee4'), ('\u1ee6', '\u1ee7'), ('\u1ee7', '\u1ee6'),
+        ('\u1ee8', '\u1ee9'), ('\u1ee9', '\u1ee8'), ('\u1eea', '\u1eeb'),
+        ('\u1eeb', '\u1eea'), ('\u1eec', '\u1eed'), ('\u1eed', '\u1eec'),
+        ('\u1eee', '\u1eef'), ('\u1eef', '\u1eee'), ('\u1ef0', '\u1ef1'),
+        ('\u1ef1', '\u1ef0'), ('\u1ef2', '\u1ef3'), ('\u1ef3', '\u1ef2'),
+        ('\u1ef4', '\u1ef5'), ('\u1ef5', '\u1ef4'), ('\u1ef6', '\u1ef7'),
+        ('\u1ef7', '\u1ef6'), ('\u1ef8', '\u1ef9'), ('\u1ef9', '\u1ef8'),
+        ('\u1efa', '\u1efb'), ('\u1efb', '\u1efa'), ('\u1efc', '\u1efd'),
+        ('\u1efd', '\u1efc'), ('\u1efe', '\u1eff'), ('\u1eff', '\u1efe'),
+        ('\u1f00', '\u1f08'), ('\u1f01', '\u1f09'), ('\u1f02', '\u1f0a'),
+        ('\u1f03', '\u1f0b'), ('\u1f04', '\u1f0c'), ('\u1f05', '\u1f0d'),
+        ('\u1f06', '\u1f0e'), ('\u1f07', '\u1f0f'), ('\u1f08', '\u1f00'),
+        ('\u1f09', '\u1f01'), ('\u1f0a', '\u1f02'), ('\u1f0b', '\u1f03'),
+        ('\u1f0c', '\u1f04'), ('\u1f0d', '\u1f05'), ('\u1f0e', '\u1f06'),
+        ('\u1f0f', '\u1f07'), ('\u1f10', '\u1f18'), ('\u1f11', '\u1f19'),
+        ('\u1f12', '\u1f1a'), ('\u1f13', '\u1f1b'), ('\u1f14', '\u1f1c'),
+        ('\u1f15', '\u1f1d'), ('\u1f18', '\u1f10'), ('\u1f19', '\u1f11'),
+        ('\u1f1a', '\u1f12'), ('\u1f1b', '\u1f13'), ('\u1f1c', '\u1f14'),
+        ('\u1f1d', '\u1f15'), ('\u1f20', '\u1f28'), ('\u1f21', '\u1f29'),
+        ('\u1f22', '\u1f2a'), ('\u1f23', '\u1f2b'), ('\u1f24', '\u1f2c'),
+        ('\u1f25', '\u1f2d'), ('\u1f26', '\u1f2e'), ('\u1f27', '\u1f2f'),
+        ('\u1f28', '\u1f20'), ('\u1f29', '\u1f21'), ('\u1f2a', '\u1f22'),
+        ('\u1f2b', '\u1f23'), ('\u1f2c', '\u1f24'), ('\u1f2d', '\u1f25'),
+        ('\u1f2e', '\u1f26'), ('\u1f2f', '\u1f27'), ('\u1f30', '\u1f38'),
+        ('\u1f31', '\u1f39'), ('\u1f32', '\u1f3a'), ('\u1f33', '\u1f3b'),
+        ('\u1f34', '\u1f3c'), ('\u1f35', '\u1f3d'), ('\u1f36', '\u1f3e'),
+        ('\u1f37', '\u1f3f'), ('\u1f38', '\u1f30'), ('\u1f39', '\u1f31'),
+        ('\u1f3a', '\u1f32'), ('\u1f3b', '\u1f33'), ('\u1f3c', '\u1f34'),
+        ('\u1f3d', '\u1f35'), ('\u1f3e', '\u1f36'), ('\u1f3f', '\u1f37'),
+        ('\u1f40', '\u1f48'), ('\u1f41', '\u1f49'), ('\u1f42', '\u1f4a'),
+        ('\u1f43', '\u1f4b'), ('\u1f44', '\u1f4c'), ('\u1f45', '\u1f4d'),
+        ('\u1f48', '\u1f40'), ('\u1f49', '\u1f41'), ('\u1f4a', '\u1f42'),
+        ('\u1f4b', '\u1f43'), ('\u1f4c', '\u1f44'), ('\u1f4d', '\u1f45'),
+        ('\u1f51', '\u1f59'), ('\u1f53', '\u1f5b'), ('\u1f55', '\u1f5d'),
+        ('\u1f57', '\u1f5f'), ('\u1f59', '\u1f51'), ('\u1f5b', '\u1f53'),
+        ('\u1f5d', '\u1f55'), ('\u1f5f', '\u1f57'), ('\u1f60', '\u1f68'),
+        ('\u1f61', '\u1f69'), ('\u1f62', '\u1f6a'), ('\u1f63', '\u1f6b'),
+        ('\u1f64', '\u1f6c'), ('\u1f65', '\u1f6d'), ('\u1f66', '\u1f6e'),
+        ('\u1f67', '\u1f6f'), ('\u1f68', '\u1f60'), ('\u1f69', '\u1f61'),
+        ('\u1f6a', '\u1f62'), ('\u1f6b', '\u1f63'), ('\u1f6c', '\u1f64'),
+        ('\u1f6d', '\u1f65'), ('\u1f6e', '\u1f66'), ('\u1f6f', '\u1f67'),
+        ('\u1f70', '\u1fba'), ('\u1f71', '\u1fbb'), ('\u1f72', '\u1fc8'),
+        ('\u1f73', '\u1fc9'), ('\u1f74', '\u1fca'), ('\u1f75', '\u1fcb'),
+        ('\u1f76', '\u1fda'), ('\u1f77', '\u1fdb'), ('\u1f78', '\u1ff8'),
+        ('\u1f79', '\u1ff9'), ('\u1f7a', '\u1fea'), ('\u1f7b', '\u1feb'),
+        ('\u1f7c', '\u1ffa'), ('\u1f7d', '\u1ffb'), ('\u1f80', '\u1f88'),
+        ('\u1f81', '\u1f89'), ('\u1f82', '\u1f8a'), ('\u1f83', '\u1f8b'),
+        ('\u1f84', '\u1f8c'), ('\u1f85', '\u1f8d'), ('\u1f86', '\u1f8e'),
+        ('\u1f87', '\u1f8f'), ('\u1f88', '\u1f80'), ('\u1f89', '\u1f81'),
+        ('\u1f8a', '\u1f82'), ('\u1f8b', '\u1f83'), ('\u1f8c', '\u1f84'),
+        ('\u1f8d', '\u1f85'), ('\u1f8e', '\u1f86'), ('\u1f8f', '\u1f87'),
+        ('\u1f90', '\u1f98'), ('\u1f91', '\u1f99'), ('\u1f92', '\u1f9a'),
+        ('\u1f93', '\u1f9b'), ('\u1f94', '\u1f9c'), ('\u1f95', '\u1f9d'),
+        ('\u1f96', '\u1f9e'), ('\u1f97', '\u1f9f'), ('\u1f98', '\u1f90'),
+        ('\u1f99', '\u1f91'), ('\u1f9a', '\u1f92'), ('\u1f9b', '\u1f93'),
+        ('\u1f9c', '\u1f94'), ('\u1f9d', '\u1f95'), ('\u1f9e', '\u1f96'),
+        ('\u1f9f', '\u1f97'), ('\u1fa0', '\u1fa8'), ('\u1fa1', '\u1fa9'),
+        ('\u1fa2', '\u1faa'), ('\u1fa3', '\u1fab'), ('\u1fa4', '\u1fac'),
+        ('\u1fa5', '\u1fad'), ('\u1fa6', '\u1fae'), ('\u1fa7', '\u1faf'),
+        ('\u1fa8', '\u1fa0'), ('\u1fa9', '\u1fa1'), ('\u1faa', '\u1fa2'),
+        ('\u1fab', '\u1fa3'), ('\u1fac', '\u1fa4'), ('\u1fad', '\u1fa5'),
+        ('\u1fae', '\u1fa6'), ('\u1faf', '\u1fa7'), ('\u1fb0', '\u1fb8'),
+        ('\u1fb1', '\u1fb9'), ('\u1fb3', '\u1fbc'), ('\u1fb8', '\u1fb0'),
+        ('\u1fb9', '\u1fb1'), ('\u1fba', '\u1f70'), ('\u1fbb', '\u1f71'),
+        ('\u1fbc', '\u1fb3'), ('\u1fbe', '\u0345'), ('\u1fc3', '\u1fcc'),
+        ('\u1fc8', '\u1f72'), ('\u1fc9', '\u1f73'), ('\u1fca', '\u1f74'),
+        ('\u1fcb', '\u1f75'), ('\u1fcc', '\u1fc3'), ('\u1fd0', '\u1fd8'),
+        ('\u1fd1', '\u1fd9'), ('\u1fd8', '\u1fd0'), ('\u1fd9', '\u1fd1'),
+        ('\u1fda', '\u1f76'), ('\u1fdb', '\u1f77'), ('\u1fe0', '\u1fe8'),
+        ('\u1fe1', '\u1fe9'), ('\u1fe5', '\u1fec'), ('\u1fe8', '\u1fe0'),
+        ('\u1fe9', '\u1fe1'), ('\u1fea', '\u1f7a'), ('\u1feb', '\u1f7b'),
+        ('\u1fec', '\u1fe5'), ('\u1ff3', '\u1ffc'), ('\u1ff8', '\u1f78'),
+        ('\u1ff9', '\u1f79'), ('\u1ffa', '\u1f7c'), ('\u1ffb', '\u1f7d'),
+        ('\u1ffc', '\u1ff3'), ('\u2126', '\u03a9'), ('\u212a', '\u004b'),
+        ('\u212b', '\u00c5'), ('\u2132', '\u214e'), ('\u214e', '\u2132'),
+        ('\u2160', '\u2170'), ('\u2161', '\u2171'), ('\u2162', '\u2172'),
+        ('\u2163', '\u2173'), ('\u2164', '\u2174'), ('\u2165', '\u2175'),
+        ('\u2166', '\u2176'), ('\u2167', '\u2177'), ('\u2168', '\u2178'),
+        ('\u2169', '\u2179'), ('\u216a', '\u217a'), ('\u216b', '\u217b'),
+        ('\u216c', '\u217c'), ('\u216d', '\u217d'), ('\u216e', '\u217e'),
+        ('\u216f', '\u217f'), ('\u2170', '\u2160'), ('\u2171', '\u2161'),
+        ('\u2172', '\u2162'), ('\u2173', '\u2163'), ('\u2174', '\u2164'),
+        ('\u2175', '\u2165'), ('\u2176', '\u2166'), ('\u2177', '\u2167'),
+        ('\u2178', '\u2168'), ('\u2179', '\u2169'), ('\u217a', '\u216a'),
+        ('\u217b', '\u216b'), ('\u217c', '\u216c'), ('\u217d', '\u216d'),
+        ('\u217e', '\u216e'), ('\u217f', '\u216f'), ('\u2183', '\u2184'),
+        ('\u2184', '\u2183'), ('\u24b6', '\u24d0'), ('\u24b7', '\u24d1'),
+        ('\u24b8', '\u24d2'), ('\u24b9', '\u24d3'), ('\u24ba', '\u24d4'),
+        ('\u24bb', '\u24d5'), ('\u24bc', '\u24d6'), ('\u24bd', '\u24d7'),
+        ('\u24be', '\u24d8'), ('\u24bf', '\u24d9'), ('\u24c0', '\u24da'),
+        ('\u24c1', '\u24db'), ('\u24c2', '\u24dc'), ('\u24c3', '\u24dd'),
+        ('\u24c4', '\u24de'), ('\u24c5', '\u24df'), ('\u24c6', '\u24e0'),
+        ('\u24c7', '\u24e1'), ('\u24c8', '\u24e2'), ('\u24c9', '\u24e3'),
+        ('\u24ca', '\u24e4'), ('\u24cb', '\u24e5'), ('\u24cc', '\u24e6'),
+        ('\u24cd', '\u24e7'), ('\u24ce', '\u24e8'), ('\u24cf', '\u24e9'),
+        ('\u24d0', '\u24b6'), ('\u24d1', '\u24b7'), ('\u24d2', '\u24b8'),
+        ('\u24d3', '\u24b9'), ('\u24d4', '\u24ba'), ('\u24d5', '\u24bb'),
+        ('\u24d6', '\u24bc'), ('\u24d7', '\u24bd'), ('\u24d8', '\u24be'),
+        ('\u24d9', '\u24bf'), ('\u24da', '\u24c0'), ('\u24db', '\u24c1'),
+        ('\u24dc', '\u24c2'), ('\u24dd', '\u24c3'), ('\u24de', '\u24c4'),
+        ('\u24df', '\u24c5'), ('\u24e0', '\u24c6'), ('\u24e1', '\u24c7'),
+        ('\u24e2', '\u24c8'), ('\u24e3', '\u24c9'), ('\u24e4', '\u24ca'),
+        ('\u24e5', '\u24cb'), ('\u24e6', '\u24cc'), ('\u24e7', '\u24cd'),
+        ('\u24e8', '\u24ce'), ('\u24e9', '\u24cf'), ('\u2c00', '\u2c30'),
+        ('\u2c01', '\u2c31'), ('\u2c02', '\u2c32'), ('\u2c03', '\u2c33'),
+        ('\u2c04', '\u2c34'), ('\u2c05', '\u2c35'), ('\u2c06', '\u2c36'),
+        ('\u2c07', '\u2c37'), ('\u2c08', '\u2c38'), ('\u2c09', '\u2c39'),
+        ('\u2c0a', '\u2c3a'), ('\u2c0b', '\u2c3b'), ('\u2c0c', '\u2c3c'),
+        ('\u2c0d', '\u2c3d'), ('\u2c0e', '\u2c3e'), ('\u2c0f', '\u2c3f'),
+        ('\u2c10', '\u2c40'), ('\u2c11', '\u2c41'), ('\u2c12', '\u2c42'),
+        ('\u2c13', '\u2c43'), ('\u2c14', '\u2c44'), ('\u2c15', '\u2c45'),
+        ('\u2c16', '\u2c46'), ('\u2c17', '\u2c47'), ('\u2c18', '\u2c48'),
+        ('\u2c19', '\u2c49'), ('\u2c1a', '\u2c4a'), ('\u2c1b', '\u2c4b'),
+        ('\u2c1c', '\u2c4c'), ('\u2c1d', '\u2c4d'), ('\u2c1e', '\u2c4e'),
+        ('\u2c1f', '\u2c4f'), ('\u2c20', '\u2c50'), ('\u2c21', '\u2c51'),
+        ('\u2c22', '\u2c52'), ('\u2c23', '\u2c53'), ('\u2c24', '\u2c54'),
+        ('\u2c25', '\u2c55'), ('\u2c26', '\u2c56'), ('\u2c27', '\u2c57'),
+        ('\u2c28', '\u2c58'), ('\u2c29', '\u2c59'), ('\u2c2a', '\u2c5a'),
+        ('\u2c2b', '\u2c5b'), ('\u2c2c', '\u2c5c'), ('\u2c2d', '\u2c5d'),
+        ('\u2c2e', '\u2c5e'), ('\u2c2f', '\u2c5f'), ('\u2c30', '\u2c00'),
+        ('\u2c31', '\u2c01'), ('\u2c32', '\u2c02'), ('\u2c33', '\u2c03'),
+        ('\u2c34', '\u2c04'), ('\u2c35', '\u2c05'), ('\u2c36', '\u2c06'),
+        ('\u2c37', '\u2c07'), ('\u2c38', '\u2c08'), ('\u2c39', '\u2c09'),
+        ('\u2c3a', '\u2c0a'), ('\u2c3b', '\u2c0b'), ('\u2c3c', '\u2c0c'),
+        ('\u2c3d', '\u2c0d'), ('\u2c3e', '\u2c0e'), ('\u2c3f', '\u2c0f'),
+        ('\u2c40', '\u2c10'), ('\u2c41', '\u2c11'), ('\u2c42', '\u2c12'),
+        ('\u2c43', '\u2c13'), ('\u2c44', '\u2c14'), ('\u2c45', '\u2c15'),
+        ('\u2c46', '\u2c16'), ('\u2c47', '\u2c17'), ('\u2c48', '\u2c18'),
+        ('\u2c49', '\u2c19'), ('\u2c4a', '\u2c1a'), ('\u2c4b', '\u2c1b'),
+        ('\u2c4c', '\u2c1c'), ('\u2c4d', '\u2c1d'), ('\u2c4e', '\u2c1e'),
+        ('\u2c4f', '\u2c1f'), ('\u2c50', '\u2c20'), ('\u2c51', '\u2c21'),
+        ('\u2c52', '\u2c22'), ('\u2c53', '\u2c23'), ('\u2c54', '\u2c24'),
+        ('\u2c55', '\u2c25'), ('\u2c56', '\u2c26'), ('\u2c57', '\u2c27'),
+        ('\u2c58', '\u2c28'), ('\u2c59', '\u2c29'), ('\u2c5a', '\u2c2a'),
+        ('\u2c5b', '\u2c2b'), ('\u2c5c', '\u2c2c'), ('\u2c5d', '\u2c2d'),
+        ('\u2c5e', '\u2c2e'), ('\u2c5f', '\u2c2f'), ('\u2c60', '\u2c61'),
+        ('\u2c61', '\u2c60'), ('\u2c62', '\u026b'), ('\u2c63', '\u1d7d'),
+        ('\u2c64', '\u027d'), ('\u2c65', '\u023a'), ('\u2c66', '\u023e'),
+        ('\u2c67', '\u2c68'), ('\u2c68', '\u2c67'), ('\u2c69', '\u2c6a'),
+        ('\u2c6a', '\u2c69'), ('\u2c6b', '\u2c6c'), ('\u2c6c', '\u2c6b'),
+        ('\u2c6d', '\u0251'), ('\u2c6e', '\u0271'), ('\u2c6f', '\u0250'),
+        ('\u2c70', '\u0252'), ('\u2c72', '\u2c73'), ('\u2c73', '\u2c72'),
+        ('\u2c75', '\u2c76'), ('\u2c76', '\u2c75'), ('\u2c7e', '\u023f'),
+        ('\u2c7f', '\u0240'), ('\u2c80', '\u2c81'), ('\u2c81', '\u2c80'),
+        ('\u2c82', '\u2c83'), ('\u2c83', '\u2c82'), ('\u2c84', '\u2c85'),
+        ('\u2c85', '\u2c84'), ('\u2c86', '\u2c87'), ('\u2c87', '\u2c86'),
+        ('\u2c88', '\u2c89'), ('\u2c89', '\u2c88'), ('\u2c8a', '\u2c8b'),
+        ('\u2c8b', '\u2c8a'), ('\u2c8c', '\u2c8d'), ('\u2c8d', '\u2c8c'),
+        ('\u2c8e', '\u2c8f'), ('\u2c8f', '\u2c8e'), ('\u2c90', '\u2c91'),
+        ('\u2c91', '\u2c90'), ('\u2c92', '\u2c93'), ('\u2c93', '\u2c92'),
+        ('\u2c94', '\u2c95'), ('\u2c95', '\u2c94'), ('\u2c96', '\u2c97'),
+        ('\u2c97', '\u2c96'), ('\u2c98', '\u2c99'), ('\u2c99', '\u2c98'),
+        ('\u2c9a', '\u2c9b'), ('\u2c9b', '\u2c9a'), ('\u2c9c', '\u2c9d'),
+        ('\u2c9d', '\u2c9c'), ('\u2c9e', '\u2c9f'), ('\u2c9f', '\u2c9e'),
+        ('\u2ca0', '\u2ca1'), ('\u2ca1', '\u2ca0'), ('\u2ca2', '\u2ca3'),
+        ('\u2ca3', '\u2ca2'), ('\u2ca4', '\u2ca5'), ('\u2ca5', '\u2ca4'),
+        ('\u2ca6', '\u2ca7'), ('\u2ca7', '\u2ca6'), ('\u2ca8', '\u2ca9'),
+        ('\u2ca9', '\u2ca8'), ('\u2caa', '\u2cab'), ('\u2cab', '\u2caa'),
+        ('\u2cac', '\u2cad'), ('\u2cad', '\u2cac'), ('\u2cae', '\u2caf'),
+        ('\u2caf', '\u2cae'), ('\u2cb0', '\u2cb1'), ('\u2cb1', '\u2cb0'),
+        ('\u2cb2', '\u2cb3'), ('\u2cb3', '\u2cb2'), ('\u2cb4', '\u2cb5'),
+        ('\u2cb5', '\u2cb4'), ('\u2cb6', '\u2cb7'), ('\u2cb7', '\u2cb6'),
+        ('\u2cb8', '\u2cb9'), ('\u2cb9', '\u2cb8'), ('\u2cba', '\u2cbb'),
+        ('\u2cbb', '\u2cba'), ('\u2cbc', '\u2cbd'), ('\u2cbd', '\u2cbc'),
+        ('\u2cbe', '\u2cbf'), ('\u2cbf', '\u2cbe'), ('\u2cc0', '\u2cc1'),
+        ('\u2cc1', '\u2cc0'), ('\u2cc2', '\u2cc3'), ('\u2cc3', '\u2cc2'),
+        ('\u2cc4', '\u2cc5'), ('\u2cc5', '\u2cc4'), ('\u2cc6', '\u2cc7'),
+        ('\u2cc7', '\u2cc6'), ('\u2cc8', '\u2cc9'), ('\u2cc9', '\u2cc8'),
+        ('\u2cca', '\u2ccb'), ('\u2ccb', '\u2cca'), ('\u2ccc', '\u2ccd'),
+        ('\u2ccd', '\u2ccc'), ('\u2cce', '\u2ccf'), ('\u2ccf', '\u2cce'),
+        ('\u2cd0', '\u2cd1'), ('\u2cd1', '\u2cd0'), ('\u2cd2', '\u2cd3'),
+        ('\u2cd3', '\u2cd2'), ('\u2cd4', '\u2cd5'), ('\u2cd5', '\u2cd4'),
+        ('\u2cd6', '\u2cd7'), ('\u2cd7', '\u2cd6'), ('\u2cd8', '\u2cd9'),
+        ('\u2cd9', '\u2cd8'), ('\u2cda', '\u2cdb'), ('\u2cdb', '\u2cda'),
+        ('\u2cdc', '\u2cdd'), ('\u2cdd', '\u2cdc'), ('\u2cde', '\u2cdf'),
+        ('\u2cdf', '\u2cde'), ('\u2ce0', '\u2ce1'), ('\u2ce1', '\u2ce0'),
+        ('\u2ce2', '\u2ce3'), ('\u2ce3', '\u2ce2'), ('\u2ceb', '\u2cec'),
+        ('\u2cec', '\u2ceb'), ('\u2ced', '\u2cee'), ('\u2cee', '\u2ced'),
+        ('\u2cf2', '\u2cf3'), ('\u2cf3', '\u2cf2'), ('\u2d00', '\u10a0'),
+        ('\u2d01', '\u10a1'), ('\u2d02', '\u10a2'), ('\u2d03', '\u10a3'),
+        ('\u2d04', '\u10a4'), ('\u2d05', '\u10a5'), ('\u2d06', '\u10a6'),
+        ('\u2d07', '\u10a7'), ('\u2d08', '\u10a8'), ('\u2d09', '\u10a9'),
+        ('\u2d0a', '\u10aa'), ('\u2d0b', '\u10ab'), ('\u2d0c', '\u10ac'),
+        ('\u2d0d', '\u10ad'), ('\u2d0e', '\u10ae'), ('\u2d0f', '\u10af'),
+        ('\u2d10', '\u10b0'), ('\u2d11', '\u10b1'), ('\u2d12', '\u10b2'),
+        ('\u2d13', '\u10b3'), ('\u2d14', '\u10b4'), ('\u2d15', '\u10b5'),
+        ('\u2d16', '\u10b6'), ('\u2d17', '\u10b7'), ('\u2d18', '\u10b8'),
+        ('\u2d19', '\u10b9'), ('\u2d1a', '\u10ba'), ('\u2d1b', '\u10bb'),
+        ('\u2d1c', '\u10bc'), ('\u2d1d', '\u10bd'), ('\u2d1e', '\u10be'),
+        ('\u2d1f', '\u10bf'), ('\u2d20', '\u10c0'), ('\u2d21', '\u10c1'),
+        ('\u2d22', '\u10c2'), ('\u2d23', '\u10c3'), ('\u2d24', '\u10c4'),
+        ('\u2d25', '\u10c5'), ('\u2d27', '\u10c7'), ('\u2d2d', '\u10cd'),
+        ('\ua640', '\ua641'), ('\ua641', '\ua640'), ('\ua642', '\ua643'),
+        ('\ua643', '\ua642'), ('\ua644', '\ua645'), ('\ua645', '\ua644'),
+        ('\ua646', '\ua647'), ('\ua647', '\ua646'), ('\ua648', '\ua649'),
+        ('\ua649', '\ua648'), ('\ua64a', '\ua64b'), ('\ua64b', '\u1c88'),
+        ('\ua64c', '\ua64d'), ('\ua64d', '\ua64c'), ('\ua64e', '\ua64f'),
+        ('\ua64f', '\ua64e'), ('\ua650', '\ua651'), ('\ua651', '\ua650'),
+        ('\ua652', '\ua653'), ('\ua653', '\ua652'), ('\ua654', '\ua655'),
+        ('\ua655', '\ua654'), ('\ua656', '\ua657'), ('\ua657', '\ua656'),
+        ('\ua658', '\ua659'), ('\ua659', '\ua658'), ('\ua65a', '\ua65b'),
+        ('\ua65b', '\ua65a'), ('\ua65c', '\ua65d'), ('\ua65d', '\ua65c'),
+        ('\ua65e', '\ua65f'), ('\ua65f', '\ua65e'), ('\ua660', '\ua661'),
+        ('\ua661', '\ua660'), ('\ua662', '\ua663'), ('\ua663', '\ua662'),
+        ('\ua664', '\ua665'), ('\ua665', '\ua664'), ('\ua666', '\ua667'),
+        ('\ua667', '\ua666'), ('\ua668', '\ua669'), ('\ua669', '\ua668'),
+        ('\ua66a', '\ua66b'), ('\ua66b', '\ua66a'), ('\ua66c', '\ua66d'),
+        ('\ua66d', '\ua66c'), ('\ua680', '\ua681'), ('\ua681', '\ua680'),
+        ('\ua682', '\ua683'), ('\ua683', '\ua682'), ('\ua684', '\ua685'),
+        ('\ua685', '\ua684'), ('\ua686', '\ua687'), ('\ua687', '\ua686'),
+        ('\ua688', '\ua689'), ('\ua689', '\ua688'), ('\ua68a', '\ua68b'),
+        ('\ua68b', '\ua68a'), ('\ua68c', '\ua68d'), ('\ua68d', '\ua68c'),
+        ('\ua68e', '\ua68f'), ('\ua68f', '\ua68e'), ('\ua690', '\ua691'),
+        ('\ua691', '\ua690'), ('\ua692', '\ua693'), ('\ua693', '\ua692'),
+        ('\ua694', '\ua695'), ('\ua695', '\ua694'), ('\ua696', '\ua697'),
+        ('\ua697', '\ua696'), ('\ua698', '\ua699'), ('\ua699', '\ua698'),
+        ('\ua69a', '\ua69b'), ('\ua69b', '\ua69a'), ('\ua722', '\ua723'),
+        ('\ua723', '\ua722'), ('\ua724', '\ua725'), ('\ua725', '\ua724'),
+        ('\ua726', '\ua727'), ('\ua727', '\ua726'), ('\ua728', '\ua729'),
+        ('\ua729', '\ua728'), ('\ua72a', '\ua72b'), ('\ua72b', '\ua72a'),
+        ('\ua72c', '\ua72d'), ('\ua72d', '\ua72c'), ('\ua72e', '\ua72f'),
+        ('\ua72f', '\ua72e'), ('\ua732', '\ua733'), ('\ua733', '\ua732'),
+        ('\ua734', '\ua735'), ('\ua735', '\ua734'), ('\ua736', '\ua737'),
+        ('\ua737', '\ua736'), ('\ua738', '\ua739'), ('\ua739', '\ua738'),
+        ('\ua73a', '\ua73b'), ('\ua73b', '\ua73a'), ('\ua73c', '\ua73d'),
+        ('\ua73d', '\ua73c'), ('\ua73e', '\ua73f'), ('\ua73f', '\ua73e'),
+        ('\ua740', '\ua741'), ('\ua741', '\ua740'), ('\ua742', '\ua743'),
+        ('\ua743', '\ua742'), ('\ua744', '\ua745'), ('\ua745', '\ua744'),
+        ('\ua746', '\ua747'), ('\ua747', '\ua746'), ('\ua748', '\ua749'),
+        ('\ua749', '\ua748'), ('\ua74a', '\ua74b'), ('\ua74b', '\ua74a'),
+        ('\ua74c', '\ua74d'), ('\ua74d', '\ua74c'), ('\ua74e', '\ua74f'),
+        ('\ua74f', '\ua74e'), ('\ua750', '\ua751'), ('\ua751', '\ua750'),
+        ('\ua752', '\ua753'), ('\ua753', '\ua752'), ('\ua754', '\ua755'),
+        ('\ua755', '\ua754'), ('\ua756', '\ua757'), ('\ua757', '\ua756'),
+        ('\ua758', '\ua759'), ('\ua759', '\ua758'), ('\ua75a', '\ua75b'),
+        ('\ua75b', '\ua75a'), ('\ua75c', '\ua75d'), ('\ua75d', '\ua75c'),
+        ('\ua75e', '\ua75f'), ('\ua75f', '\ua75e'), ('\ua760', '\ua761'),
+        ('\ua761', '\ua760'), ('\ua762', '\ua763'), ('\ua763', '\ua762'),
+        ('\ua764', '\ua765'), ('\ua765', '\ua764'), ('\ua766', '\ua767'),
+        ('\ua767', '\ua766'), ('\ua768', '\ua769'), ('\ua769', '\ua768'),
+        ('\ua76a', '\ua76b'), ('\ua76b', '\ua76a'), ('\ua76c', '\ua76d'),
+        ('\ua76d', '\ua76c'), ('\ua76e', '\ua76f'), ('\ua76f', '\ua76e'),
+        ('\ua779', '\ua77a'), ('\ua77a', '\ua779'), ('\ua77b', '\ua77c'),
+        ('\ua77c', '\ua77b'), ('\ua77d', '\u1d79'), ('\ua77e', '\ua77f'),
+        ('\ua77f', '\ua77e'), ('\ua780', '\ua781'), ('\ua781', '\ua780'),
+        ('\ua782', '\ua783'), ('\ua783', '\ua782'), ('\ua784', '\ua785'),
+        ('\ua785', '\ua784'), ('\ua786', '\ua787'), ('\ua787', '\ua786'),
+        ('\ua78b', '\ua78c'), ('\ua78c', '\ua78b'), ('\ua78d', '\u0265'),
+        ('\ua790', '\ua791'), ('\ua791', '\ua790'), ('\ua792', '\ua793'),
+        ('\ua793', '\ua792'), ('\ua794', '\ua7c4'), ('\ua796', '\ua797'),
+        ('\ua797', '\ua796'), ('\ua798', '\ua799'), ('\ua799', '\ua798'),
+        ('\ua79a', '\ua79b'), ('\ua79b', '\ua79a'), ('\ua79c', '\ua79d'),
+        ('\ua79d', '\ua79c'), ('\ua79e', '\ua79f'), ('\ua79f', '\ua79e'),
+        ('\ua7a0', '\ua7a1'), ('\ua7a1', '\ua7a0'), ('\ua7a2', '\ua7a3'),
+        ('\ua7a3', '\ua7a2'), ('\ua7a4', '\ua7a5'), ('\ua7a5', '\ua7a4'),
+        ('\ua7a6', '\ua7a7'), ('\ua7a7', '\ua7a6'), ('\ua7a8', '\ua7a9'),
+        ('\ua7a9', '\ua7a8'), ('\ua7aa', '\u0266'), ('\ua7ab', '\u025c'),
+        ('\ua7ac', '\u0261'), ('\ua7ad', '\u026c'), ('\ua7ae', '\u026a'),
+        ('\ua7b0', '\u029e'), ('\ua7b1', '\u0287'), ('\ua7b2', '\u029d'),
+        ('\ua7b3', '\uab53'), ('\ua7b4', '\ua7b5'), ('\ua7b5', '\ua7b4'),
+        ('\ua7b6', '\ua7b7'), ('\ua7b7', '\ua7b6'), ('\ua7b8', '\ua7b9'),
+        ('\ua7b9', '\ua7b8'), ('\ua7ba', '\ua7bb'), ('\ua7bb', '\ua7ba'),
+        ('\ua7bc', '\ua7bd'), ('\ua7bd', '\ua7bc'), ('\ua7be', '\ua7bf'),
+        ('\ua7bf', '\ua7be'), ('\ua7c0', '\ua7c1'), ('\ua7c1', '\ua7c0'),
+        ('\ua7c2', '\ua7c3'), ('\ua7c3', '\ua7c2'), ('\ua7c4', '\ua794'),
+        ('\ua7c5', '\u0282'), ('\ua7c6', '\u1d8e'), ('\ua7c7', '\ua7c8'),
+        ('\ua7c8', '\ua7c7'), ('\ua7c9', '\ua7ca'), ('\ua7ca', '\ua7c9'),
+        ('\ua7d0', '\ua7d1'), ('\ua7d1', '\ua7d0'), ('\ua7d6', '\ua7d7'),
+        ('\ua7d7', '\ua7d6'), ('\ua7d8', '\ua7d9'), ('\ua7d9', '\ua7d8'),
+        ('\ua7f5', '\ua7f6'), ('\ua7f6', '\ua7f5'), ('\uab53', '\ua7b3'),
+        ('\uab70', '\u13a0'), ('\uab71', '\u13a1'), ('\uab72', '\u13a2'),
+        ('\uab73', '\u13a3'), ('\uab74', '\u13a4'), ('\uab75', '\u13a5'),
+        ('\uab76', '\u13a6'), ('\uab77', '\u13a7'), ('\uab78', '\u13a8'),
+        ('\uab79', '\u13a9'), ('\uab7a', '\u13aa'), ('\uab7b', '\u13ab'),
+        ('\uab7c', '\u13ac'), ('\uab7d', '\u13ad'), ('\uab7e', '\u13ae'),
+        ('\uab7f', '\u13af'), ('\uab80', '\u13b0'), ('\uab81', '\u13b1'),
+        ('\uab82', '\u13b2'), ('\uab83', '\u13b3'), ('\uab84', '\u13b4'),
+        ('\uab85', '\u13b5'), ('\uab86', '\u13b6'), ('\uab87', '\u13b7'),
+        ('\uab88', '\u13b8'), ('\uab89', '\u13b9'), ('\uab8a', '\u13ba'),
+        ('\uab8b', '\u13bb'), ('\uab8c', '\u13bc'), ('\uab8d', '\u13bd'),
+        ('\uab8e', '\u13be'), ('\uab8f', '\u13bf'), ('\uab90', '\u13c0'),
+        ('\uab91', '\u13c1'), ('\uab92', '\u13c2'), ('\uab93', '\u13c3'),
+        ('\uab94', '\u13c4'), ('\uab95', '\u13c5'), ('\uab96', '\u13c6'),
+        ('\uab97', '\u13c7'), ('\uab98', '\u13c8'), ('\uab99', '\u13c9'),
+        ('\uab9a', '\u13ca'), ('\uab9b', '\u13cb'), ('\uab9c', '\u13cc'),
+        ('\uab9d', '\u13cd'), ('\uab9e', '\u13ce'), ('\uab9f', '\u13cf'),
+        ('\uaba0', '\u13d0'), ('\uaba1', '\u13d1'), ('\uaba2', '\u13d2'),
+        ('\uaba3', '\u13d3'), ('\uaba4', '\u13d4'), ('\uaba5', '\u13d5'),
+        ('\uaba6', '\u13d6'), ('\uaba7', '\u13d7'), ('\uaba8', '\u13d8'),
+        ('\uaba9', '\u13d9'), ('\uabaa', '\u13da'), ('\uabab', '\u13db'),
+        ('\uabac', '\u13dc'), ('\uabad', '\u13dd'), ('\uabae', '\u13de'),
+        ('\uabaf', '\u13df'), ('\uabb0', '\u13e0'), ('\uabb1', '\u13e1'),
+        ('\uabb2', '\u13e2'), ('\uabb3', '\u13e3'), ('\uabb4', '\u13e4'),
+        ('\uabb5', '\u13e5'), ('\uabb6', '\u13e6'), ('\uabb7', '\u13e7'),
+        ('\uabb8', '\u13e8'), ('\uabb9', '\u13e9'), ('\uabba', '\u13ea'),
+        ('\uabbb', '\u13eb'), ('\uabbc', '\u13ec'), ('\uabbd', '\u13ed'),
+        ('\uabbe', '\u13ee'), ('\uabbf', '\u13ef'), ('\uff21', '\uff41'),
+        ('\uff22', '\uff42'), ('\uff23', '\uff43'), ('\uff24', '\uff44'),
+        ('\uff25', '\uff45'), ('\uff26', '\uff46'), ('\uff27', '\uff47'),
+        ('\uff28', '\uff48'), ('\uff29', '\uff49'), ('\uff2a', '\uff4a'),
+        ('\uff2b', '\uff4b'), ('\uff2c', '\uff4c'), ('\uff2d', '\uff4d'),
+        ('\uff2e', '\uff4e'), ('\uff2f', '\uff4f'), ('\uff30', '\uff50'),
+        ('\uff31', '\uff51'), ('\uff32', '\uff52'), ('\uff33', '\uff53'),
+        ('\uff34', '\uff54'), ('\uff35', '\uff55'), ('\uff36', '\uff56'),
+        ('\uff37', '\uff57'), ('\uff38', '\uff58'), ('\uff39', '\uff59'),
+        ('\uff3a', '\uff5a'), ('\uff41', '\uff21'), ('\uff42', '\uff22'),
+        ('\uff43', '\uff23'), ('\uff44', '\uff24'), ('\uff45', '\uff25'),
+        ('\uff46', '\uff26'), ('\uff47', '\uff27'), ('\uff48', '\uff28'),
+        ('\uff49', '\uff29'), ('\uff4a', '\uff2a'), ('\uff4b', '\uff2b'),
+        ('\uff4c', '\uff2c'), ('\uff4d', '\uff2d'), ('\uff4e', '\uff2e'),
+        ('\uff4f', '\uff2f'), ('\uff50', '\uff30'), ('\uff51', '\uff31'),
+        ('\uff52', '\uff32'), ('\uff53', '\uff33'), ('\uff54', '\uff34'),
+        ('\uff55', '\uff35'), ('\uff56', '\uff36'), ('\uff57', '\uff37'),
+        ('\uff58', '\uff38'), ('\uff59', '\uff39'), ('\uff5a', '\uff3a'),
+        ('\U00010400', '\U00010428'), ('\U00010401', '\U00010429'), ('\U00010402', '\U0001042a'),
+        ('\U00010403', '\U0001042b'), ('\U00010404', '\U0001042c'), ('\U00010405', '\U0001042d'),
+        ('\U00010406', '\U0001042e'), ('\U00010407', '\U0001042f'), ('\U00010408', '\U00010430'),
+        ('\U00010409', '\U00010431'), ('\U0001040a', '\U00010432'), ('\U0001040b', '\U00010433'),
+        ('\U0001040c', '\U00010434'), ('\U0001040d', '\U00010435'), ('\U0001040e', '\U00010436'),
+        ('\U0001040f', '\U00010437'), ('\U00010410', '\U00010438'), ('\U00010411', '\U00010439'),
+        ('\U00010412', '\U0001043a'), ('\U00010413', '\U0001043b'), ('\U00010414', '\U0001043c'),
+        ('\U00010415', '\U0001043d'), ('\U00010416', '\U0001043e'), ('\U00010417', '\U0001043f'),
+        ('\U00010418', '\U00010440'), ('\U00010419', '\U00010441'), ('\U0001041a', '\U00010442'),
+        ('\U0001041b', '\U00010443'), ('\U0001041c', '\U00010444'), ('\U0001041d', '\U00010445'),
+        ('\U0001041e', '\U00010446'), ('\U0001041f', '\U00010447'), ('\U00010420', '\U00010448'),
+        ('\U00010421', '\U00010449'), ('\U00010422', '\U0001044a'), ('\U00010423', '\U0001044b'),
+        ('\U00010424', '\U0001044c'), ('\U00010425', '\U0001044d'), ('\U00010426', '\U0001044e'),
+        ('\U00010427', '\U0001044f'), ('\U00010428', '\U00010400'), ('\U00010429', '\U00010401'),
+        ('\U0001042a', '\U00010402'), ('\U0001042b', '\U00010403'), ('\U0001042c', '\U00010404'),
+        ('\U0001042d', '\U00010405'), ('\U0001042e', '\U00010406'), ('\U0001042f', '\U00010407'),
+        ('\U00010430', '\U00010408'), ('\U00010431', '\U00010409'), ('\U00010432', '\U0001040a'),
+        ('\U00010433', '\U0001040b'), ('\U00010434', '\U0001040c'), ('\U00010435', '\U0001040d'),
+        ('\U00010436', '\U0001040e'), ('\U00010437', '\U0001040f'), ('\U00010438', '\U00010410'),
+        ('\U00010439', '\U00010411'), ('\U0001043a', '\U00010412'), ('\U0001043b', '\U00010413'),
+        ('\U0001043c', '\U00010414'), ('\U0001043d', '\U00010415'), ('\U0001043e', '\U00010416'),
+        ('\U0001043f', '\U00010417'), ('\U00010440', '\U00010418'), ('\U00010441', '\U00010419'),
+        ('\U00010442', '\U0001041a'), ('\U00010443', '\U0001041b'), ('\U00010444', '\U0001041c'),
+        ('\U00010445', '\U0001041d'), ('\U00010446', '\U0001041e'), ('\U00010447', '\U0001041f'),
+        ('\U00010448', '\U00010420'), ('\U00010449', '\U00010421'), ('\U0001044a', '\U00010422'),
+        ('\U0001044b', '\U00010423'), ('\U0001044c', '\U00010424'), ('\U0001044d', '\U00010425'),
+        ('\U0001044e', '\U00010426'), ('\U0001044f', '\U00010427'), ('\U000104b0', '\U000104d8'),
+        ('\U000104b1', '\U000104d9'), ('\U000104b2', '\U000104da'), ('\U000104b3', '\U000104db'),
+        ('\U000104b4', '\U000104dc'), ('\U000104b5', '\U000104dd'), ('\U000104b6', '\U000104de'),
+        ('\U000104b7', '\U000104df'), ('\U000104b8', '\U000104e0'), ('\U000104b9', '\U000104e1'),
+        ('\U000104ba', '\U000104e2'), ('\U000104bb', '\U000104e3'), ('\U000104bc', '\U000104e4'),
+        ('\U000104bd', '\U000104e5'), ('\U000104be', '\U000104e6'), ('\U000104bf', '\U000104e7'),
+        ('\U000104c0', '\U000104e8'), ('\U000104c1', '\U000104e9'), ('\U000104c2', '\U000104ea'),
+        ('\U000104c3', '\U000104eb'), ('\U000104c4', '\U000104ec'), ('\U000104c5', '\U000104ed'),
+        ('\U000104c6', '\U000104ee'), ('\U000104c7', '\U000104ef'), ('\U000104c8', '\U000104f0'),
+        ('\U000104c9', '\U000104f1'), ('\U000104ca', '\U000104f2'), ('\U000104cb', '\U000104f3'),
+        ('\U000104cc', '\U000104f4'), ('\U000104cd', '\U000104f5'), ('\U000104ce', '\U000104f6'),
+        ('\U000104cf', '\U000104f7'), ('\U000104d0', '\U000104f8'), ('\U000104d1', '\U000104f9'),
+        ('\U000104d2', '\U000104fa'), ('\U000104d3', '\U000104fb'), ('\U000104d8', '\U000104b0'),
+        ('\U000104d9', '\U000104b1'), ('\U000104da', '\U000104b2'), ('\U000104db', '\U000104b3'),
+        ('\U000104dc', '\U000104b4'), ('\U000104dd', '\U000104b5'), ('\U000104de', '\U000104b6'),
+        ('\U000104df', '\U000104b7'), ('\U000104e0', '\U000104b8'), ('\U000104e1', '\U000104b9'),
+        ('\U000104e2', '\U000104ba'), ('\U000104e3', '\U000104bb'), ('\U000104e4', '\U000104bc'),
+        ('\U000104e5', '\U000104bd'), ('\U000104e6', '\U000104be'), ('\U000104e7', '\U000104bf'),
+        ('\U000104e8', '\U000104c0'), ('\U000104e9', '\U000104c1'), ('\U000104ea', '\U000104c2'),
+        ('\U000104eb', '\U000104c3'), ('\U000104ec', '\U000104c4'), ('\U000104ed', '\U000104c5'),
+        ('\U000104ee', '\U000104c6'), ('\U000104ef', '\U000104c7'), ('\U000104f0', '\U000104c8'),
+        ('\U000104f1', '\U000104c9'), ('\U000104f2', '\U000104ca'), ('\U000104f3', '\U000104cb'),
+        ('\U000104f4', '\U000104cc'), ('\U000104f5', '\U000104cd'), ('\U000104f6', '\U000104ce'),
+        ('\U000104f7', '\U000104cf'), ('\U000104f8', '\U000104d0'), ('\U000104f9', '\U000104d1'),
+        ('\U000104fa', '\U000104d2'), ('\U000104fb', '\U000104d3'), ('\U00010570', '\U00010597'),
+        ('\U00010571', '\U00010598'), ('\U00010572', '\U00010599'), ('\U00010573', '\U0001059a'),
+        ('\U00010574', '\U0001059b'), ('\U00010575', '\U0001059c'), ('\U00010576', '\U0001059d'),
+        ('\U00010577', '\U0001059e'), ('\U00010578', '\U0001059f'), ('\U00010579', '\U000105a0'),
+        ('\U0001057a', '\U000105a1'), ('\U0001057c', '\U000105a3'), ('\U0001057d', '\U000105a4'),
+        ('\U0001057e', '\U000105a5'), ('\U0001057f', '\U000105a6'), ('\U00010580', '\U000105a7'),
+        ('\U00010581', '\U000105a8'), ('\U00010582', '\U000105a9'), ('\U00010583', '\U000105aa'),
+        ('\U00010584', '\U000105ab'), ('\U00010585', '\U000105ac'), ('\U00010586', '\U000105ad'),
+        ('\U00010587', '\U000105ae'), ('\U00010588', '\U000105af'), ('\U00010589', '\U000105b0'),
+        ('\U0001058a', '\U000105b1'), ('\U0001058c', '\U000105b3'), ('\U0001058d', '\U000105b4'),
+        ('\U0001058e', '\U000105b5'), ('\U0001058f', '\U000105b6'), ('\U00010590', '\U000105b7'),
+        ('\U00010591', '\U000105b8'), ('\U00010592', '\U000105b9'), ('\U00010594', '\U000105bb'),
+        ('\U00010595', '\U000105bc'), ('\U00010597', '\U00010570'), ('\U00010598', '\U00010571'),
+        ('\U00010599', '\U00010572'), ('\U0001059a', '\U00010573'), ('\U0001059b', '\U00010574'),
+        ('\U0001059c', '\U00010575'), ('\U0001059d', '\U00010576'), ('\U0001059e', '\U00010577'),
+        ('\U0001059f', '\U00010578'), ('\U000105a0', '\U00010579'), ('\U000105a1', '\U0001057a'),
+        ('\U000105a3', '\U0001057c'), ('\U000105a4', '\U0001057d'), ('\U000105a5', '\U0001057e'),
+        ('\U000105a6', '\U0001057f'), ('\U000105a7', '\U00010580'), ('\U000105a8', '\U00010581'),
+        ('\U000105a9', '\U00010582'), ('\U000105aa', '\U00010583'), ('\U000105ab', '\U00010584'),
+        ('\U000105ac', '\U00010585'), ('\U000105ad', '\U00010586'), ('\U000105ae', '\U00010587'),
+        ('\U000105af', '\U00010588'), ('\U000105b0', '\U00010589'), ('\U000105b1', '\U0001058a'),
+        ('\U000105b3', '\U0001058c'), ('\U000105b4', '\U0001058d'), ('\U000105b5', '\U0001058e'),
+        ('\U000105b6', '\U0001058f'), ('\U000105b7', '\U00010590'), ('\U000105b8', '\U00010591'),
+        ('\U000105b9', '\U00010592'), ('\U000105bb', '\U00010594'), ('\U000105bc', '\U00010595'),
+        ('\U00010c80', '\U00010cc0'), ('\U00010c81', '\U00010cc1'), ('\U00010c82', '\U00010cc2'),
+        ('\U00010c83', '\U00010cc3'), ('\U00010c84', '\U00010cc4'), ('\U00010c85', '\U00010cc5'),
+        ('\U00010c86', '\U00010cc6'), ('\U00010c87', '\U00010cc7'), ('\U00010c88', '\U00010cc8'),
+        ('\U00010c89', '\U00010cc9'), ('\U00010c8a', '\U00010cca'), ('\U00010c8b', '\U00010ccb'),
+        ('\U00010c8c', '\U00010ccc'), ('\U00010c8d', '\U00010ccd'), ('\U00010c8e', '\U00010cce'),
+        ('\U00010c8f', '\U00010ccf'), ('\U00010c90', '\U00010cd0'), ('\U00010c91', '\U00010cd1'),
+        ('\U00010c92', '\U00010cd2'), ('\U00010c93', '\U00010cd3'), ('\U00010c94', '\U00010cd4'),
+        ('\U00010c95', '\U00010cd5'), ('\U00010c96', '\U00010cd6'), ('\U00010c97', '\U00010cd7'),
+        ('\U00010c98', '\U00010cd8'), ('\U00010c99', '\U00010cd9'), ('\U00010c9a', '\U00010cda'),
+        ('\U00010c9b', '\U00010cdb'), ('\U00010c9c', '\U00010cdc'), ('\U00010c9d', '\U00010cdd'),
+        ('\U00010c9e', '\U00010cde'), ('\U00010c9f', '\U00010cdf'), ('\U00010ca0', '\U00010ce0'),
+        ('\U00010ca1', '\U00010ce1'), ('\U00010ca2', '\U00010ce2'), ('\U00010ca3', '\U00010ce3'),
+        ('\U00010ca4', '\U00010ce4'), ('\U00010ca5', '\U00010ce5'), ('\U00010ca6', '\U00010ce6'),
+        ('\U00010ca7', '\U00010ce7'), ('\U00010ca8', '\U00010ce8'), ('\U00010ca9', '\U00010ce9'),
+        ('\U00010caa', '\U00010cea'), ('\U00010cab', '\U00010ceb'), ('\U00010cac', '\U00010cec'),
+        ('\U00010cad', '\U00010ced'), ('\U00010cae', '\U00010cee'), ('\U00010caf', '\U00010cef'),
+        ('\U00010cb0', '\U00010cf0'), ('\U00010cb1', '\U00010cf1'), ('\U00010cb2', '\U00010cf2'),
+        ('\U00010cc0', '\U00010c80'), ('\U00010cc1', '\U00010c81'), ('\U00010cc2', '\U00010c82'),
+        ('\U00010cc3', '\U00010c83'), ('\U00010cc4', '\U00010c84'), ('\U00010cc5', '\U00010c85'),
+        ('\U00010cc6', '\U00010c86'), ('\U00010cc7', '\U00010c87'), ('\U00010cc8', '\U00010c88'),
+        ('\U00010cc9', '\U00010c89'), ('\U00010cca', '\U00010c8a'), ('\U00010ccb', '\U00010c8b'),
+        ('\U00010ccc', '\U00010c8c'), ('\U00010ccd', '\U00010c8d'), ('\U00010cce', '\U00010c8e'),
+        ('\U00010ccf', '\U00010c8f'), ('\U00010cd0', '\U00010c90'), ('\U00010cd1', '\U00010c91'),
+        ('\U00010cd2', '\U00010c92'), ('\U00010cd3', '\U00010c93'), ('\U00010cd4', '\U00010c94'),
+        ('\U00010cd5', '\U00010c95'), ('\U00010cd6', '\U00010c96'), ('\U00010cd7', '\U00010c97'),
+        ('\U00010cd8', '\U00010c98'), ('\U00010cd9', '\U00010c99'), ('\U00010cda', '\U00010c9a'),
+        ('\U00010cdb', '\U00010c9b'), ('\U00010cdc', '\U00010c9c'), ('\U00010cdd', '\U00010c9d'),
+        ('\U00010cde', '\U00010c9e'), ('\U00010cdf', '\U00010c9f'), ('\U00010ce0', '\U00010ca0'),
+        ('\U00010ce1', '\U00010ca1'), ('\U00010ce2', '\U00010ca2'), ('\U00010ce3', '\U00010ca3'),
+        ('\U00010ce4', '\U00010ca4'), ('\U00010ce5', '\U00010ca5'), ('\U00010ce6', '\U00010ca6'),
+        ('\U00010ce7', '\U00010ca7'), ('\U00010ce8', '\U00010ca8'), ('\U00010ce9', '\U00010ca9'),
+        ('\U00010cea', '\U00010caa'), ('\U00010ceb', '\U00010cab'), ('\U00010cec', '\U00010cac'),
+        ('\U00010ced', '\U00010cad'), ('\U00010cee', '\U00010cae'), ('\U00010cef', '\U00010caf'),
+        ('\U00010cf0', '\U00010cb0'), ('\U00010cf1', '\U00010cb1'), ('\U00010cf2', '\U00010cb2'),
+        ('\U000118a0', '\U000118c0'), ('\U000118a1', '\U000118c1'), ('\U000118a2', '\U000118c2'),
+        ('\U000118a3', '\U000118c3'), ('\U000118a4', '\U000118c4'), ('\U000118a5', '\U000118c5'),
+        ('\U000118a6', '\U000118c6'), ('\U000118a7', '\U000118c7'), ('\U000118a8', '\U000118c8'),
+        ('\U000118a9', '\U000118c9'), ('\U000118aa', '\U000118ca'), ('\U000118ab', '\U000118cb'),
+        ('\U000118ac', '\U000118cc'), ('\U000118ad', '\U000118cd'), ('\U000118ae', '\U000118ce'),
+        ('\U000118af', '\U000118cf'), ('\U000118b0', '\U000118d0'), ('\U000118b1', '\U000118d1'),
+        ('\U000118b2', '\U000118d2'), ('\U000118b3', '\U000118d3'), ('\U000118b4', '\U000118d4'),
+        ('\U000118b5', '\U000118d5'), ('\U000118b6', '\U000118d6'), ('\U000118b7', '\U000118d7'),
+        ('\U000118b8', '\U000118d8'), ('\U000118b9', '\U000118d9'), ('\U000118ba', '\U000118da'),
+        ('\U000118bb', '\U000118db'), ('\U000118bc', '\U000118dc'), ('\U000118bd', '\U000118dd'),
+        ('\U000118be', '\U000118de'), ('\U000118bf', '\U000118df'), ('\U000118c0', '\U000118a0'),
+        ('\U000118c1', '\U000118a1'), ('\U000118c2', '\U000118a2'), ('\U000118c3', '\U000118a3'),
+        ('\U000118c4', '\U000118a4'), ('\U000118c5', '\U000118a5'), ('\U000118c6', '\U000118a6'),
+        ('\U000118c7', '\U000118a7'), ('\U000118c8', '\U000118a8'), ('\U000118c9', '\U000118a9'),
+        ('\U000118ca', '\U000118aa'), ('\U000118cb', '\U000118ab'), ('\U000118cc', '\U000118ac'),
+        ('\U000118cd', '\U000118ad'), ('\U000118ce', '\U000118ae'), ('\U000118cf', '\U000118af'),
+        ('\U000118d0', '\U000118b0'), ('\U000118d1', '\U000118b1'), ('\U000118d2', '\U000118b2'),
+        ('\U000118d3', '\U000118b3'), ('\U000118d4', '\U000118b4'), ('\U000118d5', '\U000118b5'),
+        ('\U000118d6', '\U000118b6'), ('\U000118d7', '\U000118b7'), ('\U000118d8', '\U000118b8'),
+        ('\U000118d9', '\U000118b9'), ('\U000118da', '\U000118ba'), ('\U000118db', '\U000118bb'),
+        ('\U000118dc', '\U000118bc'), ('\U000118dd', '\U000118bd'), ('\U000118de', '\U000118be'),
+        ('\U000118df', '\U000118bf'), ('\U00016e40', '\U00016e60'), ('\U00016e41', '\U00016e61'),
+        ('\U00016e42', '\U00016e62'), ('\U00016e43', '\U00016e63'), ('\U00016e44', '\U00016e64'),
+        ('\U00016e45', '\U00016e65'), ('\U00016e46', '\U00016e66'), ('\U00016e47', '\U00016e67'),
+        ('\U00016e48', '\U00016e68'), ('\U00016e49', '\U00016e69'), ('\U00016e4a', '\U00016e6a'),
+        ('\U00016e4b', '\U00016e6b'), ('\U00016e4c', '\U00016e6c'), ('\U00016e4d', '\U00016e6d'),
+        ('\U00016e4e', '\U00016e6e'), ('\U00016e4f', '\U00016e6f'), ('\U00016e50', '\U00016e70'),
+        ('\U00016e51', '\U00016e71'), ('\U00016e52', '\U00016e72'), ('\U00016e53', '\U00016e73'),
+        ('\U00016e54', '\U00016e74'), ('\U00016e55', '\U00016e75'), ('\U00016e56', '\U00016e76'),
+        ('\U00016e57', '\U00016e77'), ('\U00016e58', '\U00016e78'), ('\U00016e59', '\U00016e79'),
+        ('\U00016e5a', '\U00016e7a'), ('\U00016e5b', '\U00016e7b'), ('\U00016e5c', '\U00016e7c'),
+        ('\U00016e5d', '\U00016e7d'), ('\U00016e5e', '\U00016e7e'), ('\U00016e5f', '\U00016e7f'),
+        ('\U00016e60', '\U00016e40'), ('\U00016e61', '\U00016e41'), ('\U00016e62', '\U00016e42'),
+        ('\U00016e63', '\U00016e43'), ('\U00016e64', '\U00016e44'), ('\U00016e65', '\U00016e45'),
+        ('\U00016e66', '\U00016e46'), ('\U00016e67', '\U00016e47'), ('\U00016e68', '\U00016e48'),
+        ('\U00016e69', '\U00016e49'), ('\U00016e6a', '\U00016e4a'), ('\U00016e6b', '\U00016e4b'),
+        ('\U00016e6c', '\U00016e4c'), ('\U00016e6d', '\U00016e4d'), ('\U00016e6e', '\U00016e4e'),
+        ('\U00016e6f', '\U00016e4f'), ('\U00016e70', '\U00016e50'), ('\U00016e71', '\U00016e51'),
+        ('\U00016e72', '\U00016e52'), ('\U00016e73', '\U00016e53'), ('\U00016e74', '\U00016e54'),
+        ('\U00016e75', '\U00016e55'), ('\U00016e76', '\U00016e56'), ('\U00016e77', '\U00016e57'),
+        ('\U00016e78', '\U00016e58'), ('\U00016e79', '\U00016e59'), ('\U00016e7a', '\U00016e5a'),
+        ('\U00016e7b', '\U00016e5b'), ('\U00016e7c', '\U00016e5c'), ('\U00016e7d', '\U00016e5d'),
+        ('\U00016e7e', '\U00016e5e'), ('\U00016e7f', '\U00016e5f'), ('\U0001e900', '\U0001e922'),
+        ('\U0001e901', '\U0001e923'), ('\U0001e902', '\U0001e924'), ('\U0001e903', '\U0001e925'),
+        ('\U0001e904', '\U0001e926'), ('\U0001e905', '\U0001e927'), ('\U0001e906', '\U0001e928'),
+        ('\U0001e907', '\U0001e929'), ('\U0001e908', '\U0001e92a'), ('\U0001e909', '\U0001e92b'),
+        ('\U0001e90a', '\U0001e92c'), ('\U0001e90b', '\U0001e92d'), ('\U0001e90c', '\U0001e92e'),
+        ('\U0001e90d', '\U0001e92f'), ('\U0001e90e', '\U0001e930'), ('\U0001e90f', '\U0001e931'),
+        ('\U0001e910', '\U0001e932'), ('\U0001e911', '\U0001e933'), ('\U0001e912', '\U0001e934'),
+        ('\U0001e913', '\U0001e935'), ('\U0001e914', '\U0001e936'), ('\U0001e915', '\U0001e937'),
+        ('\U0001e916', '\U0001e938'), ('\U0001e917', '\U0001e939'), ('\U0001e918', '\U0001e93a'),
+        ('\U0001e919', '\U0001e93b'), ('\U0001e91a', '\U0001e93c'), ('\U0001e91b', '\U0001e93d'),
+        ('\U0001e91c', '\U0001e93e'), ('\U0001e91d', '\U0001e93f'), ('\U0001e91e', '\U0001e940'),
+        ('\U0001e91f', '\U0001e941'), ('\U0001e920', '\U0001e942'), ('\U0001e921', '\U0001e943'),
+        ('\U0001e922', '\U0001e900'), ('\U0001e923', '\U0001e901'), ('\U0001e924', '\U0001e902'),
+        ('\U0001e925', '\U0001e903'), ('\U0001e926', '\U0001e904'), ('\U0001e927', '\U0001e905'),
+        ('\U0001e928', '\U0001e906'), ('\U0001e929', '\U0001e907'), ('\U0001e92a', '\U0001e908'),
+        ('\U0001e92b', '\U0001e909'), ('\U0001e92c', '\U0001e90a'), ('\U0001e92d', '\U0001e90b'),
+        ('\U0001e92e', '\U0001e90c'), ('\U0001e92f', '\U0001e90d'), ('\U0001e930', '\U0001e90e'),
+        ('\U0001e931', '\U0001e90f'), ('\U0001e932', '\U0001e910'), ('\U0001e933', '\U0001e911'),
+        ('\U0001e934', '\U0001e912'), ('\U0001e935', '\U0001e913'), ('\U0001e936', '\U0001e914'),
+        ('\U0001e937', '\U0001e915'), ('\U0001e938', '\U0001e916'), ('\U0001e939', '\U0001e917'),
+        ('\U0001e93a', '\U0001e918'), ('\U0001e93b', '\U0001e919'), ('\U0001e93c', '\U0001e91a'),
+        ('\U0001e93d', '\U0001e91b'), ('\U0001e93e', '\U0001e91c'), ('\U0001e93f', '\U0001e91d'),
+        ('\U0001e940', '\U0001e91e'), ('\U0001e941', '\U0001e91f'), ('\U0001e942', '\U0001e920'),
+        ('\U0001e943', '\U0001e921')
+    ]
+}
+
+// Pairs of each char with a simple uppercase mapping and the char
+// it maps to, sorted by the first.
+fn upper_table() -> ~[(char, char)] {
+    ~[
+        ('\u0061', '\u0041'), ('\u0062', '\u0042'), ('\u0063', '\u0043'),
+        ('\u0064', '\u0044'), ('\u0065', '\u0045'), ('\u0066', '\u0046'),
+        ('\u0067', '\u0047'), ('\u0068', '\u0048'), ('\u0069', '\u0049'),
+        ('\u006a', '\u004a'), ('\u006b', '\u004b'), ('\u006c', '\u004c'),
+        ('\u006d', '\u004d'), ('\u006e', '\u004e'), ('\u006f', '\u004f'),
+        ('\u0070', '\u0050'), ('\u0071', '\u0051'), ('\u0072', '\u0052'),
+        ('\u0073', '\u0053'), ('\u0074', '\u0054'), ('\u0075', '\u0055'),
+        ('\u0076', '\u0056'), ('\u0077', '\u0057'), ('\u0078', '\u0058'),
+        ('\u0079', '\u0059'), ('\u007a', '\u005a'), ('\u00b5', '\u039c'),
+        ('\u00e0', '\u00c0'), ('\u00e1', '\u00c1'), ('\u00e2', '\u00c2'),
+        ('\u00e3', '\u00c3'), ('\u00e4', '\u00c4'), ('\u00e5', '\u00c5'),
+        ('\u00e6', '\u00c6'), ('\u00e7', '\u00c7'), ('\u00e8', '\u00c8'),
+        ('\u00e9', '\u00c9'), ('\u00ea', '\u00ca'), ('\u00eb', '\u00cb'),
+        ('\u00ec', '\u00cc'), ('\u00ed', '\u00cd'), ('\u00ee', '\u00ce'),
+        ('\u00ef', '\u00cf'), ('\u00f0', '\u00d0'), ('\u00f1', '\u00d1'),
+        ('\u00f2', '\u00d2'), ('\u00f3', '\u00d3'), ('\u00f4', '\u00d4'),
+        ('\u00f5', '\u00d5'), ('\u00f6', '\u00d6'), ('\u00f8', '\u00d8'),
+        ('\u00f9', '\u00d9'), ('\u00fa', '\u00da'), ('\u00fb', '\u00db'),
+        ('\u00fc', '\u00dc'), ('\u00fd', '\u00dd'), ('\u00fe', '\u00de'),
+        ('\u00ff', '\u0178'), ('\u0101', '\u0100'), ('\u0103', '\u0102'),
+        ('\u0105', '\u0104'), ('\u0107', '\u0106'), ('\u0109', '\u0108'),
+        ('\u010b', '\u010a'), ('\u010d', '\u010c'), ('\u010f', '\u010e'),
+        ('\u0111', '\u0110'), ('\u0113', '\u0112'), ('\u0115', '\u0114'),
+        ('\u0117', '\u0116'), ('\u0119', '\u0118'), ('\u011b', '\u011a'),
+        ('\u011d', '\u011c'), ('\u011f', '\u011e'), ('\u0121', '\u0120'),
+        ('\u0123', '\u0122'), ('\u0125', '\u0124'), ('\u0127', '\u0126'),
+        ('\u0129', '\u0128'), ('\u012b', '\u012a'), ('\u012d', '\u012c'),
+        ('\u012f', '\u012e'), ('\u0131', '\u0049'), ('\u0133', '\u0132'),
+        ('\u0135', '\u0134'), ('\u0137', '\u0136'), ('\u013a', '\u0139'),
+        ('\u013c', '\u013b'), ('\u013e', '\u013d'), ('\u0140', '\u013f'),
+        ('\u0142', '\u0141'), ('\u0144', '\u0143'), ('\u0146', '\u0145'),
+        ('\u0148', '\u0147'), ('\u014b', '\u014a'), ('\u014d', '\u014c'),
+        ('\u014f', '\u014e'), ('\u0151', '\u0150'), ('\u0153', '\u0152'),
+        ('\u0155', '\u0154'), ('\u0157', '\u0156'), ('\u0159', '\u0158'),
+        ('\u015b', '\u015a'), ('\u015d', '\u015c'), ('\u015f', '\u015e'),
+        ('\u0161', '\u0160'), ('\u0163', '\u0162'), ('\u0165', '\u0164'),
+        ('\u0167', '\u0166'), ('\u0169', '\u0168'), ('\u016b', '\u016a'),
+        ('\u016d', '\u016c'), ('\u016f', '\u016e'), ('\u0171', '\u0170'),
+        ('\u0173', '\u0172'), ('\u0175', '\u0174'), ('\u0177', '\u0176'),
+        ('\u017a', '\u0179'), ('\u017c', '\u017b'), ('\u017e', '\u017d'),
+        ('\u017f', '\u0053'), ('\u0180', '\u0243'), ('\u0183', '\u0182'),
+        ('\u0185', '\u0184'), ('\u0188', '\u0187'), ('\u018c', '\u018b'),
+        ('\u0192', '\u0191'), ('\u0195', '\u01f6'), ('\u0199', '\u0198'),
+        ('\u019a', '\u023d'), ('\u019e', '\u0220'), ('\u01a1', '\u01a0'),
+        ('\u01a3', '\u01a2'), ('\u01a5', '\u01a4'), ('\u01a8', '\u01a7'),
+        ('\u01ad', '\u01ac'), ('\u01b0', '\u01af'), ('\u01b4', '\u01b3'),
+        ('\u01b6', '\u01b5'), ('\u01b9', '\u01b8'), ('\u01bd', '\u01bc'),
+        ('\u01bf', '\u01f7'), ('\u01c5', '\u01c4'), ('\u01c6', '\u01c4'),
+        ('\u01c8', '\u01c7'), ('\u01c9', '\u01c7'), ('\u01cb', '\u01ca'),
+        ('\u01cc', '\u01ca'), ('\u01ce', '\u01cd'), ('\u01d0', '\u01cf'),
+        ('\u01d2', '\u01d1'), ('\u01d4', '\u01d3'), ('\u01d6', '\u01d5'),
+        ('\u01d8', '\u01d7'), ('\u01da', '\u01d9'), ('\u01dc', '\u01db'),
+        ('\u01dd', '\u018e'), ('\u01df', '\u01de'), ('\u01e1', '\u01e0'),
+        ('\u01e3', '\u01e2'), ('\u01e5', '\u01e4'), ('\u01e7', '\u01e6'),
+        ('\u01e9', '\u01e8'), ('\u01eb', '\u01ea'), ('\u01ed', '\u01ec'),
+        ('\u01ef', '\u01ee'), ('\u01f2', '\u01f1'), ('\u01f3', '\u01f1'),
+        ('\u01f5', '\u01f4'), ('\u01f9', '\u01f8'), ('\u01fb', '\u01fa'),
+        ('\u01fd', '\u01fc'), ('\u01ff', '\u01fe'), ('\u0201', '\u0200'),
+        ('\u0203', '\u0202'), ('\u0205', '\u0204'), ('\u0207', '\u0206'),
+        ('\u0209', '\u0208'), ('\u020b', '\u020a'), ('\u020d', '\u020c'),
+        ('\u020f', '\u020e'), ('\u0211', '\u0210'), ('\u0213', '\u0212'),
+        ('\u0215', '\u0214'), ('\u0217', '\u0216'), ('\u0219', '\u0218'),
+        ('\u021b', '\u021a'), ('\u021d', '\u021c'), ('\u021f', '\u021e'),
+        ('\u0223', '\u0222'), ('\u0225', '\u0224'), ('\u0227', '\u0226'),
+        ('\u0229', '\u0228'), ('\u022b', '\u022a'), ('\u022d', '\u022c'),
+        ('\u022f', '\u022e'), ('\u0231', '\u0230'), ('\u0233', '\u0232'),
+        ('\u023c', '\u023b'), ('\u023f', '\u2c7e'), ('\u0240', '\u2c7f'),
+        ('\u0242', '\u0241'), ('\u0247', '\u0246'), ('\u0249', '\u0248'),
+        ('\u024b', '\u024a'), ('\u024d', '\u024c'), ('\u024f', '\u024e'),
+        ('\u0250', '\u2c6f'), ('\u0251', '\u2c6d'), ('\u0252', '\u2c70'),
+        ('\u0253', '\u0181'), ('\u0254', '\u0186'), ('\u0256', '\u0189'),
+        ('\u0257', '\u018a'), ('\u0259', '\u018f'), ('\u025b', '\u0190'),
+        ('\u025c', '\ua7ab'), ('\u0260', '\u0193'), ('\u0261', '\ua7ac'),
+        ('\u0263', '\u0194'), ('\u0265', '\ua78d'), ('\u0266', '\ua7aa'),
+        ('\u0268', '\u0197'), ('\u0269', '\u0196'), ('\u026a', '\ua7ae'),
+        ('\u026b', '\u2c62'), ('\u026c', '\ua7ad'), ('\u026f', '\u019c'),
+        ('\u0271', '\u2c6e'), ('\u0272', '\u019d'), ('\u0275', '\u019f'),
+        ('\u027d', '\u2c64'), ('\u0280', '\u01a6'), ('\u0282', '\ua7c5'),
+        ('\u0283', '\u01a9'), ('\u0287', '\ua7b1'), ('\u0288', '\u01ae'),
+        ('\u0289', '\u0244'), ('\u028a', '\u01b1'), ('\u028b', '\u01b2'),
+        ('\u028c', '\u0245'), ('\u0292', '\u01b7'), ('\u029d', '\ua7b2'),
+        ('\u029e', '\ua7b0'), ('\u0345', '\u0399'), ('\u0371', '\u0370'),
+        ('\u0373', '\u0372'), ('\u0377', '\u0376'), ('\u037b', '\u03fd'),
+        ('\u037c', '\u03fe'), ('\u037d', '\u03ff'), ('\u03ac', '\u0386'),
+        ('\u03ad', '\u0388'), ('\u03ae', '\u0389'), ('\u03af', '\u038a'),
+        ('\u03b1', '\u0391'), ('\u03b2', '\u0392'), ('\u03b3', '\u0393'),
+        ('\u03b4', '\u0394'), ('\u03b5', '\u0395'), ('\u03b6', '\u0396'),
+        ('\u03b7', '\u0397'), ('\u03b8', '\u0398'), ('\u03b9', '\u0399'),
+        ('\u03ba', '\u039a'), ('\u03bb', '\u039b'), ('\u03bc', '\u039c'),
+        ('\u03bd', '\u039d'), ('\u03be', '\u039e'), ('\u03bf', '\u039f'),
+        ('\u03c0', '\u03a0'), ('\u03c1', '\u03a1'), ('\u03c2', '\u03a3'),
+        ('\u03c3', '\u03a3'), ('\u03c4', '\u03a4'), ('\u03c5', '\u03a5'),
+        ('\u03c6', '\u03a6'), ('\u03c7', '\u03a7'), ('\u03c8', '\u03a8'),
+        ('\u03c9', '\u03a9'), ('\u03ca', '\u03aa'), ('\u03cb', '\u03ab'),
+        ('\u03cc', '\u038c'), ('\u03cd', '\u038e'), ('\u03ce', '\u038f'),
+        ('\u03d0', '\u0392'), ('\u03d1', '\u0398'), ('\u03d5', '\u03a6'),
+        ('\u03d6', '\u03a0'), ('\u03d7', '\u03cf'), ('\u03d9', '\u03d8'),
+        ('\u03db', '\u03da'), ('\u03dd', '\u03dc'), ('\u03df', '\u03de'),
+        ('\u03e1', '\u03e0'), ('\u03e3', '\u03e2'), ('\u03e5', '\u03e4'),
+        ('\u03e7', '\u03e6'), ('\u03e9', '\u03e8'), ('\u03eb', '\u03ea'),
+        ('\u03ed', '\u03ec'), ('\u03ef', '\u03ee'), ('\u03f0', '\u039a'),
+        ('\u03f1', '\u03a1'), ('\u03f2', '\u03f9'), ('\u03f3', '\u037f'),
+        ('\u03f5', '\u0395'), ('\u03f8', '\u03f7'), ('\u03fb', '\u03fa'),
+        ('\u0430', '\u0410'), ('\u0431', '\u0411'), ('\u0432', '\u0412'),
+        ('\u0433', '\u0413'), ('\u0434', '\u0414'), ('\u0435', '\u0415'),
+        ('\u0436', '\u0416'), ('\u0437', '\u0417'), ('\u0438', '\u0418'),
+        ('\u0439', '\u0419'), ('\u043a', '\u041a'), ('\u043b', '\u041b'),
+        ('\u043c', '\u041c'), ('\u043d', '\u041d'), ('\u043e', '\u041e'),
+        ('\u043f', '\u041f'), ('\u0440', '\u0420'), ('\u0441', '\u0421'),
+        ('\u0442', '\u0422'), ('\u0443', '\u0423'), ('\u0444', '\u0424'),
+        ('\u0445', '\u0425'), ('\u0446', '\u0426'), ('\u0447', '\u0427'),
+        ('\u0448', '\u0428'), ('\u0449', '\u0429'), ('\u044a', '\u042a'),
+        ('\u044b', '\u042b'), ('\u044c', '\u042c'), ('\u044d', '\u042d'),
+        ('\u044e', '\u042e'), ('\u044f', '\u042f'), ('\u0450', '\u0400'),
+        ('\u0451', '\u0401'), ('\u0452', '\u0402'), ('\u0453', '\u0403'),
+        ('\u0454', '\u0404'), ('\u0455', '\u0405'), ('\u0456', '\u0406'),
+        ('\u0457', '\u0407'), ('\u0458', '\u0408'), ('\u0459', '\u0409'),
+        ('\u045a', '\u040a'), ('\u045b', '\u040b'), ('\u045c', '\u040c'),
+        ('\u045d', '\u040d'), ('\u045e', '\u040e'), ('\u045f', '\u040f'),
+        ('\u0461', '\u0460'), ('\u0463', '\u0462'), ('\u0465', '\u0464'),
+        ('\u0467', '\u0466'), ('\u0469', '\u0468'), ('\u046b', '\u046a'),
+        ('\u046d', '\u046c'), ('\u046f', '\u046e'), ('\u0471', '\u0470'),
+        ('\u0473', '\u0472'), ('\u0475', '\u0474'), ('\u0477', '\u0476'),
+        ('\u0479', '\u0478'), ('\u047b', '\u047a'), ('\u047d', '\u047c'),
+        ('\u047f', '\u047e'), ('\u0481', '\u0480'), ('\u048b', '\u048a'),
+        ('\u048d', '\u048c'), ('\u048f', '\u048e'), ('\u0491', '\u0490'),
+        ('\u0493', '\u0492'), ('\u0495', '\u0494'), ('\u0497', '\u0496'),
+        ('\u0499', '\u0498'), ('\u049b', '\u049a'), ('\u049d', '\u049c'),
+        ('\u049f', '\u049e'), ('\u04a1', '\u04a0'), ('\u04a3', '\u04a2'),
+        ('\u04a5', '\u04a4'), ('\u04a7', '\u04a6'), ('\u04a9', '\u04a8'),
+        ('\u04ab', '\u04aa'), ('\u04ad', '\u04ac'), ('\u04af', '\u04ae'),
+        ('\u04b1', '\u04b0'), ('\u04b3', '\u04b2'), ('\u04b5', '\u04b4'),
+        ('\u04b7', '\u04b6'), ('\u04b9', '\u04b8'), ('\u04bb', '\u04ba'),
+        ('\u04bd', '\u04bc'), ('\u04bf', '\u04be'), ('\u04c2', '\u04c1'),
+        ('\u04c4', '\u04c3'), ('\u04c6', '\u04c5'), ('\u04c8', '\u04c7'),
+        ('\u04ca', '\u04c9'), ('\u04cc', '\u04cb'), ('\u04ce', '\u04cd'),
+        ('\u04cf', '\u04c0'), ('\u04d1', '\u04d0'), ('\u04d3', '\u04d2'),
+        ('\u04d5', '\u04d4'), ('\u04d7', '\u04d6'), ('\u04d9', '\u04d8'),
+        ('\u04db', '\u04da'), ('\u04dd', '\u04dc'), ('\u04df', '\u04de'),
+        ('\u04e1', '\u04e0'), ('\u04e3', '\u04e2'), ('\u04e5', '\u04e4'),
+        ('\u04e7', '\u04e6'), ('\u04e9', '\u04e8'), ('\u04eb', '\u04ea'),
+        ('\u04ed', '\u04ec'), ('\u04ef', '\u04ee'), ('\u04f1', '\u04f0'),
+        ('\u04f3', '\u04f2'), ('\u04f5', '\u04f4'), ('\u04f7', '\u04f6'),
+        ('\u04f9', '\u04f8'), ('\u04fb', '\u04fa'), ('\u04fd', '\u04fc'),
+        ('\u04ff', '\u04fe'), ('\u0501', '\u0500'), ('\u0503', '\u0502'),
+        ('\u0505', '\u0504'), ('\u0507', '\u0506'), ('\u0509', '\u0508'),
+        ('\u050b', '\u050a'), ('\u050d', '\u050c'), ('\u050f', '\u050e'),
+        ('\u0511', '\u0510'), ('\u0513', '\u0512'), ('\u0515', '\u0514'),
+        ('\u0517', '\u0516'), ('\u0519', '\u0518'), ('\u051b', '\u051a'),
+        ('\u051d', '\u051c'), ('\u051f', '\u051e'), ('\u0521', '\u0520'),
+        ('\u0523', '\u0522'), ('\u0525', '\u0524'), ('\u0527', '\u0526'),
+        ('\u0529', '\u0528'), ('\u052b', '\u052a'), ('\u052d', '\u052c'),
+        ('\u052f', '\u052e'), ('\u0561', '\u0531'), ('\u0562', '\u0532'),
+        ('\u0563', '\u0533'), ('\u0564', '\u0534'), ('\u0565', '\u0535'),
+        ('\u0566', '\u0536'), ('\u0567', '\u0537'), ('\u0568', '\u0538'),
+        ('\u0569', '\u0539'), ('\u056a', '\u053a'), ('\u056b', '\u053b'),
+        ('\u056c', '\u053c'), ('\u056d', '\u053d'), ('\u056e', '\u053e'),
+        ('\u056f', '\u053f'), ('\u0570', '\u0540'), ('\u0571', '\u0541'),
+        ('\u0572', '\u0542'), ('\u0573', '\u0543'), ('\u0574', '\u0544'),
+        ('\u0575', '\u0545'), ('\u0576', '\u0546'), ('\u0577', '\u0547'),
+        ('\u0578', '\u0548'), ('\u0579', '\u0549'), ('\u057a', '\u054a'),
+        ('\u057b', '\u054b'), ('\u057c', '\u054c'), ('\u057d', '\u054d'),
+        ('\u057e', '\u054e'), ('\u057f', '\u054f'), ('\u0580', '\u0550'),
+        ('\u0581', '\u0551'), ('\u0582', '\u0552'), ('\u0583', '\u0553'),
+        ('\u0584', '\u0554'), ('\u0585', '\u0555'), ('\u0586', '\u0556'),
+        ('\u10d0', '\u1c90'), ('\u10d1', '\u1c91'), ('\u10d2', '\u1c92'),
+        ('\u10d3', '\u1c93'), ('\u10d4', '\u1c94'), ('\u10d5', '\u1c95'),
+        ('\u10d6', '\u1c96'), ('\u10d7', '\u1c97'), ('\u10d8', '\u1c98'),
+        ('\u10d9', '\u1c99'), ('\u10da', '\u1c9a'), ('\u10db', '\u1c9b'),
+        ('\u10dc', '\u1c9c'), ('\u10dd', '\u1c9d'), ('\u10de', '\u1c9e'),
+        ('\u10df', '\u1c9f'), ('\u10e0', '\u1ca0'), ('\u10e1', '\u1ca1'),
+        ('\u10e2', '\u1ca2'), ('\u10e3', '\u1ca3'), ('\u10e4', '\u1ca4'),
+        ('\u10e5', '\u1ca5'), ('\u10e6', '\u1ca6'), ('\u10e7', '\u1ca7'),
+        ('\u10e8', '\u1ca8'), ('\u10e9', '\u1ca9'), ('\u10ea', '\u1caa'),
+        ('\u10eb', '\u1cab'), ('\u10ec', '\u1cac'), ('\u10ed', '\u1cad'),
+        ('\u10ee', '\u1cae'), ('\u10ef', '\u1caf'), ('\u10f0', '\u1cb0'),
+        ('\u10f1', '\u1cb1'), ('\u10f2', '\u1cb2'), ('\u10f3', '\u1cb3'),
+        ('\u10f4', '\u1cb4'), ('\u10f5', '\u1cb5'), ('\u10f6', '\u1cb6'),
+        ('\u10f7', '\u1cb7'), ('\u10f8', '\u1cb8'), ('\u10f9', '\u1cb9'),
+        ('\u10fa', '\u1cba'), ('\u10fd', '\u1cbd'), ('\u10fe', '\u1cbe'),
+        ('\u10ff', '\u1cbf'), ('\u13f8', '\u13f0'), ('\u13f9', '\u13f1'),
+        ('\u13fa', '\u13f2'), ('\u13fb', '\u13f3'), ('\u13fc', '\u13f4'),
+        ('\u13fd', '\u13f5'), ('\u1c80', '\u0412'), ('\u1c81', '\u0414'),
+        ('\u1c82', '\u041e'), ('\u1c83', '\u0421'), ('\u1c84', '\u0422'),
+        ('\u1c85', '\u0422'), ('\u1c86', '\u042a'), ('\u1c87', '\u0462'),
+        ('\u1c88', '\ua64a'), ('\u1d79', '\ua77d'), ('\u1d7d', '\u2c63'),
+        ('\u1d8e', '\ua7c6'), ('\u1e01', '\u1e00'), ('\u1e03', '\u1e02'),
+        ('\u1e05', '\u1e04'), ('\u1e07', '\u1e06'), ('\u1e09', '\u1e08'),
+        ('\u1e0b', '\u1e0a'), ('\u1e0d', '\u1e0c'), ('\u1e0f', '\u1e0e'),
+        ('\u1e11', '\u1e10'), ('\u1e13', '\u1e12'), ('\u1e15', '\u1e14'),
+        ('\u1e17', '\u1e16'), ('\u1e19', '\u1e18'), ('\u1e1b', '\u1e1a'),
+        ('\u1e1d', '\u1e1c'), ('\u1e1f', '\u1e1e'), ('\u1e21', '\u1e20'),
+        ('\u1e23', '\u1e22'), ('\u1e25', '\u1e24'), ('\u1e27', '\u1e26'),
+        ('\u1e29', '\u1e28'), ('\u1e2b', '\u1e2a'), ('\u1e2d', '\u1e2c'),
+        ('\u1e2f', '\u1e2e'), ('\u1e31', '\u1e30'), ('\u1e33', '\u1e32'),
+        ('\u1e35', '\u1e34'), ('\u1e37', '\u1e36'), ('\u1e39', '\u1e38'),
+        ('\u1e3b', '\u1e3a'), ('\u1e3d', '\u1e3c'), ('\u1e3f', '\u1e3e'),
+        ('\u1e41', '\u1e40'), ('\u1e43', '\u1e42'), ('\u1e45', '\u1e44'),
+        ('\u1e47', '\u1e46'), ('\u1e49', '\u1e48'), ('\u1e4b', '\u1e4a'),
+        ('\u1e4d', '\u1e4c'), ('\u1e4f', '\u1e4e'), ('\u1e51', '\u1e50'),
+        ('\u1e53', '\u1e52'), ('\u1e55', '\u1e54'), ('\u1e57', '\u1e56'),
+        ('\u1e59', '\u1e58'), ('\u1e5b', '\u1e5a'), ('\u1e5d', '\u1e5c'),
+        ('\u1e5f', '\u1e5e'), ('\u1e61', '\u1e60'), ('\u1e63', '\u1e62'),
+        ('\u1e65', '\u1e64'), ('\u1e67', '\u1e66'), ('\u1e69', '\u1e68'),
+        ('\u1e6b', '\u1e6a'), ('\u1e6d', '\u1e6c'), ('\u1e6f', '\u1e6e'),
+        ('\u1e71', '\u1e70'), ('\u1e73', '\u1e72'), ('\u1e75', '\u1e74'),
+        ('\u1e77', '\u1e76'), ('\u1e79', '\u1e78'), ('\u1e7b', '\u1e7a'),
+        ('\u1e7d', '\u1e7c'), ('\u1e7f', '\u1e7e'), ('\u1e81', '\u1e80'),
+        ('\u1e83', '\u1e82'), ('\u1e85', '\u1e84'), ('\u1e87', '\u1e86'),
+        ('\u1e89', '\u1e88'), ('\u1e8b', '\u1e8a'), ('\u1e8d', '\u1e8c'),
+        ('\u1e8f', '\u1e8e'), ('\u1e91', '\u1e90'), ('\u1e93', '\u1e92'),
+        ('\u1e95', '\u1e94'), ('\u1e9b', '\u1e60'), ('\u1ea1', '\u1ea0'),
+        ('\u1ea3', '\u1ea2'), ('\u1ea5', '\u1ea4'), ('\u1ea7', '\u1ea6'),
+        ('\u1ea9', '\u1ea8'), ('\u1eab', '\u1eaa'), ('\u1ead', '\u1eac'),
+        ('\u1eaf', '\u1eae'), ('\u1eb1', '\u1eb0'), ('\u1eb3', '\u1eb2'),
+        ('\u1eb5', '\u1eb4'), ('\u1eb7', '\u1eb6'), ('\u1eb9', '\u1eb8'),
+        ('\u1ebb', '\u1eba'), ('\u1ebd', '\u1ebc'), ('\u1ebf', '\u1ebe'),
+        ('\u1ec1', '\u1ec0'), ('\u1ec3', '\u1ec2'), ('\u1ec5', '\u1ec4'),
+        ('\u1ec7', '\u1ec6'), ('\u1ec9', '\u1ec8'), ('\u1ecb', '\u1eca'),
+        ('\u1ecd', '\u1ecc'), ('\u1ecf', '\u1ece'), ('\u1ed1', '\u1ed0'),
+        ('\u1ed3', '\u1ed2'), ('\u1ed5', '\u1ed4'), ('\u1ed7', '\u1ed6'),
+        ('\u1ed9', '\u1ed8'), ('\u1edb', '\u1eda'), ('\u1edd', '\u1edc'),
+        ('\u1edf', '\u1ede'), ('\u1ee1', '\u1ee0'), ('\u1ee3', '\u1ee2'),
+        ('\u1ee5', '\u1ee4'), ('\u1ee7', '\u1ee6'), ('\u1ee9', '\u1ee8'),
+        ('\u1eeb', '\u1eea'), ('\u1eed', '\u1eec'), ('\u1eef', '\u1eee'),
+        ('\u1ef1', '\u1ef0'), ('\u1ef3', '\u1ef2'), ('\u1ef5', '\u1ef4'),
+        ('\u1ef7', '\u1ef6'), ('\u1ef9', '\u1ef8'), ('\u1efb', '\u1efa'),
+        ('\u1efd', '\u1efc'), ('\u1eff', '\u1efe'), ('\u1f00', '\u1f08'),
+        ('\u1f01', '\u1f09'), ('\u1f02', '\u1f0a'), ('\u1f03', '\u1f0b'),
+        ('\u1f04', '\u1f0c'), ('\u1f05', '\u1f0d'), ('\u1f06', '\u1f0e'),
+        ('\u1f07', '\u1f0f'), ('\u1f10', '\u1f18'), ('\u1f11', '\u1f19'),
+        ('\u1f12', '\u1f1a'), ('\u1f13', '\u1f1b'), ('\u1f14', '\u1f1c'),
+        ('\u1f15', '\u1f1d'), ('\u1f20', '\u1f28'), ('\u1f21', '\u1f29'),
+        ('\u1f22', '\u1f2a'), ('\u1f23', '\u1f2b'), ('\u1f24', '\u1f2c'),
+        ('\u1f25', '\u1f2d'), ('\u1f26', '\u1f2e'), ('\u1f27', '\u1f2f'),
+        ('\u1f30', '\u1f38'), ('\u1f31', '\u1f39'), ('\u1f32', '\u1f3a'),
+        ('\u1f33', '\u1f3b'), ('\u1f34', '\u1f3c'), ('\u1f35', '\u1f3d'),
+        ('\u1f36', '\u1f3e'), ('\u1f37', '\u1f3f'), ('\u1f40', '\u1f48'),
+        ('\u1f41', '\u1f49'), ('\u1f42', '\u1f4a'), ('\u1f43', '\u1f4b'),
+        ('\u1f44', '\u1f4c'), ('\u1f45', '\u1f4d'), ('\u1f51', '\u1f59'),
+        ('\u1f53', '\u1f5b'), ('\u1f55', '\u1f5d'), ('\u1f57', '\u1f5f'),
+        ('\u1f60', '\u1f68'), ('\u1f61', '\u1f69'), ('\u1f62', '\u1f6a'),
+        ('\u1f63', '\u1f6b'), ('\u1f64', '\u1f6c'), ('\u1f65', '\u1f6d'),
+        ('\u1f66', '\u1f6e'), ('\u1f67', '\u1f6f'), ('\u1f70', '\u1fba'),
+        ('\u1f71', '\u1fbb'), ('\u1f72', '\u1fc8'), ('\u1f73', '\u1fc9'),
+        ('\u1f74', '\u1fca'), ('\u1f75', '\u1fcb'), ('\u1f76', '\u1fda'),
+        ('\u1f77', '\u1fdb'), ('\u1f78', '\u1ff8'), ('\u1f79', '\u1ff9'),
+        ('\u1f7a', '\u1fea'), ('\u1f7b', '\u1feb'), ('\u1f7c', '\u1ffa'),
+        ('\u1f7d', '\u1ffb'), ('\u1f80', '\u1f88'), ('\u1f81', '\u1f89'),
+        ('\u1f82', '\u1f8a'), ('\u1f83', '\u1f8b'), ('\u1f84', '\u1f8c'),
+        ('\u1f85', '\u1f8d'), ('\u1f86', '\u1f8e'), ('\u1f87', '\u1f8f'),
+        ('\u1f90', '\u1f98'), ('\u1f91', '\u1f99'), ('\u1f92', '\u1f9a'),
+        ('\u1f93', '\u1f9b'), ('\u1f94', '\u1f9c'), ('\u1f95', '\u1f9d'),
+        ('\u1f96', '\u1f9e'), ('\u1f97', '\u1f9f'), ('\u1fa0', '\u1fa8'),
+        ('\u1fa1', '\u1fa9'), ('\u1fa2', '\u1faa'), ('\u1fa3', '\u1fab'),
+        ('\u1fa4', '\u1fac'), ('\u1fa5', '\u1fad'), ('\u1fa6', '\u1fae'),
+        ('\u1fa7', '\u1faf'), ('\u1fb0', '\u1fb8'), ('\u1fb1', '\u1fb9'),
+        ('\u1fb3', '\u1fbc'), ('\u1fbe', '\u0399'), ('\u1fc3', '\u1fcc'),
+        ('\u1fd0', '\u1fd8'), ('\u1fd1', '\u1fd9'), ('\u1fe0', '\u1fe8'),
+        ('\u1fe1', '\u1fe9'), ('\u1fe5', '\u1fec'), ('\u1ff3', '\u1ffc'),
+        ('\u214e', '\u2132'), ('\u2170', '\u2160'), ('\u2171', '\u2161'),
+        ('\u2172', '\u2162'), ('\u2173', '\u2163'), ('\u2174', '\u2164'),
+        ('\u2175', '\u2165'), ('\u2176', '\u2166'), ('\u2177', '\u2167'),
+        ('\u2178', '\u2168'), ('\u2179', '\u2169'), ('\u217a', '\u216a'),
+        ('\u217b', '\u216b'), ('\u217c', '\u216c'), ('\u217d', '\u216d'),
+        ('\u217e', '\u216e'), ('\u217f', '\u216f'), ('\u2184', '\u2183'),
+        ('\u24d0', '\u24b6'), ('\u24d1', '\u24b7'), ('\u24d2', '\u24b8'),
+        ('\u24d3', '\u24b9'), ('\u24d4', '\u24ba'), ('\u24d5', '\u24bb'),
+        ('\u24d6', '\u24bc'), ('\u24d7', '\u24bd'), ('\u24d8', '\u24be'),
+        ('\u24d9', '\u24bf'), ('\u24da', '\u24c0'), ('\u24db', '\u24c1'),
+        ('\u24dc', '\u24c2'), ('\u24dd', '\u24c3'), ('\u24de', '\u24c4'),
+        ('\u24df', '\u24c5'), ('\u24e0', '\u24c6'), ('\u24e1', '\u24c7'),
+        ('\u24e2', '\u24c8'), ('\u24e3', '\u24c9'), ('\u24e4', '\u24ca'),
+        ('\u24e5', '\u24cb'), ('\u24e6', '\u24cc'), ('\u24e7', '\u24cd'),
+        ('\u24e8', '\u24ce'), ('\u24e9', '\u24cf'), ('\u2c30', '\u2c00'),
+        ('\u2c31', '\u2c01'), ('\u2c32', '\u2c02'), ('\u2c33', '\u2c03'),
+        ('\u2c34', '\u2c04'), ('\u2c35', '\u2c05'), ('\u2c36', '\u2c06'),
+        ('\u2c37', '\u2c07'), ('\u2c38', '\u2c08'), ('\u2c39', '\u2c09'),
+        ('\u2c3a', '\u2c0a'), ('\u2c3b', '\u2c0b'), ('\u2c3c', '\u2c0c'),
+        ('\u2c3d', '\u2c0d'), ('\u2c3e', '\u2c0e'), ('\u2c3f', '\u2c0f'),
+        ('\u2c40', '\u2c10'), ('\u2c41', '\u2c11'), ('\u2c42', '\u2c12'),
+        ('\u2c43', '\u2c13'), ('\u2c44', '\u2c14'), ('\u2c45', '\u2c15'),
+        ('\u2c46', '\u2c16'), ('\u2c47', '\u2c17'), ('\u2c48', '\u2c18'),
+        ('\u2c49', '\u2c19'), ('\u2c4a', '\u2c1a'), ('\u2c4b', '\u2c1b'),
+        ('\u2c4c', '\u2c1c'), ('\u2c4d', '\u2c1d'), ('\u2c4e', '\u2c1e'),
+        ('\u2c4f', '\u2c1f'), ('\u2c50', '\u2c20'), ('\u2c51', '\u2c21'),
+        ('\u2c52', '\u2c22'), ('\u2c53', '\u2c23'), ('\u2c54', '\u2c24'),
+        ('\u2c55', '\u2c25'), ('\u2c56', '\u2c26'), ('\u2c57', '\u2c27'),
+        ('\u2c58', '\u2c28'), ('\u2c59', '\u2c29'), ('\u2c5a', '\u2c2a'),
+        ('\u2c5b', '\u2c2b'), ('\u2c5c', '\u2c2c'), ('\u2c5d', '\u2c2d'),
+        ('\u2c5e', '\u2c2e'), ('\u2c5f', '\u2c2f'), ('\u2c61', '\u2c60'),
+        ('\u2c65', '\u023a'), ('\u2c66', '\u023e'), ('\u2c68', '\u2c67'),
+        ('\u2c6a', '\u2c69'), ('\u2c6c', '\u2c6b'), ('\u2c73', '\u2c72'),
+        ('\u2c76', '\u2c75'), ('\u2c81', '\u2c80'), ('\u2c83', '\u2c82'),
+        ('\u2c85', '\u2c84'), ('\u2c87', '\u2c86'), ('\u2c89', '\u2c88'),
+        ('\u2c8b', '\u2c8a'), ('\u2c8d', '\u2c8c'), ('\u2c8f', '\u2c8e'),
+        ('\u2c91', '\u2c90'), ('\u2c93', '\u2c92'), ('\u2c95', '\u2c94'),
+        ('\u2c97', '\u2c96'), ('\u2c99', '\u2c98'), ('\u2c9b', '\u2c9a'),
+        ('\u2c9d', '\u2c9c'), ('\u2c9f', '\u2c9e'), ('\u2ca1', '\u2ca0'),
+        ('\u2ca3', '\u2ca2'), ('\u2ca5', '\u2ca4'), ('\u2ca7', '\u2ca6'),
+        ('\u2ca9', '\u2ca8'), ('\u2cab', '\u2caa'), ('\u2cad', '\u2cac'),
+        ('\u2caf', '\u2cae'), ('\u2cb1', '\u2cb0'), ('\u2cb3', '\u2cb2'),
+        ('\u2cb5', '\u2cb4'), ('\u2cb7', '\u2cb6'), ('\u2cb9', '\u2cb8'),
+        ('\u2cbb', '\u2cba'), ('\u2cbd', '\u2cbc'), ('\u2cbf', '\u2cbe'),
+        ('\u2cc1', '\u2cc0'), ('\u2cc3', '\u2cc2'), ('\u2cc5', '\u2cc4'),
+        ('\u2cc7', '\u2cc6'), ('\u2cc9', '\u2cc8'), ('\u2ccb', '\u2cca'),
+        ('\u2ccd', '\u2ccc'), ('\u2ccf', '\u2cce'), ('\u2cd1', '\u2cd0'),
+        ('\u2cd3', '\u2cd2'), ('\u2cd5', '\u2cd4'), ('\u2cd7', '\u2cd6'),
+        ('\u2cd9', '\u2cd8'), ('\u2cdb', '\u2cda'), ('\u2cdd', '\u2cdc'),
+        ('\u2cdf', '\u2cde'), ('\u2ce1', '\u2ce0'), ('\u2ce3', '\u2ce2'),
+        ('\u2cec', '\u2ceb'), ('\u2cee', '\u2ced'), ('\u2cf3', '\u2cf2'),
+        ('\u2d00', '\u10a0'), ('\u2d01', '\u10a1'), ('\u2d02', '\u10a2'),
+        ('\u2d03', '\u10a3'), ('\u2d04', '\u10a4'), ('\u2d05', '\u10a5'),
+        ('\u2d06', '\u10a6'), ('\u2d07', '\u10a7'), ('\u2d08', '\u10a8'),
+        ('\u2d09', '\u10a9'), ('\u2d0a', '\u10aa'), ('\u2d0b', '\u10ab'),
+        ('\u2d0c', '\u10ac'), ('\u2d0d', '\u10ad'), ('\u2d0e', '\u10ae'),
+        ('\u2d0f', '\u10af'), ('\u2d10', '\u10b0'), ('\u2d11', '\u10b1'),
+        ('\u2d12', '\u10b2'), ('\u2d13', '\u10b3'), ('\u2d14', '\u10b4'),
+        ('\u2d15', '\u10b5'), ('\u2d16', '\u10b6'), ('\u2d17', '\u10b7'),
+        ('\u2d18', '\u10b8'), ('\u2d19', '\u10b9'), ('\u2d1a', '\u10ba'),
+        ('\u2d1b', '\u10bb'), ('\u2d1c', '\u10bc'), ('\u2d1d', '\u10bd'),
+        ('\u2d1e', '\u10be'), ('\u2d1f', '\u10bf'), ('\u2d20', '\u10c0'),
+        ('\u2d21', '\u10c1'), ('\u2d22', '\u10c2'), ('\u2d23', '\u10c3'),
+        ('\u2d24', '\u10c4'), ('\u2d25', '\u10c5'), ('\u2d27', '\u10c7'),
+        ('\u2d2d', '\u10cd'), ('\ua641', '\ua640'), ('\ua643', '\ua642'),
+        ('\ua645', '\ua644'), ('\ua647', '\ua646'), ('\ua649', '\ua648'),
+        ('\ua64b', '\ua64a'), ('\ua64d', '\ua64c'), ('\ua64f', '\ua64e'),
+        ('\ua651', '\ua650'), ('\ua653', '\ua652'), ('\ua655', '\ua654'),
+        ('\ua657', '\ua656'), ('\ua659', '\ua658'), ('\ua65b', '\ua65a'),
+        ('\ua65d', '\ua65c'), ('\ua65f', '\ua65e'), ('\ua661', '\ua660'),
+        ('\ua663', '\ua662'), ('\ua665', '\ua664'), ('\ua667', '\ua666'),
+        ('\ua669', '\ua668'), ('\ua66b', '\ua66a'), ('\ua66d', '\ua66c'),
+        ('\ua681', '\ua680'), ('\ua683', '\ua682'), ('\ua685', '\ua684'),
+        ('\ua687', '\ua686'), ('\ua689', '\ua688'), ('\ua68b', '\ua68a'),
+        ('\ua68d', '\ua68c'), ('\ua68f', '\ua68e'), ('\ua691', '\ua690'),
+        ('\ua693', '\ua692'), ('\ua695', '\ua694'), ('\ua697', '\ua696'),
+        ('\ua699', '\ua698'), ('\ua69b', '\ua69a'), ('\ua723', '\ua722'),
+        ('\ua725', '\ua724'), ('\ua727', '\ua726'), ('\ua729', '\ua728'),
+        ('\ua72b', '\ua72a'), ('\ua72d', '\ua72c'), ('\ua72f', '\ua72e'),
+        ('\ua733', '\ua732'), ('\ua735', '\ua734'), ('\ua737', '\ua736'),
+        ('\ua739', '\ua738'), ('\ua73b', '\ua73a'), ('\ua73d', '\ua73c'),
+        ('\ua73f', '\ua73e'), ('\ua741', '\ua740'), ('\ua743', '\ua742'),
+        ('\ua745', '\ua744'), ('\ua747', '\ua746'), ('\ua749', '\ua748'),
+        ('\ua74b', '\ua74a'), ('\ua74d', '\ua74c'), ('\ua74f', '\ua74e'),
+        ('\ua751', '\ua750'), ('\ua753', '\ua752'), ('\ua755', '\ua754'),
+        ('\ua757', '\ua756'), ('\ua759', '\ua758'), ('\ua75b', '\ua75a'),
+        ('\ua75d', '\ua75c'), ('\ua75f', '\ua75e'), ('\ua761', '\ua760'),
+        ('\ua763', '\ua762'), ('\ua765', '\ua764'), ('\ua767', '\ua766'),
+        ('\ua769', '\ua768'), ('\ua76b', '\ua76a'), ('\ua76d', '\ua76c'),
+        ('\ua76f', '\ua76e'), ('\ua77a', '\ua779'), ('\ua77c', '\ua77b'),
+        ('\ua77f', '\ua77e'), ('\ua781', '\ua780'), ('\ua783', '\ua782'),
+        ('\ua785', '\ua784'), ('\ua787', '\ua786'), ('\ua78c', '\ua78b'),
+        ('\ua791', '\ua790'), ('\ua793', '\ua792'), ('\ua794', '\ua7c4'),
+        ('\ua797', '\ua796'), ('\ua799', '\ua798'), ('\ua79b', '\ua79a'),
+        ('\ua79d', '\ua79c'), ('\ua79f', '\ua79e'), ('\ua7a1', '\ua7a0'),
+        ('\ua7a3', '\ua7a2'), ('\ua7a5', '\ua7a4'), ('\ua7a7', '\ua7a6'),
+        ('\ua7a9', '\ua7a8'), ('\ua7b5', '\ua7b4'), ('\ua7b7', '\ua7b6'),
+        ('\ua7b9', '\ua7b8'), ('\ua7bb', '\ua7ba'), ('\ua7bd', '\ua7bc'),
+        ('\ua7bf', '\ua7be'), ('\ua7c1', '\ua7c0'), ('\ua7c3', '\ua7c2'),
+        ('\ua7c8', '\ua7c7'), ('\ua7ca', '\ua7c9'), ('\ua7d1', '\ua7d0'),
+        ('\ua7d7', '\ua7d6'), ('\ua7d9', '\ua7d8'), ('\ua7f6', '\ua7f5'),
+        ('\uab53', '\ua7b3'), ('\uab70', '\u13a0'), ('\uab71', '\u13a1'),
+        ('\uab72', '\u13a2'), ('\uab73', '\u13a3'), ('\uab74', '\u13a4'),
+        ('\uab75', '\u13a5'), ('\uab76', '\u13a6'), ('\uab77', '\u13a7'),
+        ('\uab78', '\u13a8'), ('\uab79', '\u13a9'), ('\uab7a', '\u13aa'),
+        ('\uab7b', '\u13ab'), ('\uab7c', '\u13ac'), ('\uab7d', '\u13ad'),
+        ('\uab7e', '\u13ae'), ('\uab7f', '\u13af'), ('\uab80', '\u13b0'),
+        ('\uab81', '\u13b1'), ('\uab82', '\u13b2'), ('\uab83', '\u13b3'),
+        ('\uab84', '\u13b4'), ('\uab85', '\u13b5'), ('\uab86', '\u13b6'),
+        ('\uab87', '\u13b7'), ('\uab88', '\u13b8'), ('\uab89', '\u13b9'),
+        ('\uab8a', '\u13ba'), ('\uab8b', '\u13bb'), ('\uab8c', '\u13bc'),
+        ('\uab8d', '\u13bd'), ('\uab8e', '\u13be'), ('\uab8f', '\u13bf'),
+        ('\uab90', '\u13c0'), ('\uab91', '\u13c1'), ('\uab92', '\u13c2'),
+        ('\uab93', '\u13c3'), ('\uab94', '\u13c4'), ('\uab95', '\u13c5'),
+        ('\uab96', '\u13c6'), ('\uab97', '\u13c7'), ('\uab98', '\u13c8'),
+        ('\uab99', '\u13c9'), ('\uab9a', '\u13ca'), ('\uab9b', '\u13cb'),
+        ('\uab9c', '\u13cc'), ('\uab9d', '\u13cd'), ('\uab9e', '\u13ce'),
+        ('\uab9f', '\u13cf'), ('\uaba0', '\u13d0'), ('\uaba1', '\u13d1'),
+        ('\uaba2', '\u13d2'), ('\uaba3', '\u13d3'), ('\uaba4', '\u13d4'),
+        ('\uaba5', '\u13d5'), ('\uaba6', '\u13d6'), ('\uaba7', '\u13d7'),
+        ('\uaba8', '\u13d8'), ('\uaba9', '\u13d9'), ('\uabaa', '\u13da'),
+        ('\uabab', '\u13db'), ('\uabac', '\u13dc'), ('\uabad', '\u13dd'),
+        ('\uabae', '\u13de'), ('\uabaf', '\u13df'), ('\uabb0', '\u13e0'),
+        ('\uabb1', '\u13e1'), ('\uabb2', '\u13e2'), ('\uabb3', '\u13e3'),
+        ('\uabb4', '\u13e4'), ('\uabb5', '\u13e5'), ('\uabb6', '\u13e6'),
+        ('\uabb7', '\u13e7'), ('\uabb8', '\u13e8'), ('\uabb9', '\u13e9'),
+        ('\uabba', '\u13ea'), ('\uabbb', '\u13eb'), ('\uabbc', '\u13ec'),
+        ('\uabbd', '\u13ed'), ('\uabbe', '\u13ee'), ('\uabbf', '\u13ef'),
+        ('\uff41', '\uff21'), ('\uff42', '\uff22'), ('\uff43', '\uff23'),
+        ('\uff44', '\uff24'), ('\uff45', '\uff25'), ('\uff46', '\uff26'),
+        ('\uff47', '\uff27'), ('\uff48', '\uff28'), ('\uff49', '\uff29'),
+        ('\uff4a', '\uff2a'), ('\uff4b', '\uff2b'), ('\uff4c', '\uff2c'),
+        ('\uff4d', '\uff2d'), ('\uff4e', '\uff2e'), ('\uff4f', '\uff2f'),
+        ('\uff50', '\uff30'), ('\uff51', '\uff31'), ('\uff52', '\uff32'),
+        ('\uff53', '\uff33'), ('\uff54', '\uff34'), ('\uff55', '\uff35'),
+        ('\uff56', '\uff36'), ('\uff57', '\uff37'), ('\uff58', '\uff38'),
+        ('\uff59', '\uff39'), ('\uff5a', '\uff3a'), ('\U00010428', '\U00010400'),
+        ('\U00010429', '\U00010401'), ('\U0001042a', '\U00010402'), ('\U0001042b', '\U00010403'),
+        ('\U0001042c', '\U00010404'), ('\U0001042d', '\U00010405'), ('\U0001042e', '\U00010406'),
+        ('\U0001042f', '\U00010407'), ('\U00010430', '\U00010408'), ('\U00010431', '\U00010409'),
+        ('\U00010432', '\U0001040a'), ('\U00010433', '\U0001040b'), ('\U00010434', '\U0001040c'),
+        ('\U00010435', '\U0001040d'), ('\U00010436', '\U0001040e'), ('\U00010437', '\U0001040f'),
+        ('\U00010438', '\U00010410'), ('\U00010439', '\U00010411'), ('\U0001043a', '\U00010412'),
+        ('\U0001043b', '\U00010413'), ('\U0001043c', '\U00010414'), ('\U0001043d', '\U00010415'),
+        ('\U0001043e', '\U00010416'), ('\U0001043f', '\U00010417'), ('\U00010440', '\U00010418'),
+        ('\U00010441', '\U00010419'), ('\U00010442', '\U0001041a'), ('\U00010443', '\U0001041b'),
+        ('\U00010444', '\U0001041c'), ('\U00010445', '\U0001041d'), ('\U00010446', '\U0001041e'),
+        ('\U00010447', '\U0001041f'), ('\U00010448', '\U00010420'), ('\U00010449', '\U00010421'),
+        ('\U0001044a', '\U00010422'), ('\U0001044b', '\U00010423'), ('\U0001044c', '\U00010424'),
+        ('\U0001044d', '\U00010425'), ('\U0001044e', '\U00010426'), ('\U0001044f', '\U00010427'),
+        ('\U000104d8', '\U000104b0'), ('\U000104d9', '\U000104b1'), ('\U000104da', '\U000104b2'),
+        ('\U000104db', '\U000104b3'), ('\U000104dc', '\U000104b4'), ('\U000104dd', '\U000104b5'),
+        ('\U000104de', '\U000104b6'), ('\U000104df', '\U000104b7'), ('\U000104e0', '\U000104b8'),
+        ('\U000104e1', '\U000104b9'), ('\U000104e2', '\U000104ba'), ('\U000104e3', '\U000104bb'),
+        ('\U000104e4', '\U000104bc'), ('\U000104e5', '\U000104bd'), ('\U000104e6', '\U000104be'),
+        ('\U000104e7', '\U000104bf'), ('\U000104e8', '\U000104c0'), ('\U000104e9', '\U000104c1'),
+        ('\U000104ea', '\U000104c2'), ('\U000104eb', '\U000104c3'), ('\U000104ec', '\U000104c4'),
+        ('\U000104ed', '\U000104c5'), ('\U000104ee', '\U000104c6'), ('\U000104ef', '\U000104c7'),
+        ('\U000104f0', '\U000104c8'), ('\U000104f1', '\U000104c9'), ('\U000104f2', '\U000104ca'),
+        ('\U000104f3', '\U000104cb'), ('\U000104f4', '\U000104cc'), ('\U000104f5', '\U000104cd'),
+        ('\U000104f6', '\U000104ce'), ('\U000104f7', '\U000104cf'), ('\U000104f8', '\U000104d0'),
+        ('\U000104f9', '\U000104d1'), ('\U000104fa', '\U000104d2'), ('\U000104fb', '\U000104d3'),
+        ('\U00010597', '\U00010570'), ('\U00010598', '\U00010571'), ('\U00010599', '\U00010572'),
+        ('\U0001059a', '\U00010573'), ('\U0001059b', '\U00010574'), ('\U0001059c', '\U00010575'),
+        ('\U0001059d', '\U00010576'), ('\U0001059e', '\U00010577'), ('\U0001059f', '\U00010578'),
+        ('\U000105a0', '\U00010579'), ('\U000105a1', '\U0001057a'), ('\U000105a3', '\U0001057c'),
+        ('\U000105a4', '\U0001057d'), ('\U000105a5', '\U0001057e'), ('\U000105a6', '\U0001057f'),
+        ('\U000105a7', '\U00010580'), ('\U000105a8', '\U00010581'), ('\U000105a9', '\U00010582'),
+        ('\U000105aa', '\U00010583'), ('\U000105ab', '\U00010584'), ('\U000105ac', '\U00010585'),
+        ('\U000105ad', '\U00010586'), ('\U000105ae', '\U00010587'), ('\U000105af', '\U00010588'),
+        ('\U000105b0', '\U00010589'), ('\U000105b1', '\U0001058a'), ('\U000105b3', '\U0001058c'),
+        ('\U000105b4', '\U0001058d'), ('\U000105b5', '\U0001058e'), ('\U000105b6', '\U0001058f'),
+        ('\U000105b7', '\U00010590'), ('\U000105b8', '\U00010591'), ('\U000105b9', '\U00010592'),
+        ('\U000105bb', '\U00010594'), ('\U000105bc', '\U00010595'), ('\U00010cc0', '\U00010c80'),
+        ('\U00010cc1', '\U00010c81'), ('\U00010cc2', '\U00010c82'), ('\U00010cc3', '\U00010c83'),
+        ('\U00010cc4', '\U00010c84'), ('\U00010cc5', '\U00010c85'), ('\U00010cc6', '\U00010c86'),
+        ('\U00010cc7', '\U00010c87'), ('\U00010cc8', '\U00010c88'), ('\U00010cc9', '\U00010c89'),
+        ('\U00010cca', '\U00010c8a'), ('\U00010ccb', '\U00010c8b'), ('\U00010ccc', '\U00010c8c'),
+        ('\U00010ccd', '\U00010c8d'), ('\U00010cce', '\U00010c8e'), ('\U00010ccf', '\U00010c8f'),
+        ('\U00010cd0', '\U00010c90'), ('\U00010cd1', '\U00010c91'), ('\U00010cd2', '\U00010c92'),
+        ('\U00010cd3', '\U00010c93'), ('\U00010cd4', '\U00010c94'), ('\U00010cd5', '\U00010c95'),
+        ('\U00010cd6', '\U00010c96'), ('\U00010cd7', '\U00010c97'), ('\U00010cd8', '\U00010c98'),
+        ('\U00010cd9', '\U00010c99'), ('\U00010cda', '\U00010c9a'), ('\U00010cdb', '\U00010c9b'),
+        ('\U00010cdc', '\U00010c9c'), ('\U00010cdd', '\U00010c9d'), ('\U00010cde', '\U00010c9e'),
+        ('\U00010cdf', '\U00010c9f'), ('\U00010ce0', '\U00010ca0'), ('\U00010ce1', '\U00010ca1'),
+        ('\U00010ce2', '\U00010ca2'), ('\U00010ce3', '\U00010ca3'), ('\U00010ce4', '\U00010ca4'),
+        ('\U00010ce5', '\U00010ca5'), ('\U00010ce6', '\U00010ca6'), ('\U00010ce7', '\U00010ca7'),
+        ('\U00010ce8', '\U00010ca8'), ('\U00010ce9', '\U00010ca9'), ('\U00010cea', '\U00010caa'),
+        ('\U00010ceb', '\U00010cab'), ('\U00010cec', '\U00010cac'), ('\U00010ced', '\U00010cad'),
+        ('\U00010cee', '\U00010cae'), ('\U00010cef', '\U00010caf'), ('\U00010cf0', '\U00010cb0'),
+        ('\U00010cf1', '\U00010cb1'), ('\U00010cf2', '\U00010cb2'), ('\U000118c0', '\U000118a0'),
+        ('\U000118c1', '\U000118a1'), ('\U000118c2', '\U000118a2'), ('\U000118c3', '\U000118a3'),
+        ('\U000118c4', '\U000118a4'), ('\U000118c5', '\U000118a5'), ('\U000118c6', '\U000118a6'),
+        ('\U000118c7', '\U000118a7'), ('\U000118c8', '\U000118a8'), ('\U000118c9', '\U000118a9'),
+        ('\U000118ca', '\U000118aa'), ('\U000118cb', '\U000118ab'), ('\U000118cc', '\U000118ac'),
+        ('\U000118cd', '\U000118ad'), ('\U000118ce', '\U000118ae'), ('\U000118cf', '\U000118af'),
+        ('\U000118d0', '\U000118b0'), ('\U000118d1', '\U000118b1'), ('\U000118d2', '\U000118b2'),
+        ('\U000118d3', '\U000118b3'), ('\U000118d4', '\U000118b4'), ('\U000118d5', '\U000118b5'),
+        ('\U000118d6', '\U000118b6'), ('\U000118d7', '\U000118b7'), ('\U000118d8', '\U000118b8'),
+        ('\U000118d9', '\U000118b9'), ('\U000118da', '\U000118ba'), ('\U000118db', '\U000118bb'),
+        ('\U000118dc', '\U000118bc'), ('\U000118dd', '\U000118bd'), ('\U000118de', '\U000118be'),
+        ('\U000118df', '\U000118bf'), ('\U00016e60', '\U00016e40'), ('\U00016e61', '\U00016e41'),
+        ('\U00016e62', '\U00016e42'), ('\U00016e63', '\U00016e43'), ('\U00016e64', '\U00016e44'),
+        ('\U00016e65', '\U00016e45'), ('\U00016e66', '\U00016e46'), ('\U00016e67', '\U00016e47'),
+        ('\U00016e68', '\U00016e48'), ('\U00016e69', '\U00016e49'), ('\U00016e6a', '\U00016e4a'),
+        ('\U00016e6b', '\U00016e4b'), ('\U00016e6c', '\U00016e4c'), ('\U00016e6d', '\U00016e4d'),
+        ('\U00016e6e', '\U00016e4e'), ('\U00016e6f', '\U00016e4f'), ('\U00016e70', '\U00016e50'),
+        ('\U00016e71', '\U00016e51'), ('\U00016e72', '\U00016e52'), ('\U00016e73', '\U00016e53'),
+        ('\U00016e74', '\U00016e54'), ('\U00016e75', '\U00016e55'), ('\U00016e76', '\U00016e56'),
+        ('\U00016e77', '\U00016e57'), ('\U00016e78', '\U00016e58'), ('\U00016e79', '\U00016e59'),
+        ('\U00016e7a', '\U00016e5a'), ('\U00016e7b', '\U00016e5b'), ('\U00016e7c', '\U00016e5c'),
+        ('\U00016e7d', '\U00016e5d'), ('\U00016e7e', '\U00016e5e'), ('\U00016e7f', '\U00016e5f'),
+        ('\U0001e922', '\U0001e900'), ('\U0001e923', '\U0001e901'), ('\U0001e924', '\U0001e902'),
+        ('\U0001e925', '\U0001e903'), ('\U0001e926', '\U0001e904'), ('\U0001e927', '\U0001e905'),
+        ('\U0001e928', '\U0001e906'), ('\U0001e929', '\U0001e907'), ('\U0001e92a', '\U0001e908'),
+        ('\U0001e92b', '\U0001e909'), ('\U0001e92c', '\U0001e90a'), ('\U0001e92d', '\U0001e90b'),
+        ('\U0001e92e', '\U0001e90c'), ('\U0001e92f', '\U0001e90d'), ('\U0001e930', '\U0001e90e'),
+        ('\U0001e931', '\U0001e90f'), ('\U0001e932', '\U0001e910'), ('\U0001e933', '\U0001e911'),
+        ('\U0001e934', '\U0001e912'), ('\U0001e935', '\U0001e913'), ('\U0001e936', '\U0001e914'),
+        ('\U0001e937', '\U0001e915'), ('\U0001e938', '\U0001e916'), ('\U0001e939', '\U0001e917'),
+        ('\U0001e93a', '\U0001e918'), ('\U0001e93b', '\U0001e919'), ('\U0001e93c', '\U0001e91a'),
+        ('\U0001e93d', '\U0001e91b'), ('\U0001e93e', '\U0001e91c'), ('\U0001e93f', '\U0001e91d'),
+        ('\U0001e940', '\U0001e91e'), ('\U0001e941', '\U0001e91f'), ('\U0001e942', '\U0001e920'),
+        ('\U0001e943', '\U0001e921')
+    ]
+}
+
+// Pairs of each char with a simple lowercase mapping and the char
+// it maps to, sorted by the first.
+fn lower_table() -> ~[(char, char)] {
+    ~[
+        ('\u0041', '\u0061'), ('\u0042', '\u0062'), ('\u0043', '\u0063'),
+        ('\u0044', '\u0064'), ('\u0045', '\u0065'), ('\u0046', '\u0066'),
+        ('\u0047', '\u0067'), ('\u0048', '\u0068'), ('\u0049', '\u0069'),
+        ('\u004a', '\u006a'), ('\u004b', '\u006b'), ('\u004c', '\u006c'),
+        ('\u004d', '\u006d'), ('\u004e', '\u006e'), ('\u004f', '\u006f'),
+        ('\u0050', '\u0070'), ('\u0051', '\u0071'), ('\u0052', '\u0072'),
+        ('\u0053', '\u0073'), ('\u0054', '\u0074'), ('\u0055', '\u0075'),
+        ('\u0056', '\u0076'), ('\u0057', '\u0077'), ('\u0058', '\u0078'),
+        ('\u0059', '\u0079'), ('\u005a', '\u007a'), ('\u00c0', '\u00e0'),
+        ('\u00c1', '\u00e1'), ('\u00c2', '\u00e2'), ('\u00c3', '\u00e3'),
+        ('\u00c4', '\u00e4'), ('\u00c5', '\u00e5'), ('\u00c6', '\u00e6'),
+        ('\u00c7', '\u00e7'), ('\u00c8', '\u00e8'), ('\u00c9', '\u00e9'),
+        ('\u00ca', '\u00ea'), ('\u00cb', '\u00eb'), ('\u00cc', '\u00ec'),
+        ('\u00cd', '\u00ed'), ('\u00ce', '\u00ee'), ('\u00cf', '\u00ef'),
+        ('\u00d0', '\u00f0'), ('\u00d1', '\u00f1'), ('\u00d2', '\u00f2'),
+        ('\u00d3', '\u00f3'), ('\u00d4', '\u00f4'), ('\u00d5', '\u00f5'),
+        ('\u00d6', '\u00f6'), ('\u00d8', '\u00f8'), ('\u00d9', '\u00f9'),
+        ('\u00da', '\u00fa'), ('\u00db', '\u00fb'), ('\u00dc', '\u00fc'),
+        ('\u00dd', '\u00fd'), ('\u00de', '\u00fe'), ('\u0100', '\u0101'),
+        ('\u0102', '\u0103'), ('\u0104', '\u0105'), ('\u0106', '\u0107'),
+        ('\u0108', '\u0109'), ('\u010a', '\u010b'), ('\u010c', '\u010d'),
+        ('\u010e', '\u010f'), ('\u0110', '\u0111'), ('\u0112', '\u0113'),
+        ('\u0114', '\u0115'), ('\u0116', '\u0117'), ('\u0118', '\u0119'),
+        ('\u011a', '\u011b'), ('\u011c', '\u011d'), ('\u011e', '\u011f'),
+        ('\u0120', '\u0121'), ('\u0122', '\u0123'), ('\u0124', '\u0125'),
+        ('\u0126', '\u0127'), ('\u0128', '\u0129'), ('\u012a', '\u012b'),
+        ('\u012c', '\u012d'), ('\u012e', '\u012f'), ('\u0130', '\u0069'),
+        ('\u0132', '\u0133'), ('\u0134', '\u0135'), ('\u0136', '\u0137'),
+        ('\u0139', '\u013a'), ('\u013b', '\u013c'), ('\u013d', '\u013e'),
+        ('\u013f', '\u0140'), ('\u0141', '\u0142'), ('\u0143', '\u0144'),
+        ('\u0145', '\u0146'), ('\u0147', '\u0148'), ('\u014a', '\u014b'),
+        ('\u014c', '\u014d'), ('\u014e', '\u014f'), ('\u0150', '\u0151'),
+        ('\u0152', '\u0153'), ('\u0154', '\u0155'), ('\u0156', '\u0157'),
+        ('\u0158', '\u0159'), ('\u015a', '\u015b'), ('\u015c', '\u015d'),
+        ('\u015e', '\u015f'), ('\u0160', '\u0161'), ('\u0162', '\u0163'),
+        ('\u0164', '\u0165'), ('\u0166', '\u0167'), ('\u0168', '\u0169'),
+        ('\u016a', '\u016b'), ('\u016c', '\u016d'), ('\u016e', '\u016f'),
+        ('\u0170', '\u0171'), ('\u0172', '\u0173'), ('\u0174', '\u0175'),
+        ('\u0176', '\u0177'), ('\u0178', '\u00ff'), ('\u0179', '\u017a'),
+        ('\u017b', '\u017c'), ('\u017d', '\u017e'), ('\u0181', '\u0253'),
+        ('\u0182', '\u0183'), ('\u0184', '\u0185'), ('\u0186', '\u0254'),
+        ('\u0187', '\u0188'), ('\u0189', '\u0256'), ('\u018a', '\u0257'),
+        ('\u018b', '\u018c'), ('\u018e', '\u01dd'), ('\u018f', '\u0259'),
+        ('\u0190', '\u025b'), ('\u0191', '\u0192'), ('\u0193', '\u0260'),
+        ('\u0194', '\u0263'), ('\u0196', '\u0269'), ('\u0197', '\u0268'),
+        ('\u0198', '\u0199'), ('\u019c', '\u026f'), ('\u019d', '\u0272'),
+        ('\u019f', '\u0275'), ('\u01a0', '\u01a1'), ('\u01a2', '\u01a3'),
+        ('\u01a4', '\u01a5'), ('\u01a6', '\u0280'), ('\u01a7', '\u01a8'),
+        ('\u01a9', '\u0283'), ('\u01ac', '\u01ad'), ('\u01ae', '\u0288'),
+        ('\u01af', '\u01b0'), ('\u01b1', '\u028a'), ('\u01b2', '\u028b'),
+        ('\u01b3', '\u01b4'), ('\u01b5', '\u01b6'), ('\u01b7', '\u0292'),
+        ('\u01b8', '\u01b9'), ('\u01bc', '\u01bd'), ('\u01c4', '\u01c6'),
+        ('\u01c5', '\u01c6'), ('\u01c7', '\u01c9'), ('\u01c8', '\u01c9'),
+        ('\u01ca', '\u01cc'), ('\u01cb', '\u01cc'), ('\u01cd', '\u01ce'),
+        ('\u01cf', '\u01d0'), ('\u01d1', '\u01d2'), ('\u01d3', '\u01d4'),
+        ('\u01d5', '\u01d6'), ('\u01d7', '\u01d8'), ('\u01d9', '\u01da'),
+        ('\u01db', '\u01dc'), ('\u01de', '\u01df'), ('\u01e0', '\u01e1'),
+        ('\u01e2', '\u01e3'), ('\u01e4', '\u01e5'), ('\u01e6', '\u01e7'),
+        ('\u01e8', '\u01e9'), ('\u01ea', '\u01eb'), ('\u01ec', '\u01ed'),
+        ('\u01ee', '\u01ef'), ('\u01f1', '\u01f3'), ('\u01f2', '\u01f3'),
+        ('\u01f4', '\u01f5'), ('\u01f6', '\u0195'), ('\u01f7', '\u01bf'),
+        ('\u01f8', '\u01f9'), ('\u01fa', '\u01fb'), ('\u01fc', '\u01fd'),
+        ('\u01fe', '\u01ff'), ('\u0200', '\u0201'), ('\u0202', '\u0203'),
+        ('\u0204', '\u0205'), ('\u0206', '\u0207'), ('\u0208', '\u0209'),
+        ('\u020a', '\u020b'), ('\u020c', '\u020d'), ('\u020e', '\u020f'),
+        ('\u0210', '\u0211'), ('\u0212', '\u0213'), ('\u0214', '\u0215'),
+        ('\u0216', '\u0217'), ('\u0218', '\u0219'), ('\u021a', '\u021b'),
+        ('\u021c', '\u021d'), ('\u021e', '\u021f'), ('\u0220', '\u019e'),
+        ('\u0222', '\u0223'), ('\u0224', '\u0225'), ('\u0226', '\u0227'),
+        ('\u0228', '\u0229'), ('\u022a', '\u022b'), ('\u022c', '\u022d'),
+        ('\u022e', '\u022f'), ('\u0230', '\u0231'), ('\u0232', '\u0233'),
+        ('\u023a', '\u2c65'), ('\u023b', '\u023c'), ('\u023d', '\u019a'),
+        ('\u023e', '\u2c66'), ('\u0241', '\u0242'), ('\u0243', '\u0180'),
+        ('\u0244', '\u0289'), ('\u0245', '\u028c'), ('\u0246', '\u0247'),
+        ('\u0248', '\u0249'), ('\u024a', '\u024b'), ('\u024c', '\u024d'),
+        ('\u024e', '\u024f'), ('\u0370', '\u0371'), ('\u0372', '\u0373'),
+        ('\u0376', '\u0377'), ('\u037f', '\u03f3'), ('\u0386', '\u03ac'),
+        ('\u0388', '\u03ad'), ('\u0389', '\u03ae'), ('\u038a', '\u03af'),
+        ('\u038c', '\u03cc'), ('\u038e', '\u03cd'), ('\u038f', '\u03ce'),
+        ('\u0391', '\u03b1'), ('\u0392', '\u03b2'), ('\u0393', '\u03b3'),
+        ('\u0394', '\u03b4'), ('\u0395', '\u03b5'), ('\u0396', '\u03b6'),
+        ('\u0397', '\u03b7'), ('\u0398', '\u03b8'), ('\u0399', '\u03b9'),
+        ('\u039a', '\u03ba'), ('\u039b', '\u03bb'), ('\u039c', '\u03bc'),
+        ('\u039d', '\u03bd'), ('\u039e', '\u03be'), ('\u039f', '\u03bf'),
+        ('\u03a0', '\u03c0'), ('\u03a1', '\u03c1'), ('\u03a3', '\u03c3'),
+        ('\u03a4', '\u03c4'), ('\u03a5', '\u03c5'), ('\u03a6', '\u03c6'),
+        ('\u03a7', '\u03c7'), ('\u03a8', '\u03c8'), ('\u03a9', '\u03c9'),
+        ('\u03aa', '\u03ca'), ('\u03ab', '\u03cb'), ('\u03cf', '\u03d7'),
+        ('\u03d8', '\u03d9'), ('\u03da', '\u03db'), ('\u03dc', '\u03dd'),
+        ('\u03de', '\u03df'), ('\u03e0', '\u03e1'), ('\u03e2', '\u03e3'),
+        ('\u03e4', '\u03e5'), ('\u03e6', '\u03e7'), ('\u03e8', '\u03e9'),
+        ('\u03ea', '\u03eb'), ('\u03ec', '\u03ed'), ('\u03ee', '\u03ef'),
+        ('\u03f4', '\u03b8'), ('\u03f7', '\u03f8'), ('\u03f9', '\u03f2'),
+        ('\u03fa', '\u03fb'), ('\u03fd', '\u037b'), ('\u03fe', '\u037c'),
+        ('\u03ff', '\u037d'), ('\u0400', '\u0450'), ('\u0401', '\u0451'),
+        ('\u0402', '\u0452'), ('\u0403', '\u0453'), ('\u0404', '\u0454'),
+        ('\u0405', '\u0455'), ('\u0406', '\u0456'), ('\u0407', '\u0457'),
+        ('\u0408', '\u0458'), ('\u0409', '\u0459'), ('\u040a', '\u045a'),
+        ('\u040b', '\u045b'), ('\u040c', '\u045c'), ('\u040d', '\u045d'),
+        ('\u040e', '\u045e'), ('\u040f', '\u045f'), ('\u0410', '\u0430'),
+        ('\u0411', '\u0431'), ('\u0412', '\u0432'), ('\u0413', '\u0433'),
+        ('\u0414', '\u0434'), ('\u0415', '\u0435'), ('\u0416', '\u0436'),
+        ('\u0417', '\u0437'), ('\u0418', '\u0438'), ('\u0419', '\u0439'),
+        ('\u041a', '\u043a'), ('\u041b', '\u043b'), ('\u041c', '\u043c'),
+        ('\u041d', '\u043d'), ('\u041e', '\u043e'), ('\u041f', '\u043f'),
+        ('\u0420', '\u0440'), ('\u0421', '\u0441'), ('\u0422', '\u0442'),
+        ('\u0423', '\u0443'), ('\u0424', '\u0444'), ('\u0425', '\u0445'),
+        ('\u0426', '\u0446'), ('\u0427', '\u0447'), ('\u0428', '\u0448'),
+        ('\u0429', '\u0449'), ('\u042a', '\u044a'), ('\u042b', '\u044b'),
+        ('\u042c', '\u044c'), ('\u042d', '\u044d'), ('\u042e', '\u044e'),
+        ('\u042f', '\u044f'), ('\u0460', '\u0461'), ('\u0462', '\u0463'),
+        ('\u0464', '\u0465'), ('\u0466', '\u0467'), ('\u0468', '\u0469'),
+        ('\u046a', '\u046b'), ('\u046c', '\u046d'), ('\u046e', '\u046f'),
+        ('\u0470', '\u0471'), ('\u0472', '\u0473'), ('\u0474', '\u0475'),
+        ('\u0476', '\u0477'), ('\u0478', '\u0479'), ('\u047a', '\u047b'),
+        ('\u047c', '\u047d'), ('\u047e', '\u047f'), ('\u0480', '\u0481'),
+        ('\u048a', '\u048b'), ('\u048c', '\u048d'), ('\u048e', '\u048f'),
+        ('\u0490', '\u0491'), ('\u0492', '\u0493'), ('\u0494', '\u0495'),
+        ('\u0496', '\u0497'), ('\u0498', '\u0499'), ('\u049a', '\u049b'),
+        ('\u049c', '\u049d'), ('\u049e', '\u049f'), ('\u04a0', '\u04a1'),
+        ('\u04a2', '\u04a3'), ('\u04a4', '\u04a5'), ('\u04a6', '\u04a7'),
+        ('\u04a8', '\u04a9'), ('\u04aa', '\u04ab'), ('\u04ac', '\u04ad'),
+        ('\u04ae', '\u04af'), ('\u04b0', '\u04b1'), ('\u04b2', '\u04b3'),
+        ('\u04b4', '\u04b5'), ('\u04b6', '\u04b7'), ('\u04b8', '\u04b9'),
+        ('\u04ba', '\u04bb'), ('\u04bc', '\u04bd'), ('\u04be', '\u04bf'),
+        ('\u04c0', '\u04cf'), ('\u04c1', '\u04c2'), ('\u04c3', '\u04c4'),
+        ('\u04c5', '\u04c6'), ('\u04c7', '\u04c8'), ('\u04c9', '\u04ca'),
+        ('\u04cb', '\u04cc'), ('\u04cd', '\u04ce'), ('\u04d0', '\u04d1'),
+        ('\u04d2', '\u04d3'), ('\u04d4', '\u04d5'), ('\u04d6', '\u04d7'),
+        ('\u04d8', '\u04d9'), ('\u04da', '\u04db'), ('\u04dc', '\u04dd'),
+        ('\u04de', '\u04df'), ('\u04e0', '\u04e1'), ('\u04e2', '\u04e3'),
+        ('\u04e4', '\u04e5'), ('\u04e6', '\u04e7'), ('\u04e8', '\u04e9'),
+        ('\u04ea', '\u04eb'), ('\u04ec', '\u04ed'), ('\u04ee', '\u04ef'),
+        ('\u04f0', '\u04f1'), ('\u04f2', '\u04f3'), ('\u04f4', '\u04f5'),
+        ('\u04f6', '\u04f7'), ('\u04f8', '\u04f9'), ('\u04fa', '\u04fb'),
+        ('\u04fc', '\u04fd'), ('\u04fe', '\u04ff'), ('\u0500', '\u0501'),
+        ('\u0502', '\u0503'), ('\u0504', '\u0505'), ('\u0506', '\u0507'),
+        ('\u0508', '\u0509'), ('\u050a', '\u050b'), ('\u050c', '\u050d'),
+        ('\u050e', '\u050f'), ('\u0510', '\u0511'), ('\u0512', '\u0513'),
+        ('\u0514', '\u0515'), ('\u0516', '\u0517'), ('\u0518', '\u0519'),
+        ('\u051a', '\u051b'), ('\u051c', '\u051d'), ('\u051e', '\u051f'),
+        ('\u0520', '\u0521'), ('\u0522', '\u0523'), ('\u0524', '\u0525'),
+        ('\u0526', '\u0527'), ('\u0528', '\u0529'), ('\u052a', '\u052b'),
+        ('\u052c', '\u052d'), ('\u052e', '\u052f'), ('\u0531', '\u0561'),
+        ('\u0532', '\u0562'), ('\u0533', '\u0563'), ('\u0534', '\u0564'),
+        ('\u0535', '\u0565'), ('\u0536', '\u0566'), ('\u0537', '\u0567'),
+        ('\u0538', '\u0568'), ('\u0539', '\u0569'), ('\u053a', '\u056a'),
+        ('\u053b', '\u056b'), ('\u053c', '\u056c'), ('\u053d', '\u056d'),
+        ('\u053e', '\u056e'), ('\u053f', '\u056f'), ('\u0540', '\u0570'),
+        ('\u0541', '\u0571'), ('\u0542', '\u0572'), ('\u0543', '\u0573'),
+        ('\u0544', '\u0574'), ('\u0545', '\u0575'), ('\u0546', '\u0576'),
+        ('\u0547', '\u0577'), ('\u0548', '\u0578'), ('\u0549', '\u0579'),
+        ('\u054a', '\u057a'), ('\u054b', '\u057b'), ('\u054c', '\u057c'),
+        ('\u054d', '\u057d'), ('\u054e', '\u057e'), ('\u054f', '\u057f'),
+        ('\u0550', '\u0580'), ('\u0551', '\u0581'), ('\u0552', '\u0582'),
+        ('\u0553', '\u0583'), ('\u0554', '\u0584'), ('\u0555', '\u0585'),
+        ('\u0556', '\u0586'), ('\u10a0', '\u2d00'), ('\u10a1', '\u2d01'),
+        ('\u10a2', '\u2d02'), ('\u10a3', '\u2d03'), ('\u10a4', '\u2d04'),
+        ('\u10a5', '\u2d05'), ('\u10a6', '\u2d06'), ('\u10a7', '\u2d07'),
+        ('\u10a8', '\u2d08'), ('\u10a9', '\u2d09'), ('\u10aa', '\u2d0a'),
+        ('\u10ab', '\u2d0b'), ('\u10ac', '\u2d0c'), ('\u10ad', '\u2d0d'),
+        ('\u10ae', '\u2d0e'), ('\u10af', '\u2d0f'), ('\u10b0', '\u2d10'),
+        ('\u10b1', '\u2d11'), ('\u10b2', '\u2d12'), ('\u10b3', '\u2d13'),
+        ('\u10b4', '\u2d14'), ('\u10b5', '\u2d15'), ('\u10b6', '\u2d16'),
+        ('\u10b7', '\u2d17'), ('\u10b8', '\u2d18'), ('\u10b9', '\u2d19'),
+        ('\u10ba', '\u2d1a'), ('\u10bb', '\u2d1b'), ('\u10bc', '\u2d1c'),
+        ('\u10bd', '\u2d1d'), ('\u10be', '\u2d1e'), ('\u10bf', '\u2d1f'),
+        ('\u10c0', '\u2d20'), ('\u10c1', '\u2d21'), ('\u10c2', '\u2d22'),
+        ('\u10c3', '\u2d23'), ('\u10c4', '\u2d24'), ('\u10c5', '\u2d25'),
+        ('\u10c7', '\u2d27'), ('\u10cd', '\u2d2d'), ('\u13a0', '\uab70'),
+        ('\u13a1', '\uab71'), ('\u13a2', '\uab72'), ('\u13a3', '\uab73'),
+        ('\u13a4', '\uab74'), ('\u13a5', '\uab75'), ('\u13a6', '\uab76'),
+        ('\u13a7', '\uab77'), ('\u13a8', '\uab78'), ('\u13a9', '\uab79'),
+        ('\u13aa', '\uab7a'), ('\u13ab', '\uab7b'), ('\u13ac', '\uab7c'),
+        ('\u13ad', '\uab7d'), ('\u13ae', '\uab7e'), ('\u13af', '\uab7f'),
+        ('\u13b0', '\uab80'), ('\u13b1', '\uab81'), ('\u13b2', '\uab82'),
+        ('\u13b3', '\uab83'), ('\u13b4', '\uab84'), ('\u13b5', '\uab85'),
+        ('\u13b6', '\uab86'), ('\u13b7', '\uab87'), ('\u13b8', '\uab88'),
+        ('\u13b9', '\uab89'), ('\u13ba', '\uab8a'), ('\u13bb', '\uab8b'),
+        ('\u13bc', '\uab8c'), ('\u13bd', '\uab8d'), ('\u13be', '\uab8e'),
+        ('\u13bf', '\uab8f'), ('\u13c0', '\uab90'), ('\u13c1', '\uab91'),
+        ('\u13c2', '\uab92'), ('\u13c3', '\uab93'), ('\u13c4', '\uab94'),
+        ('\u13c5', '\uab95'), ('\u13c6', '\uab96'), ('\u13c7', '\uab97'),
+        ('\u13c8', '\uab98'), ('\u13c9', '\uab99'), ('\u13ca', '\uab9a'),
+        ('\u13cb', '\uab9b'), ('\u13cc', '\uab9c'), ('\u13cd', '\uab9d'),
+        ('\u13ce', '\uab9e'), ('\u13cf', '\uab9f'), ('\u13d0', '\uaba0'),
+        ('\u13d1', '\uaba1'), ('\u13d2', '\uaba2'), ('\u13d3', '\uaba3'),
+        ('\u13d4', '\uaba4'), ('\u13d5', '\uaba5'), ('\u13d6', '\uaba6'),
+        ('\u13d7', '\uaba7'), ('\u13d8', '\uaba8'), ('\u13d9', '\uaba9'),
+        ('\u13da', '\uabaa'), ('\u13db', '\uabab'), ('\u13dc', '\uabac'),
+        ('\u13dd', '\uabad'), ('\u13de', '\uabae'), ('\u13df', '\uabaf'),
+        ('\u13e0', '\uabb0'), ('\u13e1', '\uabb1'), ('\u13e2', '\uabb2'),
+        ('\u13e3', '\uabb3'), ('\u13e4', '\uabb4'), ('\u13e5', '\uabb5'),
+        ('\u13e6', '\uabb6'), ('\u13e7', '\uabb7'), ('\u13e8', '\uabb8'),
+        ('\u13e9', '\uabb9'), ('\u13ea', '\uabba'), ('\u13eb', '\uabbb'),
+        ('\u13ec', '\uabbc'), ('\u13ed', '\uabbd'), ('\u13ee', '\uabbe'),
+        ('\u13ef', '\uabbf'), ('\u13f0', '\u13f8'), ('\u13f1', '\u13f9'),
+        ('\u13f2', '\u13fa'), ('\u13f3', '\u13fb'), ('\u13f4', '\u13fc'),
+        ('\u13f5', '\u13fd'), ('\u1c90', '\u10d0'), ('\u1c91', '\u10d1'),
+        ('\u1c92', '\u10d2'), ('\u1c93', '\u10d3'), ('\u1c94', '\u10d4'),
+        ('\u1c95', '\u10d5'), ('\u1c96', '\u10d6'), ('\u1c97', '\u10d7'),
+        ('\u1c98', '\u10d8'), ('\u1c99', '\u10d9'), ('\u1c9a', '\u10da'),
+        ('\u1c9b', '\u10db'), ('\u1c9c', '\u10dc'), ('\u1c9d', '\u10dd'),
+        ('\u1c9e', '\u10de'), ('\u1c9f', '\u10df'), ('\u1ca0', '\u10e0'),
+        ('\u1ca1', '\u10e1'), ('\u1ca2', '\u10e2'), ('\u1ca3', '\u10e3'),
+        ('\u1ca4', '\u10e4'), ('\u1ca5', '\u10e5'), ('\u1ca6', '\u10e6'),
+        ('\u1ca7', '\u10e7'), ('\u1ca8', '\u10e8'), ('\u1ca9', '\u10e9'),
+        ('\u1caa', '\u10ea'), ('\u1cab', '\u10eb'), ('\u1cac', '\u10ec'),
+        ('\u1cad', '\u10ed'), ('\u1cae', '\u10ee'), ('\u1caf', '\u10ef'),
+        ('\u1cb0', '\u10f0'), ('\u1cb1', '\u10f1'), ('\u1cb2', '\u10f2'),
+        ('\u1cb3', '\u10f3'), ('\u1cb4', '\u10f4'), ('\u1cb5', '\u10f5'),
+        ('\u1cb6', '\u10f6'), ('\u1cb7', '\u10f7'), ('\u1cb8', '\u10f8'),
+        ('\u1cb9', '\u10f9'), ('\u1cba', '\u10fa'), ('\u1cbd', '\u10fd'),
+        ('\u1cbe', '\u10fe'), ('\u1cbf', '\u10ff'), ('\u1e00', '\u1e01'),
+        ('\u1e02', '\u1e03'), ('\u1e04', '\u1e05'), ('\u1e06', '\u1e07'),
+        ('\u1e08', '\u1e09'), ('\u1e0a', '\u1e0b'), ('\u1e0c', '\u1e0d'),
+        ('\u1e0e', '\u1e0f'), ('\u1e10', '\u1e11'), ('\u1e12', '\u1e13'),
+        ('\u1e14', '\u1e15'), ('\u1e16', '\u1e17'), ('\u1e18', '\u1e19'),
+        ('\u1e1a', '\u1e1b'), ('\u1e1c', '\u1e1d'), ('\u1e1e', '\u1e1f'),
+        ('\u1e20', '\u1e21'), ('\u1e22', '\u1e23'), ('\u1e24', '\u1e25'),
+        ('\u1e26', '\u1e27'), ('\u1e28', '\u1e29'), ('\u1e2a', '\u1e2b'),
+        ('\u1e2c', '\u1e2d'), ('\u1e2e', '\u1e2f'), ('\u1e30', '\u1e31'),
+        ('\u1e32', '\u1e33'), ('\u1e34', '\u1e35'), ('\u1e36', '\u1e37'),
+        ('\u1e38', '\u1e39'), ('\u1e3a', '\u1e3b'), ('\u1e3c', '\u1e3d'),
+        ('\u1e3e', '\u1e3f'), ('\u1e40', '\u1e41'), ('\u1e42', '\u1e43'),
+        ('\u1e44', '\u1e45'), ('\u1e46', '\u1e47'), ('\u1e48', '\u1e49'),
+        ('\u1e4a', '\u1e4b'), ('\u1e4c', '\u1e4d'), ('\u1e4e', '\u1e4f'),
+        ('\u1e50', '\u1e51'), ('\u1e52', '\u1e53'), ('\u1e54', '\u1e55'),
+        ('\u1e56', '\u1e57'), ('\u1e58', '\u1e59'), ('\u1e5a', '\u1e5b'),
+        ('\u1e5c', '\u1e5d'), ('\u1e5e', '\u1e5f'), ('\u1e60', '\u1e61'),
+        ('\u1e62', '\u1e63'), ('\u1e64', '\u1e65'), ('\u1e66', '\u1e67'),
+        ('\u1e68', '\u1e69'), ('\u1e6a', '\u1e6b'), ('\u1e6c', '\u1e6d'),
+        ('\u1e6e', '\u1e6f'), ('\u1e70', '\u1e71'), ('\u1e72', '\u1e73'),
+        ('\u1e74', '\u1e75'), ('\u1e76', '\u1e77'), ('\u1e78', '\u1e79'),
+        ('\u1e7a', '\u1e7b'), ('\u1e7c', '\u1e7d'), ('\u1e7e', '\u1e7f'),
+        ('\u1e80', '\u1e81'), ('\u1e82', '\u1e83'), ('\u1e84', '\u1e85'),
+        ('\u1e86', '\u1e87'), ('\u1e88', '\u1e89'), ('\u1e8a', '\u1e8b'),
+        ('\u1e8c', '\u1e8d'), ('\u1e8e', '\u1e8f'), ('\u1e90', '\u1e91'),
+        ('\u1e92', '\u1e93'), ('\u1e94', '\u1e95'), ('\u1e9e', '\u00df'),
+        ('\u1ea0', '\u1ea1'), ('\u1ea2', '\u1ea3'), ('\u1ea4', '\u1ea5'),
+        ('\u1ea6', '\u1ea7'), ('\u1ea8', '\u1ea9'), ('\u1eaa', '\u1eab'),
+        ('\u1eac', '\u1ead'), ('\u1eae', '\u1eaf'), ('\u1eb0', '\u1eb1'),
+        ('\u1eb2', '\u1eb3'), ('\u1eb4', '\u1eb5'), ('\u1eb6', '\u1eb7'),
+        ('\u1eb8', '\u1eb9'), ('\u1eba', '\u1ebb'), ('\u1ebc', '\u1ebd'),
+        ('\u1ebe', '\u1ebf'), ('\u1ec0', '\u1ec1'), ('\u1ec2', '\u1ec3'),
+        ('\u1ec4', '\u1ec5'), ('\u1ec6', '\u1ec7'), ('\u1ec8', '\u1ec9'),
+        ('\u1eca', '\u1ecb'), ('\u1ecc', '\u1ecd'), ('\u1ece', '\u1ecf'),
+        ('\u1ed0', '\u1ed1'), ('\u1ed2', '\u1ed3'), ('\u1ed4', '\u1ed5'),
+        ('\u1ed6', '\u1ed7'), ('\u1ed8', '\u1ed9'), ('\u1eda', '\u1edb'),
+        ('\u1edc', '\u1edd'), ('\u1ede', '\u1edf'), ('\u1ee0', '\u1ee1'),
+        ('\u1ee2', '\u1ee3'), ('\u1ee4', '\u1ee5'), ('\u1ee6', '\u1ee7'),
+        ('\u1ee8', '\u1ee9'), ('\u1eea', '\u1eeb'), ('\u1eec', '\u1eed'),
+        ('\u1eee', '\u1eef'), ('\u1ef0', '\u1ef1'), ('\u1ef2', '\u1ef3'),
+        ('\u1ef4', '\u1ef5'), ('\u1ef6', '\u1ef7'), ('\u1ef8', '\u1ef9'),
+        ('\u1efa', '\u1efb'), ('\u1efc', '\u1efd'), ('\u1efe', '\u1eff'),
+        ('\u1f08', '\u1f00'), ('\u1f09', '\u1f01'), ('\u1f0a', '\u1f02'),
+        ('\u1f0b', '\u1f03'), ('\u1f0c', '\u1f04'), ('\u1f0d', '\u1f05'),
+        ('\u1f0e', '\u1f06'), ('\u1f0f', '\u1f07'), ('\u1f18', '\u1f10'),
+        ('\u1f19', '\u1f11'), ('\u1f1a', '\u1f12'), ('\u1f1b', '\u1f13'),
+        ('\u1f1c', '\u1f14'), ('\u1f1d', '\u1f15'), ('\u1f28', '\u1f20'),
+        ('\u1f29', '\u1f21'), ('\u1f2a', '\u1f22'), ('\u1f2b', '\u1f23'),
+        ('\u1f2c', '\u1f24'), ('\u1f2d', '\u1f25'), ('\u1f2e', '\u1f26'),
+        ('\u1f2f', '\u1f27'), ('\u1f38', '\u1f30'), ('\u1f39', '\u1f31'),
+        ('\u1f3a', '\u1f32'), ('\u1f3b', '\u1f33'), ('\u1f3c', '\u1f34'),
+        ('\u1f3d', '\u1f35'), ('\u1f3e', '\u1f36'), ('\u1f3f', '\u1f37'),
+        ('\u1f48', '\u1f40'), ('\u1f49', '\u1f41'), ('\u1f4a', '\u1f42'),
+        ('\u1f4b', '\u1f43'), ('\u1f4c', '\u1f44'), ('\u1f4d', '\u1f45'),
+        ('\u1f59', '\u1f51'), ('\u1f5b', '\u1f53'), ('\u1f5d', '\u1f55'),
+        ('\u1f5f', '\u1f57'), ('\u1f68', '\u1f60'), ('\u1f69', '\u1f61'),
+        ('\u1f6a', '\u1f62'), ('\u1f6b', '\u1f63'), ('\u1f6c', '\u1f64'),
+        ('\u1f6d', '\u1f65'), ('\u1f6e', '\u1f66'), ('\u1f6f', '\u1f67'),
+        ('\u1f88', '\u1f80'), ('\u1f89', '\u1f81'), ('\u1f8a', '\u1f82'),
+        ('\u1f8b', '\u1f83'), ('\u1f8c', '\u1f84'), ('\u1f8d', '\u1f85'),
+        ('\u1f8e', '\u1f86'), ('\u1f8f', '\u1f87'), ('\u1f98', '\u1f90'),
+        ('\u1f99', '\u1f91'), ('\u1f9a', '\u1f92'), ('\u1f9b', '\u1f93'),
+        ('\u1f9c', '\u1f94'), ('\u1f9d', '\u1f95'), ('\u1f9e', '\u1f96'),
+        ('\u1f9f', '\u1f97'), ('\u1fa8', '\u1fa0'), ('\u1fa9', '\u1fa1'),
+        ('\u1faa', '\u1fa2'), ('\u1fab', '\u1fa3'), ('\u1fac', '\u1fa4'),
+        ('\u1fad', '\u1fa5'), ('\u1fae', '\u1fa6'), ('\u1faf', '\u1fa7'),
+        ('\u1fb8', '\u1fb0'), ('\u1fb9', '\u1fb1'), ('\u1fba', '\u1f70'),
+        ('\u1fbb', '\u1f71'), ('\u1fbc', '\u1fb3'), ('\u1fc8', '\u1f72'),
+        ('\u1fc9', '\u1f73'), ('\u1fca', '\u1f74'), ('\u1fcb', '\u1f75'),
+        ('\u1fcc', '\u1fc3'), ('\u1fd8', '\u1fd0'), ('\u1fd9', '\u1fd1'),
+        ('\u1fda', '\u1f76'), ('\u1fdb', '\u1f77'), ('\u1fe8', '\u1fe0'),
+        ('\u1fe9', '\u1fe1'), ('\u1fea', '\u1f7a'), ('\u1feb', '\u1f7b'),
+        ('\u1fec', '\u1fe5'), ('\u1ff8', '\u1f78'), ('\u1ff9', '\u1f79'),
+        ('\u1ffa', '\u1f7c'), ('\u1ffb', '\u1f7d'), ('\u1ffc', '\u1ff3'),
+        ('\u2126', '\u03c9'), ('\u212a', '\u006b'), ('\u212b', '\u00e5'),
+        ('\u2132', '\u214e'), ('\u2160', '\u2170'), ('\u2161', '\u2171'),
+        ('\u2162', '\u2172'), ('\u2163', '\u2173'), ('\u2164', '\u2174'),
+        ('\u2165', '\u2175'), ('\u2166', '\u2176'), ('\u2167', '\u2177'),
+        ('\u2168', '\u2178'), ('\u2169', '\u2179'), ('\u216a', '\u217a'),
+        ('\u216b', '\u217b'), ('\u216c', '\u217c'), ('\u216d', '\u217d'),
+        ('\u216e', '\u217e'), ('\u216f', '\u217f'), ('\u2183', '\u2184'),
+        ('\u24b6', '\u24d0'), ('\u24b7', '\u24d1'), ('\u24b8', '\u24d2'),
+        ('\u24b9', '\u24d3'), ('\u24ba', '\u24d4'), ('\u24bb', '\u24d5'),
+        ('\u24bc', '\u24d6'), ('\u24bd', '\u24d7'), ('\u24be', '\u24d8'),
+        ('\u24bf', '\u24d9'), ('\u24c0', '\u24da'), ('\u24c1', '\u24db'),
+        ('\u24c2', '\u24dc'), ('\u24c3', '\u24dd'), ('\u24c4', '\u24de'),
+        ('\u24c5', '\u24df'), ('\u24c6', '\u24e0'), ('\u24c7', '\u24e1'),
+        ('\u24c8', '\u24e2'), ('\u24c9', '\u24e3'), ('\u24ca', '\u24e4'),
+        ('\u24cb', '\u24e5'), ('\u24cc', '\u24e6'), ('\u24cd', '\u24e7'),
+        ('\u24ce', '\u24e8'), ('\u24cf', '\u24e9'), ('\u2c00', '\u2c30'),
+        ('\u2c01', '\u2c31'), ('\u2c02', '\u2c32'), ('\u2c03', '\u2c33'),
+        ('\u2c04', '\u2c34'), ('\u2c05', '\u2c35'), ('\u2c06', '\u2c36'),
+        ('\u2c07', '\u2c37'), ('\u2c08', '\u2c38'), ('\u2c09', '\u2c39'),
+        ('\u2c0a', '\u2c3a'), ('\u2c0b', '\u2c3b'), ('\u2c0c', '\u2c3c'),
+        ('\u2c0d', '\u2c3d'), ('\u2c0e', '\u2c3e'), ('\u2c0f', '\u2c3f'),
+        ('\u2c10', '\u2c40'), ('\u2c11', '\u2c41'), ('\u2c12', '\u2c42'),
+        ('\u2c13', '\u2c43'), ('\u2c14', '\u2c44'), ('\u2c15', '\u2c45'),
+        ('\u2c16', '\u2c46'), ('\u2c17', '\u2c47'), ('\u2c18', '\u2c48'),
+        ('\u2c19', '\u2c49'), ('\u2c1a', '\u2c4a'), ('\u2c1b', '\u2c4b'),
+        ('\u2c1c', '\u2c4c'), ('\u2c1d', '\u2c4d'), ('\u2c1e', '\u2c4e'),
+        ('\u2c1f', '\u2c4f'), ('\u2c20', '\u2c50'), ('\u2c21', '\u2c51'),
+        ('\u2c22', '\u2c52'), ('\u2c23', '\u2c53'), ('\u2c24', '\u2c54'),
+        ('\u2c25', '\u2c55'), ('\u2c26', '\u2c56'), ('\u2c27', '\u2c57'),
+        ('\u2c28', '\u2c58'), ('\u2c29', '\u2c59'), ('\u2c2a', '\u2c5a'),
+        ('\u2c2b', '\u2c5b'), ('\u2c2c', '\u2c5c'), ('\u2c2d', '\u2c5d'),
+        ('\u2c2e', '\u2c5e'), ('\u2c2f', '\u2c5f'), ('\u2c60', '\u2c61'),
+        ('\u2c62', '\u026b'), ('\u2c63', '\u1d7d'), ('\u2c64', '\u027d'),
+        ('\u2c67', '\u2c68'), ('\u2c69', '\u2c6a'), ('\u2c6b', '\u2c6c'),
+        ('\u2c6d', '\u0251'), ('\u2c6e', '\u0271'), ('\u2c6f', '\u0250'),
+        ('\u2c70', '\u0252'), ('\u2c72', '\u2c73'), ('\u2c75', '\u2c76'),
+        ('\u2c7e', '\u023f'), ('\u2c7f', '\u0240'), ('\u2c80', '\u2c81'),
+        ('\u2c82', '\u2c83'), ('\u2c84', '\u2c85'), ('\u2c86', '\u2c87'),
+        ('\u2c88', '\u2c89'), ('\u2c8a', '\u2c8b'), ('\u2c8c', '\u2c8d'),
+        ('\u2c8e', '\u2c8f'), ('\u2c90', '\u2c91'), ('\u2c92', '\u2c93'),
+        ('\u2c94', '\u2c95'), ('\u2c96', '\u2c97'), ('\u2c98', '\u2c99'),
+        ('\u2c9a', '\u2c9b'), ('\u2c9c', '\u2c9d'), ('\u2c9e', '\u2c9f'),
+        ('\u2ca0', '\u2ca1'), ('\u2ca2', '\u2ca3'), ('\u2ca4', '\u2ca5'),
+        ('\u2ca6', '\u2ca7'), ('\u2ca8', '\u2ca9'), ('\u2caa', '\u2cab'),
+        ('\u2cac', '\u2cad'), ('\u2cae', '\u2caf'), ('\u2cb0', '\u2cb1'),
+        ('\u2cb2', '\u2cb3'), ('\u2cb4', '\u2cb5'), ('\u2cb6', '\u2cb7'),
+        ('\u2cb8', '\u2cb9'), ('\u2cba', '\u2cbb'), ('\u2cbc', '\u2cbd'),
+        ('\u2cbe', '\u2cbf'), ('\u2cc0', '\u2cc1'), ('\u2cc2', '\u2cc3'),
+        ('\u2cc4', '\u2cc5'), ('\u2cc6', '\u2cc7'), ('\u2cc8', '\u2cc9'),
+        ('\u2cca', '\u2ccb'), ('\u2ccc', '\u2ccd'), ('\u2cce', '\u2ccf'),
+        ('\u2cd0', '\u2cd1'), ('\u2cd2', '\u2cd3'), ('\u2cd4', '\u2cd5'),
+        ('\u2cd6', '\u2cd7'), ('\u2cd8', '\u2cd9'), ('\u2cda', '\u2cdb'),
+        ('\u2cdc', '\u2cdd'), ('\u2cde', '\u2cdf'), ('\u2ce0', '\u2ce1'),
+        ('\u2ce2', '\u2ce3'), ('\u2ceb', '\u2cec'), ('\u2ced', '\u2cee'),
+        ('\u2cf2', '\u2cf3'), ('\ua640', '\ua641'), ('\ua642', '\ua643'),
+        ('\ua644', '\ua645'), ('\ua646', '\ua647'), ('\ua648', '\ua649'),
+        ('\ua64a', '\ua64b'), ('\ua64c', '\ua64d'), ('\ua64e', '\ua64f'),
+        ('\ua650', '\ua651'), ('\ua652', '\ua653'), ('\ua654', '\ua655'),
+        ('\ua656', '\ua657'), ('\ua658', '\ua659'), ('\ua65a', '\ua65b'),
+        ('\ua65c', '\ua65d'), ('\ua65e', '\ua65f'), ('\ua660', '\ua661'),
+        ('\ua662', '\ua663'), ('\ua664', '\ua665'), ('\ua666', '\ua667'),
+        ('\ua668', '\ua669'), ('\ua66a', '\ua66b'), ('\ua66c', '\ua66d'),
+        ('\ua680', '\ua681'), ('\ua682', '\ua683'), ('\ua684', '\ua685'),
+        ('\ua686', '\ua687'), ('\ua688', '\ua689'), ('\ua68a', '\ua68b'),
+        ('\ua68c', '\ua68d'), ('\ua68e', '\ua68f'), ('\ua690', '\ua691'),
+        ('\ua692', '\ua693'), ('\ua694', '\ua695'), ('\ua696', '\ua697'),
+        ('\ua698', '\ua699'), ('\ua69a', '\ua69b'), ('\ua722', '\ua723'),
+        ('\ua724', '\ua725'), ('\ua726', '\ua727'), ('\ua728', '\ua729'),
+        ('\ua72a', '\ua72b'), ('\ua72c', '\ua72d'), ('\ua72e', '\ua72f'),
+        ('\ua732', '\ua733'), ('\ua734', '\ua735'), ('\ua736', '\ua737'),
+        ('\ua738', '\ua739'), ('\ua73a', '\ua73b'), ('\ua73c', '\ua73d'),
+        ('\ua73e', '\ua73f'), ('\ua740', '\ua741'), ('\ua742', '\ua743'),
+        ('\ua744', '\ua745'), ('\ua746', '\ua747'), ('\ua748', '\ua749'),
+        ('\ua74a', '\ua74b'), ('\ua74c', '\ua74d'), ('\ua74e', '\ua74f'),
+        ('\ua750', '\ua751'), ('\ua752', '\ua753'), ('\ua754', '\ua755'),
+        ('\ua756', '\ua757'), ('\ua758', '\ua759'), ('\ua75a', '\ua75b'),
+        ('\ua75c', '\ua75d'), ('\ua75e', '\ua75f'), ('\ua760', '\ua761'),
+        ('\ua762', '\ua763'), ('\ua764', '\ua765'), ('\ua766', '\ua767'),
+        ('\ua768', '\ua769'), ('\ua76a', '\ua76b'), ('\ua76c', '\ua76d'),
+        ('\ua76e', '\ua76f'), ('\ua779', '\ua77a'), ('\ua77b', '\ua77c'),
+        ('\ua77d', '\u1d79'), ('\ua77e', '\ua77f'), ('\ua780', '\ua781'),
+        ('\ua782', '\ua783'), ('\ua784', '\ua785'), ('\ua786', '\ua787'),
+        ('\ua78b', '\ua78c'), ('\ua78d', '\u0265'), ('\ua790', '\ua791'),
+        ('\ua792', '\ua793'), ('\ua796', '\ua797'), ('\ua798', '\ua799'),
+        ('\ua79a', '\ua79b'), ('\ua79c', '\ua79d'), ('\ua79e', '\ua79f'),
+        ('\ua7a0', '\ua7a1'), ('\ua7a2', '\ua7a3'), ('\ua7a4', '\ua7a5'),
+        ('\ua7a6', '\ua7a7'), ('\ua7a8', '\ua7a9'), ('\ua7aa', '\u0266'),
+        ('\ua7ab', '\u025c'), ('\ua7ac', '\u0261'), ('\ua7ad', '\u026c'),
+        ('\ua7ae', '\u026a'), ('\ua7b0', '\u029e'), ('\ua7b1', '\u0287'),
+        ('\ua7b2', '\u029d'), ('\ua7b3', '\uab53'), ('\ua7b4', '\ua7b5'),
+        ('\ua7b6', '\ua7b7'), ('\ua7b8', '\ua7b9'), ('\ua7ba', '\ua7bb'),
+        ('\ua7bc', '\ua7bd'), ('\ua7be', '\ua7bf'), ('\ua7c0', '\ua7c1'),
+        ('\ua7c2', '\ua7c3'), ('\ua7c4', '\ua794'), ('\ua7c5', '\u0282'),
+        ('\ua7c6', '\u1d8e'), ('\ua7c7', '\ua7c8'), ('\ua7c9', '\ua7ca'),
+        ('\ua7d0', '\ua7d1'), ('\ua7d6', '\ua7d7'), ('\ua7d8', '\ua7d9'),
+        ('\ua7f5', '\ua7f6'), ('\uff21', '\uff41'), ('\uff22', '\uff42'),
+        ('\uff23', '\uff43'), ('\uff24', '\uff44'), ('\uff25', '\uff45'),
+        ('\uff26', '\uff46'), ('\uff27', '\uff47'), ('\uff28', '\uff48'),
+        ('\uff29', '\uff49'), ('\uff2a', '\uff4a'), ('\uff2b', '\uff4b'),
+        ('\uff2c', '\uff4c'), ('\uff2d', '\uff4d'), ('\uff2e', '\uff4e'),
+        ('\uff2f', '\uff4f'), ('\uff30', '\uff50'), ('\uff31', '\uff51'),
+        ('\uff32', '\uff52'), ('\uff33', '\uff53'), ('\uff34', '\uff54'),
+        ('\uff35', '\uff55'), ('\uff36', '\uff56'), ('\uff37', '\uff57'),
+        ('\uff38', '\uff58'), ('\uff39', '\uff59'), ('\uff3a', '\uff5a'),
+        ('\U00010400', '\U00010428'), ('\U00010401', '\U00010429'), ('\U00010402', '\U0001042a'),
+        ('\U00010403', '\U0001042b'), ('\U00010404', '\U0001042c'), ('\U00010405', '\U0001042d'),
+        ('\U00010406', '\U0001042e'), ('\U00010407', '\U0001042f'), ('\U00010408', '\U00010430'),
+        ('\U00010409', '\U00010431'), ('\U0001040a', '\U00010432'), ('\U0001040b', '\U00010433'),
+        ('\U0001040c', '\U00010434'), ('\U0001040d', '\U00010435'), ('\U0001040e', '\U00010436'),
+        ('\U0001040f', '\U00010437'), ('\U00010410', '\U00010438'), ('\U00010411', '\U00010439'),
+        ('\U00010412', '\U0001043a'), ('\U00010413', '\U0001043b'), ('\U00010414', '\U0001043c'),
+        ('\U00010415', '\U0001043d'), ('\U00010416', '\U0001043e'), ('\U00010417', '\U0001043f'),
+        ('\U00010418', '\U00010440'), ('\U00010419', '\U00010441'), ('\U0001041a', '\U00010442'),
+        ('\U0001041b', '\U00010443'), ('\U0001041c', '\U00010444'), ('\U0001041d', '\U00010445'),
+        ('\U0001041e', '\U00010446'), ('\U0001041f', '\U00010447'), ('\U00010420', '\U00010448'),
+        ('\U00010421', '\U00010449'), ('\U00010422', '\U0001044a'), ('\U00010423', '\U0001044b'),
+        ('\U00010424', '\U0001044c'), ('\U00010425', '\U0001044d'), ('\U00010426', '\U0001044e'),
+        ('\U00010427', '\U0001044f'), ('\U000104b0', '\U000104d8'), ('\U000104b1', '\U000104d9'),
+        ('\U000104b2', '\U000104da'), ('\U000104b3', '\U000104db'), ('\U000104b4', '\U000104dc'),
+        ('\U000104b5', '\U000104dd'), ('\U000104b6', '\U000104de'), ('\U000104b7', '\U000104df'),
+        ('\U000104b8', '\U000104e0'), ('\U000104b9', '\U000104e1'), ('\U000104ba', '\U000104e2'),
+        ('\U000104bb', '\U000104e3'), ('\U000104bc', '\U000104e4'), ('\U000104bd', '\U000104e5'),
+        ('\U000104be', '\U000104e6'), ('\U000104bf', '\U000104e7'), ('\U000104c0', '\U000104e8'),
+        ('\U000104c1', '\U000104e9'), ('\U000104c2', '\U000104ea'), ('\U000104c3', '\U000104eb'),
+        ('\U000104c4', '\U000104ec'), ('\U000104c5', '\U000104ed'), ('\U000104c6', '\U000104ee'),
+        ('\U000104c7', '\U000104ef'), ('\U000104c8', '\U000104f0'), ('\U000104c9', '\U000104f1'),
+        ('\U000104ca', '\U000104f2'), ('\U000104cb', '\U000104f3'), ('\U000104cc', '\U000104f4'),
+        ('\U000104cd', '\U000104f5'), ('\U000104ce', '\U000104f6'), ('\U000104cf', '\U000104f7'),
+        ('\U000104d0', '\U000104f8'), ('\U000104d1', '\U000104f9'), ('\U000104d2', '\U000104fa'),
+        ('\U000104d3', '\U000104fb'), ('\U00010570', '\U00010597'), ('\U00010571', '\U00010598'),
+        ('\U00010572', '\U00010599'), ('\U00010573', '\U0001059a'), ('\U00010574', '\U0001059b'),
+        ('\U00010575', '\U0001059c'), ('\U00010576', '\U0001059d'), ('\U00010577', '\U0001059e'),
+        ('\U00010578', '\U0001059f'), ('\U00010579', '\U000105a0'), ('\U0001057a', '\U000105a1'),
+        ('\U0001057c', '\U000105a3'), ('\U0001057d', '\U000105a4'), ('\U0001057e', '\U000105a5'),
+        ('\U0001057f', '\U000105a6'), ('\U00010580', '\U000105a7'), ('\U00010581', '\U000105a8'),
+        ('\U00010582', '\U000105a9'), ('\U00010583', '\U000105aa'), ('\U00010584', '\U000105ab'),
+        ('\U00010585', '\U000105ac'), ('\U00010586', '\U000105ad'), ('\U00010587', '\U000105ae'),
+        ('\U00010588', '\U000105af'), ('\U00010589', '\U000105b0'), ('\U0001058a', '\U000105b1'),
+        ('\U0001058c', '\U000105b3'), ('\U0001058d', '\U000105b4'), ('\U0001058e', '\U000105b5'),
+        ('\U0001058f', '\U000105b6'), ('\U00010590', '\U000105b7'), ('\U00010591', '\U000105b8'),
+        ('\U00010592', '\U000105b9'), ('\U00010594', '\U000105bb'), ('\U00010595', '\U000105bc'),
+        ('\U00010c80', '\U00010cc0'), ('\U00010c81', '\U00010cc1'), ('\U00010c82', '\U00010cc2'),
+        ('\U00010c83', '\U00010cc3'), ('\U00010c84', '\U00010cc4'), ('\U00010c85', '\U00010cc5'),
+        ('\U00010c86', '\U00010cc6'), ('\U00010c87', '\U00010cc7'), ('\U00010c88', '\U00010cc8'),
+        ('\U00010c89', '\U00010cc9'), ('\U00010c8a', '\U00010cca'), ('\U00010c8b', '\U00010ccb'),
+        ('\U00010c8c', '\U00010ccc'), ('\U00010c8d', '\U00010ccd'), ('\U00010c8e', '\U00010cce'),
+        ('\U00010c8f', '\U00010ccf'), ('\U00010c90', '\U00010cd0'), ('\U00010c91', '\U00010cd1'),
+        ('\U00010c92', '\U00010cd2'), ('\U00010c93', '\U00010cd3'), ('\U00010c94', '\U00010cd4'),
+        ('\U00010c95', '\U00010cd5'), ('\U00010c96', '\U00010cd6'), ('\U00010c97', '\U00010cd7'),
+        ('\U00010c98', '\U00010cd8'), ('\U00010c99', '\U00010cd9'), ('\U00010c9a', '\U00010cda'),
+        ('\U00010c9b', '\U00010cdb'), ('\U00010c9c', '\U00010cdc'), ('\U00010c9d', '\U00010cdd'),
+        ('\U00010c9e', '\U00010cde'), ('\U00010c9f', '\U00010cdf'), ('\U00010ca0', '\U00010ce0'),
+        ('\U00010ca1', '\U00010ce1'), ('\U00010ca2', '\U00010ce2'), ('\U00010ca3', '\U00010ce3'),
+        ('\U00010ca4', '\U00010ce4'), ('\U00010ca5', '\U00010ce5'), ('\U00010ca6', '\U00010ce6'),
+        ('\U00010ca7', '\U00010ce7'), ('\U00010ca8', '\U00010ce8'), ('\U00010ca9', '\U00010ce9'),
+        ('\U00010caa', '\U00010cea'), ('\U00010cab', '\U00010ceb'), ('\U00010cac', '\U00010cec'),
+        ('\U00010cad', '\U00010ced'), ('\U00010cae', '\U00010cee'), ('\U00010caf', '\U00010cef'),
+        ('\U00010cb0', '\U00010cf0'), ('\U00010cb1', '\U00010cf1'), ('\U00010cb2', '\U00010cf2'),
+        ('\U000118a0', '\U000118c0'), ('\U000118a1', '\U000118c1'), ('\U000118a2', '\U000118c2'),
+        ('\U000118a3', '\U000118c3'), ('\U000118a4', '\U000118c4'), ('\U000118a5', '\U000118c5'),
+        ('\U000118a6', '\U000118c6'), ('\U000118a7', '\U000118c7'), ('\U000118a8', '\U000118c8'),
+        ('\U000118a9', '\U000118c9'), ('\U000118aa', '\U000118ca'), ('\U000118ab', '\U000118cb'),
+        ('\U000118ac', '\U000118cc'), ('\U000118ad', '\U000118cd'), ('\U000118ae', '\U000118ce'),
+        ('\U000118af', '\U000118cf'), ('\U000118b0', '\U000118d0'), ('\U000118b1', '\U000118d1'),
+        ('\U000118b2', '\U000118d2'), ('\U000118b3', '\U000118d3'), ('\U000118b4', '\U000118d4'),
+        ('\U000118b5', '\U000118d5'), ('\U000118b6', '\U000118d6'), ('\U000118b7', '\U000118d7'),
+        ('\U000118b8', '\U000118d8'), ('\U000118b9', '\U000118d9'), ('\U000118ba', '\U000118da'),
+        ('\U000118bb', '\U000118db'), ('\U000118bc', '\U000118dc'), ('\U000118bd', '\U000118dd'),
+        ('\U000118be', '\U000118de'), ('\U000118bf', '\U000118df'), ('\U00016e40', '\U00016e60'),
+        ('\U00016e41', '\U00016e61'), ('\U00016e42', '\U00016e62'), ('\U00016e43', '\U00016e63'),
+        ('\U00016e44', '\U00016e64'), ('\U00016e45', '\U00016e65'), ('\U00016e46', '\U00016e66'),
+        ('\U00016e47', '\U00016e67'), ('\U00016e48', '\U00016e68'), ('\U00016e49', '\U00016e69'),
+        ('\U00016e4a', '\U00016e6a'), ('\U00016e4b', '\U00016e6b'), ('\U00016e4c', '\U00016e6c'),
+        ('\U00016e4d', '\U00016e6d'), ('\U00016e4e', '\U00016e6e'), ('\U00016e4f', '\U00016e6f'),
+        ('\U00016e50', '\U00016e70'), ('\U00016e51', '\U00016e71'), ('\U00016e52', '\U00016e72'),
+        ('\U00016e53', '\U00016e73'), ('\U00016e54', '\U00016e74'), ('\U00016e55', '\U00016e75'),
+        ('\U00016e56', '\U00016e76'), ('\U00016e57', '\U00016e77'), ('\U00016e58', '\U00016e78'),
+        ('\U00016e59', '\U00016e79'), ('\U00016e5a', '\U00016e7a'), ('\U00016e5b', '\U00016e7b'),
+        ('\U00016e5c', '\U00016e7c'), ('\U00016e5d', '\U00016e7d'), ('\U00016e5e', '\U00016e7e'),
+        ('\U00016e5f', '\U00016e7f'), ('\U0001e900', '\U0001e922'), ('\U0001e901', '\U0001e923'),
+        ('\U0001e902', '\U0001e924'), ('\U0001e903', '\U0001e925'), ('\U0001e904', '\U0001e926'),
+        ('\U0001e905', '\U0001e927'), ('\U0001e906', '\U0001e928'), ('\U0001e907', '\U0001e929'),
+        ('\U0001e908', '\U0001e92a'), ('\U0001e909', '\U0001e92b'), ('\U0001e90a', '\U0001e92c'),
+        ('\U0001e90b', '\U0001e92d'), ('\U0001e90c', '\U0001e92e'), ('\U0001e90d', '\U0001e92f'),
+        ('\U0001e90e', '\U0001e930'), ('\U0001e90f', '\U0001e931'), ('\U0001e910', '\U0001e932'),
+        ('\U0001e911', '\U0001e933'), ('\U0001e912', '\U0001e934'), ('\U0001e913', '\U0001e935'),
+        ('\U0001e914', '\U0001e936'), ('\U0001e915', '\U0001e937'), ('\U0001e916', '\U0001e938'),
+        ('\U0001e917', '\U0001e939'), ('\U0001e918', '\U0001e93a'), ('\U0001e919', '\U0001e93b'),
+        ('\U0001e91a', '\U0001e93c'), ('\U0001e91b', '\U0001e93d'), ('\U0001e91c', '\U0001e93e'),
+        ('\U0001e91d', '\U0001e93f'), ('\U0001e91e', '\U0001e940'), ('\U0001e91f', '\U0001e941'),
+        ('\U0001e920', '\U0001e942'), ('\U0001e921', '\U0001e943')
+    ]
+}
+
+// Pairs of each char with a simple titlecase mapping and the char
+// it maps to, sorted by the first.
+fn title_table() -> ~[(char, char)] {
+    ~[
+        ('\u0061', '\u0041'), ('\u0062', '\u0042'), ('\u0063', '\u0043'),
+        ('\u0064', '\u0044'), ('\u0065', '\u0045'), ('\u0066', '\u0046'),
+        ('\u0067', '\u0047'), ('\u0068', '\u0048'), ('\u0069', '\u0049'),
+        ('\u006a', '\u004a'), ('\u006b', '\u004b'), ('\u006c', '\u004c'),
+        ('\u006d', '\u004d'), ('\u006e', '\u004e'), ('\u006f', '\u004f'),
+        ('\u0070', '\u0050'), ('\u0071', '\u0051'), ('\u0072', '\u0052'),
+        ('\u0073', '\u0053'), ('\u0074', '\u0054'), ('\u0075', '\u0055'),
+        ('\u0076', '\u0056'), ('\u0077', '\u0057'), ('\u0078', '\u0058'),
+        ('\u0079', '\u0059'), ('\u007a', '\u005a'), ('\u00b5', '\u039c'),
+        ('\u00e0', '\u00c0'), ('\u00e1', '\u00c1'), ('\u00e2', '\u00c2'),
+        ('\u00e3', '\u00c3'), ('\u00e4', '\u00c4'), ('\u00e5', '\u00c5'),
+        ('\u00e6', '\u00c6'), ('\u00e7', '\u00c7'), ('\u00e8', '\u00c8'),
+        ('\u00e9', '\u00c9'), ('\u00ea', '\u00ca'), ('\u00eb', '\u00cb'),
+        ('\u00ec', '\u00cc'), ('\u00ed', '\u00cd'), ('\u00ee', '\u00ce'),
+        ('\u00ef', '\u00cf'), ('\u00f0', '\u00d0'), ('\u00f1', '\u00d1'),
+        ('\u00f2', '\u00d2'), ('\u00f3', '\u00d3'), ('\u00f4', '\u00d4'),
+        ('\u00f5', '\u00d5'), ('\u00f6', '\u00d6'), ('\u00f8', '\u00d8'),
+        ('\u00f9', '\u00d9'), ('\u00fa', '\u00da'), ('\u00fb', '\u00db'),
+        ('\u00fc', '\u00dc'), ('\u00fd', '\u00dd'), ('\u00fe', '\u00de'),
+        ('\u00ff', '\u0178'), ('\u0101', '\u0100'), ('\u0103', '\u0102'),
+        ('\u0105', '\u0104'), ('\u0107', '\u0106'), ('\u0109', '\u0108'),
+        ('\u010b', '\u010a'), ('\u010d', '\u010c'), ('\u010f', '\u010e'),
+        ('\u0111', '\u0110'), ('\u0113', '\u0112'), ('\u0115', '\u0114'),
+        ('\u0117', '\u0116'), ('\u0119', '\u0118'), ('\u011b', '\u011a'),
+        ('\u011d', '\u011c'), ('\u011f', '\u011e'), ('\u0121', '\u0120'),
+        ('\u0123', '\u0122'), ('\u0125', '\u0124'), ('\u0127', '\u0126'),
+        ('\u0129', '\u0128'), ('\u012b', '\u012a'), ('\u012d', '\u012c'),
+        ('\u012f', '\u012e'), ('\u0131', '\u0049'), ('\u0133', '\u0132'),
+        ('\u0135', '\u0134'), ('\u0137', '\u0136'), ('\u013a', '\u0139'),
+        ('\u013c', '\u013b'), ('\u013e', '\u013d'), ('\u0140', '\u013f'),
+        ('\u0142', '\u0141'), ('\u0144', '\u0143'), ('\u0146', '\u0145'),
+        ('\u0148', '\u0147'), ('\u014b', '\u014a'), ('\u014d', '\u014c'),
+        ('\u014f', '\u014e'), ('\u0151', '\u0150'), ('\u0153', '\u0152'),
+        ('\u0155', '\u0154'), ('\u0157', '\u0156'), ('\u0159', '\u0158'),
+        ('\u015b', '\u015a'), ('\u015d', '\u015c'), ('\u015f', '\u015e'),
+        ('\u0161', '\u0160'), ('\u0163', '\u0162'), ('\u0165', '\u0164'),
+        ('\u0167', '\u0166'), ('\u0169', '\u0168'), ('\u016b', '\u016a'),
+        ('\u016d', '\u016c'), ('\u016f', '\u016e'), ('\u0171', '\u0170'),
+        ('\u0173', '\u0172'), ('\u0175', '\u0174'), ('\u0177', '\u0176'),
+        ('\u017a', '\u0179'), ('\u017c', '\u017b'), ('\u017e', '\u017d'),
+        ('\u017f', '\u0053'), ('\u0180', '\u0243'), ('\u0183', '\u0182'),
+        ('\u0185', '\u0184'), ('\u0188', '\u0187'), ('\u018c', '\u018b'),
+        ('\u0192', '\u0191'), ('\u0195', '\u01f6'), ('\u0199', '\u0198'),
+        ('\u019a', '\u023d'), ('\u019e', '\u0220'), ('\u01a1', '\u01a0'),
+        ('\u01a3', '\u01a2'), ('\u01a5', '\u01a4'), ('\u01a8', '\u01a7'),
+        ('\u01ad', '\u01ac'), ('\u01b0', '\u01af'), ('\u01b4', '\u01b3'),
+        ('\u01b6', '\u01b5'), ('\u01b9', '\u01b8'), ('\u01bd', '\u01bc'),
+        ('\u01bf', '\u01f7'), ('\u01c4', '\u01c5'), ('\u01c6', '\u01c5'),
+        ('\u01c7', '\u01c8'), ('\u01c9', '\u01c8'), ('\u01ca', '\u01cb'),
+        ('\u01cc', '\u01cb'), ('\u01ce', '\u01cd'), ('\u01d0', '\u01cf'),
+        ('\u01d2', '\u01d1'), ('\u01d4', '\u01d3'), ('\u01d6', '\u01d5'),
+        ('\u01d8', '\u01d7'), ('\u01da', '\u01d9'), ('\u01dc', '\u01db'),
+        ('\u01dd', '\u018e'), ('\u01df', '\u01de'), ('\u01e1', '\u01e0'),
+        ('\u01e3', '\u01e2'), ('\u01e5', '\u01e4'), ('\u01e7', '\u01e6'),
+        ('\u01e9', '\u01e8'), ('\u01eb', '\u01ea'), ('\u01ed', '\u01ec'),
+        ('\u01ef', '\u01ee'), ('\u01f1', '\u01f2'), ('\u01f3', '\u01f2'),
+        ('\u01f5', '\u01f4'), ('\u01f9', '\u01f8'), ('\u01fb', '\u01fa'),
+        ('\u01fd', '\u01fc'), ('\u01ff', '\u01fe'), ('\u0201', '\u0200'),
+        ('\u0203', '\u0202'), ('\u0205', '\u0204'), ('\u0207', '\u0206'),
+        ('\u0209', '\u0208'), ('\u020b', '\u020a'), ('\u020d', '\u020c'),
+        ('\u020f', '\u020e'), ('\u0211', '\u0210'), ('\u0213', '\u0212'),
+        ('\u0215', '\u0214'), ('\u0217', '\u0216'), ('\u0219', '\u0218'),
+        ('\u021b', '\u021a'), ('\u021d', '\u021c'), ('\u021f', '\u021e'),
+        ('\u0223', '\u0222'), ('\u0225', '\u0224'), ('\u0227', '\u0226'),
+        ('\u0229', '\u0228'), ('\u022b', '\u022a'), ('\u022d', '\u022c'),
+        ('\u022f', '\u022e'), ('\u0231', '\u0230'), ('\u0233', '\u0232'),
+        ('\u023c', '\u023b'), ('\u023f', '\u2c7e'), ('\u0240', '\u2c7f'),
+        ('\u0242', '\u0241'), ('\u0247', '\u0246'), ('\u0249', '\u0248'),
+        ('\u024b', '\u024a'), ('\u024d', '\u024c'), ('\u024f', '\u024e'),
+        ('\u0250', '\u2c6f'), ('\u0251', '\u2c6d'), ('\u0252', '\u2c70'),
+        ('\u0253', '\u0181'), ('\u0254', '\u0186'), ('\u0256', '\u0189'),
+        ('\u0257', '\u018a'), ('\u0259', '\u018f'), ('\u025b', '\u0190'),
+        ('\u025c', '\ua7ab'), ('\u0260', '\u0193'), ('\u0261', '\ua7ac'),
+        ('\u0263', '\u0194'), ('\u0265', '\ua78d'), ('\u0266', '\ua7aa'),
+        ('\u0268', '\u0197'), ('\u0269', '\u0196'), ('\u026a', '\ua7ae'),
+        ('\u026b', '\u2c62'), ('\u026c', '\ua7ad'), ('\u026f', '\u019c'),
+        ('\u0271', '\u2c6e'), ('\u0272', '\u019d'), ('\u0275', '\u019f'),
+        ('\u027d', '\u2c64'), ('\u0280', '\u01a6'), ('\u0282', '\ua7c5'),
+        ('\u0283', '\u01a9'), ('\u0287', '\ua7b1'), ('\u0288', '\u01ae'),
+        ('\u0289', '\u0244'), ('\u028a', '\u01b1'), ('\u028b', '\u01b2'),
+        ('\u028c', '\u0245'), ('\u0292', '\u01b7'), ('\u029d', '\ua7b2'),
+        ('\u029e', '\ua7b0'), ('\u0345', '\u0399'), ('\u0371', '\u0370'),
+        ('\u0373', '\u0372'), ('\u0377', '\u0376'), ('\u037b', '\u03fd'),
+        ('\u037c', '\u03fe'), ('\u037d', '\u03ff'), ('\u03ac', '\u0386'),
+        ('\u03ad', '\u0388'), ('\u03ae', '\u0389'), ('\u03af', '\u038a'),
+        ('\u03b1', '\u0391'), ('\u03b2', '\u0392'), ('\u03b3', '\u0393'),
+        ('\u03b4', '\u0394'), ('\u03b5', '\u0395'), ('\u03b6', '\u0396'),
+        ('\u03b7', '\u0397'), ('\u03b8', '\u0398'), ('\u03b9', '\u0399'),
+        ('\u03ba', '\u039a'), ('\u03bb', '\u039b'), ('\u03bc', '\u039c'),
+        ('\u03bd', '\u039d'), ('\u03be', '\u039e'), ('\u03bf', '\u039f'),
+        ('\u03c0', '\u03a0'), ('\u03c1', '\u03a1'), ('\u03c2', '\u03a3'),
+        ('\u03c3', '\u03a3'), ('\u03c4', '\u03a4'), ('\u03c5', '\u03a5'),
+        ('\u03c6', '\u03a6'), ('\u03c7', '\u03a7'), ('\u03c8', '\u03a8'),
+        ('\u03c9', '\u03a9'), ('\u03ca', '\u03aa'), ('\u03cb', '\u03ab'),
+        ('\u03cc', '\u038c'), ('\u03cd', '\u038e'), ('\u03ce', '\u038f'),
+        ('\u03d0', '\u0392'), ('\u03d1', '\u0398'), ('\u03d5', '\u03a6'),
+        ('\u03d6', '\u03a0'), ('\u03d7', '\u03cf'), ('\u03d9', '\u03d8'),
+        ('\u03db', '\u03da'), ('\u03dd', '\u03dc'), ('\u03df', '\u03de'),
+        ('\u03e1', '\u03e0'), ('\u03e3', '\u03e2'), ('\u03e5', '\u03e4'),
+        ('\u03e7', '\u03e6'), ('\u03e9', '\u03e8'), ('\u03eb', '\u03ea'),
+        ('\u03ed', '\u03ec'), ('\u03ef', '\u03ee'), ('\u03f0', '\u039a'),
+        ('\u03f1', '\u03a1'), ('\u03f2', '\u03f9'), ('\u03f3', '\u037f'),
+        ('\u03f5', '\u0395'), ('\u03f8', '\u03f7'), ('\u03fb', '\u03fa'),
+        ('\u0430', '\u0410'), ('\u0431', '\u0411'), ('\u0432', '\u0412'),
+        ('\u0433', '\u0413'), ('\u0434', '\u0414'), ('\u0435', '\u0415'),
+        ('\u0436', '\u0416'), ('\u0437', '\u0417'), ('\u0438', '\u0418'),
+        ('\u0439', '\u0419'), ('\u043a', '\u041a'), ('\u043b', '\u041b'),
+        ('\u043c', '\u041c'), ('\u043d', '\u041d'), ('\u043e', '\u041e'),
+        ('\u043f', '\u041f'), ('\u0440', '\u0420'), ('\u0441', '\u0421'),
+        ('\u0442', '\u0422'), ('\u0443', '\u0423'), ('\u0444', '\u0424'),
+        ('\u0445', '\u0425'), ('\u0446', '\u0426'), ('\u0447', '\u0427'),
+        ('\u0448', '\u0428'), ('\u0449', '\u0429'), ('\u044a', '\u042a'),
+        ('\u044b', '\u042b'), ('\u044c', '\u042c'), ('\u044d', '\u042d'),
+        ('\u044e', '\u042e'), ('\u044f', '\u042f'), ('\u0450', '\u0400'),
+        ('\u0451', '\u0401'), ('\u0452', '\u0402'), ('\u0453', '\u0403'),
+        ('\u0454', '\u0404'), ('\u0455', '\u0405'), ('\u0456', '\u0406'),
+        ('\u0457', '\u0407'), ('\u0458', '\u0408'), ('\u0459', '\u0409'),
+        ('\u045a', '\u040a'), ('\u045b', '\u040b'), ('\u045c', '\u040c'),
+        ('\u045d', '\u040d'), ('\u045e', '\u040e'), ('\u045f', '\u040f'),
+        ('\u0461', '\u0460'), ('\u0463', '\u0462'), ('\u0465', '\u0464'),
+        ('\u0467', '\u0466'), ('\u0469', '\u0468'), ('\u046b', '\u046a'),
+        ('\u046d', '\u046c'), ('\u046f', '\u046e'), ('\u0471', '\u0470'),
+        ('\u0473', '\u0472'), ('\u0475', '\u0474'), ('\u0477', '\u0476'),
+        ('\u0479', '\u0478'), ('\u047b', '\u047a'), ('\u047d', '\u047c'),
+        ('\u047f', '\u047e'), ('\u0481', '\u0480'), ('\u048b', '\u048a'),
+        ('\u048d', '\u048c'), ('\u048f', '\u048e'), ('\u0491', '\u0490'),
+        ('\u0493', '\u0492'), ('\u0495', '\u0494'), ('\u0497', '\u0496'),
+        ('\u0499', '\u0498'), ('\u049b', '\u049a'), ('\u049d', '\u049c'),
+        ('\u049f', '\u049e'), ('\u04a1', '\u04a0'), ('\u04a3', '\u04a2'),
+        ('\u04a5', '\u04a4'), ('\u04a7', '\u04a6'), ('\u04a9', '\u04a8'),
+        ('\u04ab', '\u04aa'), ('\u04ad', '\u04ac'), ('\u04af', '\u04ae'),
+        ('\u04b1', '\u04b0'), ('\u04b3', '\u04b2'), ('\u04b5', '\u04b4'),
+        ('\u04b7', '\u04b6'), ('\u04b9', '\u04b8'), ('\u04bb', '\u04ba'),
+        ('\u04bd', '\u04bc'), ('\u04bf', '\u04be'), ('\u04c2', '\u04c1'),
+        ('\u04c4', '\u04c3'), ('\u04c6', '\u04c5'), ('\u04c8', '\u04c7'),
+        ('\u04ca', '\u04c9'), ('\u04cc', '\u04cb'), ('\u04ce', '\u04cd'),
+        ('\u04cf', '\u04c0'), ('\u04d1', '\u04d0'), ('\u04d3', '\u04d2'),
+        ('\u04d5', '\u04d4'), ('\u04d7', '\u04d6'), ('\u04d9', '\u04d8'),
+        ('\u04db', '\u04da'), ('\u04dd', '\u04dc'), ('\u04df', '\u04de'),
+        ('\u04e1', '\u04e0'), ('\u04e3', '\u04e2'), ('\u04e5', '\u04e4'),
+        ('\u04e7', '\u04e6'), ('\u04e9', '\u04e8'), ('\u04eb', '\u04ea'),
+        ('\u04ed', '\u04ec'), ('\u04ef', '\u04ee'), ('\u04f1', '\u04f0'),
+        ('\u04f3', '\u04f2'), ('\u04f5', '\u04f4'), ('\u04f7', '\u04f6'),
+        ('\u04f9', '\u04f8'), ('\u04fb', '\u04fa'), ('\u04fd', '\u04fc'),
+        ('\u04ff', '\u04fe'), ('\u0501', '\u0500'), ('\u0503', '\u0502'),
+        ('\u0505', '\u0504'), ('\u0507', '\u0506'), ('\u0509', '\u0508'),
+        ('\u050b', '\u050a'), ('\u050d', '\u050c'), ('\u050f', '\u050e'),
+        ('\u0511', '\u0510'), ('\u0513', '\u0512'), ('\u0515', '\u0514'),
+        ('\u0517', '\u0516'), ('\u0519', '\u0518'), ('\u051b', '\u051a'),
+        ('\u051d', '\u051c'), ('\u051f', '\u051e'), ('\u0521', '\u0520'),
+        ('\u0523', '\u0522'), ('\u0525', '\u0524'), ('\u0527', '\u0526'),
+        ('\u0529', '\u0528'), ('\u052b', '\u052a'), ('\u052d', '\u052c'),
+        ('\u052f', '\u052e'), ('\u0561', '\u0531'), ('\u0562', '\u0532'),
+        ('\u0563', '\u0533'), ('\u0564', '\u0534'), ('\u0565', '\u0535'),
+        ('\u0566', '\u0536'), ('\u0567', '\u0537'), ('\u0568', '\u0538'),
+        ('\u0569', '\u0539'), ('\u056a', '\u053a'), ('\u056b', '\u053b'),
+        ('\u056c', '\u053c'), ('\u056d', '\u053d'), ('\u056e', '\u053e'),
+        ('\u056f', '\u053f'), ('\u0570', '\u0540'), ('\u0571', '\u0541'),
+        ('\u0572', '\u0542'), ('\u0573', '\u0543'), ('\u0574', '\u0544'),
+        ('\u0575', '\u0545'), ('\u0576', '\u0546'), ('\u0577', '\u0547'),
+        ('\u0578', '\u0548'), ('\u0579', '\u0549'), ('\u057a', '\u054a'),
+        ('\u057b', '\u054b'), ('\u057c', '\u054c'), ('\u057d', '\u054d'),
+        ('\u057e', '\u054e'), ('\u057f', '\u054f'), ('\u0580', '\u0550'),
+        ('\u0581', '\u0551'), ('\u0582', '\u0552'), ('\u0583', '\u0553'),
+        ('\u0584', '\u0554'), ('\u0585', '\u0555'), ('\u0586', '\u0556'),
+        ('\u13f8', '\u13f0'), ('\u13f9', '\u13f1'), ('\u13fa', '\u13f2'),
+        ('\u13fb', '\u13f3'), ('\u13fc', '\u13f4'), ('\u13fd', '\u13f5'),
+        ('\u1c80', '\u0412'), ('\u1c81', '\u0414'), ('\u1c82', '\u041e'),
+        ('\u1c83', '\u0421'), ('\u1c84', '\u0422'), ('\u1c85', '\u0422'),
+        ('\u1c86', '\u042a'), ('\u1c87', '\u0462'), ('\u1c88', '\ua64a'),
+        ('\u1d79', '\ua77d'), ('\u1d7d', '\u2c63'), ('\u1d8e', '\ua7c6'),
+        ('\u1e01', '\u1e00'), ('\u1e03', '\u1e02'), ('\u1e05', '\u1e04'),
+        ('\u1e07', '\u1e06'), ('\u1e09', '\u1e08'), ('\u1e0b', '\u1e0a'),
+        ('\u1e0d', '\u1e0c'), ('\u1e0f', '\u1e0e'), ('\u1e11', '\u1e10'),
+        ('\u1e13', '\u1e12'), ('\u1e15', '\u1e14'), ('\u1e17', '\u1e16'),
+        ('\u1e19', '\u1e18'), ('\u1e1b', '\u1e1a'), ('\u1e1d', '\u1e1c'),
+        ('\u1e1f', '\u1e1e'), ('\u1e21', '\u1e20'), ('\u1e23', '\u1e22'),
+        ('\u1e25', '\u1e24'), ('\u1e27', '\u1e26'), ('\u1e29', '\u1e28'),
+        ('\u1e2b', '\u1e2a'), ('\u1e2d', '\u1e2c'), ('\u1e2f', '\u1e2e'),
+        ('\u1e31', '\u1e30'), ('\u1e33', '\u1e32'), ('\u1e35', '\u1e34'),
+        ('\u1e37', '\u1e36'), ('\u1e39', '\u1e38'), ('\u1e3b', '\u1e3a'),
+        ('\u1e3d', '\u1e3c'), ('\u1e3f', '\u1e3e'), ('\u1e41', '\u1e40'),
+        ('\u1e43', '\u1e42'), ('\u1e45', '\u1e44'), ('\u1e47', '\u1e46'),
+        ('\u1e49', '\u1e48'), ('\u1e4b', '\u1e4a'), ('\u1e4d', '\u1e4c'),
+        ('\u1e4f', '\u1e4e'), ('\u1e51', '\u1e50'), ('\u1e53', '\u1e52'),
+        ('\u1e55', '\u1e54'), ('\u1e57', '\u1e56'), ('\u1e59', '\u1e58'),
+        ('\u1e5b', '\u1e5a'), ('\u1e5d', '\u1e5c'), ('\u1e5f', '\u1e5e'),
+        ('\u1e61', '\u1e60'), ('\u1e63', '\u1e62'), ('\u1e65', '\u1e64'),
+        ('\u1e67', '\u1e66'), ('\u1e69', '\u1e68'), ('\u1e6b', '\u1e6a'),
+        ('\u1e6d', '\u1e6c'), ('\u1e6f', '\u1e6e'), ('\u1e71', '\u1e70'),
+        ('\u1e73', '\u1e72'), ('\u1e75', '\u1e74'), ('\u1e77', '\u1e76'),
+        ('\u1e79', '\u1e78'), ('\u1e7b', '\u1e7a'), ('\u1e7d', '\u1e7c'),
+        ('\u1e7f', '\u1e7e'), ('\u1e81', '\u1e80'), ('\u1e83', '\u1e82'),
+        ('\u1e85', '\u1e84'), ('\u1e87', '\u1e86'), ('\u1e89', '\u1e88'),
+        ('\u1e8b', '\u1e8a'), ('\u1e8d', '\u1e8c'), ('\u1e8f', '\u1e8e'),
+        ('\u1e91', '\u1e90'), ('\u1e93', '\u1e92'), ('\u1e95', '\u1e94'),
+        ('\u1e9b', '\u1e60'), ('\u1ea1', '\u1ea0'), ('\u1ea3', '\u1ea2'),
+        ('\u1ea5', '\u1ea4'), ('\u1ea7', '\u1ea6'), ('\u1ea9', '\u1ea8'),
+        ('\u1eab', '\u1eaa'), ('\u1ead', '\u1eac'), ('\u1eaf', '\u1eae'),
+        ('\u1eb1', '\u1eb0'), ('\u1eb3', '\u1eb2'), ('\u1eb5', '\u1eb4'),
+        ('\u1eb7', '\u1eb6'), ('\u1eb9', '\u1eb8'), ('\u1ebb', '\u1eba'),
+        ('\u1ebd', '\u1ebc'), ('\u1ebf', '\u1ebe'), ('\u1ec1', '\u1ec0'),
+        ('\u1ec3', '\u1ec2'), ('\u1ec5', '\u1ec4'), ('\u1ec7', '\u1ec6'),
+        ('\u1ec9', '\u1ec8'), ('\u1ecb', '\u1eca'), ('\u1ecd', '\u1ecc'),
+        ('\u1ecf', '\u1ece'), ('\u1ed1', '\u1ed0'), ('\u1ed3', '\u1ed2'),
+        ('\u1ed5', '\u1ed4'), ('\u1ed7', '\u1ed6'), ('\u1ed9', '\u1ed8'),
+        ('\u1edb', '\u1eda'), ('\u1edd', '\u1edc'), ('\u1edf', '\u1ede'),
+        ('\u1ee1', '\u1ee0'), ('\u1ee3', '\u1ee2'), ('\u1ee5', '\u1ee4'),
+        ('\u1ee7', '\u1ee6'), ('\u1ee9', '\u1ee8'), ('\u1eeb', '\u1eea'),
+        ('\u1eed', '\u1eec'), ('\u1eef', '\u1eee'), ('\u1ef1', '\u1ef0'),
+        ('\u1ef3', '\u1ef2'), ('\u1ef5', '\u1ef4'), ('\u1ef7', '\u1ef6'),
+        ('\u1ef9', '\u1ef8'), ('\u1efb', '\u1efa'), ('\u1efd', '\u1efc'),
+        ('\u1eff', '\u1efe'), ('\u1f00', '\u1f08'), ('\u1f01', '\u1f09'),
+        ('\u1f02', '\u1f0a'), ('\u1f03', '\u1f0b'), ('\u1f04', '\u1f0c'),
+        ('\u1f05', '\u1f0d'), ('\u1f06', '\u1f0e'), ('\u1f07', '\u1f0f'),
+        ('\u1f10', '\u1f18'), ('\u1f11', '\u1f19'), ('\u1f12', '\u1f1a'),
+        ('\u1f13', '\u1f1b'), ('\u1f14', '\u1f1c'), ('\u1f15', '\u1f1d'),
+        ('\u1f20', '\u1f28'), ('\u1f21', '\u1f29'), ('\u1f22', '\u1f2a'),
+        ('\u1f23', '\u1f2b'), ('\u1f24', '\u1f2c'), ('\u1f25', '\u1f2d'),
+        ('\u1f26', '\u1f2e'), ('\u1f27', '\u1f2f'), ('\u1f30', '\u1f38'),
+        ('\u1f31', '\u1f39'), ('\u1f32', '\u1f3a'), ('\u1f33', '\u1f3b'),
+        ('\u1f34', '\u1f3c'), ('\u1f35', '\u1f3d'), ('\u1f36', '\u1f3e'),
+        ('\u1f37', '\u1f3f'), ('\u1f40', '\u1f48'), ('\u1f41', '\u1f49'),
+        ('\u1f42', '\u1f4a'), ('\u1f43', '\u1f4b'), ('\u1f44', '\u1f4c'),
+        ('\u1f45', '\u1f4d'), ('\u1f51', '\u1f59'), ('\u1f53', '\u1f5b'),
+        ('\u1f55', '\u1f5d'), ('\u1f57', '\u1f5f'), ('\u1f60', '\u1f68'),
+        ('\u1f61', '\u1f69'), ('\u1f62', '\u1f6a'), ('\u1f63', '\u1f6b'),
+        ('\u1f64', '\u1f6c'), ('\u1f65', '\u1f6d'), ('\u1f66', '\u1f6e'),
+        ('\u1f67', '\u1f6f'), ('\u1f70', '\u1fba'), ('\u1f71', '\u1fbb'),
+        ('\u1f72', '\u1fc8'), ('\u1f73', '\u1fc9'), ('\u1f74', '\u1fca'),
+        ('\u1f75', '\u1fcb'), ('\u1f76', '\u1fda'), ('\u1f77', '\u1fdb'),
+        ('\u1f78', '\u1ff8'), ('\u1f79', '\u1ff9'), ('\u1f7a', '\u1fea'),
+        ('\u1f7b', '\u1feb'), ('\u1f7c', '\u1ffa'), ('\u1f7d', '\u1ffb'),
+        ('\u1f80', '\u1f88'), ('\u1f81', '\u1f89'), ('\u1f82', '\u1f8a'),
+        ('\u1f83', '\u1f8b'), ('\u1f84', '\u1f8c'), ('\u1f85', '\u1f8d'),
+        ('\u1f86', '\u1f8e'), ('\u1f87', '\u1f8f'), ('\u1f90', '\u1f98'),
+        ('\u1f91', '\u1f99'), ('\u1f92', '\u1f9a'), ('\u1f93', '\u1f9b'),
+        ('\u1f94', '\u1f9c'), ('\u1f95', '\u1f9d'), ('\u1f96', '\u1f9e'),
+        ('\u1f97', '\u1f9f'), ('\u1fa0', '\u1fa8'), ('\u1fa1', '\u1fa9'),
+        ('\u1fa2', '\u1faa'), ('\u1fa3', '\u1fab'), ('\u1fa4', '\u1fac'),
+        ('\u1fa5', '\u1fad'), ('\u1fa6', '\u1fae'), ('\u1fa7', '\u1faf'),
+        ('\u1fb0', '\u1fb8'), ('\u1fb1', '\u1fb9'), ('\u1fb3', '\u1fbc'),
+        ('\u1fbe', '\u0399'), ('\u1fc3', '\u1fcc'), ('\u1fd0', '\u1fd8'),
+        ('\u1fd1', '\u1fd9'), ('\u1fe0', '\u1fe8'), ('\u1fe1', '\u1fe9'),
+        ('\u1fe5', '\u1fec'), ('\u1ff3', '\u1ffc'), ('\u214e', '\u2132'),
+        ('\u2170', '\u2160'), ('\u2171', '\u2161'), ('\u2172', '\u2162'),
+        ('\u2173', '\u2163'), ('\u2174', '\u2164'), ('\u2175', '\u2165'),
+        ('\u2176', '\u2166'), ('\u2177', '\u2167'), ('\u2178', '\u2168'),
+        ('\u2179', '\u2169'), ('\u217a', '\u216a'), ('\u217b', '\u216b'),
+        ('\u217c', '\u216c'), ('\u217d', '\u216d'), ('\u217e', '\u216e'),
+        ('\u217f', '\u216f'), ('\u2184', '\u2183'), ('\u24d0', '\u24b6'),
+        ('\u24d1', '\u24b7'), ('\u24d2', '\u24b8'), ('\u24d3', '\u24b9'),
+        ('\u24d4', '\u24ba'), ('\u24d5', '\u24bb'), ('\u24d6', '\u24bc'),
+        ('\u24d7', '\u24bd'), ('\u24d8', '\u24be'), ('\u24d9', '\u24bf'),
+        ('\u24da', '\u24c0'), ('\u24db', '\u24c1'), ('\u24dc', '\u24c2'),
+        ('\u24dd', '\u24c3'), ('\u24de', '\u24c4'), ('\u24df', '\u24c5'),
+        ('\u24e0', '\u24c6'), ('\u24e1', '\u24c7'), ('\u24e2', '\u24c8'),
+        ('\u24e3', '\u24c9'), ('\u24e4', '\u24ca'), ('\u24e5', '\u24cb'),
+        ('\u24e6', '\u24cc'), ('\u24e7', '\u24cd'), ('\u24e8', '\u24ce'),
+        ('\u24e9', '\u24cf'), ('\u2c30', '\u2c00'), ('\u2c31', '\u2c01'),
+        ('\u2c32', '\u2c02'), ('\u2c33', '\u2c03'), ('\u2c34', '\u2c04'),
+        ('\u2c35', '\u2c05'), ('\u2c36', '\u2c06'), ('\u2c37', '\u2c07'),
+        ('\u2c38', '\u2c08'), ('\u2c39', '\u2c09'), ('\u2c3a', '\u2c0a'),
+        ('\u2c3b', '\u2c0b'), ('\u2c3c', '\u2c0c'), ('\u2c3d', '\u2c0d'),
+        ('\u2c3e', '\u2c0e'), ('\u2c3f', '\u2c0f'), ('\u2c40', '\u2c10'),
+        ('\u2c41', '\u2c11'), ('\u2c42', '\u2c12'), ('\u2c43', '\u2c13'),
+        ('\u2c44', '\u2c14'), ('\u2c45', '\u2c15'), ('\u2c46', '\u2c16'),
+        ('\u2c47', '\u2c17'), ('\u2c48', '\u2c18'), ('\u2c49', '\u2c19'),
+        ('\u2c4a', '\u2c1a'), ('\u2c4b', '\u2c1b'), ('\u2c4c', '\u2c1c'),
+        ('\u2c4d', '\u2c1d'), ('\u2c4e', '\u2c1e'), ('\u2c4f', '\u2c1f'),
+        ('\u2c50', '\u2c20'), ('\u2c51', '\u2c21'), ('\u2c52', '\u2c22'),
+        ('\u2c53', '\u2c23'), ('\u2c54', '\u2c24'), ('\u2c55', '\u2c25'),
+        ('\u2c56', '\u2c26'), ('\u2c57', '\u2c27'), ('\u2c58', '\u2c28'),
+        ('\u2c59', '\u2c29'), ('\u2c5a', '\u2c2a'), ('\u2c5b', '\u2c2b'),
+        ('\u2c5c', '\u2c2c'), ('\u2c5d', '\u2c2d'), ('\u2c5e', '\u2c2e'),
+        ('\u2c5f', '\u2c2f'), ('\u2c61', '\u2c60'), ('\u2c65', '\u023a'),
+        ('\u2c66', '\u023e'), ('\u2c68', '\u2c67'), ('\u2c6a', '\u2c69'),
+        ('\u2c6c', '\u2c6b'), ('\u2c73', '\u2c72'), ('\u2c76', '\u2c75'),
+        ('\u2c81', '\u2c80'), ('\u2c83', '\u2c82'), ('\u2c85', '\u2c84'),
+        ('\u2c87', '\u2c86'), ('\u2c89', '\u2c88'), ('\u2c8b', '\u2c8a'),
+        ('\u2c8d', '\u2c8c'), ('\u2c8f', '\u2c8e'), ('\u2c91', '\u2c90'),
+        ('\u2c93', '\u2c92'), ('\u2c95', '\u2c94'), ('\u2c97', '\u2c96'),
+        ('\u2c99', '\u2c98'), ('\u2c9b', '\u2c9a'), ('\u2c9d', '\u2c9c'),
+        ('\u2c9f', '\u2c9e'), ('\u2ca1', '\u2ca0'), ('\u2ca3', '\u2ca2'),
+        ('\u2ca5', '\u2ca4'), ('\u2ca7', '\u2ca6'), ('\u2ca9', '\u2ca8'),
+        ('\u2cab', '\u2caa'), ('\u2cad', '\u2cac'), ('\u2caf', '\u2cae'),
+        ('\u2cb1', '\u2cb0'), ('\u2cb3', '\u2cb2'), ('\u2cb5', '\u2cb4'),
+        ('\u2cb7', '\u2cb6'), ('\u2cb9', '\u2cb8'), ('\u2cbb', '\u2cba'),
+        ('\u2cbd', '\u2cbc'), ('\u2cbf', '\u2cbe'), ('\u2cc1', '\u2cc0'),
+        ('\u2cc3', '\u2cc2'), ('\u2cc5', '\u2cc4'), ('\u2cc7', '\u2cc6'),
+        ('\u2cc9', '\u2cc8'), ('\u2ccb', '\u2cca'), ('\u2ccd', '\u2ccc'),
+        ('\u2ccf', '\u2cce'), ('\u2cd1', '\u2cd0'), ('\u2cd3', '\u2cd2'),
+        ('\u2cd5', '\u2cd4'), ('\u2cd7', '\u2cd6'), ('\u2cd9', '\u2cd8'),
+        ('\u2cdb', '\u2cda'), ('\u2cdd', '\u2cdc'), ('\u2cdf', '\u2cde'),
+        ('\u2ce1', '\u2ce0'), ('\u2ce3', '\u2ce2'), ('\u2cec', '\u2ceb'),
+        ('\u2cee', '\u2ced'), ('\u2cf3', '\u2cf2'), ('\u2d00', '\u10a0'),
+        ('\u2d01', '\u10a1'), ('\u2d02', '\u10a2'), ('\u2d03', '\u10a3'),
+        ('\u2d04', '\u10a4'), ('\u2d05', '\u10a5'), ('\u2d06', '\u10a6'),
+        ('\u2d07', '\u10a7'), ('\u2d08', '\u10a8'), ('\u2d09', '\u10a9'),
+        ('\u2d0a', '\u10aa'), ('\u2d0b', '\u10ab'), ('\u2d0c', '\u10ac'),
+        ('\u2d0d', '\u10ad'), ('\u2d0e', '\u10ae'), ('\u2d0f', '\u10af'),
+        ('\u2d10', '\u10b0'), ('\u2d11', '\u10b1'), ('\u2d12', '\u10b2'),
+        ('\u2d13', '\u10b3'), ('\u2d14', '\u10b4'), ('\u2d15', '\u10b5'),
+        ('\u2d16', '\u10b6'), ('\u2d17', '\u10b7'), ('\u2d18', '\u10b8'),
+        ('\u2d19', '\u10b9'), ('\u2d1a', '\u10ba'), ('\u2d1b', '\u10bb'),
+        ('\u2d1c', '\u10bc'), ('\u2d1d', '\u10bd'), ('\u2d1e', '\u10be'),
+        ('\u2d1f', '\u10bf'), ('\u2d20', '\u10c0'), ('\u2d21', '\u10c1'),
+        ('\u2d22', '\u10c2'), ('\u2d23', '\u10c3'), ('\u2d24', '\u10c4'),
+        ('\u2d25', '\u10c5'), ('\u2d27', '\u10c7'), ('\u2d2d', '\u10cd'),
+        ('\ua641', '\ua640'), ('\ua643', '\ua642'), ('\ua645', '\ua644'),
+        ('\ua647', '\ua646'), ('\ua649', '\ua648'), ('\ua64b', '\ua64a'),
+        ('\ua64d', '\ua64c'), ('\ua64f', '\ua64e'), ('\ua651', '\ua650'),
+        ('\ua653', '\ua652'), ('\ua655', '\ua654'), ('\ua657', '\ua656'),
+        ('\ua659', '\ua658'), ('\ua65b', '\ua65a'), ('\ua65d', '\ua65c'),
+        ('\ua65f', '\ua65e'), ('\ua661', '\ua660'), ('\ua663', '\ua662'),
+        ('\ua665', '\ua664'), ('\ua667', '\ua666'), ('\ua669', '\ua668'),
+        ('\ua66b', '\ua66a'), ('\ua66d', '\ua66c'), ('\ua681', '\ua680'),
+        ('\ua683', '\ua682'), ('\ua685', '\ua684'), ('\ua687', '\ua686'),
+        ('\ua689', '\ua688'), ('\ua68b', '\ua68a'), ('\ua68d', '\ua68c'),
+        ('\ua68f', '\ua68e'), ('\ua691', '\ua690'), ('\ua693', '\ua692'),
+        ('\ua695', '\ua694'), ('\ua697', '\ua696'), ('\ua699', '\ua698'),
+        ('\ua69b', '\ua69a'), ('\ua723', '\ua722'), ('\ua725', '\ua724'),
+        ('\ua727', '\ua726'), ('\ua729', '\ua728'), ('\ua72b', '\ua72a'),
+        ('\ua72d', '\ua72c'), ('\ua72f', '\ua72e'), ('\ua733', '\ua732'),
+        ('\ua735', '\ua734'), ('\ua737', '\ua736'), ('\ua739', '\ua738'),
+        ('\ua73b', '\ua73a'), ('\ua73d', '\ua73c'), ('\ua73f', '\ua73e'),
+        ('\ua741', '\ua740'), ('\ua743', '\ua742'), ('\ua745', '\ua744'),
+        ('\ua747', '\ua746'), ('\ua749', '\ua748'), ('\ua74b', '\ua74a'),
+        ('\ua74d', '\ua74c'), ('\ua74f', '\ua74e'), ('\ua751', '\ua750'),
+        ('\ua753', '\ua752'), ('\ua755', '\ua754'), ('\ua757', '\ua756'),
+        ('\ua759', '\ua758'), ('\ua75b', '\ua75a'), ('\ua75d', '\ua75c'),
+        ('\ua75f', '\ua75e'), ('\ua761', '\ua760'), ('\ua763', '\ua762'),
+        ('\ua765', '\ua764'), ('\ua767', '\ua766'), ('\ua769', '\ua768'),
+        ('\ua76b', '\ua76a'), ('\ua76d', '\ua76c'), ('\ua76f', '\ua76e'),
+        ('\ua77a', '\ua779'), ('\ua77c', '\ua77b'), ('\ua77f', '\ua77e'),
+        ('\ua781', '\ua780'), ('\ua783', '\ua782'), ('\ua785', '\ua784'),
+        ('\ua787', '\ua786'), ('\ua78c', '\ua78b'), ('\ua791', '\ua790'),
+        ('\ua793', '\ua792'), ('\ua794', '\ua7c4'), ('\ua797', '\ua796'),
+        ('\ua799', '\ua798'), ('\ua79b', '\ua79a'), ('\ua79d', '\ua79c'),
+        ('\ua79f', '\ua79e'), ('\ua7a1', '\ua7a0'), ('\ua7a3', '\ua7a2'),
+        ('\ua7a5', '\ua7a4'), ('\ua7a7', '\ua7a6'), ('\ua7a9', '\ua7a8'),
+        ('\ua7b5', '\ua7b4'), ('\ua7b7', '\ua7b6'), ('\ua7b9', '\ua7b8'),
+        ('\ua7bb', '\ua7ba'), ('\ua7bd', '\ua7bc'), ('\ua7bf', '\ua7be'),
+        ('\ua7c1', '\ua7c0'), ('\ua7c3', '\ua7c2'), ('\ua7c8', '\ua7c7'),
+        ('\ua7ca', '\ua7c9'), ('\ua7d1', '\ua7d0'), ('\ua7d7', '\ua7d6'),
+        ('\ua7d9', '\ua7d8'), ('\ua7f6', '\ua7f5'), ('\uab53', '\ua7b3'),
+        ('\uab70', '\u13a0'), ('\uab71', '\u13a1'), ('\uab72', '\u13a2'),
+        ('\uab73', '\u13a3'), ('\uab74', '\u13a4'), ('\uab75', '\u13a5'),
+        ('\uab76', '\u13a6'), ('\uab77', '\u13a7'), ('\uab78', '\u13a8'),
+        ('\uab79', '\u13a9'), ('\uab7a', '\u13aa'), ('\uab7b', '\u13ab'),
+        ('\uab7c', '\u13ac'), ('\uab7d', '\u13ad'), ('\uab7e', '\u13ae'),
+        ('\uab7f', '\u13af'), ('\uab80', '\u13b0'), ('\uab81', '\u13b1'),
+        ('\uab82', '\u13b2'), ('\uab83', '\u13b3'), ('\uab84', '\u13b4'),
+        ('\uab85', '\u13b5'), ('\uab86', '\u13b6'), ('\uab87', '\u13b7'),
+        ('\uab88', '\u13b8'), ('\uab89', '\u13b9'), ('\uab8a', '\u13ba'),
+        ('\uab8b', '\u13bb'), ('\uab8c', '\u13bc'), ('\uab8d', '\u13bd'),
+        ('\uab8e', '\u13be'), ('\uab8f', '\u13bf'), ('\uab90', '\u13c0'),
+        ('\uab91', '\u13c1'), ('\uab92', '\u13c2'), ('\uab93', '\u13c3'),
+        ('\uab94', '\u13c4'), ('\uab95', '\u13c5'), ('\uab96', '\u13c6'),
+        ('\uab97', '\u13c7'), ('\uab98', '\u13c8'), ('\uab99', '\u13c9'),
+        ('\uab9a', '\u13ca'), ('\uab9b', '\u13cb'), ('\uab9c', '\u13cc'),
+        ('\uab9d', '\u13cd'), ('\uab9e', '\u13ce'), ('\uab9f', '\u13cf'),
+        ('\uaba0', '\u13d0'), ('\uaba1', '\u13d1'), ('\uaba2', '\u13d2'),
+        ('\uaba3', '\u13d3'), ('\uaba4', '\u13d4'), ('\uaba5', '\u13d5'),
+        ('\uaba6', '\u13d6'), ('\uaba7', '\u13d7'), ('\uaba8', '\u13d8'),
+        ('\uaba9', '\u13d9'), ('\uabaa', '\u13da'), ('\uabab', '\u13db'),
+        ('\uabac', '\u13dc'), ('\uabad', '\u13dd'), ('\uabae', '\u13de'),
+        ('\uabaf', '\u13df'), ('\uabb0', '\u13e0'), ('\uabb1', '\u13e1'),
+        ('\uabb2', '\u13e2'), ('\uabb3', '\u13e3'), ('\uabb4', '\u13e4'),
+        ('\uabb5', '\u13e5'), ('\uabb6', '\u13e6'), ('\uabb7', '\u13e7'),
+        ('\uabb8', '\u13e8'), ('\uabb9', '\u13e9'), ('\uabba', '\u13ea'),
+        ('\uabbb', '\u13eb'), ('\uabbc', '\u13ec'), ('\uabbd', '\u13ed'),
+        ('\uabbe', '\u13ee'), ('\uabbf', '\u13ef'), ('\uff41', '\uff21'),
+        ('\uff42', '\uff22'), ('\uff43', '\uff23'), ('\uff44', '\uff24'),
+        ('\uff45', '\uff25'), ('\uff46', '\uff26'), ('\uff47', '\uff27'),
+        ('\uff48', '\uff28'), ('\uff49', '\uff29'), ('\uff4a', '\uff2a'),
+        ('\uff4b', '\uff2b'), ('\uff4c', '\uff2c'), ('\uff4d', '\uff2d'),
+        ('\uff4e', '\uff2e'), ('\uff4f', '\uff2f'), ('\uff50', '\uff30'),
+        ('\uff51', '\uff31'), ('\uff52', '\uff32'), ('\uff53', '\uff33'),
+        ('\uff54', '\uff34'), ('\uff55', '\uff35'), ('\uff56', '\uff36'),
+        ('\uff57', '\uff37'), ('\uff58', '\uff38'), ('\uff59', '\uff39'),
+        ('\uff5a', '\uff3a'), ('\U00010428', '\U00010400'), ('\U00010429', '\U00010401'),
+        ('\U0001042a', '\U00010402'), ('\U0001042b', '\U00010403'), ('\U0001042c', '\U00010404'),
+        ('\U0001042d', '\U00010405'), ('\U0001042e', '\U00010406'), ('\U0001042f', '\U00010407'),
+        ('\U00010430', '\U00010408'), ('\U00010431', '\U00010409'), ('\U00010432', '\U0001040a'),
+        ('\U00010433', '\U0001040b'), ('\U00010434', '\U0001040c'), ('\U00010435', '\U0001040d'),
+        ('\U00010436', '\U0001040e'), ('\U00010437', '\U0001040f'), ('\U00010438', '\U00010410'),
+        ('\U00010439', '\U00010411'), ('\U0001043a', '\U00010412'), ('\U0001043b', '\U00010413'),
+        ('\U0001043c', '\U00010414'), ('\U0001043d', '\U00010415'), ('\U0001043e', '\U00010416'),
+        ('\U0001043f', '\U00010417'), ('\U00010440', '\U00010418'), ('\U00010441', '\U00010419'),
+        ('\U00010442', '\U0001041a'), ('\U00010443', '\U0001041b'), ('\U00010444', '\U0001041c'),
+        ('\U00010445', '\U0001041d'), ('\U00010446', '\U0001041e'), ('\U00010447', '\U0001041f'),
+        ('\U00010448', '\U00010420'), ('\U00010449', '\U00010421'), ('\U0001044a', '\U00010422'),
+        ('\U0001044b', '\U00010423'), ('\U0001044c', '\U00010424'), ('\U0001044d', '\U00010425'),
+        ('\U0001044e', '\U00010426'), ('\U0001044f', '\U00010427'), ('\U000104d8', '\U000104b0'),
+        ('\U000104d9', '\U000104b1'), ('\U000104da', '\U000104b2'), ('\U000104db', '\U000104b3'),
+        ('\U000104dc', '\U000104b4'), ('\U000104dd', '\U000104b5'), ('\U000104de', '\U000104b6'),
+        ('\U000104df', '\U000104b7'), ('\U000104e0', '\U000104b8'), ('\U000104e1', '\U000104b9'),
+        ('\U000104e2', '\U000104ba'), ('\U000104e3', '\U000104bb'), ('\U000104e4', '\U000104bc'),
+        ('\U000104e5', '\U000104bd'), ('\U000104e6', '\U000104be'), ('\U000104e7', '\U000104bf'),
+        ('\U000104e8', '\U000104c0'), ('\U000104e9', '\U000104c1'), ('\U000104ea', '\U000104c2'),
+        ('\U000104eb', '\U000104c3'), ('\U000104ec', '\U000104c4'), ('\U000104ed', '\U000104c5'),
+        ('\U000104ee', '\U000104c6'), ('\U000104ef', '\U000104c7'), ('\U000104f0', '\U000104c8'),
+        ('\U000104f1', '\U000104c9'), ('\U000104f2', '\U000104ca'), ('\U000104f3', '\U000104cb'),
+        ('\U000104f4', '\U000104cc'), ('\U000104f5', '\U000104cd'), ('\U000104f6', '\U000104ce'),
+        ('\U000104f7', '\U000104cf'), ('\U000104f8', '\U000104d0'), ('\U000104f9', '\U000104d1'),
+        ('\U000104fa', '\U000104d2'), ('\U000104fb', '\U000104d3'), ('\U00010597', '\U00010570'),
+        ('\U00010598', '\U00010571'), ('\U00010599', '\U00010572'), ('\U0001059a', '\U00010573'),
+        ('\U0001059b', '\U00010574'), ('\U0001059c', '\U00010575'), ('\U0001059d', '\U00010576'),
+        ('\U0001059e', '\U00010577'), ('\U0001059f', '\U00010578'), ('\U000105a0', '\U00010579'),
+        ('\U000105a1', '\U0001057a'), ('\U000105a3', '\U0001057c'), ('\U000105a4', '\U0001057d'),
+        ('\U000105a5', '\U0001057e'), ('\U000105a6', '\U0001057f'), ('\U000105a7', '\U00010580'),
+        ('\U000105a8', '\U00010581'), ('\U000105a9', '\U00010582'), ('\U000105aa', '\U00010583'),
+        ('\U000105ab', '\U00010584'), ('\U000105ac', '\U00010585'), ('\U000105ad', '\U00010586'),
+        ('\U000105ae', '\U00010587'), ('\U000105af', '\U00010588'), ('\U000105b0', '\U00010589'),
+        ('\U000105b1', '\U0001058a'), ('\U000105b3', '\U0001058c'), ('\U000105b4', '\U0001058d'),
+        ('\U000105b5', '\U0001058e'), ('\U000105b6', '\U0001058f'), ('\U000105b7', '\U00010590'),
+        ('\U000105b8', '\U00010591'), ('\U000105b9', '\U00010592'), ('\U000105bb', '\U00010594'),
+        ('\U000105bc', '\U00010595'), ('\U00010cc0', '\U00010c80'), ('\U00010cc1', '\U00010c81'),
+        ('\U00010cc2', '\U00010c82'), ('\U00010cc3', '\U00010c83'), ('\U00010cc4', '\U00010c84'),
+        ('\U00010cc5', '\U00010c85'), ('\U00010cc6', '\U00010c86'), ('\U00010cc7', '\U00010c87'),
+        ('\U00010cc8', '\U00010c88'), ('\U00010cc9', '\U00010c89'), ('\U00010cca', '\U00010c8a'),
+        ('\U00010ccb', '\U00010c8b'), ('\U00010ccc', '\U00010c8c'), ('\U00010ccd', '\U00010c8d'),
+        ('\U00010cce', '\U00010c8e'), ('\U00010ccf', '\U00010c8f'), ('\U00010cd0', '\U00010c90'),
+        ('\U00010cd1', '\U00010c91'), ('\U00010cd2', '\U00010c92'), ('\U00010cd3', '\U00010c93'),
+        ('\U00010cd4', '\U00010c94'), ('\U00010cd5', '\U00010c95'), ('\U00010cd6', '\U00010c96'),
+        ('\U00010cd7', '\U00010c97'), ('\U00010cd8', '\U00010c98'), ('\U00010cd9', '\U00010c99'),
+        ('\U00010cda', '\U00010c9a'), ('\U00010cdb', '\U00010c9b'), ('\U00010cdc', '\U00010c9c'),
+        ('\U00010cdd', '\U00010c9d'), ('\U00010cde', '\U00010c9e'), ('\U00010cdf', '\U00010c9f'),
+        ('\U00010ce0', '\U00010ca0'), ('\U00010ce1', '\U00010ca1'), ('\U00010ce2', '\U00010ca2'),
+        ('\U00010ce3', '\U00010ca3'), ('\U00010ce4', '\U00010ca4'), ('\U00010ce5', '\U00010ca5'),
+        ('\U00010ce6', '\U00010ca6'), ('\U00010ce7', '\U00010ca7'), ('\U00010ce8', '\U00010ca8'),
+        ('\U00010ce9', '\U00010ca9'), ('\U00010cea', '\U00010caa'), ('\U00010ceb', '\U00010cab'),
+        ('\U00010cec', '\U00010cac'), ('\U00010ced', '\U00010cad'), ('\U00010cee', '\U00010cae'),
+        ('\U00010cef', '\U00010caf'), ('\U00010cf0', '\U00010cb0'), ('\U00010cf1', '\U00010cb1'),
+        ('\U00010cf2', '\U00010cb2'), ('\U000118c0', '\U000118a0'), ('\U000118c1', '\U000118a1'),
+        ('\U000118c2', '\U000118a2'), ('\U000118c3', '\U000118a3'), ('\U000118c4', '\U000118a4'),
+        ('\U000118c5', '\U000118a5'), ('\U000118c6', '\U000118a6'), ('\U000118c7', '\U000118a7'),
+        ('\U000118c8', '\U000118a8'), ('\U000118c9', '\U000118a9'), ('\U000118ca', '\U000118aa'),
+        ('\U000118cb', '\U000118ab'), ('\U000118cc', '\U000118ac'), ('\U000118cd', '\U000118ad'),
+        ('\U000118ce', '\U000118ae'), ('\U000118cf', '\U000118af'), ('\U000118d0', '\U000118b0'),
+        ('\U000118d1', '\U000118b1'), ('\U000118d2', '\U000118b2'), ('\U000118d3', '\U000118b3'),
+        ('\U000118d4', '\U000118b4'), ('\U000118d5', '\U000118b5'), ('\U000118d6', '\U000118b6'),
+        ('\U000118d7', '\U000118b7'), ('\U000118d8', '\U000118b8'), ('\U000118d9', '\U000118b9'),
+        ('\U000118da', '\U000118ba'), ('\U000118db', '\U000118bb'), ('\U000118dc', '\U000118bc'),
+        ('\U000118dd', '\U000118bd'), ('\U000118de', '\U000118be'), ('\U000118df', '\U000118bf'),
+        ('\U00016e60', '\U00016e40'), ('\U00016e61', '\U00016e41'), ('\U00016e62', '\U00016e42'),
+        ('\U00016e63', '\U00016e43'), ('\U00016e64', '\U00016e44'), ('\U00016e65', '\U00016e45'),
+        ('\U00016e66', '\U00016e46'), ('\U00016e67', '\U00016e47'), ('\U00016e68', '\U00016e48'),
+        ('\U00016e69', '\U00016e49'), ('\U00016e6a', '\U00016e4a'), ('\U00016e6b', '\U00016e4b'),
+        ('\U00016e6c', '\U00016e4c'), ('\U00016e6d', '\U00016e4d'), ('\U00016e6e', '\U00016e4e'),
+        ('\U00016e6f', '\U00016e4f'), ('\U00016e70', '\U00016e50'), ('\U00016e71', '\U00016e51'),
+        ('\U00016e72', '\U00016e52'), ('\U00016e73', '\U00016e53'), ('\U00016e74', '\U00016e54'),
+        ('\U00016e75', '\U00016e55'), ('\U00016e76', '\U00016e56'), ('\U00016e77', '\U00016e57'),
+        ('\U00016e78', '\U00016e58'), ('\U00016e79', '\U00016e59'), ('\U00016e7a', '\U00016e5a'),
+        ('\U00016e7b', '\U00016e5b'), ('\U00016e7c', '\U00016e5c'), ('\U00016e7d', '\U00016e5d'),
+        ('\U00016e7e', '\U00016e5e'), ('\U00016e7f', '\U00016e5f'), ('\U0001e922', '\U0001e900'),
+        ('\U0001e923', '\U0001e901'), ('\U0001e924', '\U0001e902'), ('\U0001e925', '\U0001e903'),
+        ('\U0001e926', '\U0001e904'), ('\U0001e927', '\U0001e905'), ('\U0001e928', '\U0001e906'),
+        ('\U0001e929', '\U0001e907'), ('\U0001e92a', '\U0001e908'), ('\U0001e92b', '\U0001e909'),
+        ('\U0001e92c', '\U0001e90a'), ('\U0001e92d', '\U0001e90b'), ('\U0001e92e', '\U0001e90c'),
+        ('\U0001e92f', '\U0001e90d'), ('\U0001e930', '\U0001e90e'), ('\U0001e931', '\U0001e90f'),
+        ('\U0001e932', '\U0001e910'), ('\U0001e933', '\U0001e911'), ('\U0001e934', '\U0001e912'),
+        ('\U0001e935', '\U0001e913'), ('\U0001e936', '\U0001e914'), ('\U0001e937', '\U0001e915'),
+        ('\U0001e938', '\U0001e916'), ('\U0001e939', '\U0001e917'), ('\U0001e93a', '\U0001e918'),
+        ('\U0001e93b', '\U0001e919'), ('\U0001e93c', '\U0001e91a'), ('\U0001e93d', '\U0001e91b'),
+        ('\U0001e93e', '\U0001e91c'), ('\U0001e93f', '\U0001e91d'), ('\U0001e940', '\U0001e91e'),
+        ('\U0001e941', '\U0001e91f'), ('\U0001e942', '\U0001e920'), ('\U0001e943', '\U0001e921')
+    ]
+}
+
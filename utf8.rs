@@ -0,0 +1,92 @@
+// The UTF-8 decoding used by the engines. Callers feeding them input in
+// pieces can use it to split the input between chars exactly as they do.
+
+// The length of the char whose first byte is b.
+fn char_width(b: u8) -> uint {
+    if b < 0x80 {
+        1
+    } else if b < 0xe0 {
+        2
+    } else if b < 0xf0 {
+        3
+    } else {
+        4
+    }
+}
+
+// Whether a char starts at byte i of s, or i is its end.
+fn is_char_boundary(s: &[u8], i: uint) -> bool {
+    i == s.len() || s[i] & 0xc0 != 0x80
+}
+
+// Decode the char starting at byte i of s, which must be valid UTF-8,
+// returning it and the index of the byte following it.
+fn decode(s: &[u8], i: uint) -> (char, uint) {
+    let b = s[i] as uint;
+    let n = char_width(s[i]);
+    if n == 1 {
+        return (b as char, i + 1);
+    }
+    let mut c = b & (0x7f >> n);
+    for uint::range(1, n) |j| {
+        c = (c << 6) | (s[i + j] as uint & 0x3f);
+    }
+    return (c as char, i + n);
+}
+
+// Decode the char ending before byte i of s, which must be valid UTF-8,
+// returning it and the index of its first byte.
+fn decode_last(s: &[u8], i: uint) -> (char, uint) {
+    let mut start = i - 1;
+    while start > 0 && s[start] & 0xc0 == 0x80 {
+        start -= 1;
+    }
+    let (c, _) = decode(s, start);
+    return (c, start);
+}
+
+// The number of bytes at the end of s that start a char without
+// finishing it, as when s is a piece of a longer text. They should be
+// decoded with the bytes following them.
+fn partial_suffix_len(s: &[u8]) -> uint {
+    let mut start = s.len();
+    while start > 0 && start + 4 > s.len() {
+        start -= 1;
+        if is_char_boundary(s, start) {
+            let n = s.len() - start;
+            return if char_width(s[start]) > n { n } else { 0 };
+        }
+    }
+    return 0;
+}
+
+#[test]
+fn test_decode() {
+    let s = str::to_bytes("a\u00e9\u20ac\U0001f600");
+    assert decode(s, 0) == ('a', 1);
+    assert decode(s, 1) == ('\u00e9', 3);
+    assert decode(s, 3) == ('\u20ac', 6);
+    assert decode(s, 6) == ('\U0001f600', 10);
+    assert decode_last(s, 10) == ('\U0001f600', 6);
+    assert decode_last(s, 6) == ('\u20ac', 3);
+    assert decode_last(s, 3) == ('\u00e9', 1);
+    assert decode_last(s, 1) == ('a', 0);
+}
+
+#[test]
+fn test_boundaries() {
+    let s = str::to_bytes("a\u00e9\u20ac");
+    assert is_char_boundary(s, 0);
+    assert is_char_boundary(s, 1);
+    assert !is_char_boundary(s, 2);
+    assert is_char_boundary(s, 3);
+    assert !is_char_boundary(s, 5);
+    assert is_char_boundary(s, 6);
+    assert char_width(s[3]) == 3;
+    assert partial_suffix_len(s) == 0;
+    assert partial_suffix_len(vec::slice(s, 0, 5)) == 2;
+    assert partial_suffix_len(vec::slice(s, 0, 4)) == 1;
+    assert partial_suffix_len(vec::slice(s, 0, 2)) == 1;
+    assert partial_suffix_len(vec::slice(s, 0, 1)) == 0;
+    assert partial_suffix_len([]) == 0;
+}
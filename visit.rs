@@ -0,0 +1,102 @@
+use parse::{Regexp, Concat, Alternate, Star, Plus, Quest, Capture, LookAhead,
+            NegLookAhead};
+#[cfg(test)]
+use parse::{Literal, Class, parse};
+
+// Traversal of regexps, so that analyses and rewrites only need to match
+// on the variants they care about.
+
+trait Visitor {
+    // Called before the children of re are visited, which they are only
+    // if this returns true.
+    fn visit_pre(&self, re: @Regexp) -> bool;
+    // Called after the children of re are visited.
+    fn visit_post(&self, re: @Regexp);
+}
+
+// The direct subexpressions of re, in pattern order.
+fn children(re: &Regexp) -> ~[@Regexp] {
+    match *re {
+        Concat(subs) | Alternate(subs) => subs,
+        Star(sub, _) | Plus(sub, _) | Quest(sub, _) | Capture(_, _, sub) |
+        LookAhead(sub) | NegLookAhead(sub) => ~[sub],
+        _ => ~[]
+    }
+}
+
+// Visit re and its subexpressions depth first.
+fn walk<V: Visitor>(re: @Regexp, v: &V) {
+    if v.visit_pre(re) {
+        for children(re).each |sub| {
+            walk(*sub, v);
+        }
+    }
+    v.visit_post(re);
+}
+
+// re with each direct subexpression replaced by f of it. Recursive
+// rewrites call this for the variants they leave alone.
+fn map_children(re: @Regexp, f: fn(@Regexp) -> @Regexp) -> @Regexp {
+    match *re {
+        Concat(subs) => @Concat(subs.map(|sub| f(*sub))),
+        Alternate(subs) => @Alternate(subs.map(|sub| f(*sub))),
+        Star(sub, greedy) => @Star(f(sub), greedy),
+        Plus(sub, greedy) => @Plus(f(sub), greedy),
+        Quest(sub, greedy) => @Quest(f(sub), greedy),
+        Capture(cap, name, sub) => @Capture(cap, name, f(sub)),
+        LookAhead(sub) => @LookAhead(f(sub)),
+        NegLookAhead(sub) => @NegLookAhead(f(sub)),
+        _ => re
+    }
+}
+
+#[cfg(test)]
+struct Counter {
+    mut literals: uint,
+    mut depth: uint,
+    mut max_depth: uint
+}
+
+#[cfg(test)]
+impl Counter: Visitor {
+    fn visit_pre(&self, re: @Regexp) -> bool {
+        match *re {
+            Literal(_) => self.literals += 1,
+            _ => {}
+        }
+        self.depth += 1;
+        if self.depth > self.max_depth {
+            self.max_depth = self.depth;
+        }
+        match *re {
+            Capture(cap, _, _) => cap != 2,
+            _ => true
+        }
+    }
+    fn visit_post(&self, _re: @Regexp) {
+        self.depth -= 1;
+    }
+}
+
+#[test]
+fn test_walk() {
+    let counter = Counter {literals: 0, depth: 0, max_depth: 0};
+    walk(parse("ab(c|d*)(ef)").get(), &counter);
+    assert counter.literals == 4;
+    assert counter.max_depth == 5;
+    assert counter.depth == 0;
+}
+
+#[test]
+fn test_map_children() {
+    fn upper(re: @Regexp) -> @Regexp {
+        match *re {
+            Literal(c) if c >= 'a' && c <= 'z' => {
+                @Literal((c as uint - 32) as char)
+            }
+            Class(_) => re,
+            _ => map_children(re, upper)
+        }
+    }
+    assert upper(parse("a(?P<x>b|c*)[d]").get()).to_str() == ~"A(?P<x>B|C*)[d]";
+}